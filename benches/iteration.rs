@@ -0,0 +1,70 @@
+//! Micro-benchmarks for neighbor iteration.
+//!
+//! Compares the allocation-free iterators returned by [`Graph`] against the boxed
+//! iterators of the object-safe [`DynGraph`] facade on a two-hop walk, the access
+//! pattern that dominates triangle counting.
+//!
+//! Run with `cargo bench --bench iteration`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use kambo_graph::traits::dyn_graph::DynGraph;
+use kambo_graph::{Graph, GraphMut, SimpleGraph};
+
+const VERTICES: usize = 2_000;
+const DEGREE: usize = 16;
+const ROUNDS: u32 = 10;
+
+fn build_graph() -> SimpleGraph<usize> {
+    let mut graph = SimpleGraph::new_undirected();
+    for v in 0..VERTICES {
+        graph.add_vertex(v).unwrap();
+    }
+    for u in 0..VERTICES {
+        for step in 1..=DEGREE / 2 {
+            let v = (u + step * step) % VERTICES;
+            if u != v && !graph.neighbors(&u).unwrap().any(|&w| w == v) {
+                graph.add_edge(&u, &v).unwrap();
+            }
+        }
+    }
+    graph
+}
+
+fn two_hop_static<G: Graph>(graph: &G) -> usize {
+    let mut walks = 0;
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            walks += graph.neighbors(v).map_or(0, Iterator::count);
+        }
+    }
+    walks
+}
+
+fn two_hop_dyn<V>(graph: &dyn DynGraph<Vertex = V>) -> usize {
+    let mut walks = 0;
+    for u in graph.dyn_vertices() {
+        for v in graph.dyn_neighbors(u).into_iter().flatten() {
+            walks += graph.dyn_neighbors(v).map_or(0, Iterator::count);
+        }
+    }
+    walks
+}
+
+fn measure(name: &str, mut f: impl FnMut() -> usize) {
+    let mut best = Duration::MAX;
+    let mut result = 0;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        result = black_box(f());
+        best = best.min(start.elapsed());
+    }
+    println!("{name:<24} {best:>12?} (walks: {result})");
+}
+
+fn main() {
+    let graph = build_graph();
+    measure("two_hop/static", || two_hop_static(&graph));
+    measure("two_hop/dyn", || two_hop_dyn(&graph));
+}
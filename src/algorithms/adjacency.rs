@@ -0,0 +1,127 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::Graph;
+
+/// Builds symmetric neighbor sets of `graph`, ignoring edge directions and
+/// self-loops.
+pub(crate) fn undirected_adjacency<G>(graph: &G) -> HashMap<&G::Vertex, HashSet<&G::Vertex>>
+where
+    G: Graph,
+{
+    let mut adjacency: HashMap<&G::Vertex, HashSet<&G::Vertex>> =
+        graph.vertices().map(|v| (v, HashSet::new())).collect();
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if u != v {
+                adjacency.entry(u).or_default().insert(v);
+                adjacency.entry(v).or_default().insert(u);
+            }
+        }
+    }
+    adjacency
+}
+
+/// Vertices numbered in iteration order with their out-neighbors by index.
+pub(crate) struct IndexedGraph<'a, V> {
+    pub(crate) vertices: Vec<&'a V>,
+    #[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+    pub(crate) index: HashMap<&'a V, usize>,
+    pub(crate) out: Vec<Vec<usize>>,
+}
+
+impl<'a, V> IndexedGraph<'a, V>
+where
+    V: Eq + Hash,
+{
+    /// Numbers the vertices of `graph` and collects their out-neighbors.
+    pub(crate) fn new<G>(graph: &'a G) -> Self
+    where
+        G: Graph<Vertex = V>,
+    {
+        let (vertices, index) = number_vertices(graph);
+        let out = vertices
+            .iter()
+            .map(|&v| out_indices(graph, v, &index))
+            .collect();
+        Self {
+            vertices,
+            index,
+            out,
+        }
+    }
+
+    /// Numbers the vertices of `graph` and collects their out-neighbors in
+    /// parallel.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn par_new<G>(graph: &'a G) -> Self
+    where
+        G: Graph<Vertex = V> + Sync,
+        V: Send + Sync,
+    {
+        let (vertices, index) = number_vertices(graph);
+        let out = vertices
+            .par_iter()
+            .map(|&v| out_indices(graph, v, &index))
+            .collect();
+        Self {
+            vertices,
+            index,
+            out,
+        }
+    }
+
+    /// Returns sorted, duplicate-free neighbor lists ignoring edge directions
+    /// and self-loops.
+    pub(crate) fn symmetric(&self, directed: bool) -> Vec<Vec<usize>> {
+        let mut neighbors = self.out.clone();
+        if directed {
+            for (u, targets) in self.out.iter().enumerate() {
+                for &v in targets {
+                    neighbors[v].push(u);
+                }
+            }
+        }
+        for (u, list) in neighbors.iter_mut().enumerate() {
+            list.retain(|&v| v != u);
+            list.sort_unstable();
+            list.dedup();
+        }
+        neighbors
+    }
+
+    /// Returns whether each vertex has a self-loop.
+    pub(crate) fn looped(&self) -> Vec<bool> {
+        self.out
+            .iter()
+            .enumerate()
+            .map(|(v, targets)| targets.contains(&v))
+            .collect()
+    }
+}
+
+fn number_vertices<G>(graph: &G) -> (Vec<&G::Vertex>, HashMap<&G::Vertex, usize>)
+where
+    G: Graph,
+{
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index = vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    (vertices, index)
+}
+
+fn out_indices<G>(graph: &G, v: &G::Vertex, index: &HashMap<&G::Vertex, usize>) -> Vec<usize>
+where
+    G: Graph,
+{
+    graph
+        .neighbors(v)
+        .into_iter()
+        .flatten()
+        .map(|w| index[w])
+        .collect()
+}
@@ -0,0 +1,311 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    algorithms::adjacency::undirected_adjacency,
+    error::AlgorithmError,
+    traits::graph::{EdgeList, GraphMut},
+    Graph, GraphError,
+};
+#[cfg(feature = "rand")]
+use crate::{
+    graphs::simple::SimpleGraph,
+    traits::weighted::{WeightedGraph, WeightedGraphMut},
+};
+
+/// Relabels the vertices of a graph with opaque identifiers, so the graph can be
+/// shared without revealing who its vertices are.
+///
+/// The vertices are numbered `0` to `n - 1` in a random order drawn from `rng`,
+/// so the identifiers carry no information about the original labels or the
+/// order in which the vertices were added. Edges, directions and weights are
+/// kept.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to relabel.
+/// * `rng` - The source of randomness for the numbering.
+///
+/// # Returns
+///
+/// The relabeled graph and the key mapping every original vertex to its
+/// identifier. The key is what re-identifies the vertices and should not be
+/// shared with the graph.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::anonymization::relabel_opaque, graph, Graph};
+///
+/// let graph = graph! { "ana" - "bruno", "bruno" - "carla" };
+/// let (anonymous, key) = relabel_opaque(&graph, &mut rand::rng());
+///
+/// assert_eq!(anonymous.edge_count(), 2);
+/// assert!(anonymous.contains_edge(&key["ana"], &key["bruno"]));
+/// assert!(!anonymous.contains_edge(&key["ana"], &key["carla"]));
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn relabel_opaque<G, R>(
+    graph: &G,
+    rng: &mut R,
+) -> (SimpleGraph<usize, G::Weight>, HashMap<G::Vertex, usize>)
+where
+    G: WeightedGraph,
+    R: rand::Rng + ?Sized,
+{
+    use rand::seq::SliceRandom;
+
+    let mut ids: Vec<usize> = (0..graph.order()).collect();
+    ids.shuffle(rng);
+    let key: HashMap<G::Vertex, usize> = graph.vertices().cloned().zip(ids).collect();
+
+    let mut anonymous = if graph.is_directed() {
+        SimpleGraph::new_weighted_directed()
+    } else {
+        SimpleGraph::new_weighted_undirected()
+    };
+    for &id in key.values() {
+        let _ = anonymous.add_vertex(id);
+    }
+    for (u, &i) in &key {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            let j = key[v];
+            if !graph.is_directed() && j < i {
+                continue;
+            }
+            match graph.edge_weight(u, v) {
+                Some(weight) => {
+                    let _ = anonymous.add_weighted_edge(&i, &j, weight.clone());
+                }
+                None => {
+                    let _ = anonymous.add_edge(&i, &j);
+                }
+            }
+        }
+    }
+    (anonymous, key)
+}
+
+/// Checks whether a graph is k-degree anonymous: every vertex shares its degree
+/// with at least `k - 1` other vertices, so an adversary who knows the degree of
+/// a vertex cannot narrow it down to fewer than `k` candidates.
+///
+/// Edge directions and self-loops are ignored when counting degrees. Every
+/// graph is 0- and 1-degree anonymous.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::anonymization::is_k_degree_anonymous, graph};
+///
+/// let cycle = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 1 };
+/// assert!(is_k_degree_anonymous(&cycle, 4));
+///
+/// let path = graph! { 1 - 2, 2 - 3, 3 - 4 };
+/// assert!(is_k_degree_anonymous(&path, 2));
+/// assert!(!is_k_degree_anonymous(&path, 3));
+/// ```
+#[must_use]
+pub fn is_k_degree_anonymous<G>(graph: &G, k: usize) -> bool
+where
+    G: Graph,
+{
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for neighbors in undirected_adjacency(graph).values() {
+        *counts.entry(neighbors.len()).or_insert(0) += 1;
+    }
+    counts.values().all(|&count| count >= k)
+}
+
+/// Makes an undirected graph k-degree anonymous by adding edges, following the
+/// degree anonymization approach of Liu and Terzi.
+///
+/// The degree sequence is first raised to the closest k-anonymous sequence,
+/// the one with the smallest total increase, by dynamic programming over the
+/// sorted degrees in `O(nk)` time. The missing degrees are then realized
+/// greedily, joining the vertices that lack the most degree to each other.
+/// When some missing degree cannot be realized without repeating an edge, the
+/// procedure is repeated on the graph with the edges added so far. Only edges
+/// are added, so every original edge is kept and no vertex is added or removed.
+///
+/// The number of added edges is small in practice but not guaranteed to be
+/// minimal. Self-loops are ignored when counting degrees.
+///
+/// # Arguments
+///
+/// * `graph` - The undirected graph to anonymize in place.
+/// * `k` - The number of vertices each degree must be shared by.
+///
+/// # Returns
+///
+/// The added edges.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is directed, if `k` is
+/// zero, or if `k` exceeds the number of vertices of a non-empty graph. Returns
+/// the error of [`GraphMut::add_edge`] if the graph rejects one of the edges,
+/// in which case the edges before it have been added.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::anonymization::{is_k_degree_anonymous, k_degree_anonymize},
+///     graph, Graph,
+/// };
+///
+/// // A star: the center is the only vertex of degree 4.
+/// let mut graph = graph! { 0 - 1, 0 - 2, 0 - 3, 0 - 4 };
+/// let added = k_degree_anonymize(&mut graph, 2).unwrap();
+///
+/// assert!(is_k_degree_anonymous(&graph, 2));
+/// assert_eq!(graph.edge_count(), 4 + added.len());
+/// ```
+pub fn k_degree_anonymize<G>(graph: &mut G, k: usize) -> Result<EdgeList<G::Vertex>, GraphError>
+where
+    G: GraphMut,
+{
+    if graph.is_directed() {
+        return Err(AlgorithmError::RequiresUndirected.into());
+    }
+    let n = graph.order();
+    if k == 0 || (n > 0 && k > n) {
+        return Err(AlgorithmError::InvalidArgument(format!(
+            "k must be between 1 and the number of vertices, got {k}"
+        ))
+        .into());
+    }
+
+    let (vertices, mut neighbors) = {
+        let adjacency = undirected_adjacency(graph);
+        let vertices: Vec<&G::Vertex> = adjacency.keys().copied().collect();
+        let index: HashMap<&G::Vertex, usize> =
+            vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        let neighbors: Vec<HashSet<usize>> = vertices
+            .iter()
+            .map(|v| adjacency[v].iter().map(|w| index[w]).collect())
+            .collect();
+        let vertices: Vec<G::Vertex> = vertices.into_iter().cloned().collect();
+        (vertices, neighbors)
+    };
+
+    let mut added = Vec::new();
+    loop {
+        let degrees: Vec<usize> = neighbors.iter().map(HashSet::len).collect();
+        let mut residual: Vec<usize> = anonymous_degrees(&degrees, k)
+            .into_iter()
+            .zip(&degrees)
+            .map(|(target, degree)| target - degree)
+            .collect();
+        let before = added.len();
+        realize(&mut neighbors, &mut residual, &mut added);
+        let Some(v) = (0..n).find(|&v| residual[v] > 0) else {
+            break;
+        };
+        if added.len() == before {
+            // Nothing could be realized, so perturb the degrees by joining `v`,
+            // which lacks degree, to the vertex of lowest degree it is not
+            // adjacent to. `v` has a degree below the maximum, so one exists.
+            let u = (0..n)
+                .filter(|&u| u != v && !neighbors[v].contains(&u))
+                .min_by_key(|&u| neighbors[u].len());
+            if let Some(u) = u {
+                neighbors[v].insert(u);
+                neighbors[u].insert(v);
+                added.push((v, u));
+            }
+        }
+    }
+
+    let added: EdgeList<G::Vertex> = added
+        .into_iter()
+        .map(|(u, v)| (vertices[u].clone(), vertices[v].clone()))
+        .collect();
+    for (u, v) in &added {
+        graph.add_edge(u, v)?;
+    }
+    Ok(added)
+}
+
+/// Raises `degrees` to the k-anonymous sequence with the smallest total
+/// increase. Sorted in decreasing order, the optimal sequence splits the
+/// degrees into consecutive groups of `k` to `2k - 1` vertices, each raised to
+/// its largest degree.
+fn anonymous_degrees(degrees: &[usize], k: usize) -> Vec<usize> {
+    let n = degrees.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_unstable_by(|&a, &b| degrees[b].cmp(&degrees[a]));
+    let sorted: Vec<usize> = order.iter().map(|&v| degrees[v]).collect();
+    let mut prefix = vec![0; n + 1];
+    for (i, &d) in sorted.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + d;
+    }
+    // The cost of raising `sorted[start..end]` to `sorted[start]`.
+    let cost =
+        |start: usize, end: usize| sorted[start] * (end - start) - (prefix[end] - prefix[start]);
+
+    // `best[end]` is the smallest cost of anonymizing `sorted[..end]` and
+    // `split[end]` the start of its last group.
+    let mut best = vec![usize::MAX; n + 1];
+    let mut split = vec![0; n + 1];
+    best[0] = 0;
+    for end in k..=n {
+        let first = end.saturating_sub(2 * k - 1);
+        for start in first..=end - k {
+            if best[start] == usize::MAX {
+                continue;
+            }
+            let total = best[start] + cost(start, end);
+            if total < best[end] {
+                best[end] = total;
+                split[end] = start;
+            }
+        }
+    }
+
+    let mut targets = vec![0; n];
+    let mut end = n;
+    while end > 0 {
+        let start = split[end];
+        for &v in &order[start..end] {
+            targets[v] = sorted[start];
+        }
+        end = start;
+    }
+    targets
+}
+
+/// Adds edges between vertices lacking degree, always serving the vertex that
+/// lacks the most and joining it to the non-adjacent vertices that lack the
+/// most. Stops when no vertex lacks degree or the neediest one cannot be
+/// served completely.
+fn realize(
+    neighbors: &mut [HashSet<usize>],
+    residual: &mut [usize],
+    added: &mut Vec<(usize, usize)>,
+) {
+    let n = neighbors.len();
+    loop {
+        let Some(v) = (0..n)
+            .filter(|&v| residual[v] > 0)
+            .max_by_key(|&v| residual[v])
+        else {
+            return;
+        };
+        let mut candidates: Vec<usize> = (0..n)
+            .filter(|&u| u != v && residual[u] > 0 && !neighbors[v].contains(&u))
+            .collect();
+        candidates.sort_unstable_by(|&a, &b| residual[b].cmp(&residual[a]));
+        if candidates.len() < residual[v] {
+            return;
+        }
+        for &u in &candidates[..residual[v]] {
+            neighbors[v].insert(u);
+            neighbors[u].insert(v);
+            residual[u] -= 1;
+            added.push((v, u));
+        }
+        residual[v] = 0;
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Limits how long a budgeted algorithm may run.
+///
+/// A budget is exhausted once its deadline passes or its cancellation flag is
+/// set, whichever comes first. The default budget is unlimited.
+#[derive(Debug, Clone, Default)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    cancelled: Option<Arc<AtomicBool>>,
+}
+
+impl Budget {
+    /// Creates a budget that is never exhausted.
+    #[must_use]
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Sets the deadline to `timeout` from now.
+    #[must_use]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    /// Sets the instant after which the budget is exhausted.
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Exhausts the budget as soon as `cancelled` is set to `true`, typically from
+    /// another thread.
+    #[must_use]
+    pub fn with_cancel_flag(mut self, cancelled: Arc<AtomicBool>) -> Self {
+        self.cancelled = Some(cancelled);
+        self
+    }
+
+    /// Returns `true` once the deadline has passed or cancellation was requested.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.cancelled
+            .as_ref()
+            .is_some_and(|c| c.load(Ordering::Relaxed))
+            || self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+}
+
+/// The result of a budgeted algorithm.
+///
+/// When the budget ran out, `value` holds the best-effort result computed so far;
+/// each budgeted function documents what that partial value guarantees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partial<T> {
+    /// The computed result.
+    pub value: T,
+    /// `true` if the computation finished within its budget.
+    pub complete: bool,
+}
+
+impl<T> Partial<T> {
+    /// Returns the value if the computation finished, discarding partial results.
+    #[must_use]
+    pub fn into_complete(self) -> Option<T> {
+        self.complete.then_some(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::{
+        algorithms::shortest_path::dijkstra_within, traits::measure::OverflowPolicy, SimpleGraph,
+    };
+
+    #[test]
+    fn unlimited_budgets_never_run_out() {
+        assert!(!Budget::unlimited().is_exhausted());
+        assert!(!Budget::default().is_exhausted());
+        let later = Budget::unlimited().with_timeout(Duration::from_hours(1));
+        assert!(!later.is_exhausted());
+    }
+
+    #[test]
+    fn deadlines_exhaust_budgets() {
+        assert!(Budget::unlimited()
+            .with_timeout(Duration::ZERO)
+            .is_exhausted());
+        let past = Instant::now();
+        assert!(Budget::unlimited().with_deadline(past).is_exhausted());
+    }
+
+    #[test]
+    fn cancel_flags_exhaust_clones_from_other_threads() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let budget = Budget::unlimited().with_cancel_flag(Arc::clone(&flag));
+        let clone = budget.clone();
+        assert!(!budget.is_exhausted());
+        thread::spawn(move || flag.store(true, Ordering::Relaxed))
+            .join()
+            .unwrap();
+        assert!(budget.is_exhausted());
+        assert!(clone.is_exhausted());
+    }
+
+    #[test]
+    fn partial_results_are_only_complete_within_budget() {
+        let complete = Partial {
+            value: 3,
+            complete: true,
+        };
+        assert_eq!(complete.into_complete(), Some(3));
+        let partial = Partial {
+            value: 1,
+            complete: false,
+        };
+        assert_eq!(partial.into_complete(), None);
+    }
+
+    #[test]
+    fn exhausted_budgets_stop_searches_early() {
+        let graph = SimpleGraph::from_weighted_edges([(0, 1, 2u32), (1, 2, 3)], true);
+        let policy = OverflowPolicy::Checked;
+        let finished = dijkstra_within(&graph, &0, policy, &Budget::unlimited()).unwrap();
+        assert!(finished.complete);
+        assert_eq!(finished.value.len(), 3);
+
+        let expired = Budget::unlimited().with_timeout(Duration::ZERO);
+        let stopped = dijkstra_within(&graph, &0, policy, &expired).unwrap();
+        assert!(!stopped.complete);
+        assert!(stopped.value.is_empty());
+    }
+}
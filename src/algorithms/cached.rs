@@ -0,0 +1,381 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
+    hash::{Hash, Hasher},
+};
+
+use crate::{algorithms::topological::topological_sort, Graph, GraphError};
+
+/// A 64-bit summary of the vertices and edges of a graph, used to tell whether a
+/// cached result was computed on the current graph.
+///
+/// The fingerprint does not depend on iteration order and changes, with high
+/// probability, whenever a vertex or an edge is added or removed. Edge weights
+/// are not included. It is meant for comparisons within one process; use
+/// [`canonical_checksum`](crate::utils::canonical::canonical_checksum) for a
+/// checksum that is stable across machines and Rust releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GraphFingerprint {
+    directed: bool,
+    order: usize,
+    edge_count: usize,
+    digest: u64,
+}
+
+impl GraphFingerprint {
+    /// Computes the fingerprint of a graph in `O(n + m)` time.
+    #[must_use]
+    pub fn of<G>(graph: &G) -> Self
+    where
+        G: Graph,
+    {
+        scan(graph, false).0
+    }
+
+    /// Returns `true` if `graph` has this fingerprint.
+    #[must_use]
+    pub fn matches<G>(&self, graph: &G) -> bool
+    where
+        G: Graph,
+    {
+        *self == Self::of(graph)
+    }
+}
+
+/// How [`CachedComponents::revalidate`] or
+/// [`CachedTopologicalOrder::revalidate`] brought a result up to date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Revalidation {
+    /// The graph has not changed since the result was computed.
+    Fresh,
+    /// The graph changed and the result was updated without recomputing it from
+    /// scratch.
+    Repaired,
+    /// The graph changed and the result was computed again.
+    Recomputed,
+}
+
+/// Finalizer of the `SplitMix64` generator, spreading the bits of `x`.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn hash_of<V: Hash>(v: &V) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    v.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes the edge `(a, b)` from the hashes of its endpoints, symmetrically for
+/// undirected graphs.
+fn edge_hash(directed: bool, a: u64, b: u64) -> u64 {
+    if directed {
+        mix(mix(a).wrapping_add(b))
+    } else {
+        mix(a.wrapping_add(b)) ^ mix(a ^ b)
+    }
+}
+
+/// Computes the fingerprint of `graph` and, if `incident` is set, the digest of
+/// the edges at every vertex, counting both ends of every arc.
+fn scan<G>(graph: &G, incident: bool) -> (GraphFingerprint, HashMap<&G::Vertex, u64>)
+where
+    G: Graph,
+{
+    let directed = graph.is_directed();
+    let hashes: HashMap<&G::Vertex, u64> = graph.vertices().map(|v| (v, hash_of(v))).collect();
+    let mut digests: HashMap<&G::Vertex, u64> = HashMap::new();
+    if incident {
+        digests = hashes.keys().map(|&v| (v, 0)).collect();
+    }
+    let mut digest = hashes
+        .values()
+        .fold(0u64, |sum, &h| sum.wrapping_add(mix(h)));
+    for (&u, &hu) in &hashes {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            let edge = edge_hash(directed, hu, hashes[v]);
+            digest = digest.wrapping_add(edge);
+            if incident {
+                for end in [u, v] {
+                    if let Some(sum) = digests.get_mut(end) {
+                        *sum = sum.wrapping_add(edge);
+                    }
+                }
+            }
+        }
+    }
+    let fingerprint = GraphFingerprint {
+        directed,
+        order: hashes.len(),
+        edge_count: graph.size(),
+        digest,
+    };
+    (fingerprint, digests)
+}
+
+/// The connected components of a graph, stamped with the fingerprint of the
+/// graph they were computed on.
+///
+/// Edge directions are ignored, so directed graphs are split into weakly
+/// connected components. After the graph changes, [`CachedComponents::revalidate`]
+/// only searches the components touched by the changes again.
+///
+/// ```
+/// use kambo_graph::{algorithms::cached::{CachedComponents, Revalidation}, graph, GraphMut};
+///
+/// let mut graph = graph! { 1 - 2, 3 - 4, 5 };
+/// let mut components = CachedComponents::new(&graph);
+/// assert_eq!(components.count(), 3);
+///
+/// graph.add_edge(&2, &3).unwrap();
+/// assert!(components.is_stale(&graph));
+/// assert_eq!(components.revalidate(&graph), Revalidation::Repaired);
+/// assert_eq!(components.component_of(&1), components.component_of(&4));
+/// assert_eq!(components.count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachedComponents<V> {
+    labels: HashMap<V, usize>,
+    count: usize,
+    fingerprint: GraphFingerprint,
+    /// Digest of the edges at every vertex when the labels were last updated.
+    incident: HashMap<V, u64>,
+}
+
+impl<V> CachedComponents<V>
+where
+    V: Eq + Hash + Clone,
+{
+    /// Computes the components of `graph`.
+    #[must_use]
+    pub fn new<G>(graph: &G) -> Self
+    where
+        G: Graph<Vertex = V>,
+    {
+        let mut components = Self {
+            labels: HashMap::new(),
+            count: 0,
+            fingerprint: GraphFingerprint::of(graph),
+            incident: HashMap::new(),
+        };
+        components.revalidate(graph);
+        components
+    }
+
+    /// Returns the number of components.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the component number of `v`, in `0..count`, or `None` if `v` was
+    /// not in the graph.
+    #[must_use]
+    pub fn component_of(&self, v: &V) -> Option<usize> {
+        self.labels.get(v).copied()
+    }
+
+    /// Returns the component number of every vertex.
+    #[must_use]
+    pub fn labels(&self) -> &HashMap<V, usize> {
+        &self.labels
+    }
+
+    /// Returns the fingerprint of the graph the components were computed on.
+    #[must_use]
+    pub fn fingerprint(&self) -> GraphFingerprint {
+        self.fingerprint
+    }
+
+    /// Returns `true` if `graph` differs from the graph the components were
+    /// computed on.
+    #[must_use]
+    pub fn is_stale<G>(&self, graph: &G) -> bool
+    where
+        G: Graph<Vertex = V>,
+    {
+        !self.fingerprint.matches(graph)
+    }
+
+    /// Brings the components up to date with `graph`.
+    ///
+    /// A component whose vertices all kept the same vertices and edges around
+    /// them is still a component, so only the other ones and the new vertices are
+    /// searched again. Component numbers may change.
+    ///
+    /// # Returns
+    ///
+    /// [`Revalidation::Fresh`] if the graph has not changed, and
+    /// [`Revalidation::Repaired`] otherwise.
+    pub fn revalidate<G>(&mut self, graph: &G) -> Revalidation
+    where
+        G: Graph<Vertex = V>,
+    {
+        let (fingerprint, incident) = scan(graph, true);
+        if fingerprint == self.fingerprint && self.labels.len() == fingerprint.order {
+            return Revalidation::Fresh;
+        }
+        trace_span!("revalidate_components", vertices = fingerprint.order);
+
+        // A component is dirty if one of its vertices was removed or had an edge
+        // added or removed; every other component is unchanged.
+        let mut dirty: HashSet<usize> = HashSet::new();
+        for (v, &label) in &self.labels {
+            if incident.get(v) != self.incident.get(v) {
+                dirty.insert(label);
+            }
+        }
+        let pending: HashSet<&V> = incident
+            .keys()
+            .copied()
+            .filter(|&v| self.labels.get(v).is_none_or(|label| dirty.contains(label)))
+            .collect();
+
+        // Edges at pending vertices only lead to pending vertices, since they
+        // either are new or were inside a dirty component.
+        let mut adjacency: HashMap<&V, Vec<&V>> = HashMap::new();
+        for &u in &pending {
+            for v in graph.neighbors(u).into_iter().flatten() {
+                adjacency.entry(u).or_default().push(v);
+                adjacency.entry(v).or_default().push(u);
+            }
+        }
+
+        let mut numbers: HashMap<usize, usize> = HashMap::new();
+        let mut labels: HashMap<V, usize> = HashMap::with_capacity(incident.len());
+        for (v, &label) in &self.labels {
+            if !dirty.contains(&label) && incident.contains_key(v) {
+                let next = numbers.len();
+                labels.insert(v.clone(), *numbers.entry(label).or_insert(next));
+            }
+        }
+        let mut count = numbers.len();
+        for &root in &pending {
+            if labels.contains_key(root) {
+                continue;
+            }
+            labels.insert(root.clone(), count);
+            let mut queue = VecDeque::from([root]);
+            while let Some(u) = queue.pop_front() {
+                for &v in adjacency.get(u).into_iter().flatten() {
+                    if !labels.contains_key(v) {
+                        labels.insert(v.clone(), count);
+                        queue.push_back(v);
+                    }
+                }
+            }
+            count += 1;
+        }
+        trace_event!(dirty = pending.len(), count, "components repaired");
+
+        self.labels = labels;
+        self.count = count;
+        self.fingerprint = fingerprint;
+        self.incident = incident.into_iter().map(|(v, d)| (v.clone(), d)).collect();
+        Revalidation::Repaired
+    }
+}
+
+/// A topological ordering of a directed acyclic graph, stamped with the
+/// fingerprint of the graph it was computed on.
+#[derive(Debug, Clone)]
+pub struct CachedTopologicalOrder<V> {
+    order: Vec<V>,
+    fingerprint: GraphFingerprint,
+}
+
+impl<V> CachedTopologicalOrder<V>
+where
+    V: Eq + Hash + Clone,
+{
+    /// Computes a topological ordering of `graph` with
+    /// [`topological_sort`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` if the graph is undirected or
+    /// contains a cycle.
+    pub fn new<G>(graph: &G) -> Result<Self, GraphError>
+    where
+        G: Graph<Vertex = V>,
+    {
+        Ok(Self {
+            order: topological_sort(graph)?,
+            fingerprint: GraphFingerprint::of(graph),
+        })
+    }
+
+    /// Returns the vertices in topological order.
+    #[must_use]
+    pub fn order(&self) -> &[V] {
+        &self.order
+    }
+
+    /// Returns the fingerprint of the graph the ordering was computed on.
+    #[must_use]
+    pub fn fingerprint(&self) -> GraphFingerprint {
+        self.fingerprint
+    }
+
+    /// Returns `true` if `graph` differs from the graph the ordering was computed
+    /// on.
+    #[must_use]
+    pub fn is_stale<G>(&self, graph: &G) -> bool
+    where
+        G: Graph<Vertex = V>,
+    {
+        !self.fingerprint.matches(graph)
+    }
+
+    /// Brings the ordering up to date with `graph`.
+    ///
+    /// If no vertex was added and every arc still goes forward in the cached
+    /// ordering, which holds after removing vertices or arcs, the removed vertices
+    /// are dropped and the ordering is kept. Otherwise it is computed again.
+    ///
+    /// # Returns
+    ///
+    /// How the ordering was brought up to date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` if the graph is now undirected or
+    /// contains a cycle. The cached ordering is left unchanged.
+    pub fn revalidate<G>(&mut self, graph: &G) -> Result<Revalidation, GraphError>
+    where
+        G: Graph<Vertex = V>,
+    {
+        let fingerprint = GraphFingerprint::of(graph);
+        if fingerprint == self.fingerprint {
+            return Ok(Revalidation::Fresh);
+        }
+        trace_span!("revalidate_topological_order", vertices = fingerprint.order);
+
+        let position: HashMap<&V, usize> = self
+            .order
+            .iter()
+            .filter(|v| graph.contains_vertex(v))
+            .enumerate()
+            .map(|(i, v)| (v, i))
+            .collect();
+        let forward = graph.is_directed()
+            && position.len() == graph.order()
+            && graph.vertices().all(|u| {
+                graph
+                    .neighbors(u)
+                    .into_iter()
+                    .flatten()
+                    .all(|v| position[u] < position[v])
+            });
+        if forward {
+            self.order.retain(|v| graph.contains_vertex(v));
+            self.fingerprint = fingerprint;
+            return Ok(Revalidation::Repaired);
+        }
+
+        self.order = topological_sort(graph)?;
+        self.fingerprint = fingerprint;
+        Ok(Revalidation::Recomputed)
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use crate::{algorithms::adjacency::undirected_adjacency, Graph};
+
+/// Counts the triangles each vertex belongs to.
+///
+/// Edge directions are ignored and self-loops do not form triangles.
+///
+/// # Returns
+///
+/// A map from every vertex to the number of triangles containing it.
+pub fn triangles<G>(graph: &G) -> HashMap<G::Vertex, usize>
+where
+    G: Graph,
+{
+    trace_span!("triangles");
+    let adjacency = undirected_adjacency(graph);
+    trace_event!(vertices = adjacency.len(), "adjacency built");
+    adjacency
+        .iter()
+        .map(|(&v, neighbors)| {
+            let mut count = 0;
+            for &a in neighbors {
+                for &b in neighbors {
+                    if a != b && adjacency[a].contains(b) {
+                        count += 1;
+                    }
+                }
+            }
+            (v.clone(), count / 2)
+        })
+        .collect()
+}
+
+/// Computes the local clustering coefficient of each vertex, the fraction of pairs
+/// of its neighbors that are adjacent.
+///
+/// Edge directions are ignored. Vertices with fewer than two neighbors have a
+/// coefficient of `0.0`.
+///
+/// # Returns
+///
+/// A map from every vertex to its clustering coefficient in `[0, 1]`.
+pub fn local_clustering<G>(graph: &G) -> HashMap<G::Vertex, f64>
+where
+    G: Graph,
+{
+    let adjacency = undirected_adjacency(graph);
+    let triangles = triangles(graph);
+    adjacency
+        .into_iter()
+        .map(|(v, neighbors)| {
+            let k = neighbors.len();
+            let coefficient = if k < 2 {
+                0.0
+            } else {
+                #[allow(clippy::cast_precision_loss)]
+                let c = (2 * triangles[v]) as f64 / (k * (k - 1)) as f64;
+                c
+            };
+            (v.clone(), coefficient)
+        })
+        .collect()
+}
+
+/// Computes the average local clustering coefficient over all vertices.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The mean of [`local_clustering`].
+/// * `None` - If the graph has no vertices.
+pub fn average_clustering<G>(graph: &G) -> Option<f64>
+where
+    G: Graph,
+{
+    let clustering = local_clustering(graph);
+    if clustering.is_empty() {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let n = clustering.len() as f64;
+    Some(clustering.values().sum::<f64>() / n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimpleGraph;
+
+    /// A triangle `0, 1, 2` with a pendant vertex `3` attached to `0`.
+    fn paw() -> SimpleGraph<u32> {
+        SimpleGraph::from_edges([(0, 1), (1, 2), (2, 0), (0, 3)], false)
+    }
+
+    #[test]
+    fn counts_triangles() {
+        assert_eq!(
+            triangles(&paw()),
+            HashMap::from([(0, 1), (1, 1), (2, 1), (3, 0)])
+        );
+
+        let k4 =
+            SimpleGraph::from_edges((0..4).flat_map(|u| (u + 1..4).map(move |v| (u, v))), false);
+        assert!(triangles(&k4).values().all(|&count| count == 3));
+
+        // Directions are ignored and self-loops close no triangle.
+        let directed = SimpleGraph::from_edges([(0, 1), (1, 2), (0, 2), (2, 2)], true);
+        assert_eq!(
+            triangles(&directed),
+            HashMap::from([(0, 1), (1, 1), (2, 1)])
+        );
+    }
+
+    #[test]
+    fn computes_local_clustering() {
+        let clustering = local_clustering(&paw());
+        assert!((clustering[&0] - 1.0 / 3.0).abs() < 1e-12);
+        assert!((clustering[&1] - 1.0).abs() < 1e-12);
+        assert!((clustering[&2] - 1.0).abs() < 1e-12);
+        assert!(clustering[&3].abs() < 1e-12);
+    }
+
+    #[test]
+    fn averages_clustering() {
+        let average = average_clustering(&paw()).unwrap();
+        assert!((average - 7.0 / 12.0).abs() < 1e-12);
+
+        let star = SimpleGraph::from_edges([(0, 1), (0, 2), (0, 3)], false);
+        assert!(average_clustering(&star).unwrap().abs() < 1e-12);
+        assert_eq!(
+            average_clustering(&SimpleGraph::<u32>::new_undirected()),
+            None
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use std::{cmp::Reverse, collections::HashSet};
+
+use crate::{error::AlgorithmError, graphs::small::SmallGraph, Graph, GraphError};
+
+/// The largest graph accepted by [`chromatic_number`]. The algorithm stores one
+/// `u32` per vertex subset, so this bound keeps memory use at 128 MiB.
+pub const MAX_EXACT_COLORING_VERTICES: usize = 25;
+
+/// Primes just below `2^62`. Each one contributes at least 61 bits to the
+/// modulus used to certify that an inclusion–exclusion sum is exactly zero.
+const PRIMES: [u64; 12] = [
+    0x3fff_ffff_ffff_ffc7,
+    0x3fff_ffff_ffff_ffa9,
+    0x3fff_ffff_ffff_ff8b,
+    0x3fff_ffff_ffff_ff71,
+    0x3fff_ffff_ffff_ff67,
+    0x3fff_ffff_ffff_ff59,
+    0x3fff_ffff_ffff_ff55,
+    0x3fff_ffff_ffff_ff3d,
+    0x3fff_ffff_ffff_ff35,
+    0x3fff_ffff_ffff_feef,
+    0x3fff_ffff_ffff_fee1,
+    0x3fff_ffff_ffff_fec3,
+];
+
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    // The remainder is below `p`, so it fits in a `u64`.
+    #[allow(clippy::cast_possible_truncation)]
+    let product = (u128::from(a) * u128::from(b) % u128::from(p)) as u64;
+    product
+}
+
+fn pow_mod(mut base: u64, mut exp: usize, p: u64) -> u64 {
+    let mut result = 1;
+    base %= p;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Returns `Σ_X (-1)^(n - |X|) i(X)^k mod p`, which counts the ordered `k`-tuples
+/// of independent sets covering every vertex.
+fn covers_mod(independent: &[u32], n: usize, k: usize, p: u64) -> u64 {
+    let mut sum = 0;
+    for (x, &count) in independent.iter().enumerate() {
+        let term = pow_mod(u64::from(count), k, p);
+        if (n - x.count_ones() as usize).is_multiple_of(2) {
+            sum = (sum + term) % p;
+        } else {
+            sum = (sum + p - term) % p;
+        }
+    }
+    sum
+}
+
+/// Decides whether the graph can be colored with `k` colors.
+///
+/// The covering count is nonnegative, so any nonzero residue proves it positive.
+/// It is declared zero only once the product of the primes tried exceeds its
+/// bound `2^(n (k + 1))`, which makes the answer exact.
+fn is_colorable(independent: &[u32], n: usize, k: usize) -> bool {
+    let needed = n * (k + 1) / 61 + 1;
+    PRIMES
+        .iter()
+        .take(needed)
+        .any(|&p| covers_mod(independent, n, k, p) != 0)
+}
+
+/// Colors the vertices in order of decreasing degree with the smallest free
+/// color and returns the number of colors used, an upper bound on the chromatic
+/// number.
+///
+/// `neighbors` holds sorted, symmetric neighbor lists without self-loops.
+pub(crate) fn greedy_colors(neighbors: &[Vec<usize>]) -> usize {
+    let mut order: Vec<usize> = (0..neighbors.len()).collect();
+    order.sort_by_key(|&v| Reverse(neighbors[v].len()));
+    let mut colors: Vec<Option<usize>> = vec![None; neighbors.len()];
+    let mut count = 0;
+    for v in order {
+        let used: HashSet<usize> = neighbors[v].iter().filter_map(|&w| colors[w]).collect();
+        let color = (0..=used.len())
+            .find(|c| !used.contains(c))
+            .unwrap_or(used.len());
+        colors[v] = Some(color);
+        count = count.max(color + 1);
+    }
+    count
+}
+
+/// Grows a clique from every vertex, adding the candidate with most neighbors
+/// first, and returns the largest size found, a lower bound on the chromatic
+/// number.
+///
+/// `neighbors` holds sorted, symmetric neighbor lists without self-loops.
+pub(crate) fn greedy_clique(neighbors: &[Vec<usize>]) -> usize {
+    (0..neighbors.len())
+        .map(|start| {
+            let mut candidates = neighbors[start].clone();
+            let mut size = 1;
+            while let Some(&next) = candidates.iter().max_by_key(|&&c| neighbors[c].len()) {
+                candidates.retain(|c| neighbors[next].binary_search(c).is_ok());
+                size += 1;
+            }
+            size
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Computes the chromatic number of a graph exactly, the fewest colors needed so
+/// that adjacent vertices get different colors.
+///
+/// The graph is copied into a [`SmallGraph`] and the number of independent sets
+/// inside every vertex subset is computed with a dynamic program over bitsets.
+/// Candidate values of `k`, between a greedy clique size and a greedy coloring,
+/// are then binary searched with the inclusion–exclusion test of Björklund,
+/// Husfeldt and Koivisto, in `O(2^n n)` time per test. Edge directions are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to color.
+///
+/// # Returns
+///
+/// The chromatic number, `0` for the empty graph.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph has more than
+/// [`MAX_EXACT_COLORING_VERTICES`] vertices or a self-loop, which no coloring
+/// can satisfy.
+pub fn chromatic_number<G>(graph: &G) -> Result<usize, GraphError>
+where
+    G: Graph,
+{
+    if graph.order() > MAX_EXACT_COLORING_VERTICES {
+        return Err(AlgorithmError::TooLarge {
+            limit: MAX_EXACT_COLORING_VERTICES,
+            actual: graph.order(),
+        }
+        .into());
+    }
+    let (small, _) = SmallGraph::from_graph(graph)?;
+    let n = small.order();
+    if n == 0 {
+        return Ok(0);
+    }
+
+    let mut adjacency = vec![0u32; n];
+    for (u, row) in adjacency.iter_mut().enumerate() {
+        // Rows only have bits below `n <= 25`.
+        #[allow(clippy::cast_possible_truncation)]
+        let out = small.row(u) as u32;
+        if out & (1 << u) != 0 {
+            return Err(AlgorithmError::SelfLoop.into());
+        }
+        *row |= out;
+    }
+    for u in 0..n {
+        for v in 0..n {
+            if adjacency[u] & (1 << v) != 0 {
+                adjacency[v] |= 1 << u;
+            }
+        }
+    }
+    if adjacency.iter().all(|&row| row == 0) {
+        return Ok(1);
+    }
+    trace_span!("chromatic_number", vertices = n);
+
+    // independent[X] counts the independent sets inside X, including the empty
+    // set: those avoiding the lowest vertex v of X plus those containing it.
+    let mut independent = vec![0u32; 1 << n];
+    independent[0] = 1;
+    for x in 1..independent.len() {
+        let v = x.trailing_zeros() as usize;
+        let without = x & !(1 << v);
+        let with = without & !(adjacency[v] as usize);
+        independent[x] = independent[without] + independent[with];
+    }
+
+    // The chromatic number lies in lower..=upper, and upper is always feasible.
+    let neighbors: Vec<Vec<usize>> = adjacency
+        .iter()
+        .map(|&row| (0..n).filter(|&v| row & (1 << v) != 0).collect())
+        .collect();
+    let mut lower = greedy_clique(&neighbors);
+    let mut upper = greedy_colors(&neighbors);
+    while lower < upper {
+        let k = lower + (upper - lower) / 2;
+        trace_event!(k, "testing colorability");
+        if is_colorable(&independent, n, k) {
+            upper = k;
+        } else {
+            lower = k + 1;
+        }
+    }
+    Ok(upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GraphMut, SimpleGraph};
+
+    fn complete(n: usize) -> SimpleGraph<usize> {
+        SimpleGraph::from_edges((0..n).flat_map(|u| (u + 1..n).map(move |v| (u, v))), false)
+    }
+
+    fn cycle(n: usize) -> SimpleGraph<usize> {
+        SimpleGraph::from_edges((0..n).map(|v| (v, (v + 1) % n)), false)
+    }
+
+    #[test]
+    fn colors_complete_graphs_and_cycles() {
+        for n in 2..=8 {
+            assert_eq!(chromatic_number(&complete(n)).unwrap(), n);
+        }
+        assert_eq!(chromatic_number(&cycle(5)).unwrap(), 3);
+        assert_eq!(chromatic_number(&cycle(6)).unwrap(), 2);
+        assert_eq!(chromatic_number(&cycle(25)).unwrap(), 3);
+    }
+
+    #[test]
+    fn colors_graphs_without_large_cliques() {
+        let outer = (0..5).map(|v| (v, (v + 1) % 5));
+        let spokes = (0..5).map(|v| (v, v + 5));
+        let inner = (0..5).map(|v| (v + 5, (v + 2) % 5 + 5));
+        let petersen = SimpleGraph::from_edges(outer.chain(spokes).chain(inner), false);
+        assert_eq!(chromatic_number(&petersen).unwrap(), 3);
+
+        // The Grötzsch graph, the Mycielskian of C_5, is triangle-free and needs
+        // four colors.
+        let cycle = (0..5).map(|v| (v, (v + 1) % 5));
+        let shadows = (0..5).flat_map(|v| [(v + 5, (v + 1) % 5), (v + 5, (v + 4) % 5)]);
+        let apex = (5..10).map(|v| (v, 10));
+        let grotzsch = SimpleGraph::from_edges(cycle.chain(shadows).chain(apex), false);
+        assert_eq!(chromatic_number(&grotzsch).unwrap(), 4);
+    }
+
+    #[test]
+    fn handles_trivial_and_directed_graphs() {
+        assert_eq!(
+            chromatic_number(&SimpleGraph::<usize>::new_undirected()).unwrap(),
+            0
+        );
+        let mut edgeless = SimpleGraph::new_undirected();
+        for v in 0..4 {
+            edgeless.add_vertex(v).unwrap();
+        }
+        assert_eq!(chromatic_number(&edgeless).unwrap(), 1);
+
+        let directed = SimpleGraph::from_edges([(0, 1), (1, 2), (2, 0)], true);
+        assert_eq!(chromatic_number(&directed).unwrap(), 3);
+    }
+
+    #[test]
+    fn rejects_self_loops_and_large_graphs() {
+        let looped = SimpleGraph::from_edges([(0, 1), (1, 1)], false);
+        assert!(matches!(
+            chromatic_number(&looped),
+            Err(GraphError::Algorithm(AlgorithmError::SelfLoop))
+        ));
+        assert!(matches!(
+            chromatic_number(&cycle(MAX_EXACT_COLORING_VERTICES + 1)),
+            Err(GraphError::Algorithm(_))
+        ));
+    }
+}
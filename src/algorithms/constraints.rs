@@ -0,0 +1,287 @@
+use std::{cmp::Ordering, collections::HashMap, fmt::Debug, hash::Hash};
+
+use crate::{
+    algorithms::shortest_path::weighted_arcs,
+    error::AlgorithmError,
+    traits::{
+        measure::Measure,
+        weighted::{WeightedEdge, WeightedGraph},
+    },
+    GraphError,
+};
+
+/// The outcome of solving a system of difference constraints.
+#[derive(Debug, Clone)]
+pub enum Feasibility<V, W> {
+    /// The system holds for this value of every variable.
+    Feasible(HashMap<V, W>),
+    /// The system has no solution. The listed constraints, each `(x, y, bound)`
+    /// for `x - y <= bound`, form a cycle whose bounds sum to a negative amount,
+    /// so they cannot hold together.
+    Infeasible(Vec<WeightedEdge<V, W>>),
+}
+
+impl<V, W> Feasibility<V, W> {
+    /// Returns `true` if the system has a solution.
+    #[must_use]
+    pub fn is_feasible(&self) -> bool {
+        matches!(self, Self::Feasible(_))
+    }
+
+    /// Returns the solution, or `None` if the system is infeasible.
+    #[must_use]
+    pub fn assignment(&self) -> Option<&HashMap<V, W>> {
+        match self {
+            Self::Feasible(values) => Some(values),
+            Self::Infeasible(_) => None,
+        }
+    }
+}
+
+/// A system of difference constraints `x - y <= bound` over variables of type
+/// `V`.
+///
+/// Each constraint is an arc from `y` to `x` of weight `bound` in the constraint
+/// graph, and the system is feasible exactly when that graph has no cycle of
+/// negative weight. [`DifferenceConstraints::solve`] runs Bellman-Ford from every
+/// variable at once, so each value is the length of a shortest path ending at it
+/// and is at most zero. Adding the same amount to every value gives another
+/// solution, which is how nonnegative start times are obtained in scheduling.
+///
+/// Other relations are expressed with the same form: `x - y >= b` is
+/// `y - x <= -b`, and `x - y == b` is the pair of both.
+///
+/// ```
+/// use kambo_graph::algorithms::constraints::DifferenceConstraints;
+///
+/// // Task b starts at least 3 after a, and c at most 1 after b but at least 5 after a.
+/// let mut system = DifferenceConstraints::new();
+/// system.add_constraint("a", "b", -3);
+/// system.add_constraint("c", "b", 1);
+/// system.add_constraint("a", "c", -5);
+/// let times = system.solve().unwrap();
+/// let times = times.assignment().unwrap();
+/// assert!(times[&"b"] - times[&"a"] >= 3);
+/// assert!(times[&"c"] - times[&"b"] <= 1);
+///
+/// // c at most 4 after a contradicts the rest.
+/// system.add_constraint("c", "a", 4);
+/// assert!(!system.solve().unwrap().is_feasible());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DifferenceConstraints<V, W> {
+    variables: Vec<V>,
+    index: HashMap<V, usize>,
+    /// The constraints as `(x, y, bound)` over variable numbers.
+    constraints: Vec<(usize, usize, W)>,
+}
+
+impl<V, W> Default for DifferenceConstraints<V, W> {
+    fn default() -> Self {
+        Self {
+            variables: Vec::new(),
+            index: HashMap::new(),
+            constraints: Vec::new(),
+        }
+    }
+}
+
+impl<V, W> DifferenceConstraints<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Measure,
+{
+    /// Creates an empty system.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variable without constraining it. Variables are also added by
+    /// [`DifferenceConstraints::add_constraint`]; a variable added twice is kept
+    /// once.
+    pub fn add_variable(&mut self, x: V) -> &mut Self {
+        self.variable(x);
+        self
+    }
+
+    /// Adds the constraint `x - y <= bound`.
+    pub fn add_constraint(&mut self, x: V, y: V, bound: W) -> &mut Self {
+        let (x, y) = (self.variable(x), self.variable(y));
+        self.constraints.push((x, y, bound));
+        self
+    }
+
+    /// Returns the number of variables.
+    #[must_use]
+    pub fn variable_count(&self) -> usize {
+        self.variables.len()
+    }
+
+    /// Returns the number of constraints.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Returns `true` if the system has no constraints.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    /// Decides whether the system has a solution and finds one, in `O(n m)` time
+    /// for `n` variables and `m` constraints.
+    ///
+    /// # Returns
+    ///
+    /// * `Feasibility::Feasible` - A value for every variable satisfying every
+    ///   constraint.
+    /// * `Feasibility::Infeasible` - Constraints that contradict each other.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::Algorithm` - If a bound is invalid (such as `NaN`).
+    /// * `GraphError::WeightOverflow` - If a sum of bounds overflows.
+    pub fn solve(&self) -> Result<Feasibility<V, W>, GraphError> {
+        if let Some((x, y, bound)) = self.constraints.iter().find(|(_, _, b)| !b.is_valid()) {
+            return Err(AlgorithmError::InvalidArgument(format!(
+                "Constraint {:?} - {:?} <= {bound:?} has an invalid bound",
+                self.variables[*x], self.variables[*y]
+            ))
+            .into());
+        }
+        let arcs: Vec<(usize, usize, &W)> = self
+            .constraints
+            .iter()
+            .map(|(x, y, bound)| (*y, *x, bound))
+            .collect();
+        let variables: Vec<&V> = self.variables.iter().collect();
+        solve(&variables, &arcs)
+    }
+
+    fn variable(&mut self, x: V) -> usize {
+        if let Some(&i) = self.index.get(&x) {
+            return i;
+        }
+        self.index.insert(x.clone(), self.variables.len());
+        self.variables.push(x);
+        self.variables.len() - 1
+    }
+}
+
+/// Solves the system of difference constraints given by a constraint graph, in
+/// which an edge `(y, x)` of weight `bound` stands for `x - y <= bound`.
+///
+/// An undirected edge constrains both differences, so it bounds `|x - y|`. See
+/// [`DifferenceConstraints`] for the method and the meaning of the result.
+///
+/// # Arguments
+///
+/// * `graph` - The constraint graph, with a variable per vertex.
+///
+/// # Returns
+///
+/// * `Feasibility::Feasible` - A value for every vertex satisfying every
+///   constraint.
+/// * `Feasibility::Infeasible` - Constraints that contradict each other, as
+///   `(x, y, bound)` for the edges `(y, x)` of a negative cycle.
+///
+/// # Errors
+///
+/// * `GraphError::Algorithm` - If an edge has no weight or an invalid
+///   weight (such as `NaN`).
+/// * `GraphError::WeightOverflow` - If a path length overflows.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::constraints::solve_difference_constraints, graph};
+///
+/// // x2 - x1 <= 4, x3 - x2 <= -1 and x1 - x3 <= -2.
+/// let graph = graph! { 1 -> 2 [weight = 4], 2 -> 3 [weight = -1], 3 -> 1 [weight = -2] };
+/// let values = solve_difference_constraints(&graph).unwrap();
+/// let values = values.assignment().unwrap();
+/// assert!(values[&3] - values[&2] <= -1 && values[&1] - values[&3] <= -2);
+/// ```
+pub fn solve_difference_constraints<G>(
+    graph: &G,
+) -> Result<Feasibility<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    let variables: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        variables.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let arcs: Vec<(usize, usize, &G::Weight)> = weighted_arcs(graph)?
+        .into_iter()
+        .map(|(u, v, weight)| (index[u], index[v], weight))
+        .collect();
+    solve(&variables, &arcs)
+}
+
+/// Runs Bellman-Ford on the constraint graph with every distance starting at
+/// zero, as if from a source joined to every vertex.
+fn solve<V, W>(
+    variables: &[&V],
+    arcs: &[(usize, usize, &W)],
+) -> Result<Feasibility<V, W>, GraphError>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Measure,
+{
+    let n = variables.len();
+    trace_span!(
+        "difference_constraints",
+        variables = n,
+        constraints = arcs.len()
+    );
+    let mut distance = vec![W::zero(); n];
+    let mut last = vec![usize::MAX; n];
+    // With the implicit source there are `n + 1` vertices, so distances settle
+    // within `n` rounds unless a negative cycle keeps improving them.
+    let mut improved = None;
+    for _ in 0..=n {
+        improved = None;
+        for (a, &(y, x, bound)) in arcs.iter().enumerate() {
+            let next = distance[y]
+                .checked_add(bound)
+                .ok_or(GraphError::WeightOverflow)?;
+            if next.compare(&distance[x]) == Ordering::Less {
+                distance[x] = next;
+                last[x] = a;
+                improved = Some(x);
+            }
+        }
+        if improved.is_none() {
+            break;
+        }
+    }
+    let Some(mut v) = improved else {
+        trace_event!("system is feasible");
+        let values = variables.iter().map(|&v| v.clone()).zip(distance);
+        return Ok(Feasibility::Feasible(values.collect()));
+    };
+
+    // Following the arcs that last improved every distance from a vertex
+    // improved in the final round walks into a negative cycle.
+    trace_event!("system is infeasible");
+    let mut seen = vec![false; n];
+    while !seen[v] && last[v] != usize::MAX {
+        seen[v] = true;
+        v = arcs[last[v]].0;
+    }
+    let start = v;
+    let mut cycle = Vec::new();
+    loop {
+        let (y, x, bound) = arcs[last[v]];
+        cycle.push((variables[x].clone(), variables[y].clone(), bound.clone()));
+        v = y;
+        if v == start {
+            break;
+        }
+    }
+    cycle.reverse();
+    Ok(Feasibility::Infeasible(cycle))
+}
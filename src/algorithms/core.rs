@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{algorithms::adjacency::undirected_adjacency, Graph};
+
+/// Computes the core number of each vertex with the Batagelj–Zaversnik
+/// algorithm.
+///
+/// The core number of `v` is the largest `k` such that `v` belongs to the
+/// `k`-core, the maximal subgraph in which every vertex has degree at least `k`.
+/// Edge directions and self-loops are ignored.
+///
+/// # Returns
+///
+/// A map from every vertex to its core number.
+pub fn core_numbers<G>(graph: &G) -> HashMap<G::Vertex, usize>
+where
+    G: Graph,
+{
+    trace_span!("core_numbers");
+    peel(graph)
+        .into_iter()
+        .map(|(v, core)| (v.clone(), core))
+        .collect()
+}
+
+/// Computes a degeneracy ordering, the order in which vertices are removed when
+/// a vertex of minimum degree is removed repeatedly.
+///
+/// Every vertex has at most [`degeneracy`] neighbors after it in the ordering,
+/// which bounds the work of algorithms processing the vertices in this order,
+/// such as Bron–Kerbosch clique enumeration. Reversed, it is the smallest-last
+/// ordering of Matula and Beck: coloring greedily in that order uses at most
+/// `degeneracy + 1` colors. Edge directions and self-loops are ignored.
+///
+/// # Returns
+///
+/// Every vertex, in removal order.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::core::degeneracy_ordering, graph};
+///
+/// // A triangle with a pendant vertex: the pendant vertex goes first.
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 3 - 4 };
+/// let order = degeneracy_ordering(&graph);
+/// assert_eq!(order[0], 4);
+/// assert_eq!(order.len(), 4);
+/// ```
+#[must_use]
+pub fn degeneracy_ordering<G>(graph: &G) -> Vec<G::Vertex>
+where
+    G: Graph,
+{
+    trace_span!("degeneracy_ordering");
+    peel(graph).into_iter().map(|(v, _)| v.clone()).collect()
+}
+
+/// Computes the degeneracy of a graph, the largest `k` such that the graph has a
+/// non-empty `k`-core, or equivalently its largest core number.
+///
+/// Edge directions and self-loops are ignored. The empty graph has degeneracy
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::core::degeneracy, graph};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 3 - 4 };
+/// assert_eq!(degeneracy(&graph), 2);
+/// ```
+#[must_use]
+pub fn degeneracy<G>(graph: &G) -> usize
+where
+    G: Graph,
+{
+    peel(graph)
+        .into_iter()
+        .map(|(_, core)| core)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Removes a vertex of minimum degree repeatedly, returning the vertices in
+/// removal order with their core numbers.
+fn peel<G>(graph: &G) -> Vec<(&G::Vertex, usize)>
+where
+    G: Graph,
+{
+    let adjacency = undirected_adjacency(graph);
+    let mut degree: HashMap<&G::Vertex, usize> =
+        adjacency.iter().map(|(&v, n)| (v, n.len())).collect();
+    let max_degree = degree.values().copied().max().unwrap_or(0);
+
+    let mut buckets: Vec<HashSet<&G::Vertex>> = vec![HashSet::new(); max_degree + 1];
+    for (&v, &d) in &degree {
+        buckets[d].insert(v);
+    }
+
+    // Vertices are peeled in nondecreasing degree order. Decrementing a neighbor
+    // never moves it below the current bucket, so the cursor only moves forward.
+    let mut removed: HashSet<&G::Vertex> = HashSet::with_capacity(adjacency.len());
+    let mut order = Vec::with_capacity(adjacency.len());
+    let mut d = 0;
+    while d < buckets.len() {
+        let Some(&v) = buckets[d].iter().next() else {
+            d += 1;
+            continue;
+        };
+        buckets[d].remove(v);
+        removed.insert(v);
+        order.push((v, d));
+
+        for &w in &adjacency[v] {
+            if removed.contains(w) {
+                continue;
+            }
+            let dw = degree[w];
+            if dw > d {
+                buckets[dw].remove(w);
+                buckets[dw - 1].insert(w);
+                degree.insert(w, dw - 1);
+            }
+        }
+    }
+
+    trace_event!(vertices = order.len(), max_core = d, "peeling complete");
+    order
+}
+
+/// Returns the vertices of the `k`-core, the maximal subgraph in which every
+/// vertex has degree at least `k`.
+///
+/// # Returns
+///
+/// The vertices (cloned) whose core number is at least `k`.
+pub fn k_core<G>(graph: &G, k: usize) -> HashSet<G::Vertex>
+where
+    G: Graph,
+{
+    core_numbers(graph)
+        .into_iter()
+        .filter(|&(_, c)| c >= k)
+        .map(|(v, _)| v)
+        .collect()
+}
@@ -0,0 +1,831 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "rand")]
+use std::collections::HashSet;
+
+use crate::{algorithms::adjacency::IndexedGraph, error::AlgorithmError, Graph, GraphError};
+
+/// The widest frontier accepted by the exact counting functions. The transfer
+/// dynamic program keeps one state per admissible assignment of the frontier, so
+/// its memory grows exponentially with this width.
+pub const MAX_EXACT_COUNTING_WIDTH: usize = 16;
+
+/// Symmetric adjacency of a graph over vertex indices, ignoring edge directions.
+struct Indexed<'a, V> {
+    #[cfg_attr(not(feature = "rand"), allow(dead_code))]
+    vertices: Vec<&'a V>,
+    neighbors: Vec<Vec<usize>>,
+    looped: Vec<bool>,
+}
+
+fn index_graph<G>(graph: &G) -> Indexed<'_, G::Vertex>
+where
+    G: Graph,
+{
+    let indexed = IndexedGraph::new(graph);
+    let neighbors = indexed.symmetric(graph.is_directed());
+    let looped = indexed.looped();
+    Indexed {
+        vertices: indexed.vertices,
+        neighbors,
+        looped,
+    }
+}
+
+/// Orders the vertices greedily so that few processed vertices still have
+/// unprocessed neighbors.
+///
+/// Returns the order and its width, the largest number of vertices the transfer
+/// dynamic program has to remember at once.
+fn frontier_order(neighbors: &[Vec<usize>]) -> (Vec<usize>, usize) {
+    let n = neighbors.len();
+    let mut pending: Vec<usize> = neighbors.iter().map(Vec::len).collect();
+    let mut done = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut frontier = 0;
+    let mut width = 0;
+    for _ in 0..n {
+        let growth = |v: usize| {
+            let closed = neighbors[v]
+                .iter()
+                .filter(|&&u| done[u] && pending[u] == 1)
+                .count();
+            let processed = neighbors[v].iter().filter(|&&u| done[u]).count();
+            (usize::from(pending[v] > 0) + n - closed, n - processed, v)
+        };
+        let v = (0..n)
+            .filter(|&v| !done[v])
+            .min_by_key(|&v| growth(v))
+            .expect("an unprocessed vertex remains");
+        width = width.max(frontier + 1);
+        frontier = growth(v).0 + frontier - n;
+        for &u in &neighbors[v] {
+            pending[u] -= 1;
+        }
+        done[v] = true;
+        order.push(v);
+    }
+    (order, width)
+}
+
+/// Returns a vertex order whose width the transfer dynamic program can handle.
+fn checked_order(neighbors: &[Vec<usize>]) -> Result<Vec<usize>, GraphError> {
+    let (order, width) = frontier_order(neighbors);
+    if width > MAX_EXACT_COUNTING_WIDTH {
+        return Err(AlgorithmError::TooLarge {
+            limit: MAX_EXACT_COUNTING_WIDTH,
+            actual: width,
+        }
+        .into());
+    }
+    Ok(order)
+}
+
+fn overflow() -> GraphError {
+    AlgorithmError::ResultOverflow.into()
+}
+
+fn accumulate<K>(states: &mut HashMap<K, u128>, key: K, count: u128) -> Result<(), GraphError>
+where
+    K: Eq + std::hash::Hash,
+{
+    let entry = states.entry(key).or_insert(0);
+    *entry = entry.checked_add(count).ok_or_else(overflow)?;
+    Ok(())
+}
+
+/// Moves `v` into the frontier and drops the frontier vertices with no
+/// unprocessed neighbor left.
+///
+/// Returns which of the frontier positions, `v` included, are kept.
+fn advance(
+    frontier: &mut Vec<usize>,
+    pending: &mut [usize],
+    neighbors: &[usize],
+    v: usize,
+) -> Vec<bool> {
+    for &u in neighbors {
+        pending[u] -= 1;
+    }
+    frontier.push(v);
+    let keep: Vec<bool> = frontier.iter().map(|&u| pending[u] > 0).collect();
+    let mut position = 0;
+    frontier.retain(|_| {
+        position += 1;
+        keep[position - 1]
+    });
+    keep
+}
+
+/// Counts the independent sets of a graph exactly, including the empty set.
+///
+/// The vertices are processed in a greedily chosen order while a transfer
+/// dynamic program tracks, for every subset of the processed vertices that still
+/// have unprocessed neighbors, how many independent sets agree with it. This is
+/// efficient for graphs of small pathwidth such as lattice strips, whatever
+/// their size. Edge directions are ignored, and a vertex with a self-loop never
+/// belongs to an independent set.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+///
+/// # Returns
+///
+/// The number of independent sets, which is the partition function of the
+/// hard-core model at fugacity one.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the order found needs a frontier
+/// wider than [`MAX_EXACT_COUNTING_WIDTH`] or the count overflows a `u128`.
+pub fn count_independent_sets<G>(graph: &G) -> Result<u128, GraphError>
+where
+    G: Graph,
+{
+    let indexed = index_graph(graph);
+    let order = checked_order(&indexed.neighbors)?;
+    trace_span!("count_independent_sets", vertices = order.len());
+
+    let mut pending: Vec<usize> = indexed.neighbors.iter().map(Vec::len).collect();
+    let mut frontier: Vec<usize> = Vec::new();
+    // Bit i of a state is set when the i-th frontier vertex is in the set.
+    let mut states: HashMap<u64, u128> = HashMap::from([(0, 1)]);
+    for v in order {
+        let adjacent = frontier
+            .iter()
+            .enumerate()
+            .filter(|&(_, u)| indexed.neighbors[v].contains(u))
+            .fold(0u64, |mask, (i, _)| mask | 1 << i);
+        let mut extended = HashMap::with_capacity(states.len() * 2);
+        for (&mask, &count) in &states {
+            accumulate(&mut extended, mask, count)?;
+            if !indexed.looped[v] && mask & adjacent == 0 {
+                accumulate(&mut extended, mask | 1 << frontier.len(), count)?;
+            }
+        }
+
+        let keep = advance(&mut frontier, &mut pending, &indexed.neighbors[v], v);
+        states = HashMap::with_capacity(extended.len());
+        for (mask, count) in extended {
+            let projected = keep
+                .iter()
+                .enumerate()
+                .filter(|&(_, &kept)| kept)
+                .enumerate()
+                .filter(|&(_, (i, _))| mask & 1 << i != 0)
+                .fold(0u64, |projected, (j, _)| projected | 1 << j);
+            accumulate(&mut states, projected, count)?;
+        }
+    }
+    trace_event!(states = states.len(), "transfer complete");
+
+    states
+        .into_values()
+        .try_fold(0u128, u128::checked_add)
+        .ok_or_else(overflow)
+}
+
+/// Relabels color classes in order of first appearance, so that frontier
+/// assignments differing only by a permutation of colors share one state.
+fn canonical(labels: impl Iterator<Item = u8>) -> Vec<u8> {
+    let mut relabel: Vec<u8> = Vec::new();
+    labels
+        .map(|label| {
+            let position = relabel.iter().position(|&l| l == label).unwrap_or_else(|| {
+                relabel.push(label);
+                relabel.len() - 1
+            });
+            // The frontier holds at most `MAX_EXACT_COUNTING_WIDTH` classes.
+            #[allow(clippy::cast_possible_truncation)]
+            let position = position as u8;
+            position
+        })
+        .collect()
+}
+
+/// Counts the proper colorings of a graph with `k` colors exactly, that is the
+/// chromatic polynomial evaluated at `k`.
+///
+/// The vertices are processed in a greedily chosen order while a transfer
+/// dynamic program tracks how the processed vertices that still have
+/// unprocessed neighbors are split into color classes. Only the split matters,
+/// so a new class multiplies the count by the number of unused colors. This is
+/// efficient for graphs of small pathwidth such as lattice strips, whatever
+/// their size. Edge directions are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to color.
+/// * `k` - The number of colors available.
+///
+/// # Returns
+///
+/// The number of maps from the vertices to `k` colors giving adjacent vertices
+/// different colors, which is the zero-temperature partition function of the
+/// `k`-state Potts antiferromagnet. It is `0` if the graph has a self-loop and
+/// `1` for the empty graph.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the order found needs a frontier
+/// wider than [`MAX_EXACT_COUNTING_WIDTH`] or the count overflows a `u128`.
+pub fn count_colorings<G>(graph: &G, k: usize) -> Result<u128, GraphError>
+where
+    G: Graph,
+{
+    let indexed = index_graph(graph);
+    if indexed.looped.contains(&true) {
+        return Ok(0);
+    }
+    let order = checked_order(&indexed.neighbors)?;
+    trace_span!("count_colorings", vertices = order.len(), k);
+
+    let mut pending: Vec<usize> = indexed.neighbors.iter().map(Vec::len).collect();
+    let mut frontier: Vec<usize> = Vec::new();
+    // Entry i of a state is the color class of the i-th frontier vertex.
+    let mut states: HashMap<Vec<u8>, u128> = HashMap::from([(Vec::new(), 1)]);
+    for v in order {
+        let mut extended = HashMap::with_capacity(states.len());
+        for (labels, &count) in &states {
+            let classes = labels.iter().max().map_or(0, |&l| usize::from(l) + 1);
+            let mut blocked = vec![false; classes];
+            for (&label, u) in labels.iter().zip(&frontier) {
+                if indexed.neighbors[v].contains(u) {
+                    blocked[usize::from(label)] = true;
+                }
+            }
+            for (class, _) in blocked.iter().enumerate().filter(|&(_, &b)| !b) {
+                let mut joined = labels.clone();
+                // `class` is below the frontier width.
+                #[allow(clippy::cast_possible_truncation)]
+                joined.push(class as u8);
+                accumulate(&mut extended, joined, count)?;
+            }
+            if k > classes {
+                let fresh = u128::try_from(k - classes)
+                    .ok()
+                    .and_then(|colors| count.checked_mul(colors))
+                    .ok_or_else(overflow)?;
+                let mut opened = labels.clone();
+                #[allow(clippy::cast_possible_truncation)]
+                opened.push(classes as u8);
+                accumulate(&mut extended, opened, fresh)?;
+            }
+        }
+
+        let keep = advance(&mut frontier, &mut pending, &indexed.neighbors[v], v);
+        states = HashMap::with_capacity(extended.len());
+        for (labels, count) in extended {
+            let projected = canonical(
+                labels
+                    .into_iter()
+                    .zip(&keep)
+                    .filter(|&(_, &kept)| kept)
+                    .map(|(label, _)| label),
+            );
+            accumulate(&mut states, projected, count)?;
+        }
+    }
+    trace_event!(states = states.len(), "transfer complete");
+
+    states
+        .into_values()
+        .try_fold(0u128, u128::checked_add)
+        .ok_or_else(overflow)
+}
+
+/// Colors the vertices greedily with the smallest color unused by a neighbor.
+#[cfg(feature = "rand")]
+fn greedy_coloring(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let mut colors = vec![usize::MAX; neighbors.len()];
+    for v in 0..neighbors.len() {
+        colors[v] = (0..=neighbors[v].len())
+            .find(|c| neighbors[v].iter().all(|&u| colors[u] != *c))
+            .expect("some color is unused by the neighbors");
+    }
+    colors
+}
+
+/// Performs one heat-bath step of the Glauber dynamics for proper colorings:
+/// a uniform vertex is recolored uniformly among the colors its neighbors leave
+/// free.
+#[cfg(feature = "rand")]
+fn coloring_step<R>(neighbors: &[Vec<usize>], colors: &mut [usize], k: usize, rng: &mut R)
+where
+    R: rand::Rng + ?Sized,
+{
+    let v = rng.random_range(0..colors.len());
+    // Rejection sampling terminates quickly because fewer than `k` colors are
+    // blocked.
+    loop {
+        let c = rng.random_range(0..k);
+        if neighbors[v].iter().all(|&u| colors[u] != c) {
+            colors[v] = c;
+            return;
+        }
+    }
+}
+
+/// Performs one heat-bath step of the Glauber dynamics for the hard-core model
+/// restricted to `active`.
+#[cfg(feature = "rand")]
+fn hard_core_step<V, R>(
+    indexed: &Indexed<'_, V>,
+    active: &[usize],
+    set: &mut [bool],
+    fugacity: f64,
+    rng: &mut R,
+) where
+    R: rand::Rng + ?Sized,
+{
+    let v = active[rng.random_range(0..active.len())];
+    set[v] = !indexed.looped[v]
+        && rng.random::<f64>() * (1.0 + fugacity) < fugacity
+        && indexed.neighbors[v].iter().all(|&u| !set[u]);
+}
+
+#[cfg(feature = "rand")]
+fn check_colors(neighbors: &[Vec<usize>], looped: &[bool], k: usize) -> Result<(), GraphError> {
+    if looped.contains(&true) {
+        return Err(AlgorithmError::SelfLoop.into());
+    }
+    let max_degree = neighbors.iter().map(Vec::len).max().unwrap_or(0);
+    if k < max_degree + 2 {
+        return Err(AlgorithmError::InvalidArgument(format!(
+            "Glauber dynamics needs at least {} colors for this graph",
+            max_degree + 2
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Samples an approximately uniform proper coloring with `k` colors by running
+/// the Glauber dynamics for `steps` steps from a greedy coloring.
+///
+/// The chain is ergodic when `k` is at least the maximum degree plus two, and it
+/// mixes in `O(n log n)` steps once `k` exceeds twice the maximum degree. Edge
+/// directions are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to color.
+/// * `k` - The number of colors available.
+/// * `steps` - The number of single-vertex updates to perform.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The color, between `0` and `k - 1`, of every vertex.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph has a self-loop or `k`
+/// is below the maximum degree plus two.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn sample_coloring<G, R>(
+    graph: &G,
+    k: usize,
+    steps: usize,
+    rng: &mut R,
+) -> Result<HashMap<G::Vertex, usize>, GraphError>
+where
+    G: Graph,
+    R: rand::Rng + ?Sized,
+{
+    let indexed = index_graph(graph);
+    check_colors(&indexed.neighbors, &indexed.looped, k)?;
+    let mut colors = greedy_coloring(&indexed.neighbors);
+    if !colors.is_empty() {
+        for _ in 0..steps {
+            coloring_step(&indexed.neighbors, &mut colors, k, rng);
+        }
+    }
+    Ok(indexed.vertices.into_iter().cloned().zip(colors).collect())
+}
+
+/// Samples an independent set from the hard-core model, in which a set `I` has
+/// probability proportional to `fugacity^|I|`, by running the Glauber dynamics
+/// for `steps` steps from the empty set.
+///
+/// A fugacity of one samples independent sets uniformly. The chain mixes in
+/// `O(n log n)` steps when `fugacity < 2 / (Δ - 2)`, where `Δ` is the maximum
+/// degree. Edge directions are ignored, and a vertex with a self-loop is never
+/// selected.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+/// * `fugacity` - The weight of each selected vertex.
+/// * `steps` - The number of single-vertex updates to perform.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The vertices of the sampled independent set.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if `fugacity` is not a positive
+/// finite number.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn sample_independent_set<G, R>(
+    graph: &G,
+    fugacity: f64,
+    steps: usize,
+    rng: &mut R,
+) -> Result<HashSet<G::Vertex>, GraphError>
+where
+    G: Graph,
+    R: rand::Rng + ?Sized,
+{
+    if !(fugacity.is_finite() && fugacity > 0.0) {
+        return Err(AlgorithmError::InvalidArgument(format!(
+            "The fugacity must be positive and finite, got {fugacity}"
+        ))
+        .into());
+    }
+    let indexed = index_graph(graph);
+    let active: Vec<usize> = (0..indexed.vertices.len()).collect();
+    let mut set = vec![false; active.len()];
+    if !active.is_empty() {
+        for _ in 0..steps {
+            hard_core_step(&indexed, &active, &mut set, fugacity, rng);
+        }
+    }
+    Ok(indexed
+        .vertices
+        .iter()
+        .zip(set)
+        .filter(|&(_, selected)| selected)
+        .map(|(&v, _)| v.clone())
+        .collect())
+}
+
+/// Estimates the natural logarithm of the number of proper `k`-colorings with
+/// the Glauber dynamics.
+///
+/// Edges are added one at a time. The fraction of colorings of the graph built
+/// so far that remain proper once the next edge is added is estimated from
+/// `samples` colorings taken `steps` updates apart, and the count is `k^n` times
+/// the product of these fractions. The logarithm is returned because the count
+/// quickly exceeds the range of `f64`. Use [`count_colorings`] for an exact
+/// answer on graphs of small pathwidth.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to color.
+/// * `k` - The number of colors available.
+/// * `samples` - The number of colorings drawn per edge.
+/// * `steps` - The number of updates between consecutive samples.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The estimated logarithm, `f64::NEG_INFINITY` if some fraction was estimated
+/// as zero.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if `samples` is zero, the graph has a
+/// self-loop or `k` is below the maximum degree plus two.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn estimate_log_colorings<G, R>(
+    graph: &G,
+    k: usize,
+    samples: usize,
+    steps: usize,
+    rng: &mut R,
+) -> Result<f64, GraphError>
+where
+    G: Graph,
+    R: rand::Rng + ?Sized,
+{
+    if samples == 0 {
+        return Err(
+            AlgorithmError::InvalidArgument("At least one sample is needed".to_string()).into(),
+        );
+    }
+    let indexed = index_graph(graph);
+    check_colors(&indexed.neighbors, &indexed.looped, k)?;
+    trace_span!(
+        "estimate_log_colorings",
+        vertices = indexed.vertices.len(),
+        k
+    );
+
+    // The greedy coloring of the whole graph is proper for every subgraph.
+    let start = greedy_coloring(&indexed.neighbors);
+    let n = start.len();
+    let mut partial: Vec<Vec<usize>> = vec![Vec::new(); n];
+    #[allow(clippy::cast_precision_loss)]
+    let mut log_count = n as f64 * (k as f64).ln();
+    for u in 0..n {
+        for &v in indexed.neighbors[u].iter().filter(|&&v| u < v) {
+            let mut colors = start.clone();
+            let mut proper = 0usize;
+            for _ in 0..samples {
+                for _ in 0..steps {
+                    coloring_step(&partial, &mut colors, k, rng);
+                }
+                proper += usize::from(colors[u] != colors[v]);
+            }
+            #[allow(clippy::cast_precision_loss)]
+            let fraction = proper as f64 / samples as f64;
+            log_count += fraction.ln();
+            partial[u].push(v);
+            partial[v].push(u);
+        }
+    }
+    Ok(log_count)
+}
+
+/// Estimates the natural logarithm of the number of independent sets with the
+/// Glauber dynamics.
+///
+/// Vertices are deleted one at a time. The probability that the next vertex is
+/// left out of a uniform independent set of the remaining graph is estimated from
+/// `samples` sets taken `steps` updates apart, and the count is the product of
+/// the inverse probabilities. Use [`count_independent_sets`] for an exact answer
+/// on graphs of small pathwidth.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+/// * `samples` - The number of independent sets drawn per vertex.
+/// * `steps` - The number of updates between consecutive samples.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The estimated logarithm, `f64::INFINITY` if some probability was estimated
+/// as zero.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if `samples` is zero.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn estimate_log_independent_sets<G, R>(
+    graph: &G,
+    samples: usize,
+    steps: usize,
+    rng: &mut R,
+) -> Result<f64, GraphError>
+where
+    G: Graph,
+    R: rand::Rng + ?Sized,
+{
+    if samples == 0 {
+        return Err(
+            AlgorithmError::InvalidArgument("At least one sample is needed".to_string()).into(),
+        );
+    }
+    let indexed = index_graph(graph);
+    let n = indexed.vertices.len();
+    trace_span!("estimate_log_independent_sets", vertices = n);
+
+    let mut log_count = 0.0;
+    for first in 0..n {
+        // Deleted vertices stay out of the set, so they never block a neighbor.
+        let active: Vec<usize> = (first..n).collect();
+        let mut set = vec![false; n];
+        let mut excluded = 0usize;
+        for _ in 0..samples {
+            for _ in 0..steps {
+                hard_core_step(&indexed, &active, &mut set, 1.0, rng);
+            }
+            excluded += usize::from(!set[first]);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let probability = excluded as f64 / samples as f64;
+        log_count -= probability.ln();
+    }
+    Ok(log_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GraphMut, SimpleGraph};
+
+    fn path(n: usize) -> SimpleGraph<usize> {
+        let mut graph = SimpleGraph::from_edges((1..n).map(|v| (v - 1, v)), false);
+        if n == 1 {
+            graph.add_vertex(0).unwrap();
+        }
+        graph
+    }
+
+    fn cycle(n: usize) -> SimpleGraph<usize> {
+        SimpleGraph::from_edges((0..n).map(|v| (v, (v + 1) % n)), false)
+    }
+
+    fn petersen() -> SimpleGraph<usize> {
+        let outer = (0..5).map(|v| (v, (v + 1) % 5));
+        let spokes = (0..5).map(|v| (v, v + 5));
+        let inner = (0..5).map(|v| (v + 5, (v + 2) % 5 + 5));
+        SimpleGraph::from_edges(outer.chain(spokes).chain(inner), false)
+    }
+
+    /// Counts the independent sets by enumerating every vertex subset.
+    fn brute_force_independent_sets(graph: &SimpleGraph<usize>) -> u128 {
+        let vertices: Vec<usize> = graph.vertices().copied().collect();
+        let independent = |mask: usize| {
+            vertices.iter().enumerate().all(|(i, u)| {
+                mask & (1 << i) == 0
+                    || vertices
+                        .iter()
+                        .enumerate()
+                        .all(|(j, v)| mask & (1 << j) == 0 || !graph.contains_edge(u, v))
+            })
+        };
+        (0..1usize << vertices.len())
+            .filter(|&mask| independent(mask))
+            .map(|_| 1)
+            .sum()
+    }
+
+    /// Counts the proper colorings by enumerating every map to `k` colors.
+    fn brute_force_colorings(graph: &SimpleGraph<usize>, k: usize) -> u128 {
+        let vertices: Vec<usize> = graph.vertices().copied().collect();
+        let n = u32::try_from(vertices.len()).unwrap();
+        (0..k.pow(n))
+            .filter(|&code| {
+                let color = |i: usize| code / k.pow(u32::try_from(i).unwrap()) % k;
+                vertices.iter().enumerate().all(|(i, u)| {
+                    vertices
+                        .iter()
+                        .enumerate()
+                        .all(|(j, v)| color(i) != color(j) || !graph.contains_edge(u, v))
+                })
+            })
+            .map(|_| 1)
+            .sum()
+    }
+
+    #[test]
+    fn independent_sets_of_paths_are_fibonacci_numbers() {
+        let mut fibonacci = [1u128, 1];
+        for n in 1..=40 {
+            fibonacci = [fibonacci[1], fibonacci[0] + fibonacci[1]];
+            assert_eq!(
+                count_independent_sets(&path(n)).unwrap(),
+                fibonacci[1],
+                "P_{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn independent_sets_of_cycles_are_lucas_numbers() {
+        let mut lucas = [2u128, 1];
+        for n in 2..=40 {
+            lucas = [lucas[1], lucas[0] + lucas[1]];
+            if n >= 3 {
+                assert_eq!(
+                    count_independent_sets(&cycle(n)).unwrap(),
+                    lucas[1],
+                    "C_{n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn colorings_of_cycles_follow_the_chromatic_polynomial() {
+        for n in 3..=12 {
+            for k in 0..=5u32 {
+                let k_minus_one = i128::from(k) - 1;
+                let sign = if n % 2 == 0 { 1 } else { -1 };
+                let expected = k_minus_one.pow(u32::try_from(n).unwrap()) + sign * k_minus_one;
+                assert_eq!(
+                    i128::try_from(count_colorings(&cycle(n), k as usize).unwrap()).unwrap(),
+                    expected,
+                    "P(C_{n}, {k})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn counts_match_brute_force() {
+        let mut mixed = SimpleGraph::from_edges([(0, 1), (1, 2), (2, 0), (3, 1)], true);
+        mixed.add_vertex(4).unwrap();
+        for graph in [petersen(), mixed, path(7), cycle(9)] {
+            assert_eq!(
+                count_independent_sets(&graph).unwrap(),
+                brute_force_independent_sets(&graph)
+            );
+            for k in 0..=3 {
+                assert_eq!(
+                    count_colorings(&graph, k).unwrap(),
+                    brute_force_colorings(&graph, k)
+                );
+            }
+        }
+        assert_eq!(count_independent_sets(&petersen()).unwrap(), 76);
+        assert_eq!(count_colorings(&petersen(), 3).unwrap(), 120);
+    }
+
+    #[test]
+    fn handles_empty_graphs_and_self_loops() {
+        let empty = SimpleGraph::<usize>::new_undirected();
+        assert_eq!(count_independent_sets(&empty).unwrap(), 1);
+        assert_eq!(count_colorings(&empty, 0).unwrap(), 1);
+
+        let looped = SimpleGraph::from_edges([(0, 0), (0, 1)], false);
+        assert_eq!(count_independent_sets(&looped).unwrap(), 2);
+        assert_eq!(count_colorings(&looped, 3).unwrap(), 0);
+    }
+
+    #[test]
+    fn counts_long_ladders() {
+        // The ladder P_2 x P_n has a_n = 2 a_(n-1) + a_(n-2) independent sets.
+        let n = 60;
+        let rungs = (0..n).map(|i| (2 * i, 2 * i + 1));
+        let rails = (1..n).flat_map(|i| [(2 * i - 2, 2 * i), (2 * i - 1, 2 * i + 1)]);
+        let ladder = SimpleGraph::from_edges(rungs.chain(rails), false);
+        let (mut a, mut b) = (1u128, 3u128);
+        for _ in 1..n {
+            (a, b) = (b, 2 * b + a);
+        }
+        assert_eq!(count_independent_sets(&ladder).unwrap(), b);
+    }
+
+    #[test]
+    fn rejects_wide_graphs() {
+        let complete = SimpleGraph::from_edges(
+            (0..20).flat_map(|u| (u + 1..20).map(move |v| (u, v))),
+            false,
+        );
+        assert!(matches!(
+            count_independent_sets(&complete),
+            Err(GraphError::Algorithm(_))
+        ));
+        assert!(matches!(
+            count_colorings(&complete, 20),
+            Err(GraphError::Algorithm(_))
+        ));
+    }
+
+    #[cfg(feature = "rand")]
+    mod sampling {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        use super::*;
+
+        #[test]
+        fn samples_proper_colorings_and_independent_sets() {
+            let graph = petersen();
+            let mut rng = StdRng::seed_from_u64(1);
+            let colors = sample_coloring(&graph, 5, 500, &mut rng).unwrap();
+            assert_eq!(colors.len(), 10);
+            for u in graph.vertices() {
+                assert!(colors[u] < 5);
+                for v in graph.neighbors(u).unwrap() {
+                    assert_ne!(colors[u], colors[v]);
+                }
+            }
+
+            let set = sample_independent_set(&graph, 2.0, 500, &mut rng).unwrap();
+            for u in &set {
+                assert!(graph.neighbors(u).unwrap().all(|v| !set.contains(v)));
+            }
+        }
+
+        #[test]
+        fn estimates_are_close_to_the_exact_counts() {
+            let mut rng = StdRng::seed_from_u64(7);
+            let graph = cycle(6);
+            #[allow(clippy::cast_precision_loss)]
+            let exact = (count_colorings(&graph, 4).unwrap() as f64).ln();
+            let estimate = estimate_log_colorings(&graph, 4, 2000, 30, &mut rng).unwrap();
+            assert!((estimate - exact).abs() < 0.2, "{estimate} vs {exact}");
+
+            #[allow(clippy::cast_precision_loss)]
+            let exact = (count_independent_sets(&graph).unwrap() as f64).ln();
+            let estimate = estimate_log_independent_sets(&graph, 2000, 30, &mut rng).unwrap();
+            assert!((estimate - exact).abs() < 0.2, "{estimate} vs {exact}");
+        }
+
+        #[test]
+        fn rejects_invalid_arguments() {
+            let mut rng = StdRng::seed_from_u64(0);
+            let graph = cycle(4);
+            assert!(sample_coloring(&graph, 3, 10, &mut rng).is_err());
+            assert!(sample_independent_set(&graph, 0.0, 10, &mut rng).is_err());
+            assert!(estimate_log_colorings(&graph, 5, 0, 10, &mut rng).is_err());
+            assert!(estimate_log_independent_sets(&graph, 0, 10, &mut rng).is_err());
+            let looped = SimpleGraph::from_edges([(0, 0)], false);
+            assert!(matches!(
+                sample_coloring(&looped, 5, 10, &mut rng),
+                Err(GraphError::Algorithm(AlgorithmError::SelfLoop))
+            ));
+        }
+    }
+}
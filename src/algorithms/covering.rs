@@ -0,0 +1,317 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{error::AlgorithmError, traits::vertex_weighted::VertexWeightedGraph, GraphError};
+
+/// Reads every vertex weight as a non-negative `f64`.
+fn vertex_costs<G>(graph: &G) -> Result<HashMap<&G::Vertex, f64>, GraphError>
+where
+    G: VertexWeightedGraph,
+    G::VertexWeight: Copy + Into<f64>,
+{
+    graph
+        .vertices()
+        .map(|v| {
+            let weight: f64 = (*graph
+                .vertex_weight(v)
+                .ok_or_else(|| AlgorithmError::missing_vertex_weight(v))?)
+            .into();
+            if weight.is_nan() || weight < 0.0 {
+                return Err(AlgorithmError::invalid_vertex_weight(v, weight).into());
+            }
+            Ok((v, weight))
+        })
+        .collect()
+}
+
+/// Computes a vertex cover of small total weight with the local-ratio algorithm
+/// of Bar-Yehuda and Even.
+///
+/// Every edge pays the smaller residual weight of its endpoints; vertices whose
+/// weight is fully paid form the cover. The result weighs at most twice the
+/// optimum. Edge directions are ignored.
+///
+/// # Returns
+///
+/// A set of vertices touching every edge.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if a vertex has no weight or a negative
+/// or `NaN` weight.
+pub fn min_weight_vertex_cover<G>(graph: &G) -> Result<HashSet<G::Vertex>, GraphError>
+where
+    G: VertexWeightedGraph,
+    G::VertexWeight: Copy + Into<f64>,
+{
+    let mut residual = vertex_costs(graph)?;
+    let mut cover = HashSet::new();
+
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if cover.contains(u) || cover.contains(v) {
+                continue;
+            }
+            let paid = residual[u].min(residual[v]);
+            for w in [u, v] {
+                if let Some(r) = residual.get_mut(w) {
+                    *r -= paid;
+                    if *r <= 0.0 {
+                        cover.insert(w.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(cover)
+}
+
+/// Computes a dominating set of small total weight with the greedy algorithm.
+///
+/// At each step the vertex with the best ratio of newly dominated vertices to
+/// weight is chosen, which gives an `O(log Δ)` approximation. A vertex dominates
+/// itself and its neighbors (its out-neighbors in directed graphs).
+///
+/// # Returns
+///
+/// A set of vertices such that every vertex is in the set or adjacent to it.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if a vertex has no weight or a negative
+/// or `NaN` weight.
+pub fn min_weight_dominating_set<G>(graph: &G) -> Result<HashSet<G::Vertex>, GraphError>
+where
+    G: VertexWeightedGraph,
+    G::VertexWeight: Copy + Into<f64>,
+{
+    let costs = vertex_costs(graph)?;
+    let mut undominated: HashSet<&G::Vertex> = graph.vertices().collect();
+    let mut dominating = HashSet::new();
+
+    while !undominated.is_empty() {
+        let mut best: Option<(&G::Vertex, f64)> = None;
+        for v in graph.vertices() {
+            let gain = graph
+                .neighbors(v)
+                .into_iter()
+                .flatten()
+                .chain(std::iter::once(v))
+                .filter(|w| undominated.contains(w))
+                .count();
+            if gain == 0 {
+                continue;
+            }
+            #[allow(clippy::cast_precision_loss)]
+            let score = gain as f64 / costs[v];
+            if best.is_none_or(|(_, s)| score > s) {
+                best = Some((v, score));
+            }
+        }
+
+        let Some((chosen, _)) = best else { break };
+        undominated.remove(chosen);
+        for w in graph.neighbors(chosen).into_iter().flatten() {
+            undominated.remove(w);
+        }
+        dominating.insert(chosen.clone());
+    }
+
+    Ok(dominating)
+}
+
+/// Computes an independent set of large total weight with the GWMIN greedy
+/// algorithm.
+///
+/// At each step the remaining vertex maximizing `weight / (degree + 1)` is chosen
+/// and its neighborhood is discarded, which guarantees a weight of at least
+/// `Σ w(v) / (deg(v) + 1)`. Edge directions are ignored.
+///
+/// # Returns
+///
+/// A set of pairwise non-adjacent vertices.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if a vertex has no weight or a negative
+/// or `NaN` weight.
+pub fn max_weight_independent_set<G>(graph: &G) -> Result<HashSet<G::Vertex>, GraphError>
+where
+    G: VertexWeightedGraph,
+    G::VertexWeight: Copy + Into<f64>,
+{
+    let costs = vertex_costs(graph)?;
+    let mut adjacency: HashMap<&G::Vertex, HashSet<&G::Vertex>> =
+        graph.vertices().map(|v| (v, HashSet::new())).collect();
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if u != v {
+                adjacency.entry(u).or_default().insert(v);
+                adjacency.entry(v).or_default().insert(u);
+            }
+        }
+    }
+
+    let mut independent = HashSet::new();
+    while !adjacency.is_empty() {
+        let mut best: Option<(&G::Vertex, f64)> = None;
+        for (&v, neighbors) in &adjacency {
+            #[allow(clippy::cast_precision_loss)]
+            let score = costs[v] / (neighbors.len() + 1) as f64;
+            if best.is_none_or(|(_, s)| score > s) {
+                best = Some((v, score));
+            }
+        }
+        let Some((chosen, _)) = best else { break };
+
+        let mut removed: Vec<&G::Vertex> = adjacency[chosen].iter().copied().collect();
+        removed.push(chosen);
+        for w in removed {
+            for x in adjacency.remove(w).unwrap_or_default() {
+                if let Some(neighbors) = adjacency.get_mut(x) {
+                    neighbors.remove(w);
+                }
+            }
+        }
+        independent.insert(chosen.clone());
+    }
+
+    Ok(independent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graphs::vertex_weighted::VertexWeighted, Graph, SimpleGraph};
+
+    type Weighted = VertexWeighted<SimpleGraph<u32>, u32>;
+
+    /// A star whose center weighs `center` and whose five leaves weigh 1.
+    fn star(center: u32) -> Weighted {
+        let graph = SimpleGraph::from_edges((1..=5).map(|v| (0, v)), false);
+        VertexWeighted::with_weights(graph, |&v| if v == 0 { center } else { 1 })
+    }
+
+    /// The Petersen graph with vertex `v` weighing `v % 4 + 1`.
+    fn petersen() -> Weighted {
+        let outer = (0..5).map(|v| (v, (v + 1) % 5));
+        let spokes = (0..5).map(|v| (v, v + 5));
+        let inner = (0..5).map(|v| (v + 5, (v + 2) % 5 + 5));
+        let graph = SimpleGraph::from_edges(outer.chain(spokes).chain(inner), false);
+        VertexWeighted::with_weights(graph, |&v| v % 4 + 1)
+    }
+
+    fn weight(graph: &Weighted, set: &HashSet<u32>) -> u32 {
+        set.iter().map(|v| graph.vertex_weight(v).unwrap()).sum()
+    }
+
+    fn is_cover(graph: &Weighted, set: &HashSet<u32>) -> bool {
+        graph.vertices().all(|u| {
+            graph
+                .neighbors(u)
+                .unwrap()
+                .all(|v| set.contains(u) || set.contains(v))
+        })
+    }
+
+    #[test]
+    fn vertex_cover_avoids_heavy_vertices() {
+        let graph = star(10);
+        let cover = min_weight_vertex_cover(&graph).unwrap();
+        assert_eq!(cover, (1..=5).collect());
+
+        // Ties pay both endpoints, so a leaf may join the light center.
+        let graph = star(1);
+        let cover = min_weight_vertex_cover(&graph).unwrap();
+        assert!(cover.contains(&0));
+        assert!(is_cover(&graph, &cover));
+        assert!(weight(&graph, &cover) <= 2);
+    }
+
+    #[test]
+    fn vertex_cover_is_within_twice_the_optimum() {
+        let graph = petersen();
+        let cover = min_weight_vertex_cover(&graph).unwrap();
+        assert!(is_cover(&graph, &cover));
+        let optimum = (0..1u32 << 10)
+            .map(|mask| (0..10).filter(|v| mask & (1 << v) != 0).collect())
+            .filter(|set| is_cover(&graph, set))
+            .map(|set| weight(&graph, &set))
+            .min()
+            .unwrap();
+        assert!(weight(&graph, &cover) <= 2 * optimum);
+    }
+
+    #[test]
+    fn dominating_set_dominates_every_vertex() {
+        assert_eq!(
+            min_weight_dominating_set(&star(1)).unwrap(),
+            HashSet::from([0])
+        );
+
+        let graph = petersen();
+        let dominating = min_weight_dominating_set(&graph).unwrap();
+        for v in graph.vertices() {
+            assert!(
+                dominating.contains(v)
+                    || graph.neighbors(v).unwrap().any(|w| dominating.contains(w)),
+                "{v} is not dominated"
+            );
+        }
+    }
+
+    #[test]
+    fn independent_set_meets_the_greedy_bound() {
+        assert_eq!(
+            max_weight_independent_set(&star(10)).unwrap(),
+            HashSet::from([0])
+        );
+        assert_eq!(
+            max_weight_independent_set(&star(1)).unwrap(),
+            (1..=5).collect()
+        );
+
+        let graph = petersen();
+        let independent = max_weight_independent_set(&graph).unwrap();
+        for u in &independent {
+            assert!(graph
+                .neighbors(u)
+                .unwrap()
+                .all(|v| !independent.contains(v)));
+        }
+        // Every vertex of the Petersen graph has degree 3.
+        let total: u32 = graph
+            .vertices()
+            .map(|v| graph.vertex_weight(v).unwrap())
+            .sum();
+        assert!(4 * weight(&graph, &independent) >= total);
+    }
+
+    #[test]
+    fn rejects_missing_and_invalid_weights() {
+        let graph = SimpleGraph::from_edges([(0, 1)], false);
+        let unweighted: VertexWeighted<_, u32> = VertexWeighted::new(graph.clone());
+        assert!(matches!(
+            min_weight_vertex_cover(&unweighted),
+            Err(GraphError::Algorithm(
+                AlgorithmError::MissingVertexWeight { .. }
+            ))
+        ));
+
+        let negative = VertexWeighted::with_weights(graph.clone(), |&v| f64::from(v) - 1.0);
+        assert!(matches!(
+            min_weight_dominating_set(&negative),
+            Err(GraphError::Algorithm(
+                AlgorithmError::InvalidVertexWeight { .. }
+            ))
+        ));
+
+        let nan = VertexWeighted::with_weights(graph, |_| f64::NAN);
+        assert!(matches!(
+            max_weight_independent_set(&nan),
+            Err(GraphError::Algorithm(
+                AlgorithmError::InvalidVertexWeight { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,767 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::{
+    algorithms::{
+        cycle_basis::Cycle,
+        heap::MinScored,
+        shortest_path::{checked_weight, unweighted_edge},
+    },
+    error::AlgorithmError,
+    traits::{measure::Measure, weighted::WeightedGraph},
+    Graph, GraphError,
+};
+
+/// A lightest cycle together with its total edge weight, or `None` if there is
+/// no cycle.
+pub type LightestCycle<V, W> = Option<(Cycle<V>, W)>;
+
+/// The vertices settled by [`search`].
+type SettledMap<'a, V, W> = HashMap<&'a V, Settled<'a, V, W>>;
+
+/// A vertex on the current search path and the position of the next out-neighbor
+/// to try.
+#[derive(Debug, Clone)]
+struct Frame {
+    vertex: usize,
+    next: usize,
+    /// Whether a cycle through the root was found below this vertex.
+    found: bool,
+}
+
+/// A lazy iterator over the elementary cycles of a graph, created by
+/// [`elementary_cycles`] or [`elementary_cycles_up_to`].
+///
+/// Cycles are found one at a time, so the iteration can be stopped early even on
+/// graphs with exponentially many cycles.
+#[derive(Debug, Clone)]
+pub struct ElementaryCycles<'a, V> {
+    vertices: Vec<&'a V>,
+    out: Vec<Vec<usize>>,
+    into: Vec<Vec<usize>>,
+    directed: bool,
+    max_length: Option<usize>,
+    /// The least vertex of the cycles currently searched.
+    root: usize,
+    /// The strongly connected component of `root` among the vertices `root..n`.
+    component: Vec<bool>,
+    /// Hops from every vertex of the component back to `root`.
+    distance: Vec<usize>,
+    blocked: Vec<bool>,
+    blocked_by: Vec<Vec<usize>>,
+    frames: Vec<Frame>,
+    path: Vec<usize>,
+}
+
+/// Enumerates the elementary cycles of a graph with Johnson's algorithm.
+///
+/// An elementary cycle visits no vertex twice. Each cycle is yielded once,
+/// starting at its least vertex in the order of [`Graph::vertices`], with a delay
+/// of `O(n + m)` between consecutive cycles. Self-loops are cycles of one vertex.
+/// In an undirected graph an edge is not a cycle of length two and every cycle is
+/// yielded in one direction only.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search.
+///
+/// # Returns
+///
+/// A lazy iterator over the cycles.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::cycles::elementary_cycles, graph};
+///
+/// let graph = graph! { 1 -> 2, 2 -> 3, 3 -> 1, 2 -> 1 };
+/// let mut cycles: Vec<Vec<i32>> = elementary_cycles(&graph).collect();
+/// for cycle in &mut cycles {
+///     let least = cycle.iter().position(|&v| v == 1).unwrap();
+///     cycle.rotate_left(least);
+/// }
+/// cycles.sort();
+/// assert_eq!(cycles, vec![vec![1, 2], vec![1, 2, 3]]);
+/// ```
+#[must_use]
+pub fn elementary_cycles<G>(graph: &G) -> ElementaryCycles<'_, G::Vertex>
+where
+    G: Graph,
+{
+    ElementaryCycles::new(graph, None)
+}
+
+/// Enumerates the elementary cycles with at most `max_length` vertices.
+///
+/// Johnson's blocking rule does not hold once cycles are cut off by length, so
+/// the search instead prunes every path that cannot return to its first vertex
+/// within the limit. This keeps enumeration fast when long cycles are numerous.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search.
+/// * `max_length` - The largest number of vertices (and edges) of a cycle.
+///
+/// # Returns
+///
+/// A lazy iterator over the cycles, as for [`elementary_cycles`].
+#[must_use]
+pub fn elementary_cycles_up_to<G>(graph: &G, max_length: usize) -> ElementaryCycles<'_, G::Vertex>
+where
+    G: Graph,
+{
+    ElementaryCycles::new(graph, Some(max_length))
+}
+
+impl<'a, V> ElementaryCycles<'a, V>
+where
+    V: Eq + std::hash::Hash + Clone,
+{
+    fn new<G>(graph: &'a G, max_length: Option<usize>) -> Self
+    where
+        G: Graph<Vertex = V>,
+    {
+        let vertices: Vec<&V> = graph.vertices().collect();
+        let index: HashMap<&V, usize> = vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        let n = vertices.len();
+        let out: Vec<Vec<usize>> = vertices
+            .iter()
+            .map(|&v| {
+                graph
+                    .neighbors(v)
+                    .into_iter()
+                    .flatten()
+                    .map(|w| index[w])
+                    .collect()
+            })
+            .collect();
+        let mut into = vec![Vec::new(); n];
+        for (u, targets) in out.iter().enumerate() {
+            for &v in targets {
+                into[v].push(u);
+            }
+        }
+        Self {
+            vertices,
+            out,
+            into,
+            directed: graph.is_directed(),
+            max_length,
+            root: 0,
+            component: vec![false; n],
+            distance: vec![usize::MAX; n],
+            blocked: vec![false; n],
+            blocked_by: vec![Vec::new(); n],
+            frames: Vec::new(),
+            path: Vec::new(),
+        }
+    }
+
+    /// Finds the strongly connected component of `root` among the vertices
+    /// `root..n` and the distances back to `root` inside it.
+    ///
+    /// Returns `false` if that component holds no cycle.
+    fn prepare(&mut self, root: usize) -> bool {
+        let n = self.vertices.len();
+        self.component.fill(false);
+        self.distance.fill(usize::MAX);
+        self.distance[root] = 0;
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            for &u in &self.into[v] {
+                if u >= root && self.distance[u] == usize::MAX {
+                    self.distance[u] = self.distance[v] + 1;
+                    queue.push_back(u);
+                }
+            }
+        }
+        self.component[root] = true;
+        let mut stack = vec![root];
+        let mut size = 1;
+        while let Some(u) = stack.pop() {
+            for &v in &self.out[u] {
+                if v >= root && !self.component[v] && self.distance[v] != usize::MAX {
+                    self.component[v] = true;
+                    size += 1;
+                    stack.push(v);
+                }
+            }
+        }
+        if size == 1 && !self.out[root].contains(&root) {
+            return false;
+        }
+        self.blocked[root..n].fill(false);
+        for list in &mut self.blocked_by[root..n] {
+            list.clear();
+        }
+        true
+    }
+
+    /// Unblocks `v` and, transitively, the vertices waiting on it.
+    fn unblock(&mut self, v: usize) {
+        let mut stack = vec![v];
+        while let Some(u) = stack.pop() {
+            if self.blocked[u] {
+                self.blocked[u] = false;
+                stack.append(&mut self.blocked_by[u]);
+            }
+        }
+    }
+
+    /// Checks whether the closed search path should be yielded. Undirected
+    /// cycles are found in both directions, so only one of them is kept.
+    fn accepts(&self) -> bool {
+        match self.path.len() {
+            1 => true,
+            2 => self.directed,
+            len => self.directed || self.path[1] < self.path[len - 1],
+        }
+    }
+}
+
+impl<V> Iterator for ElementaryCycles<'_, V>
+where
+    V: Eq + std::hash::Hash + Clone,
+{
+    type Item = Cycle<V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let limit = self.max_length.unwrap_or(usize::MAX);
+        loop {
+            let Some(frame) = self.frames.last_mut() else {
+                if limit == 0 {
+                    return None;
+                }
+                let root = (self.root..self.vertices.len()).find(|&root| self.prepare(root))?;
+                self.root = root + 1;
+                self.blocked[root] = true;
+                self.frames.push(Frame {
+                    vertex: root,
+                    next: 0,
+                    found: false,
+                });
+                self.path.push(root);
+                continue;
+            };
+            let root = self.path[0];
+            let v = frame.vertex;
+
+            if let Some(&w) = self.out[v].get(frame.next) {
+                frame.next += 1;
+                if !self.component[w] {
+                    continue;
+                }
+                if w == root {
+                    frame.found = true;
+                    if self.accepts() {
+                        return Some(
+                            self.path
+                                .iter()
+                                .map(|&i| self.vertices[i].clone())
+                                .collect(),
+                        );
+                    }
+                } else if !self.blocked[w] && self.path.len() + self.distance[w] <= limit {
+                    self.blocked[w] = true;
+                    self.frames.push(Frame {
+                        vertex: w,
+                        next: 0,
+                        found: false,
+                    });
+                    self.path.push(w);
+                }
+                continue;
+            }
+
+            let found = frame.found;
+            self.frames.pop();
+            self.path.pop();
+            if self.max_length.is_some() {
+                // Without blocking the path itself is the only exclusion.
+                self.blocked[v] = false;
+            } else if found {
+                self.unblock(v);
+            } else {
+                for &w in &self.out[v] {
+                    if self.component[w] && !self.blocked_by[w].contains(&v) {
+                        self.blocked_by[w].push(v);
+                    }
+                }
+            }
+            if let Some(parent) = self.frames.last_mut() {
+                parent.found |= found;
+            }
+        }
+    }
+}
+
+/// A settled vertex of [`search`]: its distance, the previous vertex on its
+/// shortest path and the neighbor of the source that path starts with.
+struct Settled<'a, V, W> {
+    distance: W,
+    parent: Option<&'a V>,
+    branch: &'a V,
+}
+
+/// Runs Dijkstra's algorithm from `source`, never crossing the edge `skip`.
+fn search<'a, G, W, F>(
+    graph: &'a G,
+    source: &'a G::Vertex,
+    skip: Option<(&G::Vertex, &G::Vertex)>,
+    weight: F,
+) -> Result<SettledMap<'a, G::Vertex, W>, GraphError>
+where
+    G: Graph,
+    W: Measure,
+    F: Fn(&G::Vertex, &G::Vertex) -> Result<W, GraphError>,
+{
+    let skipped = |u: &G::Vertex, v: &G::Vertex| {
+        skip.is_some_and(|(a, b)| (a, b) == (u, v) || !graph.is_directed() && (a, b) == (v, u))
+    };
+    let mut settled: SettledMap<'a, G::Vertex, W> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    heap.push(MinScored(W::zero(), (source, None)));
+    while let Some(MinScored(distance, (u, parent))) = heap.pop() {
+        if settled.contains_key(u) {
+            continue;
+        }
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if !settled.contains_key(v) && !skipped(u, v) {
+                let next = distance
+                    .checked_add(&weight(u, v)?)
+                    .ok_or(GraphError::WeightOverflow)?;
+                heap.push(MinScored(next, (v, Some(u))));
+            }
+        }
+        let branch = match parent {
+            None => u,
+            Some(p) if p == source => u,
+            Some(p) => settled[p].branch,
+        };
+        settled.insert(
+            u,
+            Settled {
+                distance,
+                parent,
+                branch,
+            },
+        );
+    }
+    Ok(settled)
+}
+
+/// Returns the vertices of the shortest path from the source to `v`.
+fn path_to<'a, V, W>(settled: &SettledMap<'a, V, W>, mut v: &'a V) -> Cycle<V>
+where
+    V: Eq + std::hash::Hash + Clone,
+{
+    let mut path = vec![v.clone()];
+    while let Some(parent) = settled[v].parent {
+        path.push(parent.clone());
+        v = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Finds the lightest cycle through `v` with the given edge weights.
+fn cycle_through_vertex<G, W, F>(
+    graph: &G,
+    v: &G::Vertex,
+    weight: F,
+) -> Result<LightestCycle<G::Vertex, W>, GraphError>
+where
+    G: Graph,
+    W: Measure,
+    F: Fn(&G::Vertex, &G::Vertex) -> Result<W, GraphError>,
+{
+    let settled = search(graph, v, None, &weight)?;
+    let mut best: Option<(W, &G::Vertex, &G::Vertex)> = None;
+    let mut consider = |total: W, x, y| {
+        if best
+            .as_ref()
+            .is_none_or(|(lightest, _, _)| total.compare(lightest).is_lt())
+        {
+            best = Some((total, x, y));
+        }
+    };
+    if graph.is_directed() {
+        // Close a shortest path from v with an arc back to v.
+        for (&x, reached) in &settled {
+            if graph.contains_edge(x, v) {
+                let total = reached.distance.checked_add(&weight(x, v)?);
+                consider(total.ok_or(GraphError::WeightOverflow)?, x, v);
+            }
+        }
+    } else {
+        if graph.contains_edge(v, v) {
+            consider(weight(v, v)?, v, v);
+        }
+        // Join two shortest paths from v leaving it along different edges.
+        for (&x, first) in &settled {
+            for y in graph.neighbors(x).into_iter().flatten() {
+                let Some(second) = settled.get(y) else {
+                    continue;
+                };
+                let tree_edge = first.parent == Some(y) || second.parent == Some(x);
+                if x == y || y == v || tree_edge || first.branch == second.branch {
+                    continue;
+                }
+                let total = first
+                    .distance
+                    .checked_add(&weight(x, y)?)
+                    .and_then(|total| total.checked_add(&second.distance));
+                consider(total.ok_or(GraphError::WeightOverflow)?, x, y);
+            }
+        }
+    }
+
+    Ok(best.map(|(total, x, y)| {
+        let mut cycle = path_to(&settled, x);
+        if y != v {
+            let mut back = path_to(&settled, y);
+            back.remove(0);
+            cycle.extend(back.into_iter().rev());
+        }
+        (cycle, total)
+    }))
+}
+
+/// Finds the lightest cycle through the edge `(u, v)` with the given edge
+/// weights.
+fn cycle_through_edge<G, W, F>(
+    graph: &G,
+    u: &G::Vertex,
+    v: &G::Vertex,
+    weight: F,
+) -> Result<LightestCycle<G::Vertex, W>, GraphError>
+where
+    G: Graph,
+    W: Measure,
+    F: Fn(&G::Vertex, &G::Vertex) -> Result<W, GraphError>,
+{
+    if !graph.contains_edge(u, v) {
+        return Err(GraphError::EdgeNotFound);
+    }
+    let closing = weight(u, v)?;
+    if u == v {
+        return Ok(Some((vec![u.clone()], closing)));
+    }
+    // Any other path from v back to u closes a cycle with the edge.
+    let settled = search(graph, v, Some((u, v)), &weight)?;
+    let Some(reached) = settled.get(u) else {
+        return Ok(None);
+    };
+    let total = reached
+        .distance
+        .checked_add(&closing)
+        .ok_or(GraphError::WeightOverflow)?;
+    let mut cycle = path_to(&settled, u);
+    cycle.rotate_right(1);
+    Ok(Some((cycle, total)))
+}
+
+/// Finds a shortest cycle through a vertex, counting edges.
+///
+/// This explains why `v` lies on a cycle, for instance which dependencies form
+/// a loop through a given component. In a directed graph the cycle follows the
+/// arcs; in an undirected graph it has at least three vertices, unless `v` has a
+/// self-loop.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search.
+/// * `v` - The vertex the cycle must contain.
+///
+/// # Returns
+///
+/// * `Some(Cycle<V>)` - A cycle with the fewest edges among those through `v`,
+///   starting at `v`.
+/// * `None` - If `v` is on no cycle or is not in the graph.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::cycles::shortest_cycle_through_vertex, graph};
+///
+/// let graph = graph! { 1 -> 2, 2 -> 3, 3 -> 1, 3 -> 4, 4 -> 5, 5 -> 3 };
+/// assert_eq!(shortest_cycle_through_vertex(&graph, &4), Some(vec![4, 5, 3]));
+/// assert_eq!(shortest_cycle_through_vertex(&graph, &6), None);
+/// ```
+#[must_use]
+pub fn shortest_cycle_through_vertex<G>(graph: &G, v: &G::Vertex) -> Option<Cycle<G::Vertex>>
+where
+    G: Graph,
+{
+    if !graph.contains_vertex(v) {
+        return None;
+    }
+    trace_span!("shortest_cycle_through_vertex");
+    let hops = |_: &G::Vertex, _: &G::Vertex| Ok(1usize);
+    cycle_through_vertex(graph, v, hops)
+        .ok()
+        .flatten()
+        .map(|(cycle, _)| cycle)
+}
+
+/// Finds a shortest cycle through the edge `(u, v)`, counting edges.
+///
+/// In an undirected graph the edge itself cannot be used twice, so the cycle has
+/// at least three vertices unless the edge is a self-loop.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search.
+/// * `u` - The source of the edge.
+/// * `v` - The target of the edge.
+///
+/// # Returns
+///
+/// * `Some(Cycle<V>)` - A cycle with the fewest edges among those using `(u, v)`,
+///   starting with `u` and `v`.
+/// * `None` - If the edge is on no cycle or is not in the graph.
+#[must_use]
+pub fn shortest_cycle_through_edge<G>(
+    graph: &G,
+    u: &G::Vertex,
+    v: &G::Vertex,
+) -> Option<Cycle<G::Vertex>>
+where
+    G: Graph,
+{
+    trace_span!("shortest_cycle_through_edge");
+    let hops = |_: &G::Vertex, _: &G::Vertex| Ok(1usize);
+    cycle_through_edge(graph, u, v, hops)
+        .ok()
+        .flatten()
+        .map(|(cycle, _)| cycle)
+}
+
+/// Finds a lightest cycle through a vertex, the weight of a cycle being the sum
+/// of its edge weights.
+///
+/// Shortest paths from `v` are computed with Dijkstra's algorithm, so weights must
+/// be nonnegative. See [`shortest_cycle_through_vertex`] for the unweighted
+/// version.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `v` - The vertex the cycle must contain.
+///
+/// # Returns
+///
+/// * `Some((Cycle<V>, W))` - A lightest cycle through `v`, starting at `v`, and its
+///   weight.
+/// * `None` - If `v` is on no cycle.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `v` is not in the graph.
+/// * `GraphError::WeightOverflow` - If a path weight overflows.
+/// * `GraphError::Algorithm` - If an edge has no weight, a negative weight
+///   or an invalid weight (such as `NaN`).
+pub fn shortest_weighted_cycle_through_vertex<G>(
+    graph: &G,
+    v: &G::Vertex,
+) -> Result<LightestCycle<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    if !graph.contains_vertex(v) {
+        return Err(GraphError::VertexNotFound);
+    }
+    trace_span!("shortest_weighted_cycle_through_vertex");
+    cycle_through_vertex(graph, v, |x, y| checked_weight(graph, x, y).cloned())
+}
+
+/// Finds a lightest cycle through the edge `(u, v)`, the weight of a cycle being
+/// the sum of its edge weights, including the weight of `(u, v)`.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `u` - The source of the edge.
+/// * `v` - The target of the edge.
+///
+/// # Returns
+///
+/// * `Some((Cycle<V>, W))` - A lightest cycle using the edge, starting with `u`
+///   and `v`, and its weight.
+/// * `None` - If the edge is on no cycle.
+///
+/// # Errors
+///
+/// * `GraphError::EdgeNotFound` - If `(u, v)` is not an edge of the graph.
+/// * `GraphError::WeightOverflow` - If a path weight overflows.
+/// * `GraphError::Algorithm` - If an edge has no weight, a negative weight
+///   or an invalid weight (such as `NaN`).
+pub fn shortest_weighted_cycle_through_edge<G>(
+    graph: &G,
+    u: &G::Vertex,
+    v: &G::Vertex,
+) -> Result<LightestCycle<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    trace_span!("shortest_weighted_cycle_through_edge");
+    cycle_through_edge(graph, u, v, |x, y| checked_weight(graph, x, y).cloned())
+}
+
+/// A cycle with the smallest mean edge weight together with that mean, or `None`
+/// if there is no cycle.
+pub type MeanCycle<V> = Option<(Cycle<V>, f64)>;
+
+/// Finds a cycle of a directed weighted graph with the smallest mean weight, the
+/// total weight of its edges divided by their number.
+///
+/// This is Karp's algorithm: the lightest walks of exactly `k` edges ending at
+/// every vertex are computed for `k` up to `n`, and the minimum mean is the
+/// smallest over the vertices of the largest `(D_n(v) - D_k(v)) / (n - k)`. The
+/// cycle is read from the walk of `n` edges attaining it. It runs in `O(n m)`
+/// time and `O(n^2)` space, and allows negative weights. In timed systems the
+/// maximum cycle mean, obtained by negating the weights, bounds the throughput.
+///
+/// # Arguments
+///
+/// * `graph` - The directed weighted graph.
+///
+/// # Returns
+///
+/// * `Some((Cycle<V>, f64))` - A cycle with the smallest mean weight, and its
+///   mean.
+/// * `None` - If the graph is acyclic.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is undirected, or if an
+/// edge has no weight or a `NaN` weight.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::cycles::minimum_mean_cycle, graph};
+///
+/// // The cycle 1 -> 2 -> 1 has mean 3, the cycle 2 -> 3 -> 4 -> 2 has mean 2.
+/// let graph = graph! { 1 -> 2 [weight = 2.0], 2 -> 1 [weight = 4.0], 2 -> 3 [weight = 1.0],
+///                      3 -> 4 [weight = 2.0], 4 -> 2 [weight = 3.0] };
+/// let (cycle, mean) = minimum_mean_cycle(&graph).unwrap().unwrap();
+/// assert_eq!(mean, 2.0);
+/// assert_eq!(cycle.len(), 3);
+/// ```
+pub fn minimum_mean_cycle<G>(graph: &G) -> Result<MeanCycle<G::Vertex>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+{
+    if !graph.is_directed() {
+        return Err(AlgorithmError::RequiresDirected.into());
+    }
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let mut arcs = Vec::new();
+    for (u, v, &weight) in graph.weighted_edges() {
+        let weight: f64 = weight.into();
+        if weight.is_nan() {
+            return Err(AlgorithmError::invalid_weight(u, v, weight).into());
+        }
+        arcs.push((index[u], index[v], weight));
+    }
+    if arcs.len() < graph.size() {
+        return Err(unweighted_edge(graph));
+    }
+    let n = vertices.len();
+    trace_span!("minimum_mean_cycle", vertices = n, arcs = arcs.len());
+
+    // Lightest walks of exactly `k` arcs ending at every vertex, starting
+    // anywhere, and the arc each one ends with.
+    let mut walk = vec![vec![0.0; n]];
+    let mut last = vec![vec![usize::MAX; n]];
+    for k in 1..=n {
+        let mut lengths = vec![f64::INFINITY; n];
+        let mut arcs_in = vec![usize::MAX; n];
+        for (a, &(u, v, weight)) in arcs.iter().enumerate() {
+            let length = walk[k - 1][u] + weight;
+            if length < lengths[v] {
+                lengths[v] = length;
+                arcs_in[v] = a;
+            }
+        }
+        walk.push(lengths);
+        last.push(arcs_in);
+    }
+
+    let mut best: Option<(f64, usize)> = None;
+    for (v, &total) in walk[n].iter().enumerate() {
+        if total.is_infinite() {
+            continue;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let worst = (0..n)
+            .filter(|&k| walk[k][v].is_finite())
+            .map(|k| (total - walk[k][v]) / (n - k) as f64)
+            .fold(f64::NEG_INFINITY, f64::max);
+        if best.is_none_or(|(mean, _)| worst < mean) {
+            best = Some((worst, v));
+        }
+    }
+    let Some((_, end)) = best else {
+        trace_event!("graph is acyclic");
+        return Ok(None);
+    };
+
+    // The walk of `n` arcs repeats a vertex; split it into cycles and keep the
+    // one with the smallest mean, which guards against rounding in the choice
+    // of the end vertex.
+    let mut stack: Vec<(usize, f64)> = Vec::new();
+    let mut position = vec![usize::MAX; n];
+    let mut found: Option<(Vec<usize>, f64)> = None;
+    let mut v = end;
+    for k in (1..=n).rev() {
+        let (u, _, weight) = arcs[last[k][v]];
+        if position[v] == usize::MAX {
+            position[v] = stack.len();
+            stack.push((v, weight));
+        } else {
+            let start = position[v];
+            let cycle: Vec<(usize, f64)> = stack.drain(start..).collect();
+            for &(w, _) in &cycle {
+                position[w] = usize::MAX;
+            }
+            consider(&mut found, cycle);
+            position[v] = stack.len();
+            stack.push((v, weight));
+        }
+        v = u;
+    }
+    if position[v] != usize::MAX {
+        let start = position[v];
+        consider(&mut found, stack.drain(start..).collect());
+    }
+    let Some((cycle, mean)) = found else {
+        return Ok(None);
+    };
+    trace_event!(length = cycle.len(), mean, "cycle found");
+    // The cycle was collected walking backwards along the arcs.
+    Ok(Some((
+        cycle
+            .into_iter()
+            .rev()
+            .map(|i| vertices[i].clone())
+            .collect(),
+        mean,
+    )))
+}
+
+/// Keeps `cycle`, a list of vertices each with the weight of the arc entering
+/// it, if its mean is smaller than the one of `found`.
+fn consider(found: &mut Option<(Vec<usize>, f64)>, cycle: Vec<(usize, f64)>) {
+    let total: f64 = cycle.iter().map(|&(_, weight)| weight).sum();
+    #[allow(clippy::cast_precision_loss)]
+    let mean = total / cycle.len() as f64;
+    if found.as_ref().is_none_or(|&(_, best)| mean < best) {
+        *found = Some((cycle.into_iter().map(|(v, _)| v).collect(), mean));
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::{
+        budget::{Budget, Partial},
+        traversal::bfs_distances,
+    },
+    Graph,
+};
+
+/// Hop distances between every pair of mutually reachable vertices, indexed by
+/// source and then by target.
+pub type DistanceMatrix<V> = HashMap<V, HashMap<V, usize>>;
+
+/// Computes the eccentricity of a vertex, the greatest hop distance from it to
+/// any other vertex of the graph.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+/// * `v` - The vertex whose eccentricity is computed.
+///
+/// # Returns
+///
+/// * `Some(usize)` - The eccentricity of `v`.
+/// * `None` - If `v` is not in the graph or some vertex cannot be reached from
+///   `v` (the eccentricity is infinite).
+pub fn eccentricity<G>(graph: &G, v: &G::Vertex) -> Option<usize>
+where
+    G: Graph,
+{
+    let distances = bfs_distances(graph, v)?;
+    if distances.len() < graph.order() {
+        return None;
+    }
+    distances.into_values().max()
+}
+
+/// Computes the diameter of the graph, the maximum eccentricity of its vertices.
+///
+/// # Returns
+///
+/// * `Some(usize)` - The diameter of the graph.
+/// * `None` - If the graph is empty or disconnected (not strongly connected for
+///   directed graphs).
+pub fn diameter<G>(graph: &G) -> Option<usize>
+where
+    G: Graph,
+{
+    trace_span!("diameter");
+    graph
+        .vertices()
+        .map(|v| eccentricity(graph, v))
+        .try_fold(None, |acc: Option<usize>, e| {
+            e.map(|e| Some(acc.map_or(e, |a| a.max(e))))
+        })?
+}
+
+/// Computes the radius of the graph, the minimum eccentricity of its vertices.
+///
+/// # Returns
+///
+/// * `Some(usize)` - The radius of the graph.
+/// * `None` - If the graph is empty or disconnected (not strongly connected for
+///   directed graphs).
+pub fn radius<G>(graph: &G) -> Option<usize>
+where
+    G: Graph,
+{
+    trace_span!("radius");
+    graph
+        .vertices()
+        .map(|v| eccentricity(graph, v))
+        .try_fold(None, |acc: Option<usize>, e| {
+            e.map(|e| Some(acc.map_or(e, |a| a.min(e))))
+        })?
+}
+
+/// Returns the center of the graph, the vertices whose eccentricity equals the radius.
+///
+/// # Returns
+///
+/// A `Vec` with the central vertices (cloned). The result is empty if the graph is
+/// empty or disconnected, since every eccentricity is infinite in that case.
+pub fn center<G>(graph: &G) -> Vec<G::Vertex>
+where
+    G: Graph,
+{
+    trace_span!("center");
+    let mut eccentricities = Vec::with_capacity(graph.order());
+    for v in graph.vertices() {
+        match eccentricity(graph, v) {
+            Some(e) => eccentricities.push((v, e)),
+            None => return Vec::new(),
+        }
+    }
+
+    let Some(radius) = eccentricities.iter().map(|&(_, e)| e).min() else {
+        return Vec::new();
+    };
+
+    eccentricities
+        .into_iter()
+        .filter(|&(_, e)| e == radius)
+        .map(|(v, _)| v.clone())
+        .collect()
+}
+
+/// Computes the hop distance between every pair of vertices with one
+/// breadth-first search per vertex.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+///
+/// # Returns
+///
+/// For every vertex, the distances to the vertices reachable from it.
+#[must_use]
+pub fn all_pairs_distances<G>(graph: &G) -> DistanceMatrix<G::Vertex>
+where
+    G: Graph,
+{
+    all_pairs_distances_within(graph, &Budget::unlimited()).value
+}
+
+/// Computes all-pairs hop distances until the computation finishes or `budget`
+/// is exhausted.
+///
+/// Sources are processed one at a time, so a partial result holds the exact and
+/// complete distance rows of the sources processed so far and no row for the
+/// others.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+/// * `budget` - Limits how long the computation may run.
+///
+/// # Returns
+///
+/// The distance rows computed, marked incomplete if the budget ran out first.
+#[must_use]
+pub fn all_pairs_distances_within<G>(
+    graph: &G,
+    budget: &Budget,
+) -> Partial<DistanceMatrix<G::Vertex>>
+where
+    G: Graph,
+{
+    trace_span!("all_pairs_distances");
+    let mut rows = HashMap::new();
+    for v in graph.vertices() {
+        if budget.is_exhausted() {
+            trace_event!(sources = rows.len(), "budget exhausted");
+            return Partial {
+                value: rows,
+                complete: false,
+            };
+        }
+        if let Some(distances) = bfs_distances(graph, v) {
+            rows.insert(v.clone(), distances);
+        }
+    }
+    Partial {
+        value: rows,
+        complete: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::AtomicBool, Arc};
+
+    use super::*;
+    use crate::{GraphMut, SimpleGraph};
+
+    fn path(n: u32) -> SimpleGraph<u32> {
+        SimpleGraph::from_edges((1..n).map(|v| (v - 1, v)), false)
+    }
+
+    #[test]
+    fn measures_paths() {
+        let graph = path(5);
+        assert_eq!(eccentricity(&graph, &0), Some(4));
+        assert_eq!(eccentricity(&graph, &2), Some(2));
+        assert_eq!(eccentricity(&graph, &9), None);
+        assert_eq!(diameter(&graph), Some(4));
+        assert_eq!(radius(&graph), Some(2));
+        assert_eq!(center(&graph), [2]);
+
+        let mut center = center(&path(4));
+        center.sort_unstable();
+        assert_eq!(center, [1, 2]);
+    }
+
+    #[test]
+    fn measures_directed_cycles() {
+        let cycle = SimpleGraph::from_edges([(0, 1), (1, 2), (2, 0)], true);
+        assert_eq!(eccentricity(&cycle, &0), Some(2));
+        assert_eq!(diameter(&cycle), Some(2));
+        assert_eq!(radius(&cycle), Some(2));
+        assert_eq!(center(&cycle).len(), 3);
+
+        // A directed path is connected but not strongly connected.
+        let path = SimpleGraph::from_edges([(0, 1), (1, 2)], true);
+        assert_eq!(eccentricity(&path, &0), Some(2));
+        assert_eq!(eccentricity(&path, &2), None);
+        assert_eq!(diameter(&path), None);
+    }
+
+    #[test]
+    fn disconnected_and_empty_graphs_have_no_measures() {
+        let mut graph = path(3);
+        graph.add_vertex(7).unwrap();
+        assert_eq!(eccentricity(&graph, &0), None);
+        assert_eq!(diameter(&graph), None);
+        assert_eq!(radius(&graph), None);
+        assert!(center(&graph).is_empty());
+
+        let empty = SimpleGraph::<u32>::new_undirected();
+        assert_eq!(diameter(&empty), None);
+        assert_eq!(radius(&empty), None);
+        assert!(center(&empty).is_empty());
+    }
+
+    #[test]
+    fn computes_all_pairs_distances() {
+        let mut graph = path(3);
+        graph.add_vertex(7).unwrap();
+        let distances = all_pairs_distances(&graph);
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&0], HashMap::from([(0, 0), (1, 1), (2, 2)]));
+        assert_eq!(distances[&1], HashMap::from([(0, 1), (1, 0), (2, 1)]));
+        assert_eq!(distances[&7], HashMap::from([(7, 0)]));
+    }
+
+    #[test]
+    fn stops_all_pairs_distances_when_the_budget_runs_out() {
+        let graph = path(4);
+        let complete = all_pairs_distances_within(&graph, &Budget::unlimited());
+        assert!(complete.complete);
+        assert_eq!(complete.value, all_pairs_distances(&graph));
+
+        let budget = Budget::unlimited().with_cancel_flag(Arc::new(AtomicBool::new(true)));
+        let partial = all_pairs_distances_within(&graph, &budget);
+        assert!(!partial.complete);
+        assert!(partial.value.is_empty());
+        assert_eq!(partial.into_complete(), None);
+    }
+}
@@ -0,0 +1,653 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
+};
+
+use crate::{
+    error::AlgorithmError,
+    traits::{
+        capacitated::CapacitatedGraph,
+        measure::{Measure, Weight},
+        weighted::{WeightedEdge, WeightedGraph},
+    },
+    Graph, GraphError,
+};
+
+/// The value of a flow and the amount it sends along every edge that carries
+/// some.
+pub type Flow<V, W> = (W, Vec<WeightedEdge<V, W>>);
+
+/// The capacity of a cut and the vertices on the side of the source.
+pub type Cut<V, W> = (W, Vec<V>);
+
+/// A flow network, solved with Dinic's algorithm.
+pub(crate) struct FlowNetwork<W = usize> {
+    /// Arcs leaving every node, as indices into `head` and `capacity`.
+    arcs: Vec<Vec<usize>>,
+    /// Target of every arc; arc `i ^ 1` is the reverse of arc `i`.
+    pub(crate) head: Vec<usize>,
+    /// Residual capacity of every arc.
+    capacity: Vec<W>,
+}
+
+impl<W> FlowNetwork<W>
+where
+    W: Weight,
+{
+    pub(crate) fn new(nodes: usize) -> Self {
+        Self {
+            arcs: vec![Vec::new(); nodes],
+            head: Vec::new(),
+            capacity: Vec::new(),
+        }
+    }
+
+    /// Adds an arc and its reverse, returning the index of the arc.
+    pub(crate) fn add_arc(&mut self, from: usize, to: usize, capacity: W) -> usize {
+        let arc = self.head.len();
+        self.arcs[from].push(arc);
+        self.head.push(to);
+        self.capacity.push(capacity);
+        self.arcs[to].push(arc + 1);
+        self.head.push(from);
+        self.capacity.push(W::zero());
+        arc
+    }
+
+    /// Returns the flow sent along an arc.
+    pub(crate) fn flow(&self, arc: usize) -> W {
+        self.capacity[arc ^ 1].clone()
+    }
+
+    /// Removes an arc and the flow on it from the residual network.
+    pub(crate) fn disable(&mut self, arc: usize) {
+        self.capacity[arc] = W::zero();
+        self.capacity[arc ^ 1] = W::zero();
+    }
+
+    /// Sends as much flow as possible from `source` to `sink`, returning the
+    /// amount sent, or [`Weight::infinity`] if it reaches or exceeds it.
+    pub(crate) fn max_flow(&mut self, source: usize, sink: usize) -> W {
+        let mut total = W::zero();
+        loop {
+            let Some(level) = self.levels(source, sink) else {
+                return total;
+            };
+            let mut next = vec![0; self.arcs.len()];
+            loop {
+                let sent = self.augment(source, sink, &level, &mut next);
+                if !is_positive(&sent) {
+                    break;
+                }
+                match total.checked_add(&sent) {
+                    Some(sum) if sum.compare(&W::infinity()) == Ordering::Less => total = sum,
+                    _ => return W::infinity(),
+                }
+            }
+        }
+    }
+
+    /// Returns the nodes reachable from `source` in the residual network.
+    pub(crate) fn reachable(&self, source: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.arcs.len()];
+        seen[source] = true;
+        let mut stack = vec![source];
+        while let Some(u) = stack.pop() {
+            for &arc in &self.arcs[u] {
+                let v = self.head[arc];
+                if is_positive(&self.capacity[arc]) && !seen[v] {
+                    seen[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Computes the breadth-first levels of the residual network, or `None` if
+    /// the sink is unreachable.
+    fn levels(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        let mut level = vec![usize::MAX; self.arcs.len()];
+        level[source] = 0;
+        let mut queue = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            for &arc in &self.arcs[u] {
+                let v = self.head[arc];
+                if is_positive(&self.capacity[arc]) && level[v] == usize::MAX {
+                    level[v] = level[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        (level[sink] != usize::MAX).then_some(level)
+    }
+
+    /// Pushes as much flow as possible along one path of increasing levels,
+    /// returning the amount sent.
+    ///
+    /// The path is grown with an explicit stack of `(vertex, arc)` frames, so
+    /// long paths cannot overflow the call stack. `next[u]` skips the arcs of `u`
+    /// already known to lead to dead ends.
+    fn augment(&mut self, source: usize, sink: usize, level: &[usize], next: &mut [usize]) -> W {
+        let mut path: Vec<(usize, usize)> = Vec::new();
+        let mut u = source;
+        while u != sink {
+            let mut advanced = false;
+            while next[u] < self.arcs[u].len() {
+                let arc = self.arcs[u][next[u]];
+                let v = self.head[arc];
+                if is_positive(&self.capacity[arc]) && level[v] == level[u] + 1 {
+                    path.push((u, arc));
+                    u = v;
+                    advanced = true;
+                    break;
+                }
+                next[u] += 1;
+            }
+            if !advanced {
+                // Dead end: retreat and skip the arc that led here.
+                let Some((previous, _)) = path.pop() else {
+                    return W::zero();
+                };
+                u = previous;
+                next[u] += 1;
+            }
+        }
+
+        let mut sent = W::infinity();
+        for &(_, arc) in &path {
+            if self.capacity[arc].compare(&sent) == Ordering::Less {
+                sent = self.capacity[arc].clone();
+            }
+        }
+        for &(_, arc) in &path {
+            // `sent` is at most the residual capacity, and the reverse arc only
+            // grows up to the capacity of the arc.
+            if let Some(rest) = self.capacity[arc].checked_sub(&sent) {
+                self.capacity[arc] = rest;
+            }
+            self.capacity[arc ^ 1] = self.capacity[arc ^ 1].saturating_add(&sent);
+        }
+        sent
+    }
+}
+
+fn is_positive<W: Weight>(amount: &W) -> bool {
+    amount.compare(&W::zero()) == Ordering::Greater
+}
+
+/// The flow network of a weighted graph, with its vertices numbered.
+struct Instance<'a, V, W> {
+    network: FlowNetwork<W>,
+    vertices: Vec<&'a V>,
+    /// The arc added for every edge, in both directions for undirected edges.
+    arcs: Vec<usize>,
+    source: usize,
+    sink: usize,
+}
+
+/// Builds the flow network of a graph, reading the capacity of every edge with
+/// `capacity`.
+fn instance<'a, G, W, F>(
+    graph: &'a G,
+    source: &G::Vertex,
+    sink: &G::Vertex,
+    capacity: F,
+) -> Result<Instance<'a, G::Vertex, W>, GraphError>
+where
+    G: Graph,
+    W: Weight,
+    F: Fn(&G::Vertex, &G::Vertex) -> Option<W>,
+{
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let (Some(&s), Some(&t)) = (index.get(source), index.get(sink)) else {
+        return Err(GraphError::VertexNotFound);
+    };
+    if s == t {
+        return Err(AlgorithmError::InvalidArgument(
+            "The source and the sink of a flow must differ".to_string(),
+        )
+        .into());
+    }
+    let mut network = FlowNetwork::new(vertices.len());
+    let mut arcs = Vec::new();
+    for (i, &u) in vertices.iter().enumerate() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            let j = index[v];
+            // Undirected edges are listed from both ends; each end adds one arc.
+            if i == j {
+                continue;
+            }
+            let capacity = capacity(u, v).ok_or_else(|| AlgorithmError::missing_capacity(u, v))?;
+            if !capacity.is_valid() || capacity.is_negative() {
+                return Err(AlgorithmError::invalid_capacity(u, v, capacity).into());
+            }
+            arcs.push(network.add_arc(i, j, capacity));
+        }
+    }
+    Ok(Instance {
+        network,
+        vertices,
+        arcs,
+        source: s,
+        sink: t,
+    })
+}
+
+/// Computes a maximum flow of a network and the amount it sends along every
+/// edge.
+fn solve_flow<V, W>(instance: Instance<'_, V, W>, directed: bool) -> Result<Flow<V, W>, GraphError>
+where
+    V: Clone,
+    W: Weight,
+{
+    let Instance {
+        mut network,
+        vertices,
+        arcs,
+        source,
+        sink,
+    } = instance;
+    let value = network.max_flow(source, sink);
+    if value.compare(&W::infinity()) != Ordering::Less {
+        return Err(GraphError::WeightOverflow);
+    }
+
+    let mut flows = Vec::new();
+    let mut sent: HashMap<(usize, usize), W> = HashMap::new();
+    for arc in arcs {
+        let (u, v) = (network.head[arc ^ 1], network.head[arc]);
+        let flow = network.flow(arc);
+        if directed {
+            if is_positive(&flow) {
+                flows.push((vertices[u].clone(), vertices[v].clone(), flow));
+            }
+            continue;
+        }
+        // The two arcs of an undirected edge may both carry flow; only the
+        // difference is reported.
+        let Some(back) = sent.remove(&(v, u)) else {
+            sent.insert((u, v), flow);
+            continue;
+        };
+        let (from, to, net) = match flow.compare(&back) {
+            Ordering::Greater => (u, v, flow.checked_sub(&back)),
+            Ordering::Less => (v, u, back.checked_sub(&flow)),
+            Ordering::Equal => continue,
+        };
+        if let Some(net) = net {
+            flows.push((vertices[from].clone(), vertices[to].clone(), net));
+        }
+    }
+    trace_event!(edges = flows.len(), "flow found");
+    Ok((value, flows))
+}
+
+/// Computes a minimum cut of a network and the vertices on the side of its
+/// source.
+fn solve_cut<V, W>(instance: Instance<'_, V, W>) -> Result<Cut<V, W>, GraphError>
+where
+    V: Clone,
+    W: Weight,
+{
+    let Instance {
+        mut network,
+        vertices,
+        source,
+        sink,
+        ..
+    } = instance;
+    let value = network.max_flow(source, sink);
+    if value.compare(&W::infinity()) != Ordering::Less {
+        return Err(GraphError::WeightOverflow);
+    }
+    let side = network
+        .reachable(source)
+        .into_iter()
+        .zip(vertices)
+        .filter(|&(reached, _)| reached)
+        .map(|(_, v)| v.clone())
+        .collect();
+    Ok((value, side))
+}
+
+/// Computes a maximum flow from `source` to `sink`, with the edge weights as
+/// capacities.
+///
+/// This is Dinic's algorithm, which runs in `O(n^2 m)` time and works for any
+/// [`Weight`], so integer, fixed-point and floating-point capacities are all
+/// handled exactly as their arithmetic allows. An undirected edge can carry flow
+/// in either direction up to its capacity. Self-loops are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The network, with capacities as edge weights.
+/// * `source` - The vertex the flow leaves.
+/// * `sink` - The vertex the flow enters.
+///
+/// # Returns
+///
+/// The value of the flow and the edges that carry some, with the amount and in
+/// the direction it is sent.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` if `source` or `sink` is not in the graph.
+/// * `GraphError::Algorithm` if `source` equals `sink` or an edge has no
+///   weight or a negative or invalid one.
+/// * `GraphError::WeightOverflow` if the value of the flow reaches
+///   [`Weight::infinity`], such as when a path of infinite capacities joins the
+///   source to the sink.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::flow::maximum_flow, graph};
+///
+/// // From 1 to 4 through 2 and 3, with a shortcut from 2 to 3.
+/// let network = graph! { 1 -> 2 [weight = 3u32], 1 -> 3 [weight = 2u32], 2 -> 3 [weight = 1u32],
+///                        2 -> 4 [weight = 2u32], 3 -> 4 [weight = 3u32] };
+/// let (value, flows) = maximum_flow(&network, &1, &4).unwrap();
+/// assert_eq!(value, 5);
+/// assert!(flows.contains(&(2, 3, 1)));
+/// ```
+pub fn maximum_flow<G>(
+    graph: &G,
+    source: &G::Vertex,
+    sink: &G::Vertex,
+) -> Result<Flow<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Weight,
+{
+    let instance = instance(graph, source, sink, |u, v| graph.edge_weight(u, v).cloned())?;
+    trace_span!("maximum_flow", arcs = instance.arcs.len());
+    solve_flow(instance, graph.is_directed())
+}
+
+/// Computes a minimum cut separating `source` from `sink`, with the edge weights
+/// as capacities.
+///
+/// By the max-flow min-cut theorem its capacity equals the value of a
+/// [`maximum_flow`]; the source side is the set of vertices still reachable from
+/// the source in the residual network of that flow.
+///
+/// # Arguments
+///
+/// * `graph` - The network, with capacities as edge weights.
+/// * `source` - The vertex on the first side.
+/// * `sink` - The vertex on the other side.
+///
+/// # Returns
+///
+/// The total capacity of the edges leaving the source side, and the vertices on
+/// that side, `source` included.
+///
+/// # Errors
+///
+/// The same as [`maximum_flow`].
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::flow::minimum_cut, graph};
+///
+/// let network = graph! { 1 - 2 [weight = 5.0], 2 - 3 [weight = 1.5], 3 - 4 [weight = 4.0] };
+/// let (capacity, side) = minimum_cut(&network, &1, &4).unwrap();
+/// assert_eq!(capacity, 1.5);
+/// assert_eq!(side.len(), 2);
+/// ```
+pub fn minimum_cut<G>(
+    graph: &G,
+    source: &G::Vertex,
+    sink: &G::Vertex,
+) -> Result<Cut<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Weight,
+{
+    let instance = instance(graph, source, sink, |u, v| graph.edge_weight(u, v).cloned())?;
+    trace_span!("minimum_cut", vertices = instance.vertices.len());
+    solve_cut(instance)
+}
+
+/// Computes a maximum flow from `source` to `sink` through a network whose
+/// edges carry capacities apart from their weights.
+///
+/// This is [`maximum_flow`] reading the capacities through
+/// [`CapacitatedGraph`], so the weights of the network, such as costs or
+/// lengths, stay available to other algorithms. Every edge needs a capacity.
+///
+/// # Arguments
+///
+/// * `graph` - The network, with capacities on its edges.
+/// * `source` - The vertex the flow leaves.
+/// * `sink` - The vertex the flow enters.
+///
+/// # Returns
+///
+/// The value of the flow and the edges that carry some, with the amount and in
+/// the direction it is sent.
+///
+/// # Errors
+///
+/// The same as [`maximum_flow`], with capacities in place of weights.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::flow::capacitated_maximum_flow, graph, graphs::capacitated::Capacitated,
+/// };
+///
+/// let pipes = graph! { 1 -> 2, 2 -> 4, 1 -> 3, 3 -> 4 };
+/// let network = Capacitated::with_capacities(pipes, |&u, &v| if u == 1 || v == 1 { 5 } else { 2 });
+/// let (value, _) = capacitated_maximum_flow(&network, &1, &4).unwrap();
+/// assert_eq!(value, 4);
+/// ```
+pub fn capacitated_maximum_flow<G>(
+    graph: &G,
+    source: &G::Vertex,
+    sink: &G::Vertex,
+) -> Result<Flow<G::Vertex, G::Capacity>, GraphError>
+where
+    G: CapacitatedGraph,
+    G::Capacity: Weight,
+{
+    let instance = instance(graph, source, sink, |u, v| graph.capacity(u, v).cloned())?;
+    trace_span!("capacitated_maximum_flow", arcs = instance.arcs.len());
+    solve_flow(instance, graph.is_directed())
+}
+
+/// Computes a minimum cut separating `source` from `sink` in a network whose
+/// edges carry capacities apart from their weights.
+///
+/// This is [`minimum_cut`] reading the capacities through
+/// [`CapacitatedGraph`].
+///
+/// # Returns
+///
+/// The total capacity of the edges leaving the source side, and the vertices on
+/// that side, `source` included.
+///
+/// # Errors
+///
+/// The same as [`maximum_flow`], with capacities in place of weights.
+pub fn capacitated_minimum_cut<G>(
+    graph: &G,
+    source: &G::Vertex,
+    sink: &G::Vertex,
+) -> Result<Cut<G::Vertex, G::Capacity>, GraphError>
+where
+    G: CapacitatedGraph,
+    G::Capacity: Weight,
+{
+    let instance = instance(graph, source, sink, |u, v| graph.capacity(u, v).cloned())?;
+    trace_span!(
+        "capacitated_minimum_cut",
+        vertices = instance.vertices.len()
+    );
+    solve_cut(instance)
+}
+
+/// Computes a global minimum cut of an undirected graph, the lightest set of
+/// edges whose removal disconnects it, with the edge weights as capacities.
+///
+/// This is the Stoer–Wagner algorithm, which needs no source or sink: it runs
+/// `n - 1` maximum adjacency searches, each finding the minimum cut between the
+/// last two vertices it visits before merging them. It takes `O(n^3)` time and
+/// `O(n^2)` memory, so it suits graphs with up to a few thousand vertices. The
+/// capacity of the cut is the edge connectivity of the graph when every weight
+/// is one, and zero when the graph is disconnected. Self-loops are ignored.
+///
+/// # Returns
+///
+/// The total capacity of the edges crossing the cut, and the vertices on one of
+/// its sides.
+///
+/// # Errors
+///
+/// * `GraphError::Algorithm` if the graph is directed, has fewer than two
+///   vertices, or an edge has no weight or a negative or invalid one.
+/// * `GraphError::WeightOverflow` if a sum of capacities overflows.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::flow::global_minimum_cut, graph};
+///
+/// // Two triangles of heavy links joined by two light ones.
+/// let network = graph! { 1 - 2 [weight = 3u32], 2 - 3 [weight = 3u32], 3 - 1 [weight = 3u32],
+///                        4 - 5 [weight = 3u32], 5 - 6 [weight = 3u32], 6 - 4 [weight = 3u32],
+///                        1 - 4 [weight = 1u32], 3 - 6 [weight = 2u32] };
+/// let (capacity, mut side) = global_minimum_cut(&network).unwrap();
+/// side.sort_unstable();
+/// assert_eq!(capacity, 3);
+/// assert!(side == [1, 2, 3] || side == [4, 5, 6]);
+/// ```
+pub fn global_minimum_cut<G>(graph: &G) -> Result<Cut<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    if graph.is_directed() {
+        return Err(AlgorithmError::RequiresUndirected.into());
+    }
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let n = vertices.len();
+    if n < 2 {
+        return Err(AlgorithmError::InvalidArgument(
+            "A cut needs at least two vertices".to_string(),
+        )
+        .into());
+    }
+    trace_span!("global_minimum_cut", vertices = n);
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let mut capacity = vec![vec![G::Weight::zero(); n]; n];
+    for (i, &u) in vertices.iter().enumerate() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            let j = index[v];
+            if i == j {
+                continue;
+            }
+            let weight = graph
+                .edge_weight(u, v)
+                .ok_or_else(|| AlgorithmError::missing_capacity(u, v))?;
+            if !weight.is_valid() || weight.is_negative() {
+                return Err(AlgorithmError::invalid_capacity(u, v, weight).into());
+            }
+            capacity[i][j] = weight.clone();
+        }
+    }
+
+    // Every node stands for the original vertices merged into it.
+    let mut members: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best: Option<G::Weight> = None;
+    let mut best_side = Vec::new();
+    while active.len() > 1 {
+        // Maximum adjacency search: repeatedly visit the node most tightly
+        // connected to the visited ones.
+        let mut connection = vec![G::Weight::zero(); n];
+        let mut visited = vec![false; n];
+        let (mut previous, mut last) = (active[0], active[0]);
+        for _ in 0..active.len() {
+            let mut next = None;
+            for &j in active.iter().filter(|&&j| !visited[j]) {
+                if next.is_none_or(|k: usize| {
+                    connection[j].compare(&connection[k]) == Ordering::Greater
+                }) {
+                    next = Some(j);
+                }
+            }
+            let Some(next) = next else { break };
+            visited[next] = true;
+            (previous, last) = (last, next);
+            for &j in active.iter().filter(|&&j| !visited[j]) {
+                connection[j] = connection[j]
+                    .checked_add(&capacity[next][j])
+                    .ok_or(GraphError::WeightOverflow)?;
+            }
+        }
+
+        // The cut of the phase separates the last node from all the others.
+        let cut = active
+            .iter()
+            .filter(|&&j| j != last)
+            .try_fold(G::Weight::zero(), |sum, &j| {
+                sum.checked_add(&capacity[last][j])
+            })
+            .ok_or(GraphError::WeightOverflow)?;
+        if best
+            .as_ref()
+            .is_none_or(|weight| cut.compare(weight) == Ordering::Less)
+        {
+            best_side.clone_from(&members[last]);
+            best = Some(cut);
+        }
+
+        // Merge the last node into the one visited before it.
+        for &j in &active {
+            let merged = capacity[previous][j]
+                .checked_add(&capacity[last][j])
+                .ok_or(GraphError::WeightOverflow)?;
+            capacity[previous][j] = merged.clone();
+            capacity[j][previous] = merged;
+        }
+        capacity[previous][previous] = G::Weight::zero();
+        let moved = std::mem::take(&mut members[last]);
+        members[previous].extend(moved);
+        active.retain(|&j| j != last);
+    }
+
+    let weight = best.unwrap_or_else(G::Weight::zero);
+    let side = best_side.into_iter().map(|i| vertices[i].clone()).collect();
+    Ok((weight, side))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::weighted::WeightedGraphMut, GraphMut, SimpleGraph};
+
+    #[test]
+    fn maximum_flow_on_a_long_path_does_not_overflow_the_stack() {
+        let n = 100_000;
+        let mut graph = SimpleGraph::new_weighted_directed();
+        for v in 0..n {
+            graph.add_vertex(v).unwrap();
+        }
+        for v in 1..n {
+            let capacity = if v == n / 2 { 3 } else { 7 };
+            graph.add_weighted_edge(&(v - 1), &v, capacity).unwrap();
+        }
+
+        let (value, flow) = maximum_flow(&graph, &0, &(n - 1)).unwrap();
+        assert_eq!(value, 3);
+        assert_eq!(flow.len(), n - 1);
+        assert!(flow.iter().all(|(_, _, amount)| *amount == 3));
+    }
+}
@@ -0,0 +1,28 @@
+use std::cmp::Ordering;
+
+use crate::traits::measure::Measure;
+
+/// A priority queue entry ordered by its measure in reverse, turning
+/// [`std::collections::BinaryHeap`] into a min-heap over any [`Measure`].
+#[derive(Debug, Clone)]
+pub(crate) struct MinScored<W, T>(pub W, pub T);
+
+impl<W: Measure, T> PartialEq for MinScored<W, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: Measure, T> Eq for MinScored<W, T> {}
+
+impl<W: Measure, T> PartialOrd for MinScored<W, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Measure, T> Ord for MinScored<W, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.compare(&self.0)
+    }
+}
@@ -0,0 +1,548 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+use crate::{
+    algorithms::{
+        adjacency::{undirected_adjacency, IndexedGraph},
+        clustering::{average_clustering, triangles},
+        coloring::{chromatic_number, greedy_clique, greedy_colors},
+        core::degeneracy,
+        distance::{diameter, radius},
+    },
+    error::AlgorithmError,
+    Graph, GraphError,
+};
+
+/// The value of a graph invariant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvariantValue {
+    /// An integer invariant, such as the order or the girth.
+    Integer(i64),
+    /// A real invariant, such as the average degree or the spectral radius.
+    Real(f64),
+    /// A property that holds or not, such as connectivity.
+    Boolean(bool),
+    /// The invariant is not defined for this graph, like the diameter of a
+    /// disconnected graph, or too expensive to compute exactly.
+    Undefined,
+}
+
+impl InvariantValue {
+    /// Returns the value as a number, with booleans mapped to `0.0` and `1.0`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The numeric value.
+    /// * `None` - If the value is [`InvariantValue::Undefined`].
+    #[must_use]
+    pub fn as_f64(self) -> Option<f64> {
+        match self {
+            // Invariants of graphs that fit in memory are far below 2^52.
+            #[allow(clippy::cast_precision_loss)]
+            InvariantValue::Integer(n) => Some(n as f64),
+            InvariantValue::Real(x) => Some(x),
+            InvariantValue::Boolean(b) => Some(f64::from(u8::from(b))),
+            InvariantValue::Undefined => None,
+        }
+    }
+}
+
+impl From<usize> for InvariantValue {
+    fn from(n: usize) -> Self {
+        i64::try_from(n).map_or(InvariantValue::Undefined, InvariantValue::Integer)
+    }
+}
+
+impl From<Option<usize>> for InvariantValue {
+    fn from(n: Option<usize>) -> Self {
+        n.map_or(InvariantValue::Undefined, InvariantValue::from)
+    }
+}
+
+impl From<f64> for InvariantValue {
+    fn from(x: f64) -> Self {
+        InvariantValue::Real(x)
+    }
+}
+
+impl From<Option<f64>> for InvariantValue {
+    fn from(x: Option<f64>) -> Self {
+        x.map_or(InvariantValue::Undefined, InvariantValue::Real)
+    }
+}
+
+impl From<bool> for InvariantValue {
+    fn from(b: bool) -> Self {
+        InvariantValue::Boolean(b)
+    }
+}
+
+/// A function computing one invariant of a graph.
+pub type InvariantFn<'a, G> = Box<dyn Fn(&G) -> InvariantValue + Send + Sync + 'a>;
+
+/// Invariant values indexed by name.
+pub type InvariantMap = BTreeMap<String, InvariantValue>;
+
+/// A set of named graph invariants that can be evaluated together.
+///
+/// [`InvariantRegistry::with_builtins`] (also the [`Default`]) registers the
+/// invariants below. Unless noted, they are computed on the underlying simple
+/// undirected graph, ignoring edge directions and self-loops.
+///
+/// | Name | Value |
+/// |------|-------|
+/// | `order` | Number of vertices. |
+/// | `size` | Number of edges, as reported by [`Graph::edge_count`]. |
+/// | `min_degree`, `max_degree` | Smallest and largest degree. |
+/// | `average_degree` | Mean degree. |
+/// | `density` | Fraction of vertex pairs that are adjacent. |
+/// | `components` | Number of connected components. |
+/// | `is_connected` | Whether there is exactly one component. |
+/// | `diameter`, `radius` | See [`diameter`] and [`radius`]; directions are respected. |
+/// | `girth` | Length of a shortest cycle. |
+/// | `triangles` | Number of triangles. |
+/// | `average_clustering` | See [`average_clustering`]. |
+/// | `degeneracy` | Largest core number. |
+/// | `clique_lower_bound` | Size of a greedily found clique. |
+/// | `chromatic_upper_bound` | Colors used by a largest-degree-first greedy coloring. |
+/// | `chromatic_number` | See [`chromatic_number`]; undefined for large graphs. |
+/// | `independence_lower_bound` | The Caro–Wei bound `⌈Σ 1 / (d(v) + 1)⌉`. |
+/// | `domination_lower_bound` | `⌈n / (Δ + 1)⌉`. |
+/// | `domination_upper_bound` | Size of a greedily found dominating set. |
+///
+/// With the `linalg` feature, `spectral_radius`, `energy` and
+/// `algebraic_connectivity` from [`spectral`](crate::algorithms::spectral) are
+/// registered as well.
+///
+/// Custom invariants are added with [`InvariantRegistry::register`]:
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::invariants::{InvariantRegistry, InvariantValue},
+///     graph, Graph, SimpleGraph,
+/// };
+///
+/// let mut registry = InvariantRegistry::with_builtins();
+/// registry.register("has_loop", |g: &SimpleGraph<u32>| {
+///     g.vertices().any(|v| g.contains_edge(v, v)).into()
+/// });
+///
+/// let g = graph! { 1 - 2, 2 - 3, 3 - 1 };
+/// let values = registry.compute(&g, &["girth", "has_loop"]).unwrap();
+/// assert_eq!(values["girth"], InvariantValue::Integer(3));
+/// assert_eq!(values["has_loop"], InvariantValue::Boolean(false));
+/// ```
+///
+/// Registered functions may borrow data for `'a`, such as a graph of vertices
+/// borrowed from a buffer.
+pub struct InvariantRegistry<'a, G> {
+    invariants: BTreeMap<String, InvariantFn<'a, G>>,
+}
+
+impl<'a, G> InvariantRegistry<'a, G>
+where
+    G: Graph + 'a,
+{
+    /// Creates a registry without invariants.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            invariants: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a registry holding the built-in invariants.
+    #[must_use]
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register("order", |g: &G| g.order().into())
+            .register("size", |g: &G| g.size().into())
+            .register("min_degree", |g: &G| degrees(g).into_iter().min().into())
+            .register("max_degree", |g: &G| degrees(g).into_iter().max().into())
+            .register("average_degree", average_degree::<G>)
+            .register("density", density::<G>)
+            .register("components", |g: &G| components(g).into())
+            .register("is_connected", |g: &G| (components(g) == 1).into())
+            .register("diameter", |g: &G| diameter(g).into())
+            .register("radius", |g: &G| radius(g).into())
+            .register("girth", |g: &G| girth(g).into())
+            .register("triangles", |g: &G| {
+                (triangles(g).into_values().sum::<usize>() / 3).into()
+            })
+            .register("average_clustering", |g: &G| average_clustering(g).into())
+            .register("degeneracy", |g: &G| degeneracy(g).into())
+            .register("clique_lower_bound", |g: &G| {
+                greedy_clique(&index_neighbors(g)).into()
+            })
+            .register("chromatic_upper_bound", |g: &G| {
+                greedy_colors(&index_neighbors(g)).into()
+            })
+            .register("chromatic_number", |g: &G| chromatic_number(g).ok().into())
+            .register("independence_lower_bound", caro_wei::<G>)
+            .register("domination_lower_bound", |g: &G| {
+                let max_degree = degrees(g).into_iter().max().unwrap_or(0);
+                g.order().div_ceil(max_degree + 1).into()
+            })
+            .register("domination_upper_bound", |g: &G| {
+                greedy_dominating_set(g).into()
+            });
+
+        #[cfg(feature = "linalg")]
+        {
+            use crate::algorithms::spectral::{algebraic_connectivity, energy, spectral_radius};
+            registry
+                .register("spectral_radius", |g: &G| spectral_radius(g).into())
+                .register("energy", |g: &G| energy(g).into())
+                .register("algebraic_connectivity", |g: &G| {
+                    algebraic_connectivity(g).into()
+                });
+        }
+        registry
+    }
+
+    /// Registers an invariant, replacing any invariant with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name used to request the invariant.
+    /// * `invariant` - Computes the invariant of a graph.
+    ///
+    /// # Returns
+    ///
+    /// The registry, so that calls can be chained.
+    pub fn register<F>(&mut self, name: impl Into<String>, invariant: F) -> &mut Self
+    where
+        F: Fn(&G) -> InvariantValue + Send + Sync + 'a,
+    {
+        self.invariants.insert(name.into(), Box::new(invariant));
+        self
+    }
+
+    /// Checks if an invariant is registered under `name`.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.invariants.contains_key(name)
+    }
+
+    /// Returns the names of the registered invariants in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.invariants.keys().map(String::as_str)
+    }
+
+    /// Computes the requested invariants of a graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to inspect.
+    /// * `names` - The names of the invariants to compute.
+    ///
+    /// # Returns
+    ///
+    /// The value of every requested invariant, indexed by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` if a name is not registered. No
+    /// invariant is computed in that case.
+    pub fn compute(&self, graph: &G, names: &[&str]) -> Result<InvariantMap, GraphError> {
+        if let Some(unknown) = names.iter().find(|name| !self.contains(name)) {
+            return Err(AlgorithmError::UnknownInvariant((*unknown).to_string()).into());
+        }
+        trace_span!("compute_invariants", count = names.len());
+        Ok(names
+            .iter()
+            .map(|&name| (name.to_string(), self.invariants[name](graph)))
+            .collect())
+    }
+
+    /// Computes every registered invariant of a graph.
+    #[must_use]
+    pub fn compute_all(&self, graph: &G) -> InvariantMap {
+        trace_span!("compute_invariants", count = self.invariants.len());
+        self.invariants
+            .iter()
+            .map(|(name, invariant)| (name.clone(), invariant(graph)))
+            .collect()
+    }
+}
+
+impl<'a, G> Default for InvariantRegistry<'a, G>
+where
+    G: Graph + 'a,
+{
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+/// Computes built-in invariants of a graph by name; see [`InvariantRegistry`]
+/// for the available names.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if a name is not a built-in invariant.
+pub fn compute_invariants<G>(graph: &G, names: &[&str]) -> Result<InvariantMap, GraphError>
+where
+    G: Graph,
+{
+    InvariantRegistry::with_builtins().compute(graph, names)
+}
+
+/// The largest order for which [`stats`] computes the diameter, which takes one
+/// breadth-first search per vertex.
+pub const STATS_DIAMETER_LIMIT: usize = 1_000;
+
+/// A summary of the basic metrics of a graph, as computed by [`stats`].
+///
+/// Degrees, the density, the components and the clustering ignore edge
+/// directions and self-loops. Its [`Display`](fmt::Display) implementation
+/// prints one metric per line, with `-` for undefined metrics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GraphStats {
+    /// The number of vertices.
+    pub order: usize,
+    /// The number of edges.
+    pub size: usize,
+    /// The fraction of vertex pairs that are adjacent, or `None` for graphs
+    /// with fewer than two vertices.
+    pub density: Option<f64>,
+    /// The smallest degree, or `None` for the empty graph.
+    pub min_degree: Option<usize>,
+    /// The largest degree, or `None` for the empty graph.
+    pub max_degree: Option<usize>,
+    /// The mean degree, or `None` for the empty graph.
+    pub mean_degree: Option<f64>,
+    /// The number of connected components.
+    pub components: usize,
+    /// The average local clustering coefficient, or `None` for the empty graph.
+    pub clustering: Option<f64>,
+    /// The diameter, or `None` if the graph is empty, disconnected (not
+    /// strongly connected for directed graphs) or larger than
+    /// [`STATS_DIAMETER_LIMIT`].
+    pub diameter: Option<usize>,
+}
+
+impl fmt::Display for GraphStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn line<T: fmt::Display>(
+            f: &mut fmt::Formatter<'_>,
+            name: &str,
+            value: Option<T>,
+        ) -> fmt::Result {
+            match value {
+                Some(value) => writeln!(f, "{name:<12}{value}"),
+                None => writeln!(f, "{name:<12}-"),
+            }
+        }
+        let rounded = |value: Option<f64>| value.map(|x| format!("{x:.4}"));
+        line(f, "order", Some(self.order))?;
+        line(f, "size", Some(self.size))?;
+        line(f, "density", rounded(self.density))?;
+        line(f, "min degree", self.min_degree)?;
+        line(f, "max degree", self.max_degree)?;
+        line(f, "mean degree", rounded(self.mean_degree))?;
+        line(f, "components", Some(self.components))?;
+        line(f, "clustering", rounded(self.clustering))?;
+        line(f, "diameter", self.diameter)
+    }
+}
+
+/// Computes the [`GraphStats`] summary of a graph in one call.
+///
+/// The diameter is only computed for graphs of at most
+/// [`STATS_DIAMETER_LIMIT`] vertices.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::invariants::stats, graph};
+///
+/// let summary = stats(&graph! { 1 - 2, 2 - 3, 3 - 1, 3 - 4 });
+/// assert_eq!(summary.order, 4);
+/// assert_eq!(summary.size, 4);
+/// assert_eq!(summary.density, Some(4.0 / 6.0));
+/// assert_eq!((summary.min_degree, summary.max_degree), (Some(1), Some(3)));
+/// assert_eq!(summary.mean_degree, Some(2.0));
+/// assert_eq!(summary.components, 1);
+/// assert_eq!(summary.diameter, Some(2));
+/// assert!(summary.to_string().starts_with("order       4\nsize        4\n"));
+/// ```
+#[must_use]
+pub fn stats<G>(graph: &G) -> GraphStats
+where
+    G: Graph,
+{
+    trace_span!("stats");
+    let degrees = degrees(graph);
+    let n = degrees.len();
+    let total: usize = degrees.iter().sum();
+    #[allow(clippy::cast_precision_loss)]
+    GraphStats {
+        order: graph.order(),
+        size: graph.size(),
+        density: (n >= 2).then(|| total as f64 / (n * (n - 1)) as f64),
+        min_degree: degrees.iter().copied().min(),
+        max_degree: degrees.iter().copied().max(),
+        mean_degree: (n > 0).then(|| total as f64 / n as f64),
+        components: components(graph),
+        clustering: average_clustering(graph),
+        diameter: if n <= STATS_DIAMETER_LIMIT {
+            diameter(graph)
+        } else {
+            None
+        },
+    }
+}
+
+fn degrees<G>(graph: &G) -> Vec<usize>
+where
+    G: Graph,
+{
+    undirected_adjacency(graph)
+        .into_values()
+        .map(|neighbors| neighbors.len())
+        .collect()
+}
+
+fn average_degree<G>(graph: &G) -> InvariantValue
+where
+    G: Graph,
+{
+    let degrees = degrees(graph);
+    if degrees.is_empty() {
+        return InvariantValue::Undefined;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let average = degrees.iter().sum::<usize>() as f64 / degrees.len() as f64;
+    average.into()
+}
+
+fn density<G>(graph: &G) -> InvariantValue
+where
+    G: Graph,
+{
+    let degrees = degrees(graph);
+    let n = degrees.len();
+    if n < 2 {
+        return InvariantValue::Undefined;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let density = degrees.iter().sum::<usize>() as f64 / (n * (n - 1)) as f64;
+    density.into()
+}
+
+fn caro_wei<G>(graph: &G) -> InvariantValue
+where
+    G: Graph,
+{
+    #[allow(clippy::cast_precision_loss)]
+    let bound: f64 = degrees(graph)
+        .into_iter()
+        .map(|d| 1.0 / (d + 1) as f64)
+        .sum();
+    // The independence number is an integer at least `bound`; the tolerance keeps
+    // rounding errors from pushing an exact integer sum up by one.
+    #[allow(clippy::cast_possible_truncation)]
+    let bound = (bound - 1e-9).ceil().max(0.0) as i64;
+    InvariantValue::Integer(bound)
+}
+
+fn components<G>(graph: &G) -> usize
+where
+    G: Graph,
+{
+    let adjacency = undirected_adjacency(graph);
+    let mut seen: HashSet<&G::Vertex> = HashSet::with_capacity(adjacency.len());
+    let mut count = 0;
+    for &start in adjacency.keys() {
+        if !seen.insert(start) {
+            continue;
+        }
+        count += 1;
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            for &w in &adjacency[u] {
+                if seen.insert(w) {
+                    stack.push(w);
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Returns the length of a shortest cycle, found with one breadth-first search
+/// per vertex.
+fn girth<G>(graph: &G) -> Option<usize>
+where
+    G: Graph,
+{
+    let adjacency = undirected_adjacency(graph);
+    let mut best: Option<usize> = None;
+    for &source in adjacency.keys() {
+        let mut depth: HashMap<&G::Vertex, (usize, &G::Vertex)> = HashMap::new();
+        depth.insert(source, (0, source));
+        let mut queue = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            let (d, parent) = depth[u];
+            // Longer cycles cannot improve on the best one found.
+            if best.is_some_and(|b| 2 * d + 1 >= b) {
+                break;
+            }
+            for &w in &adjacency[u] {
+                match depth.get(w) {
+                    None => {
+                        depth.insert(w, (d + 1, u));
+                        queue.push_back(w);
+                    }
+                    Some(&(e, _)) if w != parent => {
+                        let length = d + e + 1;
+                        best = Some(best.map_or(length, |b| b.min(length)));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Returns sorted, symmetric neighbor lists of `graph` over vertex indices.
+fn index_neighbors<G>(graph: &G) -> Vec<Vec<usize>>
+where
+    G: Graph,
+{
+    IndexedGraph::new(graph).symmetric(graph.is_directed())
+}
+
+/// Returns the size of a dominating set built by repeatedly taking the vertex
+/// that dominates the most undominated vertices.
+fn greedy_dominating_set<G>(graph: &G) -> usize
+where
+    G: Graph,
+{
+    let adjacency = undirected_adjacency(graph);
+    let mut undominated: HashSet<&G::Vertex> = adjacency.keys().copied().collect();
+    let mut size = 0;
+    while !undominated.is_empty() {
+        let gain = |v: &G::Vertex| {
+            usize::from(undominated.contains(v))
+                + adjacency[v]
+                    .iter()
+                    .filter(|w| undominated.contains(*w))
+                    .count()
+        };
+        let Some(&best) = adjacency.keys().max_by_key(|&&v| gain(v)) else {
+            break;
+        };
+        undominated.remove(best);
+        for w in &adjacency[best] {
+            undominated.remove(w);
+        }
+        size += 1;
+    }
+    size
+}
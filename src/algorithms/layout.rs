@@ -0,0 +1,425 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    f64::consts::TAU,
+};
+
+use crate::{algorithms::adjacency::undirected_adjacency, Graph};
+
+/// The position of every vertex in the plane, as `(x, y)` coordinates.
+pub type Layout<V> = HashMap<V, (f64, f64)>;
+
+/// Number of barycenter sweeps, each down and then up the layers, ordering the
+/// vertices of a [`hierarchical_layout`].
+const ORDERING_SWEEPS: usize = 8;
+
+/// Places the vertices evenly on the unit circle centered at the origin, in the
+/// order of [`Graph::vertices`].
+///
+/// # Returns
+///
+/// The position of every vertex; a single vertex sits at `(1, 0)`.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::layout::circular_layout, graph};
+///
+/// let layout = circular_layout(&graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 1 });
+/// assert_eq!(layout.len(), 4);
+/// for &(x, y) in layout.values() {
+///     assert!((x.hypot(y) - 1.0).abs() < 1e-9);
+/// }
+/// ```
+#[must_use]
+pub fn circular_layout<G>(graph: &G) -> Layout<G::Vertex>
+where
+    G: Graph,
+{
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    circle(&vertices)
+        .into_iter()
+        .zip(vertices)
+        .map(|(position, v)| (v.clone(), position))
+        .collect()
+}
+
+/// Computes a force-directed layout with the Fruchterman–Reingold algorithm.
+///
+/// Every pair of vertices repels with a force `k^2 / d` and the endpoints of
+/// every edge attract with a force `d^2 / k`, where `d` is their distance and
+/// `k` the ideal edge length for `n` vertices spread over the square
+/// `[-1, 1] x [-1, 1]`. Starting from the unit circle, with the vertices in
+/// breadth-first order so that neighbors start close, each iteration moves
+/// every vertex along its net force by at most a temperature that cools
+/// linearly to zero. The result is then centered and scaled to span the square.
+/// Iterations take `O(n^2 + m)` time, and a few hundred usually settle graphs of
+/// up to a few thousand vertices. Edge directions and self-loops are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to draw.
+/// * `iterations` - The number of iterations; `0` returns the starting circle.
+///
+/// # Returns
+///
+/// The position of every vertex, inside the square `[-1, 1] x [-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::layout::force_directed_layout, graph};
+///
+/// // Two triangles joined by a bridge end up side by side.
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 4 - 5, 5 - 6, 6 - 4, 3 - 4 };
+/// let layout = force_directed_layout(&graph, 200);
+/// let distance = |u, v| {
+///     let ((x1, y1), (x2, y2)) = (layout[&u], layout[&v]);
+///     (x1 - x2).hypot(y1 - y2)
+/// };
+/// assert!(distance(1, 2) < distance(1, 6));
+/// assert!(layout.values().all(|&(x, y)| x.abs() <= 1.0 && y.abs() <= 1.0));
+/// ```
+#[must_use]
+pub fn force_directed_layout<G>(graph: &G, iterations: usize) -> Layout<G::Vertex>
+where
+    G: Graph,
+{
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let n = vertices.len();
+    trace_span!("force_directed_layout", vertices = n, iterations);
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let adjacency = undirected_adjacency(graph);
+    let edges: Vec<(usize, usize)> = vertices
+        .iter()
+        .enumerate()
+        .flat_map(|(i, v)| adjacency[v].iter().map(move |w| (i, w)))
+        .map(|(i, w)| (i, index[w]))
+        .filter(|&(i, j)| i < j)
+        .collect();
+
+    // Vertices start on the circle in breadth-first order, so neighbors start
+    // close together and the layout does not settle twisted.
+    let mut neighbors = vec![Vec::new(); n];
+    for &(i, j) in &edges {
+        neighbors[i].push(j);
+        neighbors[j].push(i);
+    }
+    let mut order = Vec::with_capacity(n);
+    let mut seen = vec![false; n];
+    for root in 0..n {
+        if seen[root] {
+            continue;
+        }
+        seen[root] = true;
+        let mut queue = VecDeque::from([root]);
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            for &j in &neighbors[i] {
+                if !seen[j] {
+                    seen[j] = true;
+                    queue.push_back(j);
+                }
+            }
+        }
+    }
+    let mut positions = vec![(0.0, 0.0); n];
+    for (position, i) in circle(&order).into_iter().zip(&order) {
+        positions[*i] = position;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let (ideal, steps) = ((4.0 / n.max(1) as f64).sqrt(), iterations as f64);
+    let initial_temperature = 0.2;
+    for iteration in 0..iterations {
+        let mut displacement = vec![(0.0, 0.0); n];
+        for i in 0..n {
+            for j in i + 1..n {
+                let (dx, dy) = (
+                    positions[i].0 - positions[j].0,
+                    positions[i].1 - positions[j].1,
+                );
+                // Coinciding vertices are pushed apart along a fixed direction.
+                let distance = dx.hypot(dy);
+                let (ux, uy, distance) = if distance > 0.0 {
+                    (dx / distance, dy / distance, distance)
+                } else {
+                    (1.0, 0.0, f64::EPSILON)
+                };
+                let force = ideal * ideal / distance;
+                displacement[i].0 += ux * force;
+                displacement[i].1 += uy * force;
+                displacement[j].0 -= ux * force;
+                displacement[j].1 -= uy * force;
+            }
+        }
+        for &(i, j) in &edges {
+            let (dx, dy) = (
+                positions[i].0 - positions[j].0,
+                positions[i].1 - positions[j].1,
+            );
+            // The attraction d^2 / k along the unit vector (dx, dy) / d.
+            let scale = dx.hypot(dy) / ideal;
+            displacement[i].0 -= dx * scale;
+            displacement[i].1 -= dy * scale;
+            displacement[j].0 += dx * scale;
+            displacement[j].1 += dy * scale;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let temperature = initial_temperature * (1.0 - iteration as f64 / steps);
+        for (position, (dx, dy)) in positions.iter_mut().zip(displacement) {
+            let length = dx.hypot(dy);
+            if length > 0.0 {
+                let step = length.min(temperature) / length;
+                position.0 += dx * step;
+                position.1 += dy * step;
+            }
+        }
+    }
+
+    fit_square(&mut positions);
+    positions
+        .into_iter()
+        .zip(vertices)
+        .map(|(position, v)| (v.clone(), position))
+        .collect()
+}
+
+/// Computes a layered drawing of a graph in the style of Sugiyama, for flows,
+/// dependencies and hierarchies.
+///
+/// In a directed graph the arcs closing cycles, found by depth-first search,
+/// are reversed, and every vertex is placed on the layer one below its deepest
+/// predecessor, so every other arc points down. In an undirected graph the
+/// layer of a vertex is its breadth-first depth from a vertex of highest degree
+/// in its component. The vertices of every layer are then ordered to reduce edge
+/// crossings by repeatedly sorting them by the mean position of their neighbors
+/// in the layer above, then below. This is a lighter version of the full
+/// method: long edges get no dummy vertices, so the crossings they cause are
+/// only partly considered, and the vertices of a layer are evenly spaced.
+/// Self-loops are ignored.
+///
+/// # Returns
+///
+/// The position of every vertex: `y` is its layer, `0` for the top layer and
+/// growing downwards, and `x` its rank in the layer, one unit apart and
+/// centered on `x = 0`.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::layout::hierarchical_layout, graph};
+///
+/// let build = graph! { 1 -> 2, 1 -> 3, 2 -> 4, 3 -> 4, 4 -> 5, 1 -> 5 };
+/// let layout = hierarchical_layout(&build);
+/// assert_eq!(layout[&1].1, 0.0);
+/// assert_eq!(layout[&4].1, 2.0);
+/// assert_eq!(layout[&5].1, 3.0);
+/// assert_eq!(layout[&2].0, -layout[&3].0);
+/// ```
+#[must_use]
+pub fn hierarchical_layout<G>(graph: &G) -> Layout<G::Vertex>
+where
+    G: Graph,
+{
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let n = vertices.len();
+    trace_span!("hierarchical_layout", vertices = n);
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let successors: Vec<Vec<usize>> = vertices
+        .iter()
+        .map(|&u| {
+            graph
+                .neighbors(u)
+                .into_iter()
+                .flatten()
+                .map(|v| index[v])
+                .collect()
+        })
+        .collect();
+    let layer = if graph.is_directed() {
+        longest_path_layers(&successors)
+    } else {
+        breadth_first_layers(&successors)
+    };
+
+    let depth = layer.iter().max().map_or(0, |&deepest| deepest + 1);
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); depth];
+    for (v, &l) in layer.iter().enumerate() {
+        layers[l].push(v);
+    }
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (u, targets) in successors.iter().enumerate() {
+        for &v in targets.iter().filter(|&&v| v != u) {
+            neighbors[u].push(v);
+            neighbors[v].push(u);
+        }
+    }
+
+    let mut rank = vec![0; n];
+    let rerank = |layer: &[usize], rank: &mut Vec<usize>| {
+        for (r, &v) in layer.iter().enumerate() {
+            rank[v] = r;
+        }
+    };
+    for current in &layers {
+        rerank(current, &mut rank);
+    }
+    for sweep in 0..2 * ORDERING_SWEEPS {
+        let downwards = sweep % 2 == 0;
+        let order: Vec<usize> = if downwards {
+            (1..depth).collect()
+        } else {
+            (0..depth.saturating_sub(1)).rev().collect()
+        };
+        for l in order {
+            let fixed = if downwards { l - 1 } else { l + 1 };
+            #[allow(clippy::cast_precision_loss)]
+            let barycenter = |v: usize| {
+                let ranks: Vec<usize> = neighbors[v]
+                    .iter()
+                    .filter(|&&w| layer[w] == fixed)
+                    .map(|&w| rank[w])
+                    .collect();
+                if ranks.is_empty() {
+                    rank[v] as f64
+                } else {
+                    ranks.iter().sum::<usize>() as f64 / ranks.len() as f64
+                }
+            };
+            let mut keyed: Vec<(f64, usize)> =
+                layers[l].iter().map(|&v| (barycenter(v), v)).collect();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            layers[l] = keyed.into_iter().map(|(_, v)| v).collect();
+            rerank(&layers[l], &mut rank);
+        }
+    }
+
+    let mut layout = Layout::with_capacity(n);
+    for (l, current) in layers.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let offset = (current.len() as f64 - 1.0) / 2.0;
+        for (r, &v) in current.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            layout.insert(vertices[v].clone(), (r as f64 - offset, l as f64));
+        }
+    }
+    layout
+}
+
+/// Centers the positions on the origin and scales them uniformly so that they
+/// span the square `[-1, 1] x [-1, 1]`.
+fn fit_square(positions: &mut [(f64, f64)]) {
+    let bounds = positions.iter().fold(
+        (
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(left, right, bottom, top), &(x, y)| {
+            (left.min(x), right.max(x), bottom.min(y), top.max(y))
+        },
+    );
+    let (left, right, bottom, top) = bounds;
+    let center = (left.midpoint(right), bottom.midpoint(top));
+    let half_span = ((right - left).max(top - bottom)) / 2.0;
+    for position in positions {
+        position.0 -= center.0;
+        position.1 -= center.1;
+        if half_span > 0.0 {
+            position.0 /= half_span;
+            position.1 /= half_span;
+        }
+    }
+}
+
+/// Returns evenly spaced points of the unit circle, one for every vertex.
+fn circle<V>(vertices: &[V]) -> Vec<(f64, f64)> {
+    #[allow(clippy::cast_precision_loss)]
+    let step = TAU / vertices.len().max(1) as f64;
+    (0..vertices.len())
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let angle = step * i as f64;
+            (angle.cos(), angle.sin())
+        })
+        .collect()
+}
+
+/// Layers a directed graph by longest path from its sources, after reversing
+/// the arcs that close cycles in a depth-first search.
+fn longest_path_layers(successors: &[Vec<usize>]) -> Vec<usize> {
+    let n = successors.len();
+    // 0: unvisited, 1: on the search stack, 2: finished.
+    let mut state = vec![0u8; n];
+    let mut arcs: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for root in 0..n {
+        if state[root] != 0 {
+            continue;
+        }
+        state[root] = 1;
+        let mut stack = vec![(root, 0)];
+        while let Some((u, next)) = stack.last_mut() {
+            let u = *u;
+            let Some(&v) = successors[u].get(*next) else {
+                state[u] = 2;
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            match state[v] {
+                _ if v == u => {}
+                0 => {
+                    arcs[u].push(v);
+                    state[v] = 1;
+                    stack.push((v, 0));
+                }
+                1 => arcs[v].push(u),
+                _ => arcs[u].push(v),
+            }
+        }
+    }
+
+    let mut indegree = vec![0; n];
+    for &v in arcs.iter().flatten() {
+        indegree[v] += 1;
+    }
+    let mut layer = vec![0; n];
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| indegree[v] == 0).collect();
+    while let Some(u) = queue.pop_front() {
+        for &v in &arcs[u] {
+            layer[v] = layer[v].max(layer[u] + 1);
+            indegree[v] -= 1;
+            if indegree[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+    layer
+}
+
+/// Layers an undirected graph by breadth-first depth from a vertex of highest
+/// degree in every component.
+fn breadth_first_layers(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let mut layer = vec![usize::MAX; neighbors.len()];
+    let mut roots: Vec<usize> = (0..neighbors.len()).collect();
+    roots.sort_by_key(|&v| std::cmp::Reverse(neighbors[v].len()));
+    for root in roots {
+        if layer[root] != usize::MAX {
+            continue;
+        }
+        layer[root] = 0;
+        let mut queue = VecDeque::from([root]);
+        while let Some(u) = queue.pop_front() {
+            for &v in &neighbors[u] {
+                if layer[v] == usize::MAX {
+                    layer[v] = layer[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+    layer
+}
@@ -0,0 +1,1017 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::RangeInclusive,
+};
+
+use crate::{
+    algorithms::{adjacency::undirected_adjacency, flow::FlowNetwork},
+    error::AlgorithmError,
+    traits::weighted::WeightedGraph,
+    Graph, GraphError,
+};
+
+/// A set of chosen edges given by their endpoints.
+pub type Matching<V> = Vec<(V, V)>;
+
+/// Splits the vertices of a symmetric adjacency into the two sides of a
+/// bipartition.
+fn bipartition<'a, V>(
+    adjacency: &HashMap<&'a V, HashSet<&'a V>>,
+) -> Result<HashMap<&'a V, bool>, GraphError>
+where
+    V: Eq + Hash,
+{
+    let mut side: HashMap<&V, bool> = HashMap::with_capacity(adjacency.len());
+    for &root in adjacency.keys() {
+        if side.contains_key(root) {
+            continue;
+        }
+        side.insert(root, false);
+        let mut queue = VecDeque::from([root]);
+        while let Some(u) = queue.pop_front() {
+            let color = side[u];
+            for &v in &adjacency[u] {
+                match side.get(v) {
+                    None => {
+                        side.insert(v, !color);
+                        queue.push_back(v);
+                    }
+                    Some(&other) if other == color => {
+                        return Err(AlgorithmError::NotBipartite.into());
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    Ok(side)
+}
+
+/// Computes a maximum b-matching of a bipartite graph: a largest set of edges in
+/// which every vertex `v` is an endpoint of at most `capacity(v)` edges.
+///
+/// With every capacity equal to one this is a maximum matching. The sides of the
+/// graph are found by two-coloring, and the matching is a maximum flow from a
+/// source joined to one side to a sink joined to the other, with the capacities
+/// on the arcs at the source and the sink. Dinic's algorithm computes it in
+/// `O(m sqrt(n))` time for unit capacities. Edge directions and self-loops are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The bipartite graph.
+/// * `capacity` - The largest number of chosen edges at every vertex.
+///
+/// # Returns
+///
+/// The chosen edges, oriented as in the graph when it is directed.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is not bipartite.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::matching::maximum_b_matching, graph};
+///
+/// // Workers 1 and 2 share the tasks 10, 11 and 12; worker 1 takes up to two.
+/// let graph = graph! { 1 - 10, 1 - 11, 1 - 12, 2 - 10, 2 - 11 };
+/// let matching = maximum_b_matching(&graph, |&v| if v == 1 { 2 } else { 1 }).unwrap();
+/// assert_eq!(matching.len(), 3);
+/// ```
+pub fn maximum_b_matching<G, F>(graph: &G, capacity: F) -> Result<Matching<G::Vertex>, GraphError>
+where
+    G: Graph,
+    F: Fn(&G::Vertex) -> usize,
+{
+    let edges = degree_constrained_subgraph(graph, |v| 0..=capacity(v))?;
+    Ok(edges.unwrap_or_default())
+}
+
+/// Computes a largest degree-constrained subgraph of a bipartite graph: a set of
+/// edges in which every vertex `v` is an endpoint of a number of edges in
+/// `bounds(v)`.
+///
+/// The lower bounds turn the problem into a circulation with demands: a flow
+/// meeting every lower bound is found first, then augmented from the source to
+/// the sink to take as many edges as the upper bounds allow. Edge directions and
+/// self-loops are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The bipartite graph.
+/// * `bounds` - The allowed numbers of chosen edges at every vertex.
+///
+/// # Returns
+///
+/// * `Some(Vec<(V, V)>)` - The chosen edges, oriented as in the graph when it is
+///   directed.
+/// * `None` - If no set of edges meets the bounds.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is not bipartite.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::matching::degree_constrained_subgraph, graph};
+///
+/// let graph = graph! { 1 - 10, 1 - 11, 2 - 10 };
+/// // Vertex 1 must take both of its edges, which leaves no edge for vertex 2.
+/// let forced = degree_constrained_subgraph(&graph, |&v| if v == 1 { 2..=2 } else { 0..=1 });
+/// assert_eq!(forced.unwrap().unwrap().len(), 2);
+///
+/// let impossible = degree_constrained_subgraph(&graph, |&v| if v == 11 { 0..=0 } else { 1..=1 });
+/// assert!(impossible.unwrap().is_none());
+/// ```
+pub fn degree_constrained_subgraph<G, F>(
+    graph: &G,
+    bounds: F,
+) -> Result<Option<Matching<G::Vertex>>, GraphError>
+where
+    G: Graph,
+    F: Fn(&G::Vertex) -> RangeInclusive<usize>,
+{
+    let adjacency = undirected_adjacency(graph);
+    let side = bipartition(&adjacency)?;
+    trace_span!("degree_constrained_subgraph", vertices = side.len());
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let n = vertices.len();
+    // Nodes: the vertices, then the source, the sink and the super source and
+    // sink that settle the lower bounds.
+    let (source, sink, demand_source, demand_sink) = (n, n + 1, n + 2, n + 3);
+    let mut network = FlowNetwork::new(n + 4);
+
+    let mut excess = vec![0isize; n + 2];
+    for (i, v) in vertices.iter().enumerate() {
+        let range = bounds(v);
+        let (low, high) = (*range.start(), *range.end());
+        if low > high {
+            return Ok(None);
+        }
+        let (from, to) = if side[v] { (i, sink) } else { (source, i) };
+        network.add_arc(from, to, high - low);
+        let low = isize::try_from(low).map_err(|_| {
+            AlgorithmError::InvalidArgument(format!("Lower bound of {v:?} is too large"))
+        })?;
+        excess[from] -= low;
+        excess[to] += low;
+    }
+
+    let mut edge_arcs = Vec::new();
+    for (i, &u) in vertices.iter().enumerate() {
+        if side[u] {
+            continue;
+        }
+        for &v in &adjacency[u] {
+            edge_arcs.push(network.add_arc(i, index[v], 1));
+        }
+    }
+
+    let circulation = network.add_arc(sink, source, usize::MAX);
+    let mut required = 0;
+    for (node, &amount) in excess.iter().enumerate() {
+        if amount > 0 {
+            network.add_arc(demand_source, node, amount.unsigned_abs());
+            required += amount.unsigned_abs();
+        } else if amount < 0 {
+            network.add_arc(node, demand_sink, amount.unsigned_abs());
+        }
+    }
+    if network.max_flow(demand_source, demand_sink) < required {
+        trace_event!(required, "lower bounds cannot be met");
+        return Ok(None);
+    }
+
+    // The feasible flow keeps every lower bound while it is augmented, since
+    // augmenting paths never return to the source or leave the sink.
+    network.disable(circulation);
+    network.max_flow(source, sink);
+
+    let chosen: Matching<G::Vertex> = edge_arcs
+        .into_iter()
+        .filter(|&arc| network.flow(arc) == 1)
+        .map(|arc| {
+            let (u, v) = (vertices[network.head[arc ^ 1]], vertices[network.head[arc]]);
+            if graph.contains_edge(u, v) {
+                (u.clone(), v.clone())
+            } else {
+                (v.clone(), u.clone())
+            }
+        })
+        .collect();
+    trace_event!(edges = chosen.len(), "subgraph found");
+    Ok(Some(chosen))
+}
+
+/// Marks a missing vertex, mate, label or edge in the index-based matching
+/// algorithms.
+const NONE: usize = usize::MAX;
+
+/// Computes a maximum matching of a general graph: a largest set of edges no two
+/// of which share a vertex.
+///
+/// Unlike [`maximum_b_matching`], the graph need not be bipartite. Edmonds'
+/// blossom algorithm grows alternating trees from the unmatched vertices and
+/// contracts every odd cycle it meets into a single vertex, so that augmenting
+/// paths through odd cycles are found too. It runs in `O(n^3)` time after a
+/// greedy initial matching. Edge directions and self-loops are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph whose vertices are paired.
+///
+/// # Returns
+///
+/// The chosen edges, oriented as in the graph when it is directed.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::matching::maximum_matching, graph};
+///
+/// // A triangle with a pendant vertex at each corner pairs every vertex.
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 1 - 4, 2 - 5, 3 - 6 };
+/// assert_eq!(maximum_matching(&graph).len(), 3);
+///
+/// // A triangle alone leaves a vertex unmatched.
+/// assert_eq!(maximum_matching(&graph! { 1 - 2, 2 - 3, 3 - 1 }).len(), 1);
+/// ```
+#[must_use]
+pub fn maximum_matching<G>(graph: &G) -> Matching<G::Vertex>
+where
+    G: Graph,
+{
+    let (vertices, neighbors) = indexed_adjacency(graph);
+    let n = vertices.len();
+    trace_span!("maximum_matching", vertices = n);
+
+    let mut mate = vec![NONE; n];
+    for u in 0..n {
+        if mate[u] == NONE {
+            if let Some(&v) = neighbors[u].iter().find(|&&v| mate[v] == NONE) {
+                mate[u] = v;
+                mate[v] = u;
+            }
+        }
+    }
+    let mut search = BlossomSearch::new(n);
+    for root in 0..n {
+        if mate[root] != NONE {
+            continue;
+        }
+        // Flip the matched and unmatched edges along the augmenting path.
+        let mut v = search.augmenting_path(&neighbors, &mate, root);
+        while v != NONE {
+            let previous = search.parent[v];
+            let next = mate[previous];
+            mate[v] = previous;
+            mate[previous] = v;
+            v = next;
+        }
+    }
+
+    let matching = matched_pairs(graph, &vertices, &mate);
+    trace_event!(edges = matching.len(), "matching found");
+    matching
+}
+
+/// Computes a maximum weight matching of a general graph: a set of edges no two
+/// of which share a vertex, with the largest total weight.
+///
+/// This is the primal-dual form of Edmonds' blossom algorithm, which keeps a
+/// dual variable for every vertex and every odd cycle contracted into a
+/// blossom, and only grows alternating trees along edges whose reduced cost is
+/// zero. It runs in `O(n^3)` time. The matching is not necessarily a largest
+/// one: an edge of negative weight is never chosen, and two light edges can
+/// give way to a single heavier one. Edges without a weight weigh `1`. Edge
+/// directions and self-loops are ignored, and of two opposite arcs the heavier
+/// one is used.
+///
+/// Weights are converted to `f64`. Integer weights, and more generally weights
+/// whose halves are exact, give exact results.
+///
+/// # Arguments
+///
+/// * `graph` - The graph whose vertices are paired.
+///
+/// # Returns
+///
+/// The chosen edges, oriented as in the graph when it is directed.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if a weight is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::matching::maximum_weight_matching, graph};
+///
+/// // The heavy middle edge is worth more than the two outer edges together.
+/// let path = graph! { 1 - 2 [weight = 2], 2 - 3 [weight = 5], 3 - 4 [weight = 2] };
+/// let matching = maximum_weight_matching(&path).unwrap();
+/// assert_eq!(matching.len(), 1);
+/// assert!(matching[0] == (2, 3) || matching[0] == (3, 2));
+///
+/// let path = graph! { 1 - 2 [weight = 3], 2 - 3 [weight = 5], 3 - 4 [weight = 3] };
+/// assert_eq!(maximum_weight_matching(&path).unwrap().len(), 2);
+/// ```
+pub fn maximum_weight_matching<G>(graph: &G) -> Result<Matching<G::Vertex>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+{
+    let (vertices, neighbors) = indexed_adjacency(graph);
+    trace_span!("maximum_weight_matching", vertices = vertices.len());
+    let weight = |u: &G::Vertex, v: &G::Vertex| -> Option<f64> {
+        let forward = graph.edge_weight(u, v).map(|&w| w.into());
+        let backward = graph.edge_weight(v, u).map(|&w| w.into());
+        match (forward, backward) {
+            (Some(forward), Some(backward)) => Some(forward.max(backward)),
+            (forward, backward) => forward.or(backward),
+        }
+    };
+
+    let mut edges = Vec::new();
+    for (u, adjacent) in neighbors.iter().enumerate() {
+        for &v in adjacent.iter().filter(|&&v| u < v) {
+            let w = weight(vertices[u], vertices[v]).unwrap_or(1.0);
+            if !w.is_finite() {
+                return Err(AlgorithmError::invalid_weight(vertices[u], vertices[v], w).into());
+            }
+            edges.push((u, v, w));
+        }
+    }
+
+    let mate = WeightedBlossoms::new(vertices.len(), edges).solve();
+    let matching = matched_pairs(graph, &vertices, &mate);
+    trace_event!(edges = matching.len(), "matching found");
+    Ok(matching)
+}
+
+/// Numbers the vertices of a graph and lists the neighbors of every vertex by
+/// number, ignoring edge directions and self-loops.
+fn indexed_adjacency<G>(graph: &G) -> (Vec<&G::Vertex>, Vec<Vec<usize>>)
+where
+    G: Graph,
+{
+    let adjacency = undirected_adjacency(graph);
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let neighbors = vertices
+        .iter()
+        .map(|v| adjacency[v].iter().map(|w| index[w]).collect())
+        .collect();
+    (vertices, neighbors)
+}
+
+/// Lists every matched pair once, oriented as in the graph.
+fn matched_pairs<G>(graph: &G, vertices: &[&G::Vertex], mate: &[usize]) -> Matching<G::Vertex>
+where
+    G: Graph,
+{
+    mate.iter()
+        .enumerate()
+        .filter(|&(u, &v)| v != NONE && u < v)
+        .map(|(u, &v)| {
+            let (u, v) = (vertices[u], vertices[v]);
+            if graph.contains_edge(u, v) {
+                (u.clone(), v.clone())
+            } else {
+                (v.clone(), u.clone())
+            }
+        })
+        .collect()
+}
+
+/// The alternating forest of a search for an augmenting path, in which the
+/// vertices of every contracted blossom point to its base.
+struct BlossomSearch {
+    parent: Vec<usize>,
+    base: Vec<usize>,
+    visited: Vec<bool>,
+    in_blossom: Vec<bool>,
+    queue: VecDeque<usize>,
+}
+
+impl BlossomSearch {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: vec![NONE; n],
+            base: (0..n).collect(),
+            visited: vec![false; n],
+            in_blossom: vec![false; n],
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Grows an alternating tree from the unmatched vertex `root`, returning
+    /// the unmatched vertex ending an augmenting path, whose vertices are then
+    /// linked by `parent` and `mate`, or `NONE`.
+    fn augmenting_path(&mut self, neighbors: &[Vec<usize>], mate: &[usize], root: usize) -> usize {
+        self.parent.fill(NONE);
+        self.visited.fill(false);
+        for (v, base) in self.base.iter_mut().enumerate() {
+            *base = v;
+        }
+        self.queue.clear();
+        self.visited[root] = true;
+        self.queue.push_back(root);
+
+        while let Some(v) = self.queue.pop_front() {
+            for &w in &neighbors[v] {
+                if self.base[v] == self.base[w] || mate[v] == w {
+                    continue;
+                }
+                if w == root || (mate[w] != NONE && self.parent[mate[w]] != NONE) {
+                    // An edge between two outer vertices closes an odd cycle.
+                    let base = self.common_ancestor(mate, v, w);
+                    self.in_blossom.fill(false);
+                    self.mark_path(mate, v, base, w);
+                    self.mark_path(mate, w, base, v);
+                    for u in 0..self.base.len() {
+                        if self.in_blossom[self.base[u]] {
+                            self.base[u] = base;
+                            if !self.visited[u] {
+                                self.visited[u] = true;
+                                self.queue.push_back(u);
+                            }
+                        }
+                    }
+                } else if self.parent[w] == NONE {
+                    self.parent[w] = v;
+                    if mate[w] == NONE {
+                        return w;
+                    }
+                    self.visited[mate[w]] = true;
+                    self.queue.push_back(mate[w]);
+                }
+            }
+        }
+        NONE
+    }
+
+    /// Returns the base of the nearest common ancestor of the outer vertices
+    /// `first` and `second` in the alternating tree.
+    fn common_ancestor(&self, mate: &[usize], mut first: usize, mut second: usize) -> usize {
+        let mut on_path = vec![false; self.base.len()];
+        loop {
+            first = self.base[first];
+            on_path[first] = true;
+            if mate[first] == NONE {
+                break;
+            }
+            first = self.parent[mate[first]];
+        }
+        loop {
+            second = self.base[second];
+            if on_path[second] {
+                return second;
+            }
+            second = self.parent[mate[second]];
+        }
+    }
+
+    /// Marks the blossoms on the path from `v` up to `base`, linking the inner
+    /// vertices of the path back towards `child` so the cycle can be traversed
+    /// both ways.
+    fn mark_path(&mut self, mate: &[usize], mut v: usize, base: usize, mut child: usize) {
+        while self.base[v] != base {
+            self.in_blossom[self.base[v]] = true;
+            self.in_blossom[self.base[mate[v]]] = true;
+            self.parent[v] = child;
+            child = mate[v];
+            v = self.parent[mate[v]];
+        }
+    }
+}
+
+/// The kind of dual adjustment closing a stage of the weighted blossom
+/// algorithm.
+enum DualStep {
+    /// A vertex dual reached zero: the matching is optimal.
+    Done,
+    /// An edge became tight and joins the alternating forest.
+    Tighten(usize),
+    /// The dual of an inner blossom reached zero and it is expanded.
+    Expand(usize),
+}
+
+/// The state of the weighted blossom algorithm, following the formulation of
+/// Galil's survey "Efficient algorithms for finding maximum matching in
+/// graphs".
+///
+/// Vertices are numbered `0..n` and blossoms `n..2n`. Edge `k` has the
+/// endpoints `2k` and `2k + 1`, so `p ^ 1` is the other end of endpoint `p`.
+/// Labels are `0` for free, `1` for outer (S) and `2` for inner (T) vertices and
+/// blossoms.
+struct WeightedBlossoms {
+    n: usize,
+    edges: Vec<(usize, usize, f64)>,
+    endpoint: Vec<usize>,
+    incident: Vec<Vec<usize>>,
+    mate: Vec<usize>,
+    label: Vec<u8>,
+    label_end: Vec<usize>,
+    in_blossom: Vec<usize>,
+    blossom_parent: Vec<usize>,
+    blossom_children: Vec<Vec<usize>>,
+    blossom_base: Vec<usize>,
+    blossom_endpoints: Vec<Vec<usize>>,
+    best_edge: Vec<usize>,
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    dual: Vec<f64>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl WeightedBlossoms {
+    fn new(n: usize, edges: Vec<(usize, usize, f64)>) -> Self {
+        let max_weight = edges.iter().fold(0.0, |max: f64, &(_, _, w)| max.max(w));
+        let endpoint = edges.iter().flat_map(|&(u, v, _)| [u, v]).collect();
+        let mut incident = vec![Vec::new(); n];
+        for (k, &(u, v, _)) in edges.iter().enumerate() {
+            incident[u].push(2 * k + 1);
+            incident[v].push(2 * k);
+        }
+        let mut dual = vec![max_weight; n];
+        dual.resize(2 * n, 0.0);
+        Self {
+            n,
+            allowed: vec![false; edges.len()],
+            edges,
+            endpoint,
+            incident,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            label_end: vec![NONE; 2 * n],
+            in_blossom: (0..n).collect(),
+            blossom_parent: vec![NONE; 2 * n],
+            blossom_children: vec![Vec::new(); 2 * n],
+            blossom_base: (0..n).chain(std::iter::repeat_n(NONE, n)).collect(),
+            blossom_endpoints: vec![Vec::new(); 2 * n],
+            best_edge: vec![NONE; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            unused_blossoms: (n..2 * n).collect(),
+            dual,
+            queue: Vec::new(),
+        }
+    }
+
+    /// Returns the reduced cost of edge `k`, zero when the edge is tight.
+    fn slack(&self, k: usize) -> f64 {
+        let (u, v, w) = self.edges[k];
+        self.dual[u] + self.dual[v] - 2.0 * w
+    }
+
+    /// Returns the vertices inside blossom `b`.
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(b) = stack.pop() {
+            if b < self.n {
+                leaves.push(b);
+            } else {
+                stack.extend(self.blossom_children[b].iter().rev());
+            }
+        }
+        leaves
+    }
+
+    /// Labels the top-level blossom containing `w`, reached through endpoint
+    /// `p`, and the mate of an inner blossom.
+    fn assign_label(&mut self, w: usize, label: u8, p: usize) {
+        let b = self.in_blossom[w];
+        self.label[w] = label;
+        self.label[b] = label;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = NONE;
+        self.best_edge[b] = NONE;
+        if label == 1 {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            let base_mate = self.mate[self.blossom_base[b]];
+            self.assign_label(self.endpoint[base_mate], 1, base_mate ^ 1);
+        }
+    }
+
+    /// Traces back from the outer vertices `v` and `w` to find the base of the
+    /// blossom their edge closes, or `NONE` if their trees are distinct and the
+    /// edge completes an augmenting path.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = Vec::new();
+        let mut base = NONE;
+        while v != NONE || w != NONE {
+            let b = self.in_blossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            if self.label_end[b] == NONE {
+                v = NONE;
+            } else {
+                v = self.endpoint[self.label_end[b]];
+                let b = self.in_blossom[v];
+                v = self.endpoint[self.label_end[b]];
+            }
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    /// Contracts the odd cycle closed by edge `k` into a new outer blossom with
+    /// the given base vertex.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let Some(blossom) = self.unused_blossoms.pop() else {
+            return;
+        };
+        self.blossom_base[blossom] = base;
+        self.blossom_parent[blossom] = NONE;
+        self.blossom_parent[bb] = blossom;
+
+        let mut children = Vec::new();
+        let mut endpoints = Vec::new();
+        while bv != bb {
+            self.blossom_parent[bv] = blossom;
+            children.push(bv);
+            endpoints.push(self.label_end[bv]);
+            v = self.endpoint[self.label_end[bv]];
+            bv = self.in_blossom[v];
+        }
+        children.push(bb);
+        children.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+        while bw != bb {
+            self.blossom_parent[bw] = blossom;
+            children.push(bw);
+            endpoints.push(self.label_end[bw] ^ 1);
+            w = self.endpoint[self.label_end[bw]];
+            bw = self.in_blossom[w];
+        }
+        self.blossom_children[blossom] = children;
+        self.blossom_endpoints[blossom] = endpoints;
+
+        self.label[blossom] = 1;
+        self.label_end[blossom] = self.label_end[bb];
+        self.dual[blossom] = 0.0;
+        for leaf in self.leaves(blossom) {
+            if self.label[self.in_blossom[leaf]] == 2 {
+                // Former inner vertices become outer and must be scanned.
+                self.queue.push(leaf);
+            }
+            self.in_blossom[leaf] = blossom;
+        }
+
+        // Keep, for every neighboring outer blossom, the least slack edge to it.
+        let mut best_to = vec![NONE; 2 * self.n];
+        for child in self.blossom_children[blossom].clone() {
+            let lists: Vec<Vec<usize>> = match self.blossom_best_edges[child].take() {
+                Some(list) => vec![list],
+                None => self
+                    .leaves(child)
+                    .into_iter()
+                    .map(|leaf| self.incident[leaf].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for k in lists.into_iter().flatten() {
+                let (mut near, mut far, _) = self.edges[k];
+                if self.in_blossom[far] == blossom {
+                    std::mem::swap(&mut near, &mut far);
+                }
+                let bj = self.in_blossom[far];
+                if bj != blossom
+                    && self.label[bj] == 1
+                    && (best_to[bj] == NONE || self.slack(k) < self.slack(best_to[bj]))
+                {
+                    best_to[bj] = k;
+                }
+            }
+            self.best_edge[child] = NONE;
+        }
+        let best_edges: Vec<usize> = best_to.into_iter().filter(|&k| k != NONE).collect();
+        self.best_edge[blossom] = NONE;
+        for &k in &best_edges {
+            if self.best_edge[blossom] == NONE
+                || self.slack(k) < self.slack(self.best_edge[blossom])
+            {
+                self.best_edge[blossom] = k;
+            }
+        }
+        self.blossom_best_edges[blossom] = Some(best_edges);
+    }
+
+    /// Expands blossom `b` into its children, relabeling them when `b` is an
+    /// inner blossom of the current stage.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for child in self.blossom_children[b].clone() {
+            self.blossom_parent[child] = NONE;
+            if child < self.n {
+                self.in_blossom[child] = child;
+            } else if end_stage && self.dual[child] == 0.0 {
+                self.expand_blossom(child, end_stage);
+            } else {
+                for leaf in self.leaves(child) {
+                    self.in_blossom[leaf] = child;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == 2 {
+            // Relabel the even-length path through the blossom from the child
+            // entered by the tree to the base.
+            let children = self.blossom_children[b].clone();
+            let endpoints = self.blossom_endpoints[b].clone();
+            let len = children.len();
+            let entry = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+            let mut j = children.iter().position(|&c| c == entry).unwrap_or(0);
+            let (step, trick) = if j % 2 == 1 { (1, 0) } else { (len - 1, 1) };
+            let back = |j: usize| (j + len - trick) % len;
+            let mut p = self.label_end[b];
+            while j != 0 {
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[endpoints[back(j)] ^ trick ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allowed[endpoints[back(j)] / 2] = true;
+                j = (j + step) % len;
+                p = endpoints[back(j)] ^ trick;
+                self.allowed[p / 2] = true;
+                j = (j + step) % len;
+            }
+            let child = children[j];
+            let entered = self.endpoint[p ^ 1];
+            self.label[entered] = 2;
+            self.label[child] = 2;
+            self.label_end[entered] = p;
+            self.label_end[child] = p;
+            self.best_edge[child] = NONE;
+            j = (j + step) % len;
+            // The children off the path lose their labels unless one of their
+            // vertices was reached from outside the blossom.
+            while children[j] != entry {
+                let child = children[j];
+                j = (j + step) % len;
+                if self.label[child] == 1 {
+                    continue;
+                }
+                if let Some(v) = self.leaves(child).into_iter().find(|&v| self.label[v] != 0) {
+                    self.label[v] = 0;
+                    let base_mate = self.mate[self.blossom_base[child]];
+                    self.label[self.endpoint[base_mate]] = 0;
+                    self.assign_label(v, 2, self.label_end[v]);
+                }
+            }
+        }
+
+        self.label[b] = 0;
+        self.label_end[b] = NONE;
+        self.blossom_children[b].clear();
+        self.blossom_endpoints[b].clear();
+        self.blossom_base[b] = NONE;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Flips the matching along the even-length path through `blossom` from
+    /// vertex `v` to its base, making `v` the new base.
+    fn augment_blossom(&mut self, blossom: usize, v: usize) {
+        let mut t = v;
+        while self.blossom_parent[t] != blossom {
+            t = self.blossom_parent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let len = self.blossom_children[blossom].len();
+        let start = self.blossom_children[blossom]
+            .iter()
+            .position(|&c| c == t)
+            .unwrap_or(0);
+        let mut j = start;
+        let (step, trick) = if start % 2 == 1 { (1, 0) } else { (len - 1, 1) };
+        while j != 0 {
+            j = (j + step) % len;
+            let t = self.blossom_children[blossom][j];
+            let p = self.blossom_endpoints[blossom][(j + len - trick) % len] ^ trick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j = (j + step) % len;
+            let t = self.blossom_children[blossom][j];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+        self.blossom_children[blossom].rotate_left(start);
+        self.blossom_endpoints[blossom].rotate_left(start);
+        self.blossom_base[blossom] = self.blossom_base[self.blossom_children[blossom][0]];
+    }
+
+    /// Flips the matching along the augmenting path through edge `k`.
+    fn augment_matching(&mut self, k: usize) {
+        let (first, second, _) = self.edges[k];
+        for (mut s, mut p) in [(first, 2 * k + 1), (second, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.label_end[bs] == NONE {
+                    break;
+                }
+                let inner = self.endpoint[self.label_end[bs]];
+                let bt = self.in_blossom[inner];
+                s = self.endpoint[self.label_end[bt]];
+                let entry = self.endpoint[self.label_end[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, entry);
+                }
+                self.mate[entry] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+
+    /// Scans the queued outer vertices, growing the forest along tight edges.
+    /// Returns whether the matching was augmented.
+    fn scan(&mut self) -> bool {
+        while let Some(v) = self.queue.pop() {
+            for p in self.incident[v].clone() {
+                let k = p / 2;
+                let w = self.endpoint[p];
+                if self.in_blossom[v] == self.in_blossom[w] {
+                    continue;
+                }
+                let slack = self.slack(k);
+                if !self.allowed[k] && slack <= 0.0 {
+                    self.allowed[k] = true;
+                }
+                let bw = self.in_blossom[w];
+                if self.allowed[k] {
+                    if self.label[bw] == 0 {
+                        self.assign_label(w, 2, p ^ 1);
+                    } else if self.label[bw] == 1 {
+                        let base = self.scan_blossom(v, w);
+                        if base == NONE {
+                            self.augment_matching(k);
+                            return true;
+                        }
+                        self.add_blossom(base, k);
+                    } else if self.label[w] == 0 {
+                        // An inner blossom reached again: remember the way in
+                        // to this vertex in case the blossom is expanded.
+                        self.label[w] = 2;
+                        self.label_end[w] = p ^ 1;
+                    }
+                } else if self.label[bw] == 1 {
+                    let b = self.in_blossom[v];
+                    if self.best_edge[b] == NONE || slack < self.slack(self.best_edge[b]) {
+                        self.best_edge[b] = k;
+                    }
+                } else if self.label[w] == 0
+                    && (self.best_edge[w] == NONE || slack < self.slack(self.best_edge[w]))
+                {
+                    self.best_edge[w] = k;
+                }
+            }
+        }
+        false
+    }
+
+    /// Chooses the largest dual change keeping every reduced cost
+    /// non-negative.
+    fn dual_step(&self) -> (f64, DualStep) {
+        let mut delta = self.dual[..self.n]
+            .iter()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        let mut step = DualStep::Done;
+        for v in 0..self.n {
+            if self.label[self.in_blossom[v]] == 0 && self.best_edge[v] != NONE {
+                let slack = self.slack(self.best_edge[v]);
+                if slack < delta {
+                    delta = slack;
+                    step = DualStep::Tighten(self.best_edge[v]);
+                }
+            }
+        }
+        for b in 0..2 * self.n {
+            if self.blossom_parent[b] == NONE && self.label[b] == 1 && self.best_edge[b] != NONE {
+                let slack = self.slack(self.best_edge[b]) / 2.0;
+                if slack < delta {
+                    delta = slack;
+                    step = DualStep::Tighten(self.best_edge[b]);
+                }
+            }
+        }
+        for b in self.n..2 * self.n {
+            if self.blossom_base[b] != NONE
+                && self.blossom_parent[b] == NONE
+                && self.label[b] == 2
+                && self.dual[b] < delta
+            {
+                delta = self.dual[b];
+                step = DualStep::Expand(b);
+            }
+        }
+        (delta, step)
+    }
+
+    /// Runs the stages of the algorithm, each augmenting the matching by one
+    /// edge, and returns the mate of every vertex.
+    fn solve(mut self) -> Vec<usize> {
+        for _ in 0..self.n {
+            self.label.fill(0);
+            self.best_edge.fill(NONE);
+            for best_edges in &mut self.blossom_best_edges[self.n..] {
+                *best_edges = None;
+            }
+            self.allowed.fill(false);
+            self.queue.clear();
+            for v in 0..self.n {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+
+            let augmented = loop {
+                if self.scan() {
+                    break true;
+                }
+                let (delta, step) = self.dual_step();
+                for v in 0..self.n {
+                    match self.label[self.in_blossom[v]] {
+                        1 => self.dual[v] -= delta,
+                        2 => self.dual[v] += delta,
+                        _ => {}
+                    }
+                }
+                for b in self.n..2 * self.n {
+                    if self.blossom_base[b] != NONE && self.blossom_parent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dual[b] += delta,
+                            2 => self.dual[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+                match step {
+                    DualStep::Done => break false,
+                    DualStep::Tighten(k) => {
+                        self.allowed[k] = true;
+                        let (i, j, _) = self.edges[k];
+                        let outer = if self.label[self.in_blossom[i]] == 1 {
+                            i
+                        } else {
+                            j
+                        };
+                        self.queue.push(outer);
+                    }
+                    DualStep::Expand(b) => self.expand_blossom(b, false),
+                }
+            };
+            if !augmented {
+                break;
+            }
+            for b in self.n..2 * self.n {
+                if self.blossom_parent[b] == NONE
+                    && self.blossom_base[b] != NONE
+                    && self.label[b] == 1
+                    && self.dual[b] == 0.0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+
+        self.mate
+            .iter()
+            .map(|&p| if p == NONE { NONE } else { self.endpoint[p] })
+            .collect()
+    }
+}
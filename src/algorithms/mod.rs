@@ -0,0 +1,130 @@
+/// Breadth-first traversal helpers shared by the other algorithms.
+pub mod traversal;
+
+/// Distance-based metrics such as eccentricity, diameter, radius and center.
+pub mod distance;
+
+/// Single-source shortest paths over weighted graphs.
+pub mod shortest_path;
+
+/// Contraction hierarchies answering repeated shortest path queries.
+pub mod routing;
+
+/// Systems of difference constraints solved with shortest paths.
+pub mod constraints;
+
+/// Minimum spanning trees and forests.
+pub mod mst;
+
+/// Traveling salesman tours: construction heuristics and 2-opt/3-opt local search.
+pub mod tsp;
+
+/// Greedy spanners and spectral sparsifiers.
+pub mod spanner;
+
+/// Fundamental and minimum-weight cycle bases.
+pub mod cycle_basis;
+
+/// Lazy enumeration of elementary cycles and shortest cycles through a vertex or edge.
+pub mod cycles;
+
+/// Maximum and maximum weight matchings of general graphs, and degree-constrained
+/// subgraphs and b-matchings of bipartite graphs built on maximum flow.
+pub mod matching;
+
+/// Acyclic, strongly connected and minimum out-degree orientations of undirected graphs.
+pub mod orientation;
+
+/// Arboricity, pseudoarboricity and decompositions of the edges into forests.
+pub mod arboricity;
+
+/// Topological ordering of directed acyclic graphs.
+pub mod topological;
+
+/// Precomputed reachability indexes for directed acyclic graphs.
+pub mod reachability;
+
+/// Cached components and topological orders that detect and repair stale results.
+pub mod cached;
+
+/// Vertex cover, dominating set and independent set heuristics over vertex weights.
+pub mod covering;
+
+/// Closed and k-hop neighborhoods, neighborhood set operations and link prediction scores.
+pub mod neighborhood;
+
+/// Triangle counts and clustering coefficients.
+pub mod clustering;
+
+/// Core decomposition (k-cores).
+pub mod core;
+
+/// Structural role features and anonymous walk statistics.
+pub mod roles;
+
+/// Top-k selection of vertices by degree or by any score.
+pub mod ranking;
+
+/// Random walks with restart and uniform random spanning trees.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod random_walk;
+
+/// Node, edge, snowball and forest fire sampling returning induced subgraphs.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod sampling;
+
+/// Degree-preserving randomization and empirical significance tests of graph
+/// metrics.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod significance;
+
+/// Whole-graph similarity: edge overlap, spectral distance and `DeltaCon`.
+pub mod similarity;
+
+/// Opaque relabeling and k-degree anonymization for sharing graphs.
+pub mod anonymization;
+
+/// Exact vertex coloring of small graphs.
+pub mod coloring;
+
+/// Exact counts and Glauber dynamics sampling of proper colorings and independent sets.
+pub mod counting;
+
+/// Chromatic and Tutte polynomials of small graphs.
+pub mod polynomial;
+
+/// Adjacency and Laplacian spectra with derived invariants.
+#[cfg(feature = "linalg")]
+#[cfg_attr(docsrs, doc(cfg(feature = "linalg")))]
+pub mod spectral;
+
+/// Circular, force-directed and layered drawings computing a position for every
+/// vertex.
+pub mod layout;
+
+/// A registry of named graph invariants evaluated in bulk.
+pub mod invariants;
+
+/// Parallel versions of expensive algorithms built on rayon.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub mod parallel;
+
+/// Time budgets and cancellation for algorithms returning partial results.
+pub mod budget;
+
+/// Min-heap entries ordered by a [`Measure`](crate::traits::measure::Measure).
+pub(crate) mod heap;
+
+/// Symmetric adjacency snapshots used by algorithms that ignore edge directions.
+pub(crate) mod adjacency;
+
+/// Maximum flows, minimum cuts and global minimum cuts of networks with capacities
+/// on the edges.
+pub mod flow;
+
+/// Disjoint-set forest used by the connectivity-based algorithms.
+pub(crate) mod union_find;
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::{shortest_path::unweighted_edge, union_find::UnionFind},
+    error::AlgorithmError,
+    traits::{
+        measure::Measure,
+        weighted::{WeightedEdge, WeightedGraph},
+    },
+    GraphError,
+};
+
+/// The edges of a spanning forest as `(u, v, weight)` tuples.
+pub type SpanningForest<V, W> = Vec<WeightedEdge<V, W>>;
+
+/// Computes a minimum spanning forest of an undirected weighted graph using
+/// Kruskal's algorithm.
+///
+/// The edges are read from [`WeightedGraph::weighted_edges`], so graph types
+/// that store an edge list are not scanned vertex by vertex.
+///
+/// Edges are ordered with [`Measure::compare`], so any weight type implementing
+/// [`Measure`] can be used, including negative weights.
+///
+/// # Arguments
+///
+/// * `graph` - The undirected weighted graph.
+///
+/// # Returns
+///
+/// The edges of the forest as `(u, v, weight)` tuples, one spanning tree per
+/// connected component.
+///
+/// # Errors
+///
+/// * `GraphError::Algorithm` - If the graph is directed, or if an edge has
+///   no weight or an invalid weight (such as `NaN`).
+pub fn minimum_spanning_forest<G>(
+    graph: &G,
+) -> Result<SpanningForest<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    if graph.is_directed() {
+        return Err(AlgorithmError::RequiresUndirected.into());
+    }
+    trace_span!("minimum_spanning_forest");
+
+    let index: HashMap<&G::Vertex, usize> =
+        graph.vertices().enumerate().map(|(i, v)| (v, i)).collect();
+
+    let mut edges: Vec<_> = graph.weighted_edges().collect();
+    if edges.len() < graph.size() {
+        return Err(unweighted_edge(graph));
+    }
+    if let Some((u, v, weight)) = edges.iter().find(|(_, _, weight)| !weight.is_valid()) {
+        return Err(AlgorithmError::invalid_weight(u, v, weight).into());
+    }
+    edges.sort_by(|a, b| a.2.compare(b.2));
+    trace_event!(edges = edges.len(), "edges sorted");
+
+    let mut sets = UnionFind::new(index.len());
+    let mut forest = Vec::new();
+    for (u, v, weight) in edges {
+        if sets.union(index[u], index[v]) {
+            forest.push((u.clone(), v.clone(), weight.clone()));
+        }
+    }
+
+    trace_event!(forest_edges = forest.len(), "forest complete");
+    Ok(forest)
+}
@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use crate::Graph;
+
+/// Returns the neighbors of `u` that are also neighbors of `v`, scanning the
+/// smaller neighborhood and testing membership in the other with
+/// [`Graph::contains_edge`].
+fn shared<'a, G>(
+    graph: &'a G,
+    u: &'a G::Vertex,
+    v: &'a G::Vertex,
+) -> Option<impl Iterator<Item = &'a G::Vertex> + 'a>
+where
+    G: Graph,
+{
+    let (small, large) = if graph.degree(u)? <= graph.degree(v)? {
+        (u, v)
+    } else {
+        (v, u)
+    };
+    Some(
+        graph
+            .neighbors(small)?
+            .filter(move |w| graph.contains_edge(large, w)),
+    )
+}
+
+/// Computes the common neighbors of two vertices.
+///
+/// In a directed graph the out-neighborhoods are compared.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+/// * `u` - The first vertex.
+/// * `v` - The second vertex.
+///
+/// # Returns
+///
+/// * `Some(HashSet<V>)` - The vertices adjacent to both `u` and `v`.
+/// * `None` - If `u` or `v` is not in the graph.
+#[must_use]
+pub fn common_neighbors<G>(graph: &G, u: &G::Vertex, v: &G::Vertex) -> Option<HashSet<G::Vertex>>
+where
+    G: Graph,
+{
+    Some(shared(graph, u, v)?.cloned().collect())
+}
+
+/// Computes the union of the neighborhoods of two vertices.
+///
+/// In a directed graph the out-neighborhoods are combined.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+/// * `u` - The first vertex.
+/// * `v` - The second vertex.
+///
+/// # Returns
+///
+/// * `Some(HashSet<V>)` - The vertices adjacent to `u` or `v`.
+/// * `None` - If `u` or `v` is not in the graph.
+#[must_use]
+pub fn neighborhood_union<G>(graph: &G, u: &G::Vertex, v: &G::Vertex) -> Option<HashSet<G::Vertex>>
+where
+    G: Graph,
+{
+    let first = graph.neighbors(u)?;
+    let second = graph.neighbors(v)?;
+    Some(first.chain(second).cloned().collect())
+}
+
+/// Computes the Jaccard coefficient of two vertices, the number of common
+/// neighbors divided by the size of the union of their neighborhoods.
+///
+/// The score is computed from counts, without building either set.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The coefficient in `[0, 1]`, `0.0` if neither vertex has a
+///   neighbor.
+/// * `None` - If `u` or `v` is not in the graph.
+#[must_use]
+pub fn jaccard_coefficient<G>(graph: &G, u: &G::Vertex, v: &G::Vertex) -> Option<f64>
+where
+    G: Graph,
+{
+    let common = shared(graph, u, v)?.count();
+    let union = graph.degree(u)? + graph.degree(v)? - common;
+    if union == 0 {
+        return Some(0.0);
+    }
+    #[allow(clippy::cast_precision_loss)]
+    Some(common as f64 / union as f64)
+}
+
+/// Computes the Adamic–Adar index of two vertices, the sum of `1 / ln(d(w))`
+/// over their common neighbors `w`.
+///
+/// Common neighbors of degree one contribute nothing, since their weight would be
+/// infinite; this only happens in directed graphs or when `u` and `v` are the
+/// same vertex.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The index, `0.0` if the vertices have no common neighbor.
+/// * `None` - If `u` or `v` is not in the graph.
+#[must_use]
+pub fn adamic_adar_index<G>(graph: &G, u: &G::Vertex, v: &G::Vertex) -> Option<f64>
+where
+    G: Graph,
+{
+    Some(
+        shared(graph, u, v)?
+            .filter_map(|w| graph.degree(w))
+            .filter(|&degree| degree > 1)
+            .fold(0.0, |index, degree| {
+                #[allow(clippy::cast_precision_loss)]
+                let degree = degree as f64;
+                index + 1.0 / degree.ln()
+            }),
+    )
+}
+
+/// Computes the closed neighborhood `N[v]`, the neighbors of `v` together with
+/// `v` itself.
+///
+/// In a directed graph the out-neighbors are used.
+///
+/// # Returns
+///
+/// * `Some(HashSet<V>)` - The closed neighborhood.
+/// * `None` - If `v` is not in the graph.
+#[must_use]
+pub fn closed_neighborhood<G>(graph: &G, v: &G::Vertex) -> Option<HashSet<G::Vertex>>
+where
+    G: Graph,
+{
+    let neighbors = graph.neighbors(v)?;
+    Some(std::iter::once(v).chain(neighbors).cloned().collect())
+}
+
+/// Computes the vertices within `k` hops of `v`, including `v` itself.
+///
+/// The breadth-first search stops after `k` layers, so only the ball around `v`
+/// is explored. `k = 1` gives the closed neighborhood and `k = 2` the vertices at
+/// distance at most two used by distance-2 domination and coloring. In a directed
+/// graph only outgoing edges are followed.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search.
+/// * `v` - The center of the neighborhood.
+/// * `k` - The largest distance from `v`.
+///
+/// # Returns
+///
+/// * `Some(HashSet<V>)` - The vertices at distance at most `k` from `v`.
+/// * `None` - If `v` is not in the graph.
+#[must_use]
+pub fn neighborhood_within<G>(graph: &G, v: &G::Vertex, k: usize) -> Option<HashSet<G::Vertex>>
+where
+    G: Graph,
+{
+    if !graph.contains_vertex(v) {
+        return None;
+    }
+    let mut seen: HashSet<&G::Vertex> = HashSet::from([v]);
+    let mut frontier = vec![v];
+    for _ in 0..k {
+        if frontier.is_empty() {
+            break;
+        }
+        let mut next = Vec::new();
+        for u in frontier {
+            for w in graph.neighbors(u).into_iter().flatten() {
+                if seen.insert(w) {
+                    next.push(w);
+                }
+            }
+        }
+        frontier = next;
+    }
+    Some(seen.into_iter().cloned().collect())
+}
@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::flow::FlowNetwork,
+    error::AlgorithmError,
+    graphs::simple::{DirectedGraph, SimpleGraph},
+    Graph, GraphError, GraphMut,
+};
+
+/// The vertices of a graph in a fixed order, and its edges as pairs of positions.
+pub(crate) type NumberedEdges<'a, V> = (Vec<&'a V>, Vec<(usize, usize)>);
+
+/// Numbers the vertices of an undirected graph and lists its edges once each,
+/// smaller number first.
+pub(crate) fn edge_list<G>(graph: &G) -> Result<NumberedEdges<'_, G::Vertex>, GraphError>
+where
+    G: Graph,
+{
+    if graph.is_directed() {
+        return Err(AlgorithmError::RequiresUndirected.into());
+    }
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let mut edges = Vec::new();
+    for (u, &vertex) in vertices.iter().enumerate() {
+        for v in graph
+            .neighbors(vertex)
+            .into_iter()
+            .flatten()
+            .map(|v| index[v])
+        {
+            if u <= v {
+                edges.push((u, v));
+            }
+        }
+    }
+    Ok((vertices, edges))
+}
+
+/// Builds the directed graph with the given vertices and arcs.
+fn oriented<V>(vertices: &[&V], arcs: impl IntoIterator<Item = (usize, usize)>) -> DirectedGraph<V>
+where
+    V: Eq + std::hash::Hash + Clone + std::fmt::Debug,
+{
+    let mut graph = SimpleGraph::new_directed();
+    for &v in vertices {
+        let _ = graph.add_vertex(v.clone());
+    }
+    for (u, v) in arcs {
+        let _ = graph.add_edge(vertices[u], vertices[v]);
+    }
+    graph
+}
+
+/// Orients every edge of an undirected graph from the endpoint that comes first
+/// in `order` to the other one.
+///
+/// The result is acyclic and `order` is one of its topological orderings; every
+/// acyclic orientation arises this way.
+///
+/// # Arguments
+///
+/// * `graph` - The undirected graph.
+/// * `order` - Every vertex of the graph exactly once.
+///
+/// # Returns
+///
+/// A directed graph with the same vertices and one arc per edge.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is directed or has a
+/// self-loop, or if `order` is not an ordering of its vertices.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::orientation::acyclic_orientation, graph, Graph};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1 };
+/// let oriented = acyclic_orientation(&graph, &[3, 1, 2]).unwrap();
+/// assert!(oriented.contains_edge(&3, &1) && oriented.contains_edge(&1, &2));
+/// assert!(oriented.contains_edge(&3, &2));
+/// ```
+pub fn acyclic_orientation<G>(
+    graph: &G,
+    order: &[G::Vertex],
+) -> Result<DirectedGraph<G::Vertex>, GraphError>
+where
+    G: Graph,
+{
+    let (vertices, edges) = edge_list(graph)?;
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let mut position = vec![usize::MAX; vertices.len()];
+    for (rank, v) in order.iter().enumerate() {
+        match index.get(v) {
+            Some(&i) if position[i] == usize::MAX => position[i] = rank,
+            _ => {
+                return Err(AlgorithmError::InvalidArgument(format!(
+                    "Vertex {v:?} is missing from the graph or repeated in the order"
+                ))
+                .into());
+            }
+        }
+    }
+    if order.len() != vertices.len() {
+        return Err(AlgorithmError::InvalidArgument(
+            "The order must contain every vertex of the graph".to_string(),
+        )
+        .into());
+    }
+    if edges.iter().any(|&(u, v)| u == v) {
+        return Err(AlgorithmError::SelfLoop.into());
+    }
+    let arcs = edges.into_iter().map(|(u, v)| {
+        if position[u] < position[v] {
+            (u, v)
+        } else {
+            (v, u)
+        }
+    });
+    Ok(oriented(&vertices, arcs))
+}
+
+/// Computes a strongly connected orientation of an undirected graph, if one
+/// exists.
+///
+/// By Robbins' theorem such an orientation exists exactly when the graph is
+/// connected and has no bridge. A depth-first search orients tree edges away from
+/// the root and the other edges back towards it, checking with low points that
+/// no tree edge is a bridge, in `O(n + m)` time. Self-loops become loop arcs.
+///
+/// # Arguments
+///
+/// * `graph` - The undirected graph.
+///
+/// # Returns
+///
+/// * `Some(DirectedGraph<V>)` - A strongly connected orientation.
+/// * `None` - If the graph is disconnected or has a bridge.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is directed.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::orientation::strong_orientation, graph};
+///
+/// let cycle = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 1 };
+/// assert!(strong_orientation(&cycle).unwrap().is_some());
+///
+/// let path = graph! { 1 - 2, 2 - 3 };
+/// assert!(strong_orientation(&path).unwrap().is_none());
+/// ```
+pub fn strong_orientation<G>(graph: &G) -> Result<Option<DirectedGraph<G::Vertex>>, GraphError>
+where
+    G: Graph,
+{
+    let (vertices, edges) = edge_list(graph)?;
+    trace_span!("strong_orientation", edges = edges.len());
+    let n = vertices.len();
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    for (e, &(u, v)) in edges.iter().enumerate() {
+        if u != v {
+            adjacency[u].push((v, e));
+            adjacency[v].push((u, e));
+        }
+    }
+    let mut arcs: Vec<(usize, usize)> = edges.iter().copied().filter(|&(u, v)| u == v).collect();
+    if n == 0 {
+        return Ok(Some(oriented(&vertices, arcs)));
+    }
+
+    let mut discovery = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut used = vec![false; edges.len()];
+    discovery[0] = 0;
+    low[0] = 0;
+    let mut visited = 1;
+    // Stack of (vertex, next adjacency position).
+    let mut stack = vec![(0, 0)];
+    while let Some((u, next)) = stack.last_mut() {
+        let u = *u;
+        if let Some(&(v, e)) = adjacency[u].get(*next) {
+            *next += 1;
+            if used[e] {
+                continue;
+            }
+            used[e] = true;
+            arcs.push((u, v));
+            if discovery[v] == usize::MAX {
+                discovery[v] = visited;
+                low[v] = visited;
+                visited += 1;
+                stack.push((v, 0));
+            } else {
+                low[u] = low[u].min(discovery[v]);
+            }
+        } else {
+            stack.pop();
+            if let Some(&(parent, _)) = stack.last() {
+                if low[u] > discovery[parent] {
+                    trace_event!("bridge found");
+                    return Ok(None);
+                }
+                low[parent] = low[parent].min(low[u]);
+            }
+        }
+    }
+    if visited < n {
+        return Ok(None);
+    }
+    Ok(Some(oriented(&vertices, arcs)))
+}
+
+/// Computes an orientation of an undirected graph minimizing the largest
+/// out-degree.
+///
+/// Deciding whether out-degree `k` suffices is a flow problem: every edge sends
+/// one unit to the endpoint it leaves, and every vertex receives at most `k`. The
+/// smallest feasible `k` is found by binary search between `ceil(m / n)` and the
+/// maximum degree. It equals the pseudoarboricity, the largest `ceil(m' / n')`
+/// over the subgraphs of the graph, and is at most its degeneracy. Self-loops
+/// count towards the out-degree of their vertex.
+///
+/// # Arguments
+///
+/// * `graph` - The undirected graph.
+///
+/// # Returns
+///
+/// A directed graph with the same vertices and one arc per edge, whose largest
+/// out-degree is as small as possible.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is directed.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::orientation::min_max_outdegree_orientation, graph, Graph};
+///
+/// // A star has an orientation with out-degree one: every leaf points to the center.
+/// let star = graph! { 0 - 1, 0 - 2, 0 - 3, 0 - 4 };
+/// let oriented = min_max_outdegree_orientation(&star).unwrap();
+/// assert!(oriented.vertices().all(|v| oriented.degree(v) <= Some(1)));
+/// ```
+pub fn min_max_outdegree_orientation<G>(graph: &G) -> Result<DirectedGraph<G::Vertex>, GraphError>
+where
+    G: Graph,
+{
+    let (vertices, edges) = edge_list(graph)?;
+    trace_span!("min_max_outdegree_orientation", edges = edges.len());
+    let (arcs, _) = orient_with_min_outdegree(vertices.len(), &edges);
+    Ok(oriented(&vertices, arcs))
+}
+
+/// Orients the numbered edges with the smallest possible largest out-degree,
+/// returning the arcs and that out-degree.
+pub(crate) fn orient_with_min_outdegree(
+    n: usize,
+    edges: &[(usize, usize)],
+) -> (Vec<(usize, usize)>, usize) {
+    let m = edges.len();
+    if m == 0 {
+        return (Vec::new(), 0);
+    }
+    let mut degree = vec![0; n];
+    for &(u, v) in edges {
+        degree[u] += 1;
+        if u != v {
+            degree[v] += 1;
+        }
+    }
+    // Out-degree at most the maximum degree is always feasible.
+    let mut high = degree.iter().copied().max().unwrap_or(0);
+    let mut best = orient_within(n, edges, high).unwrap_or_default();
+    let mut low = m.div_ceil(n);
+    while low < high {
+        let k = low + (high - low) / 2;
+        if let Some(arcs) = orient_within(n, edges, k) {
+            best = arcs;
+            high = k;
+        } else {
+            low = k + 1;
+        }
+    }
+    (best, high)
+}
+
+/// Orients the numbered edges so that no vertex has out-degree above `k`, if
+/// possible.
+fn orient_within(n: usize, edges: &[(usize, usize)], k: usize) -> Option<Vec<(usize, usize)>> {
+    // Nodes: the edges, then the vertices, the source and the sink.
+    let m = edges.len();
+    let (source, sink) = (m + n, m + n + 1);
+    let mut network = FlowNetwork::new(m + n + 2);
+    let mut choices = Vec::with_capacity(m);
+    for (e, &(u, v)) in edges.iter().enumerate() {
+        network.add_arc(source, e, 1);
+        let from_u = network.add_arc(e, m + u, 1);
+        if u != v {
+            network.add_arc(e, m + v, 1);
+        }
+        choices.push(from_u);
+    }
+    for v in 0..n {
+        network.add_arc(m + v, sink, k);
+    }
+    if network.max_flow(source, sink) < m {
+        return None;
+    }
+    Some(
+        edges
+            .iter()
+            .zip(choices)
+            .map(|(&(u, v), from_u)| {
+                if network.flow(from_u) == 1 {
+                    (u, v)
+                } else {
+                    (v, u)
+                }
+            })
+            .collect(),
+    )
+}
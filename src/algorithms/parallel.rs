@@ -0,0 +1,352 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use rayon::prelude::*;
+
+use crate::{algorithms::adjacency::IndexedGraph, error::AlgorithmError, Graph, GraphError};
+
+/// Computes hop distances from `source` with a level-synchronous breadth-first
+/// search, expanding each layer in parallel.
+///
+/// This returns the same distances as
+/// [`bfs_distances`](crate::algorithms::traversal::bfs_distances) and pays off
+/// on large graphs with wide layers.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search.
+/// * `source` - The vertex where the search starts.
+///
+/// # Returns
+///
+/// * `Some(HashMap<V, usize>)` - The distance to every vertex reachable from
+///   `source`, including `source` itself at distance `0`.
+/// * `None` - If `source` is not in the graph.
+pub fn par_bfs_distances<G>(graph: &G, source: &G::Vertex) -> Option<HashMap<G::Vertex, usize>>
+where
+    G: Graph + Sync,
+    G::Vertex: Send + Sync,
+{
+    if !graph.contains_vertex(source) {
+        return None;
+    }
+    trace_span!("par_bfs_distances");
+    let indexed = IndexedGraph::par_new(graph);
+    let start = indexed.index[source];
+
+    let distances: Vec<AtomicUsize> = (0..indexed.vertices.len())
+        .map(|_| AtomicUsize::new(usize::MAX))
+        .collect();
+    distances[start].store(0, Ordering::Relaxed);
+    let mut frontier = vec![start];
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        depth += 1;
+        // Claiming a vertex with a compare-exchange keeps each one in exactly one
+        // layer, even when several frontier vertices reach it at once.
+        frontier = frontier
+            .par_iter()
+            .flat_map_iter(|&u| {
+                indexed.out[u].iter().copied().filter(|&w| {
+                    distances[w]
+                        .compare_exchange(usize::MAX, depth, Ordering::Relaxed, Ordering::Relaxed)
+                        .is_ok()
+                })
+            })
+            .collect();
+        trace_event!(depth, layer = frontier.len(), "layer expanded");
+    }
+
+    Some(
+        indexed
+            .vertices
+            .par_iter()
+            .zip(distances.par_iter())
+            .filter_map(|(&v, d)| {
+                let d = d.load(Ordering::Relaxed);
+                (d != usize::MAX).then(|| (v.clone(), d))
+            })
+            .collect(),
+    )
+}
+
+/// Labels the connected components with parallel label propagation.
+///
+/// Every vertex starts with its own label and repeatedly takes the smallest label
+/// among itself and its neighbors, all vertices being updated in parallel, until
+/// no label changes. The number of rounds is bounded by the largest component
+/// diameter. Edge directions are ignored, so directed graphs are split into weakly
+/// connected components.
+///
+/// # Returns
+///
+/// A map from every vertex to the number of its component, components being
+/// numbered from `0` in no particular order.
+#[must_use]
+pub fn par_connected_components<G>(graph: &G) -> HashMap<G::Vertex, usize>
+where
+    G: Graph + Sync,
+    G::Vertex: Send + Sync,
+{
+    trace_span!("par_connected_components");
+    let indexed = IndexedGraph::par_new(graph);
+    let neighbors = indexed.symmetric(graph.is_directed());
+    let labels: Vec<AtomicUsize> = (0..neighbors.len()).map(AtomicUsize::new).collect();
+
+    // Only reported through `tracing`.
+    #[cfg(feature = "tracing")]
+    let mut rounds = 0;
+    loop {
+        #[cfg(feature = "tracing")]
+        {
+            rounds += 1;
+        }
+        let changed = AtomicBool::new(false);
+        neighbors.par_iter().enumerate().for_each(|(v, list)| {
+            let smallest = list
+                .iter()
+                .map(|&w| labels[w].load(Ordering::Relaxed))
+                .min()
+                .unwrap_or(usize::MAX);
+            if smallest < labels[v].fetch_min(smallest, Ordering::Relaxed) {
+                changed.store(true, Ordering::Relaxed);
+            }
+        });
+        if !changed.into_inner() {
+            break;
+        }
+    }
+    trace_event!(rounds, "labels converged");
+
+    let mut numbers: HashMap<usize, usize> = HashMap::new();
+    indexed
+        .vertices
+        .iter()
+        .zip(labels)
+        .map(|(&v, label)| {
+            let next = numbers.len();
+            let number = *numbers.entry(label.into_inner()).or_insert(next);
+            (v.clone(), number)
+        })
+        .collect()
+}
+
+/// Parameters of [`par_pagerank`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageRankConfig {
+    /// Probability of following a link rather than jumping to a random vertex.
+    pub damping: f64,
+    /// The iteration stops once the ranks change by less than this in `L1` norm.
+    pub tolerance: f64,
+    /// The iteration stops after this many rounds even if it has not converged.
+    pub max_iterations: usize,
+}
+
+impl Default for PageRankConfig {
+    fn default() -> Self {
+        Self {
+            damping: 0.85,
+            tolerance: 1e-10,
+            max_iterations: 100,
+        }
+    }
+}
+
+/// Computes the `PageRank` of every vertex by power iteration, updating all
+/// vertices in parallel.
+///
+/// Each edge of an undirected graph is followed both ways. The rank of vertices
+/// without out-neighbors is spread evenly over all vertices.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to rank.
+/// * `config` - The damping factor and stopping criteria.
+///
+/// # Returns
+///
+/// A map from every vertex to its rank. Ranks are nonnegative and sum to one.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the damping factor is not in
+/// `[0, 1]`.
+pub fn par_pagerank<G>(
+    graph: &G,
+    config: &PageRankConfig,
+) -> Result<HashMap<G::Vertex, f64>, GraphError>
+where
+    G: Graph + Sync,
+    G::Vertex: Send + Sync,
+{
+    if !(0.0..=1.0).contains(&config.damping) {
+        return Err(AlgorithmError::InvalidArgument(format!(
+            "The damping factor must be in [0, 1], got {}",
+            config.damping
+        ))
+        .into());
+    }
+    trace_span!("par_pagerank");
+    let indexed = IndexedGraph::par_new(graph);
+    let n = indexed.vertices.len();
+    if n == 0 {
+        return Ok(HashMap::new());
+    }
+
+    let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (u, targets) in indexed.out.iter().enumerate() {
+        for &v in targets {
+            incoming[v].push(u);
+        }
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let out_degree: Vec<f64> = indexed.out.iter().map(|t| t.len() as f64).collect();
+    #[allow(clippy::cast_precision_loss)]
+    let n_f64 = n as f64;
+
+    let mut ranks = vec![1.0 / n_f64; n];
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    for iteration in 0..config.max_iterations {
+        let dangling: f64 = ranks
+            .par_iter()
+            .zip(out_degree.par_iter())
+            .filter(|&(_, &d)| d == 0.0)
+            .map(|(&r, _)| r)
+            .sum();
+        let base = (1.0 - config.damping + config.damping * dangling) / n_f64;
+        let next: Vec<f64> = incoming
+            .par_iter()
+            .map(|sources| {
+                let flow: f64 = sources.iter().map(|&u| ranks[u] / out_degree[u]).sum();
+                base + config.damping * flow
+            })
+            .collect();
+        let change: f64 = next
+            .par_iter()
+            .zip(ranks.par_iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum();
+        ranks = next;
+        if change < config.tolerance {
+            trace_event!(iteration, "converged");
+            break;
+        }
+    }
+
+    Ok(indexed.vertices.into_iter().cloned().zip(ranks).collect())
+}
+
+/// Counts the triangles each vertex belongs to, processing vertices in
+/// parallel.
+///
+/// This returns the same counts as
+/// [`triangles`](crate::algorithms::clustering::triangles): edge directions are
+/// ignored and self-loops do not form triangles.
+///
+/// # Returns
+///
+/// A map from every vertex to the number of triangles containing it.
+#[must_use]
+pub fn par_triangles<G>(graph: &G) -> HashMap<G::Vertex, usize>
+where
+    G: Graph + Sync,
+    G::Vertex: Send + Sync,
+{
+    trace_span!("par_triangles");
+    let indexed = IndexedGraph::par_new(graph);
+    let neighbors = indexed.symmetric(graph.is_directed());
+    indexed
+        .vertices
+        .par_iter()
+        .zip(neighbors.par_iter())
+        .map(|(&v, list)| {
+            // Each edge among the neighbors of v is seen from both endpoints.
+            let links: usize = list
+                .iter()
+                .map(|&u| sorted_intersection_len(list, &neighbors[u]))
+                .sum();
+            (v.clone(), links / 2)
+        })
+        .collect()
+}
+
+/// Tests whether a metric of a graph is significant against a null model,
+/// generating and measuring the replicas in parallel.
+///
+/// Every replica is seeded as in
+/// [`significance_test`](crate::algorithms::significance::significance_test),
+/// so both functions return the same result for the same seed.
+///
+/// # Arguments
+///
+/// * `graph` - The observed graph.
+/// * `metric` - The metric to test.
+/// * `randomize` - Generates a random replica of a graph.
+/// * `replicas` - The number of replicas.
+/// * `seed` - The seed of the replica generators.
+///
+/// # Returns
+///
+/// The observed metric compared with its values over the replicas.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::{
+///         parallel::par_significance_test,
+///         significance::{double_edge_swap, significance_test},
+///     },
+///     graph, Graph, SimpleGraph,
+/// };
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 3 - 4, 4 - 5, 5 - 6, 6 - 4 };
+/// let max_degree = |g: &SimpleGraph<i32>| {
+///     g.vertices().filter_map(|v| g.degree(v)).max().unwrap_or(0) as f64
+/// };
+/// let randomize = |g: &SimpleGraph<i32>, rng: &mut _| double_edge_swap(g, 50, rng);
+/// let parallel = par_significance_test(&graph, max_degree, randomize, 20, 1);
+/// assert_eq!(parallel, significance_test(&graph, max_degree, randomize, 20, 1));
+/// assert_eq!(parallel.z_score, None);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn par_significance_test<G, M, N>(
+    graph: &G,
+    metric: M,
+    randomize: N,
+    replicas: usize,
+    seed: u64,
+) -> crate::algorithms::significance::Significance
+where
+    G: Graph + Sync,
+    M: Fn(&G) -> f64 + Sync,
+    N: Fn(&G, &mut rand::rngs::StdRng) -> G + Sync,
+{
+    use crate::algorithms::significance::{replica_rng, Significance};
+
+    trace_span!("par_significance_test", replicas);
+    let values = (0..replicas)
+        .into_par_iter()
+        .map(|i| metric(&randomize(graph, &mut replica_rng(seed, i))))
+        .collect();
+    Significance::new(metric(graph), values)
+}
+
+fn sorted_intersection_len(a: &[usize], b: &[usize]) -> usize {
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
@@ -0,0 +1,585 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use crate::{
+    algorithms::adjacency::undirected_adjacency, error::AlgorithmError, Graph, GraphError,
+};
+
+/// The largest graph accepted by [`tutte_polynomial`] and
+/// [`chromatic_polynomial`]. Deletion–contraction takes exponential time, and
+/// this bound keeps even dense graphs within seconds.
+pub const MAX_POLYNOMIAL_VERTICES: usize = 12;
+
+/// Coefficients of a polynomial in `x` and `y`, indexed by the power of `x` and
+/// then by the power of `y`.
+type Coefficients = Vec<Vec<u128>>;
+
+/// Symmetric edge multiplicities of a loopless multigraph.
+type Multigraph = Vec<Vec<u32>>;
+
+/// The Tutte polynomial `T(x, y)` of a graph.
+///
+/// Every coefficient of a Tutte polynomial is a nonnegative integer. Many graph
+/// invariants are evaluations of it: `T(1, 1)` counts spanning forests of the
+/// components (spanning trees of a connected graph), `T(2, 1)` counts forests and
+/// `T(2, 2)` is `2` raised to the number of edges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TuttePolynomial {
+    coefficients: Coefficients,
+}
+
+impl TuttePolynomial {
+    /// Returns the coefficient of `x^i y^j`.
+    #[must_use]
+    pub fn coefficient(&self, i: usize, j: usize) -> u128 {
+        self.coefficients
+            .get(i)
+            .and_then(|row| row.get(j))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the coefficients, indexed by the power of `x` and then by the
+    /// power of `y`. Trailing zeros are omitted.
+    #[must_use]
+    pub fn coefficients(&self) -> &[Vec<u128>] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at `(x, y)`.
+    #[must_use]
+    pub fn evaluate(&self, x: f64, y: f64) -> f64 {
+        self.coefficients.iter().rev().fold(0.0, |acc, row| {
+            #[allow(clippy::cast_precision_loss)]
+            let row_value = row.iter().rev().fold(0.0, |acc, &c| acc * y + c as f64);
+            acc * x + row_value
+        })
+    }
+}
+
+impl Display for TuttePolynomial {
+    /// Formats the polynomial with terms in decreasing powers of `x`, then `y`,
+    /// for example `x^2 + x + y`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (i, row) in self.coefficients.iter().enumerate().rev() {
+            for (j, &c) in row.iter().enumerate().rev().filter(|&(_, &c)| c != 0) {
+                if !first {
+                    write!(f, " + ")?;
+                }
+                first = false;
+                write_term(f, c, &[("x", i), ("y", j)])?;
+            }
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+/// The chromatic polynomial `P(k)` of a graph, whose value at a nonnegative
+/// integer `k` is the number of proper colorings with `k` colors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChromaticPolynomial {
+    coefficients: Vec<i128>,
+}
+
+impl ChromaticPolynomial {
+    /// Returns the coefficient of `k^i`.
+    #[must_use]
+    pub fn coefficient(&self, i: usize) -> i128 {
+        self.coefficients.get(i).copied().unwrap_or(0)
+    }
+
+    /// Returns the coefficients, indexed by the power of `k`. Trailing zeros are
+    /// omitted.
+    #[must_use]
+    pub fn coefficients(&self) -> &[i128] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at `k`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(i128)` - The value of the polynomial.
+    /// * `None` - If the computation overflows an `i128`.
+    #[must_use]
+    pub fn evaluate(&self, k: i128) -> Option<i128> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(0i128, |acc, &c| acc.checked_mul(k)?.checked_add(c))
+    }
+}
+
+impl Display for ChromaticPolynomial {
+    /// Formats the polynomial with terms in decreasing powers of `k`, for example
+    /// `k^3 - 3k^2 + 2k`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (i, &c) in self.coefficients.iter().enumerate().rev() {
+            if c == 0 {
+                continue;
+            }
+            match (first, c < 0) {
+                (true, true) => write!(f, "-")?,
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+                (true, false) => {}
+            }
+            first = false;
+            write_term(f, c.unsigned_abs(), &[("k", i)])?;
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a monomial such as `3x^2y`, leaving out unit coefficients and powers.
+fn write_term(f: &mut fmt::Formatter<'_>, c: u128, powers: &[(&str, usize)]) -> fmt::Result {
+    if c != 1 || powers.iter().all(|&(_, p)| p == 0) {
+        write!(f, "{c}")?;
+    }
+    for &(name, p) in powers {
+        match p {
+            0 => {}
+            1 => write!(f, "{name}")?,
+            _ => write!(f, "{name}^{p}")?,
+        }
+    }
+    Ok(())
+}
+
+fn add_into(target: &mut Coefficients, other: &Coefficients) {
+    if target.len() < other.len() {
+        target.resize(other.len(), Vec::new());
+    }
+    for (row, other_row) in target.iter_mut().zip(other) {
+        if row.len() < other_row.len() {
+            row.resize(other_row.len(), 0);
+        }
+        for (c, &o) in row.iter_mut().zip(other_row) {
+            *c += o;
+        }
+    }
+}
+
+fn multiply(a: &Coefficients, b: &Coefficients) -> Coefficients {
+    let mut product: Coefficients = Vec::new();
+    for (i, a_row) in a.iter().enumerate() {
+        for (j, &a_c) in a_row.iter().enumerate().filter(|&(_, &c)| c != 0) {
+            for (k, b_row) in b.iter().enumerate() {
+                for (l, &b_c) in b_row.iter().enumerate().filter(|&(_, &c)| c != 0) {
+                    if product.len() <= i + k {
+                        product.resize(i + k + 1, Vec::new());
+                    }
+                    let row = &mut product[i + k];
+                    if row.len() <= j + l {
+                        row.resize(j + l + 1, 0);
+                    }
+                    row[j + l] += a_c * b_c;
+                }
+            }
+        }
+    }
+    product
+}
+
+/// Returns `1 + y + ... + y^(m - 1)`, or `x + y + ... + y^(m - 1)` when `bridge`
+/// is set: the contribution of `m` parallel edges that are contracted together.
+fn parallel_class(m: u32, bridge: bool) -> Coefficients {
+    let mut coefficients = vec![vec![1; m as usize]];
+    if bridge {
+        coefficients[0][0] = 0;
+        coefficients.push(vec![1]);
+    }
+    coefficients
+}
+
+fn is_connected(matrix: &Multigraph) -> bool {
+    let mut seen = vec![false; matrix.len()];
+    let mut stack = vec![0];
+    seen[0] = true;
+    while let Some(u) = stack.pop() {
+        for (v, &m) in matrix[u].iter().enumerate() {
+            if m > 0 && !seen[v] {
+                seen[v] = true;
+                stack.push(v);
+            }
+        }
+    }
+    seen.into_iter().all(|s| s)
+}
+
+fn components(matrix: &Multigraph) -> Vec<Vec<usize>> {
+    let mut component = vec![usize::MAX; matrix.len()];
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for start in 0..matrix.len() {
+        if component[start] != usize::MAX {
+            continue;
+        }
+        let mut group = vec![start];
+        component[start] = groups.len();
+        let mut next = 0;
+        while next < group.len() {
+            let u = group[next];
+            next += 1;
+            for (v, &m) in matrix[u].iter().enumerate() {
+                if m > 0 && component[v] == usize::MAX {
+                    component[v] = groups.len();
+                    group.push(v);
+                }
+            }
+        }
+        groups.push(group);
+    }
+    groups
+}
+
+fn submatrix(matrix: &Multigraph, keep: &[usize]) -> Multigraph {
+    keep.iter()
+        .map(|&u| keep.iter().map(|&v| matrix[u][v]).collect())
+        .collect()
+}
+
+/// Merges `u` into `v`, dropping the edges between them.
+fn contract(matrix: &Multigraph, u: usize, v: usize) -> Multigraph {
+    let mut merged = matrix.clone();
+    let row = merged[u].clone();
+    for (w, &m) in row.iter().enumerate() {
+        if w != u && w != v {
+            merged[v][w] += m;
+            merged[w][v] = merged[v][w];
+        }
+    }
+    merged[u][v] = 0;
+    merged[v][u] = 0;
+    let keep: Vec<usize> = (0..merged.len()).filter(|&w| w != u).collect();
+    submatrix(&merged, &keep)
+}
+
+/// Deletion–contraction over loopless multigraphs, memoized on their
+/// multiplicity matrices.
+#[derive(Default)]
+struct Tutte {
+    memo: HashMap<Multigraph, Coefficients>,
+}
+
+impl Tutte {
+    fn compute(&mut self, matrix: &Multigraph) -> Coefficients {
+        let distinct = |row: &Vec<u32>| row.iter().filter(|&&m| m > 0).count();
+        // Isolated vertices contribute nothing. Sorting by degree makes isomorphic
+        // minors more likely to share a memo entry.
+        let mut keep: Vec<usize> = (0..matrix.len())
+            .filter(|&u| distinct(&matrix[u]) > 0)
+            .collect();
+        if keep.is_empty() {
+            return vec![vec![1]];
+        }
+        keep.sort_by_key(|&u| (distinct(&matrix[u]), matrix[u].iter().sum::<u32>()));
+        let matrix = submatrix(matrix, &keep);
+        if let Some(known) = self.memo.get(&matrix) {
+            return known.clone();
+        }
+
+        let groups = components(&matrix);
+        let result = if groups.len() > 1 {
+            groups.iter().fold(vec![vec![1]], |product, group| {
+                multiply(&product, &self.compute(&submatrix(&matrix, group)))
+            })
+        } else {
+            // Vertex 0 has the fewest neighbors; branch on all its edges to one
+            // neighbor at once.
+            let u = 0;
+            let v = (1..matrix.len())
+                .max_by_key(|&v| matrix[u][v])
+                .expect("a connected graph with edges has two vertices");
+            let m = matrix[u][v];
+            let mut deleted = matrix.clone();
+            deleted[u][v] = 0;
+            deleted[v][u] = 0;
+            let contracted = self.compute(&contract(&matrix, u, v));
+            if distinct(&matrix[u]) == 1 || !is_connected(&deleted) {
+                multiply(&parallel_class(m, true), &contracted)
+            } else {
+                let mut sum = self.compute(&deleted);
+                add_into(&mut sum, &multiply(&parallel_class(m, false), &contracted));
+                sum
+            }
+        };
+        self.memo.insert(matrix, result.clone());
+        result
+    }
+}
+
+fn check_size<G>(graph: &G) -> Result<(), GraphError>
+where
+    G: Graph,
+{
+    if graph.order() > MAX_POLYNOMIAL_VERTICES {
+        return Err(AlgorithmError::TooLarge {
+            limit: MAX_POLYNOMIAL_VERTICES,
+            actual: graph.order(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Builds the multiplicity matrix of the underlying simple graph, returning it
+/// with the number of self-loops and of connected components.
+fn multigraph<G>(graph: &G) -> (Multigraph, usize, usize)
+where
+    G: Graph,
+{
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let adjacency = undirected_adjacency(graph);
+    let matrix: Multigraph = vertices
+        .iter()
+        .map(|u| {
+            vertices
+                .iter()
+                .map(|v| u32::from(adjacency[u].contains(v)))
+                .collect()
+        })
+        .collect();
+    let loops = vertices
+        .iter()
+        .filter(|v| graph.contains_edge(v, v))
+        .count();
+    let components = components(&matrix).len();
+    (matrix, loops, components)
+}
+
+/// Computes the Tutte polynomial of a graph by deletion–contraction.
+///
+/// Parallel edges created by contractions are handled as one class, bridges and
+/// separate components are factored out, and intermediate minors are memoized.
+/// Edge directions are ignored, opposite arcs count as one edge and a self-loop
+/// contributes a factor `y`.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+///
+/// # Returns
+///
+/// The Tutte polynomial, `1` for a graph without edges.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph has more than
+/// [`MAX_POLYNOMIAL_VERTICES`] vertices.
+pub fn tutte_polynomial<G>(graph: &G) -> Result<TuttePolynomial, GraphError>
+where
+    G: Graph,
+{
+    check_size(graph)?;
+    let (matrix, loops, _) = multigraph(graph);
+    trace_span!("tutte_polynomial", vertices = matrix.len(), loops);
+
+    let mut tutte = Tutte::default();
+    let mut coefficients = tutte.compute(&matrix);
+    trace_event!(minors = tutte.memo.len(), "deletion-contraction complete");
+    for row in &mut coefficients {
+        row.splice(0..0, std::iter::repeat_n(0, loops));
+        while row.last() == Some(&0) {
+            row.pop();
+        }
+    }
+    Ok(TuttePolynomial { coefficients })
+}
+
+/// Computes the chromatic polynomial of a graph.
+///
+/// The polynomial is derived from the Tutte polynomial through
+/// `P(k) = (-1)^(n - c) k^c T(1 - k, 0)`, where `n` is the order and `c` the
+/// number of connected components. Edge directions are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+///
+/// # Returns
+///
+/// The chromatic polynomial, which is zero if the graph has a self-loop and `1`
+/// for the empty graph.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph has more than
+/// [`MAX_POLYNOMIAL_VERTICES`] vertices.
+pub fn chromatic_polynomial<G>(graph: &G) -> Result<ChromaticPolynomial, GraphError>
+where
+    G: Graph,
+{
+    check_size(graph)?;
+    let (matrix, loops, components) = multigraph(graph);
+    if loops > 0 {
+        return Ok(ChromaticPolynomial {
+            coefficients: Vec::new(),
+        });
+    }
+    trace_span!("chromatic_polynomial", vertices = matrix.len());
+
+    // T(1 - k, 0) = Σ_i t_i0 Σ_j C(i, j) (-k)^j
+    let tutte = Tutte::default().compute(&matrix);
+    let mut at_zero = vec![0i128; tutte.len()];
+    for (i, row) in tutte.iter().enumerate() {
+        let Some(&t) = row.first() else { continue };
+        // Coefficients are at most 2 raised to the number of edges, below 2^66.
+        #[allow(clippy::cast_possible_wrap)]
+        let t = t as i128;
+        let mut binomial = 1i128;
+        for (j, c) in at_zero.iter_mut().enumerate().take(i + 1) {
+            let sign = if j.is_multiple_of(2) { 1 } else { -1 };
+            *c += sign * binomial * t;
+            // C(i, j + 1) = C(i, j) (i - j) / (j + 1), with i and j below the
+            // number of edges.
+            #[allow(clippy::cast_possible_wrap)]
+            let next = binomial * (i - j) as i128 / (j + 1) as i128;
+            binomial = next;
+        }
+    }
+
+    let sign = if (matrix.len() - components).is_multiple_of(2) {
+        1
+    } else {
+        -1
+    };
+    let mut coefficients = vec![0i128; components];
+    coefficients.extend(at_zero.into_iter().map(|c| sign * c));
+    while coefficients.last() == Some(&0) {
+        coefficients.pop();
+    }
+    Ok(ChromaticPolynomial { coefficients })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::counting::count_colorings, GraphMut, SimpleGraph};
+
+    fn complete(n: usize) -> SimpleGraph<usize> {
+        SimpleGraph::from_edges((0..n).flat_map(|u| (u + 1..n).map(move |v| (u, v))), false)
+    }
+
+    fn cycle(n: usize) -> SimpleGraph<usize> {
+        SimpleGraph::from_edges((0..n).map(|v| (v, (v + 1) % n)), false)
+    }
+
+    fn petersen() -> SimpleGraph<usize> {
+        let outer = (0..5).map(|v| (v, (v + 1) % 5));
+        let spokes = (0..5).map(|v| (v, v + 5));
+        let inner = (0..5).map(|v| (v + 5, (v + 2) % 5 + 5));
+        SimpleGraph::from_edges(outer.chain(spokes).chain(inner), false)
+    }
+
+    #[test]
+    fn tutte_polynomials_of_complete_graphs() {
+        let triangle = tutte_polynomial(&complete(3)).unwrap();
+        assert_eq!(triangle.to_string(), "x^2 + x + y");
+
+        let k4 = tutte_polynomial(&complete(4)).unwrap();
+        assert_eq!(k4.to_string(), "x^3 + 3x^2 + 4xy + 2x + y^3 + 3y^2 + 2y");
+        assert_eq!(k4.coefficient(1, 1), 4);
+        assert_eq!(k4.coefficient(4, 0), 0);
+        // T(1, 1) counts the spanning trees and T(2, 2) = 2^m.
+        assert!((k4.evaluate(1.0, 1.0) - 16.0).abs() < 1e-9);
+        assert!((k4.evaluate(2.0, 2.0) - 64.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tutte_polynomials_of_cycles_and_forests() {
+        // T(C_n) = x^(n-1) + ... + x + y.
+        let c5 = tutte_polynomial(&cycle(5)).unwrap();
+        assert_eq!(c5.to_string(), "x^4 + x^3 + x^2 + x + y");
+
+        let forest = SimpleGraph::from_edges([(0, 1), (2, 3), (3, 4)], false);
+        assert_eq!(tutte_polynomial(&forest).unwrap().to_string(), "x^3");
+
+        let edgeless = SimpleGraph::<usize>::new_undirected();
+        assert_eq!(tutte_polynomial(&edgeless).unwrap().to_string(), "1");
+
+        let petersen = tutte_polynomial(&petersen()).unwrap();
+        assert!((petersen.evaluate(1.0, 1.0) - 2000.0).abs() < 1e-6);
+        assert!((petersen.evaluate(2.0, 2.0) - 32768.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn tutte_polynomials_of_loops_and_opposite_arcs() {
+        let looped = SimpleGraph::from_edges([(0, 0), (0, 1)], false);
+        assert_eq!(tutte_polynomial(&looped).unwrap().to_string(), "xy");
+
+        let opposite = SimpleGraph::from_edges([(0, 1), (1, 0)], true);
+        assert_eq!(tutte_polynomial(&opposite).unwrap().to_string(), "x");
+    }
+
+    #[test]
+    fn chromatic_polynomials_of_cycles() {
+        let triangle = chromatic_polynomial(&complete(3)).unwrap();
+        assert_eq!(triangle.to_string(), "k^3 - 3k^2 + 2k");
+        assert_eq!(triangle.coefficients(), &[0, 2, -3, 1]);
+
+        // P(C_n, k) = (k - 1)^n + (-1)^n (k - 1).
+        for n in 3..=10 {
+            let polynomial = chromatic_polynomial(&cycle(n)).unwrap();
+            for k in 0..=6i128 {
+                let sign = if n % 2 == 0 { 1 } else { -1 };
+                let expected = (k - 1).pow(u32::try_from(n).unwrap()) + sign * (k - 1);
+                assert_eq!(polynomial.evaluate(k), Some(expected), "P(C_{n}, {k})");
+            }
+        }
+    }
+
+    #[test]
+    fn chromatic_polynomials_agree_with_counted_colorings() {
+        let mut disconnected = complete(3);
+        disconnected.add_edge_with_vertices(5, 6).unwrap();
+        for graph in [petersen(), complete(5), disconnected] {
+            let polynomial = chromatic_polynomial(&graph).unwrap();
+            for k in 0..=4 {
+                let expected = i128::try_from(count_colorings(&graph, k).unwrap()).unwrap();
+                let k = i128::try_from(k).unwrap();
+                assert_eq!(polynomial.evaluate(k), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn chromatic_polynomials_of_degenerate_graphs() {
+        let looped = SimpleGraph::from_edges([(0, 0), (0, 1)], false);
+        let zero = chromatic_polynomial(&looped).unwrap();
+        assert_eq!(zero.to_string(), "0");
+        assert_eq!(zero.evaluate(3), Some(0));
+
+        let empty = SimpleGraph::<usize>::new_undirected();
+        assert_eq!(chromatic_polynomial(&empty).unwrap().to_string(), "1");
+
+        assert_eq!(
+            chromatic_polynomial(&complete(3))
+                .unwrap()
+                .evaluate(i128::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_large_graphs() {
+        let graph = cycle(MAX_POLYNOMIAL_VERTICES + 1);
+        assert!(matches!(
+            tutte_polynomial(&graph),
+            Err(GraphError::Algorithm(_))
+        ));
+        assert!(matches!(
+            chromatic_polynomial(&graph),
+            Err(GraphError::Algorithm(_))
+        ));
+    }
+}
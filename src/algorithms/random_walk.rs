@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    algorithms::adjacency::undirected_adjacency, error::AlgorithmError,
+    graphs::simple::SimpleGraph, traits::weighted::WeightedGraph, Graph, GraphError, GraphMut,
+};
+
+/// Checks that `start` is in the graph and `restart` is a probability.
+fn check_walk<G>(graph: &G, start: &G::Vertex, restart: f64) -> Result<(), GraphError>
+where
+    G: Graph,
+{
+    if !graph.contains_vertex(start) {
+        return Err(GraphError::VertexNotFound);
+    }
+    if !(0.0..=1.0).contains(&restart) {
+        return Err(AlgorithmError::InvalidArgument(format!(
+            "The restart probability must be between 0 and 1, got {restart}"
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Walks `length` steps from `start`, each step moving to a uniformly random
+/// out-neighbor of the current vertex.
+///
+/// With probability `restart`, a step jumps back to `start` instead, as in a
+/// random walk with restart; `0.0` gives a plain random walk. The walk ends
+/// early at a vertex without out-neighbors.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to walk on.
+/// * `start` - The first vertex of the walk.
+/// * `length` - The number of steps.
+/// * `restart` - The probability of jumping back to `start` at each step.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The visited vertices in order, starting with `start`: `length + 1` of them
+/// unless the walk ended early.
+///
+/// # Errors
+///
+/// Returns `GraphError::VertexNotFound` if `start` is not in the graph, and
+/// `GraphError::Algorithm` if `restart` is not between 0 and 1.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::random_walk::random_walk, graph};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 3 - 4 };
+/// let walk = random_walk(&graph, &1, 10, 0.15, &mut rand::rng()).unwrap();
+/// assert_eq!(walk.len(), 11);
+/// assert_eq!(walk[0], 1);
+/// ```
+pub fn random_walk<G, R>(
+    graph: &G,
+    start: &G::Vertex,
+    length: usize,
+    restart: f64,
+    rng: &mut R,
+) -> Result<Vec<G::Vertex>, GraphError>
+where
+    G: Graph,
+    R: Rng + ?Sized,
+{
+    check_walk(graph, start, restart)?;
+    let mut walk = Vec::with_capacity(length + 1);
+    let mut current = start;
+    walk.push(current.clone());
+    for _ in 0..length {
+        if restart > 0.0 && rng.random_bool(restart) {
+            current = start;
+        } else {
+            let Some(next) = graph.neighbors(current).into_iter().flatten().choose(rng) else {
+                break;
+            };
+            current = next;
+        }
+        walk.push(current.clone());
+    }
+    Ok(walk)
+}
+
+/// Walks `length` steps from `start`, each step moving to an out-neighbor of
+/// the current vertex chosen with probability proportional to the weight of the
+/// edge leading to it.
+///
+/// With probability `restart`, a step jumps back to `start` instead. The walk
+/// ends early at a vertex whose out-edges all weigh zero, or that has none.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to walk on.
+/// * `start` - The first vertex of the walk.
+/// * `length` - The number of steps.
+/// * `restart` - The probability of jumping back to `start` at each step.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The visited vertices in order, starting with `start`: `length + 1` of them
+/// unless the walk ended early.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `start` is not in the graph.
+/// * `GraphError::Algorithm` - If `restart` is not between 0 and 1, or an
+///   edge met by the walk has no weight or a negative, infinite or `NaN`
+///   weight.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::random_walk::weighted_random_walk, graph};
+///
+/// // The edge to 3 weighs nothing, so the walk never takes it.
+/// let graph = graph! { 1 - 2 [weight = 1.0], 1 - 3 [weight = 0.0] };
+/// let walk = weighted_random_walk(&graph, &1, 6, 0.0, &mut rand::rng()).unwrap();
+/// assert_eq!(walk, vec![1, 2, 1, 2, 1, 2, 1]);
+/// ```
+pub fn weighted_random_walk<G, R>(
+    graph: &G,
+    start: &G::Vertex,
+    length: usize,
+    restart: f64,
+    rng: &mut R,
+) -> Result<Vec<G::Vertex>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+    R: Rng + ?Sized,
+{
+    check_walk(graph, start, restart)?;
+    let mut walk = Vec::with_capacity(length + 1);
+    let mut current = start;
+    walk.push(current.clone());
+    let mut choices: Vec<(&G::Vertex, f64)> = Vec::new();
+    for _ in 0..length {
+        if restart > 0.0 && rng.random_bool(restart) {
+            current = start;
+            walk.push(current.clone());
+            continue;
+        }
+        choices.clear();
+        let mut total = 0.0;
+        for v in graph.neighbors(current).into_iter().flatten() {
+            let weight: f64 = graph
+                .edge_weight(current, v)
+                .copied()
+                .map(Into::into)
+                .ok_or_else(|| AlgorithmError::missing_weight(current, v))?;
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(AlgorithmError::invalid_weight(current, v, weight).into());
+            }
+            total += weight;
+            choices.push((v, weight));
+        }
+        if total <= 0.0 {
+            break;
+        }
+        let mut target = rng.random_range(0.0..total);
+        let mut next = None;
+        for &(v, weight) in &choices {
+            if weight > 0.0 {
+                next = Some(v);
+                if target < weight {
+                    break;
+                }
+                target -= weight;
+            }
+        }
+        // Rounding may leave `target` past the last weight, which then takes it.
+        if let Some(next) = next {
+            current = next;
+        }
+        walk.push(current.clone());
+    }
+    Ok(walk)
+}
+
+/// Samples a uniformly random spanning tree with Wilson's algorithm.
+///
+/// Each vertex not yet in the tree starts a random walk that is run until it
+/// hits the tree, and the walk with its loops erased is added to the tree. The
+/// result is a spanning tree chosen uniformly among all spanning trees, in
+/// expected time proportional to the mean hitting time of the graph.
+///
+/// Edge directions and self-loops are ignored. A disconnected graph gets a
+/// spanning forest, with a uniformly random spanning tree of every component.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to span.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// An undirected graph with every vertex of `graph` and the edges of the tree.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::random_walk::random_spanning_tree, graph, Graph};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 1, 1 - 3 };
+/// let tree = random_spanning_tree(&graph, &mut rand::rng());
+/// assert_eq!(tree.order(), 4);
+/// assert_eq!(tree.edge_count(), 3);
+/// assert!(tree.vertices().all(|v| tree.neighbors(v).unwrap().all(|w| graph.contains_edge(v, w))));
+/// ```
+pub fn random_spanning_tree<G, R>(graph: &G, rng: &mut R) -> SimpleGraph<G::Vertex>
+where
+    G: Graph,
+    R: Rng + ?Sized,
+{
+    let adjacency = undirected_adjacency(graph);
+    let vertices: Vec<&G::Vertex> = adjacency.keys().copied().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let neighbors: Vec<Vec<usize>> = vertices
+        .iter()
+        .map(|v| adjacency[v].iter().map(|w| index[w]).collect())
+        .collect();
+    let n = vertices.len();
+
+    let mut tree = SimpleGraph::new_undirected();
+    for &v in &vertices {
+        let _ = tree.add_vertex(v.clone());
+    }
+
+    // Every component gets a root, the first of its vertices to be reached.
+    let mut reached = vec![false; n];
+    let mut in_tree = vec![false; n];
+    for root in 0..n {
+        if reached[root] {
+            continue;
+        }
+        in_tree[root] = true;
+        reached[root] = true;
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            for &w in &neighbors[u] {
+                if !reached[w] {
+                    reached[w] = true;
+                    stack.push(w);
+                }
+            }
+        }
+    }
+
+    // `next[u]` is the exit taken by the last visit of the walk to `u`, so
+    // following it from the start of the walk traces the loop-erased walk.
+    let mut next = vec![0; n];
+    for start in 0..n {
+        let mut u = start;
+        while !in_tree[u] {
+            let Some(&w) = neighbors[u].iter().choose(rng) else {
+                break;
+            };
+            next[u] = w;
+            u = w;
+        }
+        let mut u = start;
+        while !in_tree[u] {
+            in_tree[u] = true;
+            let _ = tree.add_edge(vertices[u], vertices[next[u]]);
+            u = next[u];
+        }
+    }
+    tree
+}
@@ -0,0 +1,95 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{algorithms::heap::MinScored, traits::measure::Measure, Graph};
+
+/// Selects the `k` items with the highest scores.
+///
+/// The items are scanned once while a min-heap keeps the best `k` seen so far,
+/// so selecting from `n` items takes `O(n log k)` time and `O(k)` space instead
+/// of sorting all of them. Scores rejected by [`Measure::is_valid`], such as
+/// `NaN`, are skipped. Among items with equal scores, which ones are kept is
+/// unspecified.
+///
+/// # Arguments
+///
+/// * `scores` - The items and their scores, such as a `HashMap` of centralities
+///   or an iterator over one.
+/// * `k` - The number of items to keep.
+///
+/// # Returns
+///
+/// At most `k` items with their scores, from the highest score to the lowest.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use kambo_graph::algorithms::ranking::top_k;
+///
+/// let scores = HashMap::from([("a", 0.3), ("b", 0.9), ("c", f64::NAN), ("d", 0.5)]);
+/// assert_eq!(top_k(scores, 2), vec![("b", 0.9), ("d", 0.5)]);
+/// ```
+pub fn top_k<I, T, S>(scores: I, k: usize) -> Vec<(T, S)>
+where
+    I: IntoIterator<Item = (T, S)>,
+    S: Measure,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut heap: BinaryHeap<MinScored<S, T>> = BinaryHeap::with_capacity(k + 1);
+    for (item, score) in scores {
+        if !score.is_valid() {
+            continue;
+        }
+        if heap.len() < k {
+            heap.push(MinScored(score, item));
+        } else if heap
+            .peek()
+            .is_some_and(|lowest| score.compare(&lowest.0) == Ordering::Greater)
+        {
+            heap.pop();
+            heap.push(MinScored(score, item));
+        }
+    }
+    // The heap orders entries by reversed score, so its ascending order runs from
+    // the highest score to the lowest.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|MinScored(score, item)| (item, score))
+        .collect()
+}
+
+/// Selects the `k` vertices with the highest degree.
+///
+/// Like [`Graph::degree`], only outgoing edges count in directed graphs. See
+/// [`top_k`] for the cost and the handling of ties.
+///
+/// # Arguments
+///
+/// * `graph` - The graph.
+/// * `k` - The number of vertices to keep.
+///
+/// # Returns
+///
+/// At most `k` vertices with their degrees, from the highest degree to the
+/// lowest.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::ranking::top_k_by_degree, graph};
+///
+/// let graph = graph! { 1 - 2, 1 - 3, 1 - 4, 2 - 3 };
+/// assert_eq!(top_k_by_degree(&graph, 1), vec![(&1, 3)]);
+/// ```
+#[must_use]
+pub fn top_k_by_degree<G>(graph: &G, k: usize) -> Vec<(&G::Vertex, usize)>
+where
+    G: Graph,
+{
+    top_k(
+        graph.vertices().map(|v| (v, graph.degree(v).unwrap_or(0))),
+        k,
+    )
+}
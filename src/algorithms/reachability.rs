@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use crate::{algorithms::topological::topological_sort, Graph, GraphError};
+
+/// A precomputed index answering reachability queries on a directed acyclic graph
+/// in constant time.
+///
+/// The index decomposes the graph into vertex-disjoint chains (paths) and stores,
+/// for every vertex and every chain, the earliest position on that chain reachable
+/// from the vertex. `u` reaches `v` exactly when that position for `v`'s chain is
+/// not after `v`. Memory usage is `O(n * k)` for `n` vertices and `k` chains, so the
+/// index is most compact on deep, narrow DAGs.
+///
+/// The index is a snapshot: it must be rebuilt after the graph changes.
+#[derive(Debug, Clone)]
+pub struct ReachabilityIndex<V> {
+    index: HashMap<V, usize>,
+    chain: Vec<usize>,
+    position: Vec<usize>,
+    reach: Vec<usize>,
+    chains: usize,
+}
+
+impl<V> ReachabilityIndex<V>
+where
+    V: Eq + std::hash::Hash + Clone + std::fmt::Debug,
+{
+    /// Builds the index for a directed acyclic graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The directed acyclic graph to index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` if the graph is undirected or contains
+    /// a cycle.
+    pub fn new<G>(graph: &G) -> Result<Self, GraphError>
+    where
+        G: Graph<Vertex = V>,
+    {
+        trace_span!("reachability_index");
+        let order = topological_sort(graph)?;
+        let n = order.len();
+        let index: HashMap<V, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone(), i))
+            .collect();
+        let successors: Vec<Vec<usize>> = order
+            .iter()
+            .map(|v| {
+                graph
+                    .neighbors(v)
+                    .into_iter()
+                    .flatten()
+                    .map(|w| index[w])
+                    .collect()
+            })
+            .collect();
+
+        // Greedy chain decomposition: start a chain at the first unassigned vertex in
+        // topological order and extend it through unassigned successors.
+        let mut chain = vec![usize::MAX; n];
+        let mut position = vec![0; n];
+        let mut chains = 0;
+        for start in 0..n {
+            if chain[start] != usize::MAX {
+                continue;
+            }
+            let mut current = start;
+            let mut pos = 0;
+            loop {
+                chain[current] = chains;
+                position[current] = pos;
+                pos += 1;
+                match successors[current]
+                    .iter()
+                    .copied()
+                    .filter(|&w| chain[w] == usize::MAX)
+                    .min()
+                {
+                    Some(next) => current = next,
+                    None => break,
+                }
+            }
+            chains += 1;
+        }
+
+        trace_event!(chains, "chain decomposition complete");
+
+        // In reverse topological order, the earliest reachable position on each chain
+        // is the minimum over the vertex itself and its successors.
+        let mut reach = vec![usize::MAX; n * chains];
+        for u in (0..n).rev() {
+            let row = u * chains;
+            reach[row + chain[u]] = position[u];
+            for &w in &successors[u] {
+                for c in 0..chains {
+                    let candidate = reach[w * chains + c];
+                    if candidate < reach[row + c] {
+                        reach[row + c] = candidate;
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            index,
+            chain,
+            position,
+            reach,
+            chains,
+        })
+    }
+
+    /// Checks whether `v` is reachable from `u`.
+    ///
+    /// Every vertex reaches itself.
+    ///
+    /// # Returns
+    /// - `Some(true)` if there is a directed path from `u` to `v`.
+    /// - `Some(false)` if there is none.
+    /// - `None` if `u` or `v` was not in the indexed graph.
+    #[must_use]
+    pub fn can_reach(&self, u: &V, v: &V) -> Option<bool> {
+        let u = *self.index.get(u)?;
+        let v = *self.index.get(v)?;
+        Some(self.reach[u * self.chains + self.chain[v]] <= self.position[v])
+    }
+
+    /// Returns the number of chains in the decomposition.
+    #[must_use]
+    pub fn chain_count(&self) -> usize {
+        self.chains
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{error::AlgorithmError, GraphMut, SimpleGraph};
+
+    /// Collects the vertices reachable from `u`, `u` included.
+    fn reachable(graph: &SimpleGraph<u32>, u: u32) -> HashSet<u32> {
+        let mut seen = HashSet::from([u]);
+        let mut stack = vec![u];
+        while let Some(v) = stack.pop() {
+            for &w in graph.neighbors(&v).unwrap() {
+                if seen.insert(w) {
+                    stack.push(w);
+                }
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn answers_match_a_search() {
+        let edges = (0..40u32).flat_map(|u| {
+            (u + 1..40)
+                .filter(move |v| (u * 7 + v * 3) % 11 == 0)
+                .map(move |v| (u, v))
+        });
+        let graph = SimpleGraph::from_edges(edges, true);
+        let index = ReachabilityIndex::new(&graph).unwrap();
+        for &u in graph.vertices() {
+            let expected = reachable(&graph, u);
+            for v in graph.vertices() {
+                assert_eq!(
+                    index.can_reach(&u, v),
+                    Some(expected.contains(v)),
+                    "{u} -> {v}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn counts_chains() {
+        let path = SimpleGraph::from_edges((0..10).map(|v| (v, v + 1)), true);
+        assert_eq!(ReachabilityIndex::new(&path).unwrap().chain_count(), 1);
+
+        let mut antichain = SimpleGraph::<u32>::new_directed();
+        for v in 0..5 {
+            antichain.add_vertex(v).unwrap();
+        }
+        let index = ReachabilityIndex::new(&antichain).unwrap();
+        assert_eq!(index.chain_count(), 5);
+        assert_eq!(index.can_reach(&0, &0), Some(true));
+        assert_eq!(index.can_reach(&0, &1), Some(false));
+        assert_eq!(index.can_reach(&0, &9), None);
+    }
+
+    #[test]
+    fn rejects_cyclic_and_undirected_graphs() {
+        let cyclic = SimpleGraph::from_edges([(0, 1), (1, 0)], true);
+        assert!(matches!(
+            ReachabilityIndex::new(&cyclic),
+            Err(GraphError::Algorithm(AlgorithmError::CycleFound))
+        ));
+        let undirected = SimpleGraph::from_edges([(0, 1)], false);
+        assert!(matches!(
+            ReachabilityIndex::new(&undirected),
+            Err(GraphError::Algorithm(AlgorithmError::RequiresDirected))
+        ));
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{self, Write},
+};
+
+use crate::{
+    algorithms::{
+        clustering::{local_clustering, triangles},
+        core::core_numbers,
+    },
+    Graph,
+};
+
+/// Structural features of a single vertex, used to classify vertex roles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoleFeatures {
+    /// Number of neighbors (out-neighbors in directed graphs).
+    pub degree: usize,
+    /// Number of triangles containing the vertex.
+    pub triangles: usize,
+    /// Core number of the vertex.
+    pub core_number: usize,
+    /// Local clustering coefficient of the vertex.
+    pub clustering: f64,
+}
+
+impl RoleFeatures {
+    /// Names of the features, in the order used by [`RoleFeatures::to_array`].
+    pub const NAMES: [&'static str; 4] = ["degree", "triangles", "core_number", "clustering"];
+
+    /// Returns the features as a numeric vector, in the order of
+    /// [`RoleFeatures::NAMES`].
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_array(&self) -> [f64; 4] {
+        [
+            self.degree as f64,
+            self.triangles as f64,
+            self.core_number as f64,
+            self.clustering,
+        ]
+    }
+}
+
+/// Computes the [`RoleFeatures`] of every vertex.
+///
+/// Triangles, core numbers and clustering ignore edge directions.
+///
+/// # Returns
+///
+/// A map from every vertex to its features.
+pub fn role_features<G>(graph: &G) -> HashMap<G::Vertex, RoleFeatures>
+where
+    G: Graph,
+{
+    let triangles = triangles(graph);
+    let cores = core_numbers(graph);
+    let clustering = local_clustering(graph);
+    graph
+        .vertices()
+        .map(|v| {
+            let features = RoleFeatures {
+                degree: graph.degree(v).unwrap_or(0),
+                triangles: triangles[v],
+                core_number: cores[v],
+                clustering: clustering[v],
+            };
+            (v.clone(), features)
+        })
+        .collect()
+}
+
+/// Writes the [`RoleFeatures`] of every vertex as CSV, one row per vertex, with a
+/// header row `vertex,degree,triangles,core_number,clustering`.
+///
+/// # Arguments
+///
+/// * `graph` - The graph whose vertices are described.
+/// * `writer` - The destination of the CSV data.
+///
+/// # Errors
+///
+/// Returns any I/O error raised by `writer`.
+pub fn write_role_features_csv<G, W>(graph: &G, mut writer: W) -> io::Result<()>
+where
+    G: Graph,
+    G::Vertex: Display,
+    W: Write,
+{
+    writeln!(writer, "vertex,{}", RoleFeatures::NAMES.join(","))?;
+    for (v, f) in role_features(graph) {
+        writeln!(
+            writer,
+            "{v},{},{},{},{}",
+            f.degree, f.triangles, f.core_number, f.clustering
+        )?;
+    }
+    Ok(())
+}
+
+/// An anonymous walk: each position holds the index of the first occurrence of
+/// the visited vertex, so `a → b → a → c` becomes `[0, 1, 0, 2]`.
+pub type AnonymousWalk = Vec<usize>;
+
+/// Computes the exact distribution of anonymous walks of `length` steps starting
+/// at `v` under a uniform random walk.
+///
+/// All walks are enumerated, so the cost grows as `Δ^length`; keep `length` small
+/// (typically up to 5–7). A walk reaching a vertex without neighbors stops early
+/// and contributes its shorter pattern.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to walk on.
+/// * `v` - The starting vertex.
+/// * `length` - The number of steps of each walk.
+///
+/// # Returns
+///
+/// * `Some(HashMap<AnonymousWalk, f64>)` - The probability of every anonymous walk.
+/// * `None` - If `v` is not in the graph.
+pub fn anonymous_walk_distribution<G>(
+    graph: &G,
+    v: &G::Vertex,
+    length: usize,
+) -> Option<HashMap<AnonymousWalk, f64>>
+where
+    G: Graph,
+{
+    if !graph.contains_vertex(v) {
+        return None;
+    }
+
+    let mut distribution = HashMap::new();
+    let mut path = vec![v];
+    extend_walks(graph, &mut path, length, 1.0, &mut distribution);
+    Some(distribution)
+}
+
+fn extend_walks<'a, G>(
+    graph: &'a G,
+    path: &mut Vec<&'a G::Vertex>,
+    remaining: usize,
+    probability: f64,
+    distribution: &mut HashMap<AnonymousWalk, f64>,
+) where
+    G: Graph,
+{
+    let last = path[path.len() - 1];
+    let neighbors: Vec<&G::Vertex> = graph.neighbors(last).into_iter().flatten().collect();
+    if remaining == 0 || neighbors.is_empty() {
+        *distribution.entry(anonymize(path)).or_insert(0.0) += probability;
+        return;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let step = probability / neighbors.len() as f64;
+    for w in neighbors {
+        path.push(w);
+        extend_walks(graph, path, remaining - 1, step, distribution);
+        path.pop();
+    }
+}
+
+fn anonymize<V: PartialEq>(path: &[&V]) -> AnonymousWalk {
+    let mut seen: Vec<&V> = Vec::new();
+    path.iter()
+        .map(|&v| {
+            seen.iter().position(|&s| s == v).unwrap_or_else(|| {
+                seen.push(v);
+                seen.len() - 1
+            })
+        })
+        .collect()
+}
+
+/// Estimates the distribution of anonymous walks of `length` steps starting at
+/// `v` by sampling `samples` uniform random walks.
+///
+/// Use this instead of [`anonymous_walk_distribution`] when exhaustive enumeration
+/// is too expensive.
+///
+/// # Returns
+///
+/// * `Some(HashMap<AnonymousWalk, f64>)` - The empirical frequency of every
+///   sampled anonymous walk.
+/// * `None` - If `v` is not in the graph or `samples` is zero.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn sample_anonymous_walks<G, R>(
+    graph: &G,
+    v: &G::Vertex,
+    length: usize,
+    samples: usize,
+    rng: &mut R,
+) -> Option<HashMap<AnonymousWalk, f64>>
+where
+    G: Graph,
+    R: rand::Rng + ?Sized,
+{
+    use rand::seq::IteratorRandom;
+
+    if !graph.contains_vertex(v) || samples == 0 {
+        return None;
+    }
+
+    let mut counts: HashMap<AnonymousWalk, usize> = HashMap::new();
+    for _ in 0..samples {
+        let mut path = vec![v];
+        for _ in 0..length {
+            let last = path[path.len() - 1];
+            match graph.neighbors(last).and_then(|n| n.choose(rng)) {
+                Some(w) => path.push(w),
+                None => break,
+            }
+        }
+        *counts.entry(anonymize(&path)).or_insert(0) += 1;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let total = samples as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let distribution = counts
+        .into_iter()
+        .map(|(walk, count)| (walk, count as f64 / total))
+        .collect();
+    Some(distribution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GraphMut, SimpleGraph};
+
+    /// A triangle `0, 1, 2` with a pendant vertex `3` attached to `0`.
+    fn paw() -> SimpleGraph<u32> {
+        SimpleGraph::from_edges([(0, 1), (1, 2), (2, 0), (0, 3)], false)
+    }
+
+    #[test]
+    fn computes_role_features() {
+        let features = role_features(&paw());
+        assert_eq!(features.len(), 4);
+        assert_eq!(
+            features[&1],
+            RoleFeatures {
+                degree: 2,
+                triangles: 1,
+                core_number: 2,
+                clustering: 1.0,
+            }
+        );
+        let arrays = [
+            (features[&0].to_array(), [3.0, 1.0, 2.0, 1.0 / 3.0]),
+            (features[&3].to_array(), [1.0, 0.0, 1.0, 0.0]),
+        ];
+        for (actual, expected) in arrays {
+            assert!(actual
+                .iter()
+                .zip(expected)
+                .all(|(a, b)| (a - b).abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn writes_role_features_as_csv() {
+        let mut buffer = Vec::new();
+        write_role_features_csv(&paw(), &mut buffer).unwrap();
+        let csv = String::from_utf8(buffer).unwrap();
+        let mut lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "vertex,degree,triangles,core_number,clustering");
+        lines[1..].sort_unstable();
+        assert_eq!(lines[1], "0,3,1,2,0.3333333333333333");
+        assert_eq!(lines[2..], ["1,2,1,2,1", "2,2,1,2,1", "3,1,0,1,0"]);
+    }
+
+    #[test]
+    fn enumerates_anonymous_walks() {
+        let graph = paw();
+        let distribution = anonymous_walk_distribution(&graph, &1, 2).unwrap();
+        // From 1 the walk returns through 0 with probability 1/2 * 1/3 and
+        // through 2 with probability 1/2 * 1/2.
+        let expected = [(vec![0, 1, 0], 5.0 / 12.0), (vec![0, 1, 2], 7.0 / 12.0)];
+        assert_eq!(distribution.len(), expected.len());
+        for (walk, probability) in expected {
+            assert!(
+                (distribution[&walk] - probability).abs() < 1e-12,
+                "{walk:?}"
+            );
+        }
+
+        let total: f64 = anonymous_walk_distribution(&graph, &3, 4)
+            .unwrap()
+            .values()
+            .sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert_eq!(anonymous_walk_distribution(&graph, &9, 2), None);
+    }
+
+    #[test]
+    fn stops_walks_without_neighbors() {
+        let mut graph = SimpleGraph::from_edges([(0, 1)], true);
+        graph.add_vertex(2).unwrap();
+        assert_eq!(
+            anonymous_walk_distribution(&graph, &0, 3),
+            Some(HashMap::from([(vec![0, 1], 1.0)]))
+        );
+        assert_eq!(
+            anonymous_walk_distribution(&graph, &2, 3),
+            Some(HashMap::from([(vec![0], 1.0)]))
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn samples_anonymous_walks() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let graph = paw();
+        let mut rng = StdRng::seed_from_u64(3);
+        let sampled = sample_anonymous_walks(&graph, &1, 2, 4000, &mut rng).unwrap();
+        let exact = anonymous_walk_distribution(&graph, &1, 2).unwrap();
+        assert_eq!(sampled.len(), exact.len());
+        for (walk, probability) in exact {
+            assert!((sampled[&walk] - probability).abs() < 0.05, "{walk:?}");
+        }
+        assert_eq!(sample_anonymous_walks(&graph, &1, 2, 0, &mut rng), None);
+        assert_eq!(sample_anonymous_walks(&graph, &9, 2, 10, &mut rng), None);
+    }
+}
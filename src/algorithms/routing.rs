@@ -0,0 +1,445 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+use crate::{
+    algorithms::{
+        heap::MinScored,
+        shortest_path::{checked_weight, ShortestPath},
+    },
+    traits::{
+        measure::{Measure, OverflowPolicy},
+        weighted::WeightedGraph,
+    },
+    GraphError,
+};
+
+/// The number of vertices a witness search settles before giving up, which
+/// only adds a possibly useless shortcut.
+const WITNESS_LIMIT: usize = 100;
+
+/// A contraction hierarchy, a preprocessed form of a weighted graph answering
+/// shortest path queries much faster than Dijkstra's algorithm.
+///
+/// Building the hierarchy contracts the vertices one by one, least important
+/// first, adding a shortcut edge between two neighbors of a contracted vertex
+/// whenever the only shortest path between them ran through it. The vertex
+/// order is chosen greedily: contractions adding few shortcuts compared to the
+/// edges they remove come first, and so do vertices with few neighbors already
+/// contracted, which spreads the contractions evenly over the graph. A query then runs two Dijkstra searches that only climb to more
+/// important vertices, which on road networks settle a few hundred vertices
+/// instead of a large part of the graph.
+///
+/// The hierarchy is a snapshot: later changes to the graph are not reflected.
+/// Undirected edges can be traveled both ways and self-loops are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::routing::ContractionHierarchy, graph};
+///
+/// let roads = graph! { 1 - 2 [weight = 4], 2 - 3 [weight = 4], 1 - 4 [weight = 1],
+///                      4 - 5 [weight = 1], 5 - 3 [weight = 1], 3 - 6 [weight = 2] };
+/// let hierarchy = ContractionHierarchy::new(&roads).unwrap();
+///
+/// assert_eq!(hierarchy.distance(&1, &6).unwrap(), Some(5));
+/// let (path, length) = hierarchy.shortest_path(&2, &6).unwrap().unwrap();
+/// assert_eq!(path, [2, 3, 6]);
+/// assert_eq!(length, 6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ContractionHierarchy<V, W> {
+    vertices: Vec<V>,
+    index: HashMap<V, usize>,
+    /// The edges to more important vertices, leaving every vertex.
+    upward: Vec<Vec<(usize, W)>>,
+    /// The edges from more important vertices, entering every vertex.
+    downward: Vec<Vec<(usize, W)>>,
+    /// The vertex every shortcut skips.
+    middle: HashMap<(usize, usize), usize>,
+}
+
+impl<V, W> ContractionHierarchy<V, W>
+where
+    V: Eq + Hash + Clone,
+    W: Measure,
+{
+    /// Builds the contraction hierarchy of a graph.
+    ///
+    /// Preprocessing runs a bounded Dijkstra search from every neighbor of
+    /// every contracted vertex, which takes seconds for road networks of tens
+    /// of thousands of vertices. Dense or expander-like graphs need many
+    /// shortcuts and are better served by
+    /// [`bidirectional_dijkstra`](crate::algorithms::shortest_path::bidirectional_dijkstra).
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::Algorithm` - If an edge has no weight, a negative
+    ///   weight or an invalid weight (such as `NaN`).
+    /// * `GraphError::WeightOverflow` - If the length of a shortcut overflows.
+    pub fn new<G>(graph: &G) -> Result<Self, GraphError>
+    where
+        G: WeightedGraph<Vertex = V, Weight = W>,
+    {
+        let vertices: Vec<V> = graph.vertices().cloned().collect();
+        let index: HashMap<V, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone(), i))
+            .collect();
+        trace_span!("contraction_hierarchy", vertices = vertices.len());
+
+        let n = vertices.len();
+        let mut contraction = Contraction {
+            out: vec![HashMap::new(); n],
+            into: vec![HashMap::new(); n],
+            contracted: vec![false; n],
+            middle: HashMap::new(),
+        };
+        for (i, u) in vertices.iter().enumerate() {
+            for v in graph.neighbors(u).into_iter().flatten() {
+                let j = index[v];
+                if i != j {
+                    let weight = checked_weight(graph, u, v)?;
+                    contraction.out[i].insert(j, weight.clone());
+                    contraction.into[j].insert(i, weight.clone());
+                }
+            }
+        }
+
+        let mut removed = vec![0; n];
+        let mut queue = BinaryHeap::with_capacity(n);
+        for v in 0..n {
+            let shortcuts = contraction.shortcuts(v)?;
+            queue.push(Reverse((contraction.priority(v, &shortcuts, &removed), v)));
+        }
+        let mut rank = vec![0; n];
+        let mut next_rank = 0;
+        while let Some(Reverse((_, v))) = queue.pop() {
+            // Priorities change as neighbors are contracted; they are updated
+            // lazily, when a vertex reaches the front of the queue.
+            let shortcuts = contraction.shortcuts(v)?;
+            let priority = contraction.priority(v, &shortcuts, &removed);
+            if queue
+                .peek()
+                .is_some_and(|Reverse((next, _))| priority > *next)
+            {
+                queue.push(Reverse((priority, v)));
+                continue;
+            }
+            for &u in contraction.out[v].keys().chain(contraction.into[v].keys()) {
+                removed[u] += 1;
+            }
+            contraction.contract(v, shortcuts);
+            rank[v] = next_rank;
+            next_rank += 1;
+        }
+
+        let mut upward = vec![Vec::new(); n];
+        let mut downward = vec![Vec::new(); n];
+        for (u, edges) in contraction.out.into_iter().enumerate() {
+            for (v, weight) in edges {
+                if rank[u] < rank[v] {
+                    upward[u].push((v, weight));
+                } else {
+                    downward[v].push((u, weight));
+                }
+            }
+        }
+        trace_event!(shortcuts = contraction.middle.len(), "hierarchy built");
+        Ok(Self {
+            vertices,
+            index,
+            upward,
+            downward,
+            middle: contraction.middle,
+        })
+    }
+
+    /// Returns the number of vertices of the hierarchy.
+    #[must_use]
+    pub fn order(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of shortcuts added by the contraction.
+    #[must_use]
+    pub fn shortcut_count(&self) -> usize {
+        self.middle.len()
+    }
+
+    /// Computes the length of a shortest path from `source` to `target`.
+    ///
+    /// # Returns
+    ///
+    /// The length of the path, or `None` if `target` is not reachable from
+    /// `source`.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::VertexNotFound` - If `source` or `target` is not in the
+    ///   hierarchy.
+    /// * `GraphError::WeightOverflow` - If a path length overflows.
+    pub fn distance(&self, source: &V, target: &V) -> Result<Option<W>, GraphError> {
+        Ok(self.search(source, target)?.map(|found| found.length))
+    }
+
+    /// Finds a shortest path from `source` to `target`.
+    ///
+    /// The shortcuts of the path are expanded back into edges of the graph, in
+    /// time proportional to the number of edges of the path.
+    ///
+    /// # Returns
+    ///
+    /// The vertices of the path, `source` and `target` included, and its
+    /// length, or `None` if `target` is not reachable from `source`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`distance`](Self::distance).
+    pub fn shortest_path(&self, source: &V, target: &V) -> Result<ShortestPath<V, W>, GraphError> {
+        let Some(found) = self.search(source, target)? else {
+            return Ok(None);
+        };
+        let mut hops = Vec::new();
+        let mut current = found.meeting;
+        while let Some(&previous) = found.forward.get(&current) {
+            hops.push((previous, current));
+            current = previous;
+        }
+        hops.reverse();
+        current = found.meeting;
+        while let Some(&next) = found.backward.get(&current) {
+            hops.push((current, next));
+            current = next;
+        }
+
+        let mut path = vec![source.clone()];
+        for (u, v) in hops {
+            let mut stack = vec![(u, v)];
+            while let Some((u, v)) = stack.pop() {
+                match self.middle.get(&(u, v)) {
+                    Some(&m) => {
+                        stack.push((m, v));
+                        stack.push((u, m));
+                    }
+                    None => path.push(self.vertices[v].clone()),
+                }
+            }
+        }
+        Ok(Some((path, found.length)))
+    }
+
+    /// Runs the two upward searches from `source` and `target`.
+    fn search(&self, source: &V, target: &V) -> Result<Option<Route<W>>, GraphError> {
+        let (Some(&s), Some(&t)) = (self.index.get(source), self.index.get(target)) else {
+            return Err(GraphError::VertexNotFound);
+        };
+        let mut sides: [UpwardSearch<W>; 2] = [UpwardSearch::new(s), UpwardSearch::new(t)];
+        let mut best: Option<(W, usize)> = None;
+        loop {
+            // Advance the side with the closest queued vertex, as long as it
+            // could still improve on the best path.
+            let [forward, backward] = sides.each_ref().map(|side| {
+                side.heap
+                    .peek()
+                    .map(|MinScored(distance, _)| distance)
+                    .filter(|distance| {
+                        best.as_ref()
+                            .is_none_or(|(length, _)| distance.compare(length) == Ordering::Less)
+                    })
+            });
+            let current = match (forward, backward) {
+                (None, None) => break,
+                (Some(_), None) => 0,
+                (None, Some(_)) => 1,
+                (Some(forward), Some(backward)) => {
+                    usize::from(backward.compare(forward) == Ordering::Less)
+                }
+            };
+            let Some(MinScored(distance, u)) = sides[current].heap.pop() else {
+                break;
+            };
+            if sides[current]
+                .distance
+                .get(&u)
+                .is_some_and(|known| distance.compare(known) == Ordering::Greater)
+            {
+                continue;
+            }
+            if let Some(other) = sides[1 - current].distance.get(&u) {
+                let through = distance.add_with(other, OverflowPolicy::Checked)?;
+                if best
+                    .as_ref()
+                    .is_none_or(|(length, _)| through.compare(length) == Ordering::Less)
+                {
+                    best = Some((through, u));
+                }
+            }
+            let edges = if current == 0 {
+                &self.upward[u]
+            } else {
+                &self.downward[u]
+            };
+            for (v, weight) in edges {
+                let next = distance.add_with(weight, OverflowPolicy::Checked)?;
+                let side = &mut sides[current];
+                if side
+                    .distance
+                    .get(v)
+                    .is_none_or(|known| next.compare(known) == Ordering::Less)
+                {
+                    side.distance.insert(*v, next.clone());
+                    side.parent.insert(*v, u);
+                    side.heap.push(MinScored(next, *v));
+                }
+            }
+        }
+        let [forward, backward] = sides;
+        Ok(best.map(|(length, meeting)| Route {
+            length,
+            meeting,
+            forward: forward.parent,
+            backward: backward.parent,
+        }))
+    }
+}
+
+/// The shortest route found by a query, with the vertex where its two searches
+/// met.
+struct Route<W> {
+    length: W,
+    meeting: usize,
+    forward: HashMap<usize, usize>,
+    backward: HashMap<usize, usize>,
+}
+
+/// One of the two searches of a query.
+struct UpwardSearch<W> {
+    distance: HashMap<usize, W>,
+    parent: HashMap<usize, usize>,
+    heap: BinaryHeap<MinScored<W, usize>>,
+}
+
+impl<W: Measure> UpwardSearch<W> {
+    fn new(root: usize) -> Self {
+        Self {
+            distance: HashMap::from([(root, W::zero())]),
+            parent: HashMap::new(),
+            heap: BinaryHeap::from([MinScored(W::zero(), root)]),
+        }
+    }
+}
+
+/// The graph being contracted, with the shortcuts added so far.
+struct Contraction<W> {
+    out: Vec<HashMap<usize, W>>,
+    into: Vec<HashMap<usize, W>>,
+    contracted: Vec<bool>,
+    middle: HashMap<(usize, usize), usize>,
+}
+
+impl<W: Measure> Contraction<W> {
+    /// Lists the shortcuts needed to contract `v`, as `(from, to, length)`.
+    fn shortcuts(&self, v: usize) -> Result<Vec<(usize, usize, W)>, GraphError> {
+        let mut shortcuts = Vec::new();
+        for (&u, to_v) in &self.into[v] {
+            if self.contracted[u] {
+                continue;
+            }
+            let mut targets = Vec::new();
+            for (&w, from_v) in &self.out[v] {
+                if !self.contracted[w] && w != u {
+                    let through = to_v.add_with(from_v, OverflowPolicy::Checked)?;
+                    targets.push((w, through));
+                }
+            }
+            let Some(limit) = targets
+                .iter()
+                .map(|(_, through)| through)
+                .max_by(|first, second| first.compare(second))
+            else {
+                continue;
+            };
+            let witnesses = self.witnesses(u, v, limit);
+            for (w, through) in targets {
+                if witnesses
+                    .get(&w)
+                    .is_none_or(|witness| through.compare(witness) == Ordering::Less)
+                {
+                    shortcuts.push((u, w, through));
+                }
+            }
+        }
+        Ok(shortcuts)
+    }
+
+    /// Computes upper bounds on the distances from `u` avoiding `v` and the
+    /// contracted vertices, exact up to `limit` unless the search gives up.
+    fn witnesses(&self, u: usize, v: usize, limit: &W) -> HashMap<usize, W> {
+        let mut distance = HashMap::from([(u, W::zero())]);
+        let mut heap = BinaryHeap::from([MinScored(W::zero(), u)]);
+        let mut settled = 0;
+        while let Some(MinScored(d, x)) = heap.pop() {
+            if d.compare(limit) == Ordering::Greater || settled == WITNESS_LIMIT {
+                break;
+            }
+            if distance
+                .get(&x)
+                .is_some_and(|known| d.compare(known) == Ordering::Greater)
+            {
+                continue;
+            }
+            settled += 1;
+            for (&y, weight) in &self.out[x] {
+                if y == v || self.contracted[y] {
+                    continue;
+                }
+                let Some(next) = d.checked_add(weight) else {
+                    continue;
+                };
+                if distance
+                    .get(&y)
+                    .is_none_or(|known| next.compare(known) == Ordering::Less)
+                {
+                    distance.insert(y, next.clone());
+                    heap.push(MinScored(next, y));
+                }
+            }
+        }
+        distance
+    }
+
+    /// Returns the contraction priority of `v`, lower values being contracted
+    /// first.
+    fn priority(&self, v: usize, shortcuts: &[(usize, usize, W)], removed: &[usize]) -> i64 {
+        let edges = self.out[v]
+            .keys()
+            .chain(self.into[v].keys())
+            .filter(|&&u| !self.contracted[u])
+            .count();
+        2 * signed(shortcuts.len()) - signed(edges) + signed(removed[v])
+    }
+
+    /// Contracts `v`, adding its shortcuts.
+    fn contract(&mut self, v: usize, shortcuts: Vec<(usize, usize, W)>) {
+        for (u, w, length) in shortcuts {
+            if self.out[u]
+                .get(&w)
+                .is_some_and(|known| known.compare(&length) != Ordering::Greater)
+            {
+                continue;
+            }
+            self.out[u].insert(w, length.clone());
+            self.into[w].insert(u, length);
+            self.middle.insert((u, w), v);
+        }
+        self.contracted[v] = true;
+    }
+}
+
+fn signed(count: usize) -> i64 {
+    i64::try_from(count).unwrap_or(i64::MAX)
+}
@@ -0,0 +1,327 @@
+use std::collections::HashSet;
+
+use rand::{
+    seq::{IteratorRandom, SliceRandom},
+    Rng,
+};
+
+use crate::{
+    error::AlgorithmError,
+    graphs::simple::SimpleGraph,
+    traits::weighted::{WeightedGraph, WeightedGraphMut},
+    GraphError, GraphMut,
+};
+
+/// Samples `count` vertices uniformly at random and returns the subgraph they
+/// induce.
+///
+/// Like every sampler of this module, the sample depends only on the graph and
+/// the random numbers drawn, not on the iteration order of the graph, so a
+/// seeded generator such as `StdRng::seed_from_u64` reproduces it on any
+/// machine.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sample.
+/// * `count` - The number of vertices to sample, capped at the order of the
+///   graph.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The sampled vertices with every edge of `graph` between them, keeping
+/// directions and weights.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::sampling::node_sample, graph, Graph};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 5, 5 - 1 };
+/// let sample = node_sample(&graph, 3, &mut StdRng::seed_from_u64(7));
+/// assert_eq!(sample.order(), 3);
+/// assert!(sample.vertices().all(|v| graph.contains_vertex(v)));
+/// ```
+#[must_use]
+pub fn node_sample<G, R>(graph: &G, count: usize, rng: &mut R) -> SimpleGraph<G::Vertex, G::Weight>
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+    R: Rng + ?Sized,
+{
+    let mut vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    vertices.sort_unstable();
+    let sampled: HashSet<&G::Vertex> = vertices
+        .into_iter()
+        .choose_multiple(rng, count)
+        .into_iter()
+        .collect();
+    induced_subgraph(graph, &sampled)
+}
+
+/// Samples `count` edges uniformly at random and returns the subgraph induced
+/// by their endpoints.
+///
+/// Inducing the subgraph, rather than keeping the sampled edges alone, recovers
+/// the edges between sampled vertices that were not drawn themselves, which
+/// keeps the sample much closer to the density and clustering of the graph.
+/// Each undirected edge is drawn with the same probability as any other.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sample.
+/// * `count` - The number of edges to sample, capped at the size of the graph.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The endpoints of the sampled edges with every edge of `graph` between them,
+/// keeping directions and weights.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::sampling::edge_sample, graph, Graph};
+///
+/// // Any edge of the triangle brings the third edge of it with its endpoints.
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 4 - 5 };
+/// let sample = edge_sample(&graph, 2, &mut rand::rng());
+/// assert!(sample.edge_count() >= 2);
+/// ```
+#[must_use]
+pub fn edge_sample<G, R>(graph: &G, count: usize, rng: &mut R) -> SimpleGraph<G::Vertex, G::Weight>
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+    R: Rng + ?Sized,
+{
+    let mut visited: HashSet<&G::Vertex> = HashSet::new();
+    let mut edges = Vec::new();
+    for u in graph.vertices() {
+        visited.insert(u);
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if graph.is_directed() {
+                edges.push((u, v));
+            } else if u == v || !visited.contains(v) {
+                edges.push((u.min(v), u.max(v)));
+            }
+        }
+    }
+    edges.sort_unstable();
+    let sampled: HashSet<&G::Vertex> = edges
+        .into_iter()
+        .choose_multiple(rng, count)
+        .into_iter()
+        .flat_map(|(u, v)| [u, v])
+        .collect();
+    induced_subgraph(graph, &sampled)
+}
+
+/// Samples `count` vertices by snowball sampling and returns the subgraph they
+/// induce.
+///
+/// The sample starts at a random vertex and grows in waves: every vertex added
+/// in a wave recruits up to `fanout` random out-neighbors not sampled yet. When
+/// a wave recruits nobody before the sample is full, sampling restarts from
+/// another random vertex.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sample.
+/// * `count` - The number of vertices to sample, capped at the order of the
+///   graph.
+/// * `fanout` - The largest number of neighbors each vertex recruits.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The sampled vertices with every edge of `graph` between them, keeping
+/// directions and weights.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if `fanout` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::sampling::snowball_sample, graph, Graph};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 5, 6 - 7 };
+/// let sample = snowball_sample(&graph, 4, 2, &mut rand::rng()).unwrap();
+/// assert_eq!(sample.order(), 4);
+/// ```
+pub fn snowball_sample<G, R>(
+    graph: &G,
+    count: usize,
+    fanout: usize,
+    rng: &mut R,
+) -> Result<SimpleGraph<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+    R: Rng + ?Sized,
+{
+    if fanout == 0 {
+        return Err(AlgorithmError::InvalidArgument(
+            "A snowball sample needs a fanout of at least 1".to_string(),
+        )
+        .into());
+    }
+    let sampled = spread(graph, count, rng, |_| fanout);
+    Ok(induced_subgraph(graph, &sampled))
+}
+
+/// Samples `count` vertices by forest fire sampling and returns the subgraph
+/// they induce.
+///
+/// The fire starts at a random vertex. Every burning vertex then sets fire to
+/// `x` of its out-neighbors not burnt yet, chosen at random, where `x` follows
+/// a geometric distribution with mean `burn / (1 - burn)`, and the fire
+/// spreads from them in turn. When the fire dies out before the sample is
+/// full, a new fire starts at another random vertex. Forest fire samples
+/// preserve degree distributions and community structure well, as shown by
+/// Leskovec and Faloutsos.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sample.
+/// * `count` - The number of vertices to sample, capped at the order of the
+///   graph.
+/// * `burn` - The forward burning probability, `0.7` being a common choice.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// The burnt vertices with every edge of `graph` between them, keeping
+/// directions and weights.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if `burn` is not in `[0, 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::sampling::forest_fire_sample, graph, Graph};
+///
+/// let graph = graph! { 1 - 2, 1 - 3, 2 - 3, 3 - 4, 4 - 5, 5 - 6 };
+/// let sample = forest_fire_sample(&graph, 3, 0.7, &mut rand::rng()).unwrap();
+/// assert_eq!(sample.order(), 3);
+/// ```
+pub fn forest_fire_sample<G, R>(
+    graph: &G,
+    count: usize,
+    burn: f64,
+    rng: &mut R,
+) -> Result<SimpleGraph<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+    R: Rng + ?Sized,
+{
+    if !(0.0..1.0).contains(&burn) {
+        return Err(AlgorithmError::InvalidArgument(format!(
+            "The burning probability must be in [0, 1), got {burn}"
+        ))
+        .into());
+    }
+    let sampled = spread(graph, count, rng, |rng| {
+        let mut spread = 0;
+        while rng.random_bool(burn) {
+            spread += 1;
+        }
+        spread
+    });
+    Ok(induced_subgraph(graph, &sampled))
+}
+
+/// Grows a sample of up to `count` vertices from random starting vertices,
+/// every vertex recruiting `recruits(rng)` random out-neighbors not sampled yet.
+fn spread<'a, G, R, F>(
+    graph: &'a G,
+    count: usize,
+    rng: &mut R,
+    mut recruits: F,
+) -> HashSet<&'a G::Vertex>
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+    R: Rng + ?Sized,
+    F: FnMut(&mut R) -> usize,
+{
+    let mut starts: Vec<&G::Vertex> = graph.vertices().collect();
+    starts.sort_unstable();
+    starts.shuffle(rng);
+    let count = count.min(starts.len());
+    let mut sampled: HashSet<&G::Vertex> = HashSet::with_capacity(count);
+    let mut starts = starts.into_iter();
+    let mut frontier = Vec::new();
+    while sampled.len() < count {
+        if frontier.is_empty() {
+            let Some(start) = starts.find(|v| !sampled.contains(v)) else {
+                break;
+            };
+            sampled.insert(start);
+            frontier.push(start);
+            continue;
+        }
+        let mut next = Vec::new();
+        'wave: for u in frontier {
+            let k = recruits(rng);
+            let mut candidates: Vec<&G::Vertex> = graph
+                .neighbors(u)
+                .into_iter()
+                .flatten()
+                .filter(|v| !sampled.contains(v))
+                .collect();
+            candidates.sort_unstable();
+            let chosen = candidates.into_iter().choose_multiple(rng, k);
+            for v in chosen {
+                if sampled.len() == count {
+                    break 'wave;
+                }
+                sampled.insert(v);
+                next.push(v);
+            }
+        }
+        frontier = next;
+    }
+    sampled
+}
+
+/// Builds the subgraph of `graph` induced by `vertices`, keeping directions and
+/// weights.
+pub(crate) fn induced_subgraph<G>(
+    graph: &G,
+    vertices: &HashSet<&G::Vertex>,
+) -> SimpleGraph<G::Vertex, G::Weight>
+where
+    G: WeightedGraph,
+{
+    let mut subgraph = if graph.is_directed() {
+        SimpleGraph::new_weighted_directed()
+    } else {
+        SimpleGraph::new_weighted_undirected()
+    };
+    for &v in vertices {
+        let _ = subgraph.add_vertex(v.clone());
+    }
+    for &u in vertices {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if !vertices.contains(v) || subgraph.contains_edge(u, v) {
+                continue;
+            }
+            match graph.edge_weight(u, v) {
+                Some(weight) => {
+                    let _ = subgraph.add_weighted_edge(u, v, weight.clone());
+                }
+                None => {
+                    let _ = subgraph.add_edge(u, v);
+                }
+            }
+        }
+    }
+    subgraph
+}
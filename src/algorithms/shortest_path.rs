@@ -0,0 +1,657 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{
+    algorithms::{
+        budget::{Budget, Partial},
+        heap::MinScored,
+        traversal::{join_paths, reverse_adjacency},
+    },
+    error::AlgorithmError,
+    traits::{
+        measure::{Measure, OverflowPolicy},
+        weighted::{WeightedEdge, WeightedGraph},
+    },
+    GraphError,
+};
+
+/// Shortest path lengths from a source vertex, indexed by target, as computed
+/// within a [`Budget`].
+pub type PartialDistances<V, W> = Partial<HashMap<V, W>>;
+
+/// Shortest path lengths from a source vertex, indexed by target, or `None` if
+/// a negative cycle leaves some of them unbounded.
+pub type SignedDistances<V, W> = Option<HashMap<V, W>>;
+
+/// The vertices of a shortest path, from its start to its end, and its length,
+/// or `None` if no path exists.
+pub type ShortestPath<V, W> = Option<(Vec<V>, W)>;
+
+/// Borrowed weighted arcs, both directions of every undirected edge.
+pub(crate) type WeightedArcs<'a, V, W> = Vec<WeightedEdge<&'a V, &'a W>>;
+
+/// Computes the length of the shortest path from `source` to every reachable vertex
+/// using Dijkstra's algorithm.
+///
+/// The algorithm works for any weight type implementing [`Measure`]. Path lengths
+/// are accumulated with [`OverflowPolicy::Checked`]; use [`dijkstra_with_policy`]
+/// to saturate instead.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `source` - The vertex where every path starts.
+///
+/// # Returns
+///
+/// A map from every vertex reachable from `source` to its distance. `source` is
+/// included with distance [`Measure::zero`].
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `source` is not in the graph.
+/// * `GraphError::WeightOverflow` - If a path length overflows.
+/// * `GraphError::Algorithm` - If an edge has no weight, a negative weight
+///   or an invalid weight (such as `NaN`).
+pub fn dijkstra<G>(
+    graph: &G,
+    source: &G::Vertex,
+) -> Result<HashMap<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    dijkstra_with_policy(graph, source, OverflowPolicy::Checked)
+}
+
+/// Computes the length of the shortest path from `source` to every reachable vertex
+/// using Dijkstra's algorithm, accumulating path lengths with the given overflow
+/// policy.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `source` - The vertex where every path starts.
+/// * `policy` - What to do when a path length overflows.
+///
+/// # Returns
+///
+/// A map from every vertex reachable from `source` to its distance. With
+/// [`OverflowPolicy::Saturating`], distances that overflow are clamped to the
+/// largest representable value.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `source` is not in the graph.
+/// * `GraphError::WeightOverflow` - If a path length overflows and `policy` is
+///   [`OverflowPolicy::Checked`].
+/// * `GraphError::Algorithm` - If an edge has no weight, a negative weight
+///   or an invalid weight (such as `NaN`).
+pub fn dijkstra_with_policy<G>(
+    graph: &G,
+    source: &G::Vertex,
+    policy: OverflowPolicy,
+) -> Result<HashMap<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    dijkstra_within(graph, source, policy, &Budget::unlimited()).map(|p| p.value)
+}
+
+/// Runs Dijkstra's algorithm until it finishes or `budget` is exhausted.
+///
+/// Vertices are settled in order of distance, so a partial result holds the
+/// exact distance of every vertex closer to `source` than any vertex left out.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `source` - The vertex where every path starts.
+/// * `policy` - What to do when a path length overflows.
+/// * `budget` - Limits how long the search may run.
+///
+/// # Returns
+///
+/// The settled distances, marked incomplete if the budget ran out first.
+///
+/// # Errors
+///
+/// Same as [`dijkstra_with_policy`].
+pub fn dijkstra_within<G>(
+    graph: &G,
+    source: &G::Vertex,
+    policy: OverflowPolicy,
+    budget: &Budget,
+) -> Result<PartialDistances<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    if !graph.contains_vertex(source) {
+        return Err(GraphError::VertexNotFound);
+    }
+    trace_span!("dijkstra");
+
+    let mut distances: HashMap<G::Vertex, G::Weight> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    heap.push(MinScored(G::Weight::zero(), source.clone()));
+
+    while let Some(MinScored(distance, u)) = heap.pop() {
+        if budget.is_exhausted() {
+            trace_event!(settled = distances.len(), "budget exhausted");
+            return Ok(Partial {
+                value: distances,
+                complete: false,
+            });
+        }
+        if distances.contains_key(&u) {
+            continue;
+        }
+
+        for v in graph.neighbors(&u).into_iter().flatten() {
+            if distances.contains_key(v) {
+                continue;
+            }
+            let weight = checked_weight(graph, &u, v)?;
+            let next = distance.add_with(weight, policy)?;
+            heap.push(MinScored(next, v.clone()));
+        }
+
+        distances.insert(u, distance);
+    }
+
+    trace_event!(settled = distances.len(), "search complete");
+    Ok(Partial {
+        value: distances,
+        complete: true,
+    })
+}
+
+/// A shortest path tree rooted at a source vertex, as computed by
+/// [`dijkstra_tree`] or [`bfs_tree`](crate::algorithms::traversal::bfs_tree).
+///
+/// The tree stores the distance and the predecessor of every vertex reachable
+/// from the source, so any number of shortest paths can be read from one
+/// search.
+#[derive(Clone, Debug)]
+pub struct PathTree<V, W> {
+    source: V,
+    distances: HashMap<V, W>,
+    predecessors: HashMap<V, V>,
+}
+
+impl<V, W> PathTree<V, W>
+where
+    V: Eq + Hash + Clone,
+{
+    pub(crate) fn new(source: V, zero: W) -> Self {
+        Self {
+            distances: HashMap::from([(source.clone(), zero)]),
+            predecessors: HashMap::new(),
+            source,
+        }
+    }
+
+    pub(crate) fn insert(&mut self, v: V, distance: W, predecessor: V) {
+        self.distances.insert(v.clone(), distance);
+        self.predecessors.insert(v, predecessor);
+    }
+
+    /// Returns the root of the tree.
+    #[must_use]
+    pub fn source(&self) -> &V {
+        &self.source
+    }
+
+    /// Returns `true` if `v` is reachable from the source.
+    #[must_use]
+    pub fn contains(&self, v: &V) -> bool {
+        self.distances.contains_key(v)
+    }
+
+    /// Returns the length of a shortest path from the source to `v`, or `None`
+    /// if `v` is unreachable.
+    #[must_use]
+    pub fn distance_to(&self, v: &V) -> Option<&W> {
+        self.distances.get(v)
+    }
+
+    /// Returns the vertex before `v` on the shortest path to it, or `None` if
+    /// `v` is the source or unreachable.
+    #[must_use]
+    pub fn predecessor(&self, v: &V) -> Option<&V> {
+        self.predecessors.get(v)
+    }
+
+    /// Returns a shortest path from the source to `v`, both included, or `None`
+    /// if `v` is unreachable.
+    ///
+    /// The path is rebuilt by following predecessors, in time proportional to
+    /// its length.
+    #[must_use]
+    pub fn path_to(&self, v: &V) -> Option<Vec<V>> {
+        if !self.contains(v) {
+            return None;
+        }
+        let mut path = vec![v.clone()];
+        let mut current = v;
+        while let Some(previous) = self.predecessors.get(current) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Returns the distances of all reachable vertices, the source included.
+    #[must_use]
+    pub fn distances(&self) -> &HashMap<V, W> {
+        &self.distances
+    }
+
+    /// Consumes the tree, returning the distances of all reachable vertices.
+    #[must_use]
+    pub fn into_distances(self) -> HashMap<V, W> {
+        self.distances
+    }
+}
+
+/// Computes the shortest path tree from `source` using Dijkstra's algorithm.
+///
+/// This is [`dijkstra`] recording the predecessor of every vertex, with path
+/// lengths accumulated with [`OverflowPolicy::Checked`]. When several shortest
+/// paths reach a vertex, the tree keeps one of them.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `source` - The root of the tree.
+///
+/// # Returns
+///
+/// The tree of the shortest paths from `source` to every reachable vertex.
+///
+/// # Errors
+///
+/// Same as [`dijkstra`].
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::shortest_path::dijkstra_tree, graph};
+///
+/// let roads = graph! { 1 - 2 [weight = 7], 1 - 3 [weight = 2], 3 - 2 [weight = 3], 2 - 4 [weight = 1] };
+/// let tree = dijkstra_tree(&roads, &1).unwrap();
+/// assert_eq!(tree.distance_to(&4), Some(&6));
+/// assert_eq!(tree.path_to(&4), Some(vec![1, 3, 2, 4]));
+/// assert_eq!(tree.path_to(&1), Some(vec![1]));
+/// assert_eq!(tree.path_to(&5), None);
+/// ```
+pub fn dijkstra_tree<G>(
+    graph: &G,
+    source: &G::Vertex,
+) -> Result<PathTree<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    if !graph.contains_vertex(source) {
+        return Err(GraphError::VertexNotFound);
+    }
+    trace_span!("dijkstra_tree");
+
+    let mut tree = PathTree::new(source.clone(), G::Weight::zero());
+    let mut settled: HashSet<G::Vertex> = HashSet::new();
+    let mut heap = BinaryHeap::new();
+    heap.push(MinScored(G::Weight::zero(), (source.clone(), None)));
+
+    while let Some(MinScored(distance, (u, predecessor))) = heap.pop() {
+        if !settled.insert(u.clone()) {
+            continue;
+        }
+        for v in graph.neighbors(&u).into_iter().flatten() {
+            if settled.contains(v) {
+                continue;
+            }
+            let weight = checked_weight(graph, &u, v)?;
+            let next = distance.add_with(weight, OverflowPolicy::Checked)?;
+            heap.push(MinScored(next, (v.clone(), Some(u.clone()))));
+        }
+        if let Some(predecessor) = predecessor {
+            tree.insert(u, distance, predecessor);
+        }
+    }
+
+    trace_event!(settled = settled.len(), "search complete");
+    Ok(tree)
+}
+
+/// Finds a shortest path from `source` to `target` with bidirectional
+/// Dijkstra's algorithm.
+///
+/// One search grows from `source` along outgoing edges and another from
+/// `target` along incoming edges, always advancing the one with fewer queued
+/// vertices. The search stops once the closest queued vertices of both sides
+/// are together at least as far as the best path found through a vertex both
+/// have reached. Each side then covers about a ball of half the distance, which
+/// on road networks and other sparse graphs visits far fewer vertices than a
+/// single search. Directed graphs have their incoming edges collected first,
+/// in `O(n + m)` time.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `source` - The vertex where the path starts.
+/// * `target` - The vertex where the path ends.
+///
+/// # Returns
+///
+/// The vertices of a shortest path, `source` and `target` included, and its
+/// length, or `None` if `target` is not reachable from `source`.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `source` or `target` is not in the
+///   graph.
+/// * `GraphError::WeightOverflow` - If a path length overflows.
+/// * `GraphError::Algorithm` - If an edge has no weight, a negative weight
+///   or an invalid weight (such as `NaN`).
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::shortest_path::bidirectional_dijkstra, graph};
+///
+/// let roads = graph! { 1 - 2 [weight = 7], 1 - 3 [weight = 2], 3 - 2 [weight = 3], 2 - 4 [weight = 1] };
+/// let (path, length) = bidirectional_dijkstra(&roads, &1, &4).unwrap().unwrap();
+/// assert_eq!(path, [1, 3, 2, 4]);
+/// assert_eq!(length, 6);
+/// ```
+pub fn bidirectional_dijkstra<G>(
+    graph: &G,
+    source: &G::Vertex,
+    target: &G::Vertex,
+) -> Result<ShortestPath<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    if !graph.contains_vertex(source) || !graph.contains_vertex(target) {
+        return Err(GraphError::VertexNotFound);
+    }
+    if source == target {
+        return Ok(Some((vec![source.clone()], G::Weight::zero())));
+    }
+    trace_span!("bidirectional_dijkstra");
+
+    let reverse = graph.is_directed().then(|| reverse_adjacency(graph));
+    let mut sides: [Search<G::Vertex, G::Weight>; 2] = [Search::new(source), Search::new(target)];
+    let mut best: Option<(G::Weight, &G::Vertex)> = None;
+
+    loop {
+        for side in &mut sides {
+            side.skip_settled();
+        }
+        let (Some(MinScored(forward, _)), Some(MinScored(backward, _))) =
+            (sides[0].heap.peek(), sides[1].heap.peek())
+        else {
+            break;
+        };
+        if let Some((length, _)) = &best {
+            // No path through a vertex still queued can be shorter.
+            if forward
+                .checked_add(backward)
+                .is_none_or(|bound| bound.compare(length) != Ordering::Less)
+            {
+                break;
+            }
+        }
+
+        let current = usize::from(sides[1].heap.len() < sides[0].heap.len());
+        let Some(MinScored(distance, u)) = sides[current].heap.pop() else {
+            break;
+        };
+        sides[current].settled.insert(u);
+        let neighbors: Vec<&G::Vertex> = match (&reverse, current) {
+            (Some(reverse), 1) => reverse.get(u).cloned().unwrap_or_default(),
+            _ => graph.neighbors(u).into_iter().flatten().collect(),
+        };
+        for v in neighbors {
+            if sides[current].settled.contains(v) {
+                continue;
+            }
+            let weight = if current == 0 {
+                checked_weight(graph, u, v)?
+            } else {
+                checked_weight(graph, v, u)?
+            };
+            let next = distance.add_with(weight, OverflowPolicy::Checked)?;
+            if sides[current]
+                .distance
+                .get(v)
+                .is_some_and(|known| known.compare(&next) != Ordering::Greater)
+            {
+                continue;
+            }
+            if let Some(other) = sides[1 - current].distance.get(v) {
+                let through = next.add_with(other, OverflowPolicy::Checked)?;
+                if best
+                    .as_ref()
+                    .is_none_or(|(length, _)| through.compare(length) == Ordering::Less)
+                {
+                    best = Some((through, v));
+                }
+            }
+            sides[current].distance.insert(v, next.clone());
+            sides[current].parent.insert(v, Some(u));
+            sides[current].heap.push(MinScored(next, v));
+        }
+    }
+
+    trace_event!(
+        settled = sides[0].settled.len() + sides[1].settled.len(),
+        "search complete"
+    );
+    let [forward, backward] = sides;
+    Ok(best.map(|(length, meeting)| {
+        (
+            join_paths(&forward.parent, &backward.parent, meeting),
+            length,
+        )
+    }))
+}
+
+/// One side of a bidirectional Dijkstra search.
+struct Search<'a, V, W> {
+    distance: HashMap<&'a V, W>,
+    parent: HashMap<&'a V, Option<&'a V>>,
+    settled: HashSet<&'a V>,
+    heap: BinaryHeap<MinScored<W, &'a V>>,
+}
+
+impl<'a, V, W> Search<'a, V, W>
+where
+    V: Eq + Hash,
+    W: Measure,
+{
+    fn new(root: &'a V) -> Self {
+        Self {
+            distance: HashMap::from([(root, W::zero())]),
+            parent: HashMap::from([(root, None)]),
+            settled: HashSet::new(),
+            heap: BinaryHeap::from([MinScored(W::zero(), root)]),
+        }
+    }
+
+    /// Drops the queued entries of vertices already settled.
+    fn skip_settled(&mut self) {
+        while self
+            .heap
+            .peek()
+            .is_some_and(|MinScored(_, v)| self.settled.contains(v))
+        {
+            self.heap.pop();
+        }
+    }
+}
+
+/// Computes the length of the shortest path from `source` to every reachable vertex
+/// using the Bellman-Ford algorithm, which allows negative edge weights.
+///
+/// The edges are read once from [`WeightedGraph::weighted_edges`] and relaxed in
+/// rounds until no distance improves, at most `n - 1` times, in `O(n m)` time. An
+/// undirected edge is relaxed in both directions, so a negative undirected edge
+/// is a negative cycle.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `source` - The vertex where every path starts.
+///
+/// # Returns
+///
+/// * `Some(HashMap<V, W>)` - The distance of every vertex reachable from
+///   `source`, including `source` itself.
+/// * `None` - If a cycle of negative length is reachable from `source`, so some
+///   distances are unbounded.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `source` is not in the graph.
+/// * `GraphError::WeightOverflow` - If a path length overflows.
+/// * `GraphError::Algorithm` - If an edge has no weight or an invalid
+///   weight (such as `NaN`).
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::shortest_path::bellman_ford, graph};
+///
+/// let graph = graph! { 1 -> 2 [weight = 4], 1 -> 3 [weight = 2], 3 -> 2 [weight = -3] };
+/// let distances = bellman_ford(&graph, &1).unwrap().unwrap();
+/// assert_eq!(distances[&2], -1);
+///
+/// let cyclic = graph! { 1 -> 2 [weight = 1], 2 -> 1 [weight = -2] };
+/// assert!(bellman_ford(&cyclic, &1).unwrap().is_none());
+/// ```
+pub fn bellman_ford<G>(
+    graph: &G,
+    source: &G::Vertex,
+) -> Result<SignedDistances<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    if !graph.contains_vertex(source) {
+        return Err(GraphError::VertexNotFound);
+    }
+    let arcs = weighted_arcs(graph)?;
+    trace_span!("bellman_ford", arcs = arcs.len());
+    let index: HashMap<&G::Vertex, usize> =
+        graph.vertices().enumerate().map(|(i, v)| (v, i)).collect();
+    let mut distance: Vec<Option<G::Weight>> = vec![None; index.len()];
+    distance[index[source]] = Some(G::Weight::zero());
+
+    for _ in 0..index.len() {
+        let mut improved = false;
+        for &(u, v, weight) in &arcs {
+            let Some(through) = &distance[index[u]] else {
+                continue;
+            };
+            let next = through
+                .checked_add(weight)
+                .ok_or(GraphError::WeightOverflow)?;
+            let target = &mut distance[index[v]];
+            if target
+                .as_ref()
+                .is_none_or(|current| next.compare(current) == Ordering::Less)
+            {
+                *target = Some(next);
+                improved = true;
+            }
+        }
+        if !improved {
+            trace_event!("distances settled");
+            let vertices = graph.vertices().zip(distance);
+            return Ok(Some(
+                vertices
+                    .filter_map(|(v, d)| Some((v.clone(), d?)))
+                    .collect(),
+            ));
+        }
+    }
+    trace_event!("negative cycle reachable");
+    Ok(None)
+}
+
+/// Lists the weighted edges of a graph as arcs, both directions for undirected
+/// edges, after checking that every edge has a valid weight.
+pub(crate) fn weighted_arcs<G>(
+    graph: &G,
+) -> Result<WeightedArcs<'_, G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    let directed = graph.is_directed();
+    let mut arcs = Vec::new();
+    let mut edges = 0;
+    for (u, v, weight) in graph.weighted_edges() {
+        if !weight.is_valid() {
+            return Err(AlgorithmError::invalid_weight(u, v, weight).into());
+        }
+        arcs.push((u, v, weight));
+        if !directed && u != v {
+            arcs.push((v, u, weight));
+        }
+        edges += 1;
+    }
+    if edges < graph.size() {
+        return Err(unweighted_edge(graph));
+    }
+    Ok(arcs)
+}
+
+/// Finds an edge without a weight, for the error reported by algorithms that
+/// need every edge weighted.
+pub(crate) fn unweighted_edge<G>(graph: &G) -> GraphError
+where
+    G: WeightedGraph,
+{
+    graph
+        .vertices()
+        .find_map(|u| {
+            graph
+                .neighbors(u)
+                .into_iter()
+                .flatten()
+                .find(|v| graph.edge_weight(u, v).is_none())
+                .map(|v| AlgorithmError::missing_weight(u, v))
+        })
+        .unwrap_or_else(|| AlgorithmError::InvalidArgument("An edge has no weight".to_string()))
+        .into()
+}
+
+/// Returns the weight of the edge `(u, v)` after checking that Dijkstra-style
+/// algorithms can use it.
+pub(crate) fn checked_weight<'a, G>(
+    graph: &'a G,
+    u: &G::Vertex,
+    v: &G::Vertex,
+) -> Result<&'a G::Weight, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    let weight = graph
+        .edge_weight(u, v)
+        .ok_or_else(|| AlgorithmError::missing_weight(u, v))?;
+    if !weight.is_valid() || weight.is_negative() {
+        return Err(AlgorithmError::invalid_weight(u, v, weight).into());
+    }
+    Ok(weight)
+}
@@ -0,0 +1,246 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{graphs::simple::SimpleGraph, Graph, GraphMut};
+
+/// Randomizes a graph while keeping the degree of every vertex, by attempting
+/// `attempts` double edge swaps.
+///
+/// An attempt draws two edges `u - v` and `x - y` and rewires them into
+/// `u - y` and `x - v`, unless that would create a self-loop or repeat an edge,
+/// in which case the attempt is rejected. In directed graphs the arcs `u -> v`
+/// and `x -> y` become `u -> y` and `x -> v`, keeping every in- and
+/// out-degree. Self-loops of the graph are kept and never swapped.
+///
+/// About ten attempts per edge are usually enough to lose the structure of the
+/// original graph, giving a sample of the graphs with its degree sequence.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to randomize.
+/// * `attempts` - The number of swaps to attempt.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// A graph with the same vertices, directedness and degrees, without weights.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::significance::double_edge_swap, graph, Graph};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 5, 5 - 6, 6 - 1, 1 - 4 };
+/// let random = double_edge_swap(&graph, 100, &mut rand::rng());
+/// assert_eq!(random.edge_count(), graph.edge_count());
+/// assert!(graph.vertices().all(|v| random.degree(v) == graph.degree(v)));
+/// ```
+#[must_use]
+pub fn double_edge_swap<G, R>(graph: &G, attempts: usize, rng: &mut R) -> SimpleGraph<G::Vertex>
+where
+    G: Graph,
+    R: Rng + ?Sized,
+{
+    let directed = graph.is_directed();
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+    let mut loops = Vec::new();
+    let mut edges = Vec::new();
+    let mut arcs: HashSet<(usize, usize)> = HashSet::new();
+    for (i, &u) in vertices.iter().enumerate() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            let j = index[v];
+            if i == j {
+                loops.push(i);
+            } else if directed || i < j {
+                edges.push((i, j));
+                arcs.insert((i, j));
+                if !directed {
+                    arcs.insert((j, i));
+                }
+            }
+        }
+    }
+
+    if edges.len() >= 2 {
+        for _ in 0..attempts {
+            let first = rng.random_range(0..edges.len());
+            let second = rng.random_range(0..edges.len());
+            if first == second {
+                continue;
+            }
+            let (u, v) = edges[first];
+            let (mut x, mut y) = edges[second];
+            if !directed && rng.random_bool(0.5) {
+                std::mem::swap(&mut x, &mut y);
+            }
+            if u == y || x == v || arcs.contains(&(u, y)) || arcs.contains(&(x, v)) {
+                continue;
+            }
+            for (p, q) in [(u, v), (x, y)] {
+                arcs.remove(&(p, q));
+                if !directed {
+                    arcs.remove(&(q, p));
+                }
+            }
+            for (p, q) in [(u, y), (x, v)] {
+                arcs.insert((p, q));
+                if !directed {
+                    arcs.insert((q, p));
+                }
+            }
+            edges[first] = (u, y);
+            edges[second] = (x, v);
+        }
+    }
+
+    let mut random = if directed {
+        SimpleGraph::new_directed()
+    } else {
+        SimpleGraph::new_undirected()
+    };
+    for &v in &vertices {
+        let _ = random.add_vertex(v.clone());
+    }
+    for (u, v) in edges.into_iter().chain(loops.into_iter().map(|i| (i, i))) {
+        let _ = random.add_edge(vertices[u], vertices[v]);
+    }
+    random
+}
+
+/// The outcome of comparing an observed metric with its values over randomized
+/// replicas of a graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Significance {
+    /// The metric of the observed graph.
+    pub observed: f64,
+    /// The metric of every replica, in replica order.
+    pub replicas: Vec<f64>,
+    /// The mean of the replicas.
+    pub mean: f64,
+    /// The sample standard deviation of the replicas.
+    pub std_dev: f64,
+    /// `(observed - mean) / std_dev`, or `None` if the replicas do not vary.
+    pub z_score: Option<f64>,
+    /// The empirical probability that a replica is at least the observed value,
+    /// `(1 + r) / (n + 1)` for `r` such replicas out of `n`.
+    pub p_greater: f64,
+    /// The empirical probability that a replica is at most the observed value.
+    pub p_less: f64,
+}
+
+impl Significance {
+    /// Summarizes the replicas of a metric against its observed value.
+    ///
+    /// The p-values count the observed graph among the samples, so they are
+    /// never zero and stay valid with few replicas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::algorithms::significance::Significance;
+    ///
+    /// let test = Significance::new(9.0, vec![1.0, 2.0, 3.0, 2.0]);
+    /// assert_eq!(test.mean, 2.0);
+    /// assert_eq!(test.p_greater, 0.2);
+    /// assert_eq!(test.p_less, 1.0);
+    /// assert!(test.z_score.unwrap() > 8.0);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(observed: f64, replicas: Vec<f64>) -> Self {
+        let n = replicas.len() as f64;
+        let mean = if replicas.is_empty() {
+            f64::NAN
+        } else {
+            replicas.iter().sum::<f64>() / n
+        };
+        let std_dev = if replicas.len() < 2 {
+            0.0
+        } else {
+            (replicas.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        };
+        let z_score = (std_dev > 0.0).then(|| (observed - mean) / std_dev);
+        let greater = replicas.iter().filter(|&&x| x >= observed).count() as f64;
+        let less = replicas.iter().filter(|&&x| x <= observed).count() as f64;
+        Self {
+            observed,
+            mean,
+            std_dev,
+            z_score,
+            p_greater: (1.0 + greater) / (n + 1.0),
+            p_less: (1.0 + less) / (n + 1.0),
+            replicas,
+        }
+    }
+}
+
+/// Tests whether a metric of a graph is significant against a null model, by
+/// comparing it with the metric of `replicas` randomized copies of the graph.
+///
+/// Replica `i` is generated by `randomize` with a generator seeded from
+/// `seed` and `i`, so the test is reproducible and gives the same result as
+/// its parallel version
+/// [`par_significance_test`](crate::algorithms::parallel::par_significance_test).
+/// For degree-preserving tests, randomize with [`double_edge_swap`].
+///
+/// # Arguments
+///
+/// * `graph` - The observed graph.
+/// * `metric` - The metric to test, such as a motif count or an assortativity
+///   coefficient.
+/// * `randomize` - Generates a random replica of a graph.
+/// * `replicas` - The number of replicas.
+/// * `seed` - The seed of the replica generators.
+///
+/// # Returns
+///
+/// The observed metric compared with its values over the replicas.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::{clustering::triangles, significance::{double_edge_swap, significance_test}},
+///     graph, Graph, SimpleGraph,
+/// };
+///
+/// // Two triangles joined by an edge: more triangles than their degrees suggest.
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 1, 4 - 5, 5 - 6, 6 - 4, 3 - 4 };
+/// let count = |g: &SimpleGraph<i32>| triangles(g).values().sum::<usize>() as f64 / 3.0;
+/// let test = significance_test(
+///     &graph,
+///     count,
+///     |g, rng| double_edge_swap(g, 10 * g.size(), rng),
+///     99,
+///     42,
+/// );
+/// assert_eq!(test.observed, 2.0);
+/// assert!(test.p_greater <= 1.0);
+/// ```
+pub fn significance_test<G, M, N>(
+    graph: &G,
+    metric: M,
+    randomize: N,
+    replicas: usize,
+    seed: u64,
+) -> Significance
+where
+    G: Graph,
+    M: Fn(&G) -> f64,
+    N: Fn(&G, &mut StdRng) -> G,
+{
+    trace_span!("significance_test", replicas);
+    let values = (0..replicas)
+        .map(|i| metric(&randomize(graph, &mut replica_rng(seed, i))))
+        .collect();
+    Significance::new(metric(graph), values)
+}
+
+/// Returns the generator of replica `i` of a test seeded with `seed`.
+pub(crate) fn replica_rng(seed: u64, i: usize) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
@@ -0,0 +1,238 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{algorithms::adjacency::undirected_adjacency, Graph};
+
+/// Computes the Jaccard similarity of the edge sets of two graphs, the number
+/// of edges they share divided by the number of edges in either.
+///
+/// Edges are matched by their endpoints, so both graphs should have the same
+/// directedness; weights are ignored.
+///
+/// # Returns
+///
+/// The similarity in `[0, 1]`, `1.0` if neither graph has an edge.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::similarity::edge_jaccard, graph};
+///
+/// let monday = graph! { 1 - 2, 2 - 3, 3 - 4 };
+/// let tuesday = graph! { 1 - 2, 3 - 2, 4 - 5 };
+/// assert_eq!(edge_jaccard(&monday, &tuesday), 0.5);
+/// ```
+#[must_use]
+pub fn edge_jaccard<G, H>(first: &G, second: &H) -> f64
+where
+    G: Graph,
+    H: Graph<Vertex = G::Vertex>,
+{
+    let (first_edges, shared) = count_edges(first, second);
+    let (second_edges, _) = count_edges(second, first);
+    let union = first_edges + second_edges - shared;
+    if union == 0 {
+        return 1.0;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let similarity = shared as f64 / union as f64;
+    similarity
+}
+
+/// Counts the edges of `graph`, each undirected edge once, and how many of them
+/// `other` contains.
+fn count_edges<G, H>(graph: &G, other: &H) -> (usize, usize)
+where
+    G: Graph,
+    H: Graph<Vertex = G::Vertex>,
+{
+    let mut visited: HashSet<&G::Vertex> = HashSet::new();
+    let (mut edges, mut shared) = (0, 0);
+    for u in graph.vertices() {
+        visited.insert(u);
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if !graph.is_directed() && u != v && visited.contains(v) {
+                continue;
+            }
+            edges += 1;
+            if other.contains_edge(u, v) {
+                shared += 1;
+            }
+        }
+    }
+    (edges, shared)
+}
+
+/// Computes the spectral distance of two graphs, the Euclidean distance between
+/// the spectra of one of their matrices.
+///
+/// The smaller spectrum is padded with zeros, the eigenvalues of isolated
+/// vertices, so graphs of different orders can be compared and adding isolated
+/// vertices does not change the distance. The vertices need not correspond,
+/// so the distance compares the shapes of the graphs: isomorphic graphs are at
+/// distance zero.
+///
+/// # Arguments
+///
+/// * `first` - The first graph.
+/// * `second` - The second graph.
+/// * `kind` - The matrix whose spectra are compared.
+///
+/// # Returns
+///
+/// The distance, `0.0` for graphs with the same spectrum.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::{similarity::spectral_distance, spectral::GraphMatrix},
+///     graph,
+/// };
+///
+/// let path = graph! { 1 - 2, 2 - 3 };
+/// let relabeled = graph! { 7 - 5, 5 - 9 };
+/// let triangle = graph! { 1 - 2, 2 - 3, 3 - 1 };
+/// assert!(spectral_distance(&path, &relabeled, GraphMatrix::Laplacian) < 1e-9);
+/// assert!(spectral_distance(&path, &triangle, GraphMatrix::Laplacian) > 1.0);
+/// ```
+#[cfg(feature = "linalg")]
+#[cfg_attr(docsrs, doc(cfg(feature = "linalg")))]
+#[must_use]
+pub fn spectral_distance<G, H>(
+    first: &G,
+    second: &H,
+    kind: crate::algorithms::spectral::GraphMatrix,
+) -> f64
+where
+    G: Graph,
+    H: Graph,
+{
+    use crate::algorithms::spectral::spectrum;
+
+    let mut first = spectrum(first, kind);
+    let mut second = spectrum(second, kind);
+    let n = first.len().max(second.len());
+    for values in [&mut first, &mut second] {
+        values.resize(n, 0.0);
+        values.sort_by(f64::total_cmp);
+    }
+    first
+        .iter()
+        .zip(&second)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Computes the `DeltaCon` similarity of two graphs on the same vertices, as
+/// defined by Koutra, Vogelstein and Faloutsos.
+///
+/// `DeltaCon` compares the affinities `S = (I + ε²D - εA)^(-1)` that fast belief
+/// propagation assigns to every pair of vertices in each graph, with `ε = 1 /
+/// (1 + d)` for the largest degree `d` of either graph. Unlike the edge overlap,
+/// it weighs an edge by how much it changes the connectivity, so that an edge
+/// joining two components matters more than one inside a dense cluster.
+///
+/// Vertices are matched by label, a vertex missing from one graph being
+/// isolated there. Edge directions and self-loops are ignored. Computing `S`
+/// takes `O(n^3)` time and `O(n^2)` memory, so it suits graphs with up to a few
+/// thousand vertices.
+///
+/// # Returns
+///
+/// The similarity `1 / (1 + d)` in `(0, 1]`, where `d` is the root Euclidean
+/// distance between the affinities, `1.0` for graphs with the same edges.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::similarity::deltacon, graph};
+///
+/// let before = graph! { 1 - 2, 2 - 3, 3 - 1, 4 - 5, 5 - 6, 6 - 4, 3 - 4 };
+/// let inside = graph! { 1 - 2, 2 - 3, 4 - 5, 5 - 6, 6 - 4, 3 - 4 };
+/// let bridge = graph! { 1 - 2, 2 - 3, 3 - 1, 4 - 5, 5 - 6, 6 - 4 };
+/// assert_eq!(deltacon(&before, &before), 1.0);
+/// // Removing the bridge disconnects the graph, a larger change.
+/// assert!(deltacon(&before, &bridge) < deltacon(&before, &inside));
+/// ```
+#[must_use]
+pub fn deltacon<G, H>(first: &G, second: &H) -> f64
+where
+    G: Graph,
+    H: Graph<Vertex = G::Vertex>,
+{
+    trace_span!("deltacon", vertices = first.order());
+    let first_adjacency = undirected_adjacency(first);
+    let second_adjacency = undirected_adjacency(second);
+    let mut vertices: Vec<&G::Vertex> = first.vertices().collect();
+    vertices.extend(second.vertices().filter(|v| !first.contains_vertex(v)));
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+    let max_degree = first_adjacency
+        .values()
+        .chain(second_adjacency.values())
+        .map(HashSet::len)
+        .max()
+        .unwrap_or(0);
+    #[allow(clippy::cast_precision_loss)]
+    let epsilon = 1.0 / (1.0 + max_degree as f64);
+
+    let affinities = [&first_adjacency, &second_adjacency].map(|adjacency| {
+        let mut matrix = vec![vec![0.0; vertices.len()]; vertices.len()];
+        for (&u, neighbors) in adjacency {
+            let i = index[u];
+            #[allow(clippy::cast_precision_loss)]
+            let degree = neighbors.len() as f64;
+            matrix[i][i] = epsilon * epsilon * degree;
+            for v in neighbors {
+                matrix[i][index[v]] = -epsilon;
+            }
+        }
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] += 1.0;
+        }
+        invert(matrix)
+    });
+
+    let distance = affinities[0]
+        .iter()
+        .flatten()
+        .zip(affinities[1].iter().flatten())
+        .map(|(&x, &y)| (x.max(0.0).sqrt() - y.max(0.0).sqrt()).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    1.0 / (1.0 + distance)
+}
+
+/// Inverts a strictly diagonally dominant matrix by Gauss-Jordan elimination,
+/// which needs no pivoting for such matrices.
+fn invert(mut matrix: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    let mut inverse: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| f64::from(u8::from(i == j))).collect())
+        .collect();
+    for pivot in 0..n {
+        let scale = 1.0 / matrix[pivot][pivot];
+        for x in &mut matrix[pivot] {
+            *x *= scale;
+        }
+        for x in &mut inverse[pivot] {
+            *x *= scale;
+        }
+        let (pivot_row, pivot_inverse) = (matrix[pivot].clone(), inverse[pivot].clone());
+        for row in (0..n).filter(|&row| row != pivot) {
+            let factor = matrix[row][pivot];
+            if factor == 0.0 {
+                continue;
+            }
+            for (x, p) in matrix[row].iter_mut().zip(&pivot_row) {
+                *x -= factor * p;
+            }
+            for (x, p) in inverse[row].iter_mut().zip(&pivot_inverse) {
+                *x -= factor * p;
+            }
+        }
+    }
+    inverse
+}
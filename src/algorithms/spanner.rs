@@ -0,0 +1,264 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::{
+    algorithms::heap::MinScored,
+    error::AlgorithmError,
+    graphs::simple::SimpleGraph,
+    traits::weighted::{WeightedGraph, WeightedGraphMut},
+    GraphError, GraphMut,
+};
+
+/// The vertices of a graph and its edges with their weights, as positions into
+/// the vertex list.
+type WeightedEdges<'a, V> = (Vec<&'a V>, Vec<(usize, usize, f64)>);
+
+/// Numbers the vertices of an undirected weighted graph and lists its edges
+/// once each with their weights, skipping self-loops.
+fn weighted_edge_list<G>(graph: &G) -> Result<WeightedEdges<'_, G::Vertex>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+{
+    if graph.is_directed() {
+        return Err(AlgorithmError::RequiresUndirected.into());
+    }
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let mut edges = Vec::new();
+    for (i, &u) in vertices.iter().enumerate() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            let j = index[v];
+            if i >= j {
+                continue;
+            }
+            let weight: f64 = (*graph
+                .edge_weight(u, v)
+                .ok_or_else(|| AlgorithmError::missing_weight(u, v))?)
+            .into();
+            if weight.is_nan() || weight < 0.0 {
+                return Err(AlgorithmError::invalid_weight(u, v, weight).into());
+            }
+            edges.push((i, j, weight));
+        }
+    }
+    Ok((vertices, edges))
+}
+
+/// Computes a greedy `t`-spanner of an undirected weighted graph: a subgraph in
+/// which the distance between any two vertices is at most `stretch` times their
+/// distance in the graph.
+///
+/// Edges are scanned by increasing weight and kept only if the spanner built so
+/// far has no path between their endpoints within `stretch` times their weight,
+/// checked with a Dijkstra search cut off at that length. For `stretch = 2k - 1`
+/// the spanner has `O(n^(1 + 1/k))` edges and its total weight is within a small
+/// factor of the minimum spanning tree. With `stretch = 1` only the edges that
+/// are shortest paths between their endpoints are kept.
+///
+/// # Arguments
+///
+/// * `graph` - The undirected weighted graph.
+/// * `stretch` - The allowed distance stretch `t`, at least `1`.
+///
+/// # Returns
+///
+/// A weighted undirected graph with every vertex and the kept edges, with their
+/// original weights.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is directed, if an edge
+/// has no weight or a negative or `NaN` weight, or if `stretch` is smaller than
+/// `1` or `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::spanner::greedy_spanner, graph, Graph};
+///
+/// // The heavy edge 1 - 3 is within stretch 2 of the path through 2.
+/// let graph = graph! { 1 - 2 [weight = 1.0], 2 - 3 [weight = 1.0], 1 - 3 [weight = 1.5] };
+/// let spanner = greedy_spanner(&graph, 2.0).unwrap();
+/// assert_eq!(spanner.edge_count(), 2);
+/// assert!(!spanner.contains_edge(&1, &3));
+/// ```
+pub fn greedy_spanner<G>(
+    graph: &G,
+    stretch: f64,
+) -> Result<SimpleGraph<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+{
+    if stretch.is_nan() || stretch < 1.0 {
+        return Err(AlgorithmError::InvalidArgument(format!(
+            "The stretch of a spanner must be at least 1, got {stretch}"
+        ))
+        .into());
+    }
+    let (vertices, mut edges) = weighted_edge_list(graph)?;
+    trace_span!("greedy_spanner", edges = edges.len());
+    edges.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    let n = vertices.len();
+    let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+    let mut distance = vec![f64::INFINITY; n];
+    let mut touched = Vec::new();
+    let mut spanner = SimpleGraph::new_weighted_undirected();
+    for &v in &vertices {
+        spanner.add_vertex(v.clone())?;
+    }
+    for (u, v, weight) in edges {
+        let limit = stretch * weight;
+        if within(&adjacency, u, v, limit, &mut distance, &mut touched) {
+            continue;
+        }
+        adjacency[u].push((v, weight));
+        adjacency[v].push((u, weight));
+        if let Some(&original) = graph.edge_weight(vertices[u], vertices[v]) {
+            spanner.add_weighted_edge(vertices[u], vertices[v], original)?;
+        }
+    }
+    Ok(spanner)
+}
+
+/// Checks whether `to` is at distance at most `limit` from `from`, searching no
+/// further than `limit`. `distance` must be all infinite and is restored.
+fn within(
+    adjacency: &[Vec<(usize, f64)>],
+    from: usize,
+    to: usize,
+    limit: f64,
+    distance: &mut [f64],
+    touched: &mut Vec<usize>,
+) -> bool {
+    let mut heap = BinaryHeap::from([MinScored(0.0, from)]);
+    distance[from] = 0.0;
+    touched.push(from);
+    let mut found = false;
+    while let Some(MinScored(d, u)) = heap.pop() {
+        if d > distance[u] {
+            continue;
+        }
+        if u == to {
+            found = true;
+            break;
+        }
+        for &(v, weight) in &adjacency[u] {
+            let next = d + weight;
+            if next <= limit && next < distance[v] {
+                if distance[v].is_infinite() {
+                    touched.push(v);
+                }
+                distance[v] = next;
+                heap.push(MinScored(next, v));
+            }
+        }
+    }
+    for v in touched.drain(..) {
+        distance[v] = f64::INFINITY;
+    }
+    found
+}
+
+/// Computes a spectral sparsifier of an undirected weighted graph by sampling
+/// edges by effective resistance.
+///
+/// This is the Spielman–Srivastava construction: `samples` edges are drawn with
+/// replacement, each with probability proportional to its weight times its
+/// effective resistance, and every draw of an edge adds `w / (samples * p)` to
+/// its new weight. The Laplacian of the result approximates that of the graph,
+/// so cuts and quadratic forms are preserved in expectation; `O(n log n / ε²)`
+/// samples give a `1 ± ε` approximation with high probability. Effective
+/// resistances are read from the pseudoinverse of the Laplacian, computed with
+/// the cyclic Jacobi method in `O(n^3)` time, which suits graphs with up to a
+/// few hundred vertices.
+///
+/// # Arguments
+///
+/// * `graph` - The undirected weighted graph.
+/// * `samples` - The number of edges to draw.
+/// * `rng` - The source of randomness.
+///
+/// # Returns
+///
+/// A weighted undirected graph with every vertex and the sampled edges, at most
+/// `samples` of them, with their new weights.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is directed or if an edge
+/// has no weight or a negative or `NaN` weight.
+#[cfg(all(feature = "linalg", feature = "rand"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "linalg", feature = "rand"))))]
+pub fn spectral_sparsifier<G, R>(
+    graph: &G,
+    samples: usize,
+    rng: &mut R,
+) -> Result<SimpleGraph<G::Vertex, f64>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+    R: rand::Rng + ?Sized,
+{
+    use crate::algorithms::spectral::symmetric_eigen;
+
+    let (vertices, edges) = weighted_edge_list(graph)?;
+    trace_span!("spectral_sparsifier", edges = edges.len(), samples);
+    let n = vertices.len();
+
+    let mut laplacian = vec![vec![0.0; n]; n];
+    for &(u, v, weight) in &edges {
+        laplacian[u][u] += weight;
+        laplacian[v][v] += weight;
+        laplacian[u][v] -= weight;
+        laplacian[v][u] -= weight;
+    }
+    let eigen = symmetric_eigen(laplacian);
+    let largest = eigen.last().map_or(0.0, |&(value, _)| value);
+    let tolerance = 1e-9 * largest.max(1.0);
+    // Leverage scores w * R(u, v), with R read from the nonzero eigenpairs.
+    let scores: Vec<f64> = edges
+        .iter()
+        .map(|&(u, v, weight)| {
+            let resistance: f64 = eigen
+                .iter()
+                .filter(|(value, _)| *value > tolerance)
+                .map(|(value, vector)| (vector[u] - vector[v]).powi(2) / value)
+                .sum();
+            weight * resistance
+        })
+        .collect();
+    let total: f64 = scores.iter().sum();
+
+    let mut sparsifier = SimpleGraph::new_weighted_undirected();
+    for &v in &vertices {
+        sparsifier.add_vertex(v.clone())?;
+    }
+    if total <= 0.0 || samples == 0 {
+        return Ok(sparsifier);
+    }
+    let mut cumulative = Vec::with_capacity(scores.len());
+    let mut running = 0.0;
+    for score in &scores {
+        running += score;
+        cumulative.push(running);
+    }
+    let mut weights: HashMap<usize, f64> = HashMap::new();
+    #[allow(clippy::cast_precision_loss)]
+    let draws = samples as f64;
+    for _ in 0..samples {
+        let target = rng.random::<f64>() * total;
+        let e = cumulative
+            .partition_point(|&c| c <= target)
+            .min(edges.len() - 1);
+        let probability = scores[e] / total;
+        *weights.entry(e).or_insert(0.0) += edges[e].2 / (draws * probability);
+    }
+    for (e, weight) in weights {
+        let (u, v, _) = edges[e];
+        sparsifier.add_weighted_edge(vertices[u], vertices[v], weight)?;
+    }
+    Ok(sparsifier)
+}
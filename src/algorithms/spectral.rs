@@ -0,0 +1,274 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{algorithms::adjacency::undirected_adjacency, Graph};
+
+/// The matrix of a graph whose eigenvalues are computed by [`spectrum`].
+///
+/// Every matrix is built from the underlying simple graph: edge directions are
+/// ignored and self-loops are dropped, so the matrices are symmetric and their
+/// eigenvalues real.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GraphMatrix {
+    /// The 0/1 adjacency matrix `A`.
+    #[default]
+    Adjacency,
+    /// The Laplacian `L = D - A`, where `D` is the diagonal degree matrix.
+    Laplacian,
+    /// The signless Laplacian `Q = D + A`.
+    SignlessLaplacian,
+    /// The normalized Laplacian `I - D^(-1/2) A D^(-1/2)`, with a zero row for
+    /// isolated vertices.
+    NormalizedLaplacian,
+}
+
+/// Dense symmetric matrix stored by rows.
+pub(crate) type Matrix = Vec<Vec<f64>>;
+
+/// Builds the requested matrix of `graph`, with rows in the order of
+/// [`Graph::vertices`].
+pub(crate) fn graph_matrix<G>(graph: &G, kind: GraphMatrix) -> Matrix
+where
+    G: Graph,
+{
+    let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    let index: HashMap<&G::Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let adjacency = undirected_adjacency(graph);
+    #[allow(clippy::cast_precision_loss)]
+    let degrees: Vec<f64> = vertices.iter().map(|v| adjacency[v].len() as f64).collect();
+
+    let mut matrix = vec![vec![0.0; vertices.len()]; vertices.len()];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for &j in adjacency[vertices[i]].iter().map(|v| &index[v]) {
+            row[j] = match kind {
+                GraphMatrix::Adjacency | GraphMatrix::SignlessLaplacian => 1.0,
+                GraphMatrix::Laplacian => -1.0,
+                GraphMatrix::NormalizedLaplacian => -1.0 / (degrees[i] * degrees[j]).sqrt(),
+            };
+        }
+        row[i] = match kind {
+            GraphMatrix::Adjacency => 0.0,
+            GraphMatrix::Laplacian | GraphMatrix::SignlessLaplacian => degrees[i],
+            GraphMatrix::NormalizedLaplacian => f64::from(u8::from(degrees[i] > 0.0)),
+        };
+    }
+    matrix
+}
+
+/// Computes the eigenvalues and orthonormal eigenvectors of a symmetric matrix
+/// with the cyclic Jacobi method.
+///
+/// Returns the eigenvalues in ascending order, each paired with its eigenvector.
+pub(crate) fn symmetric_eigen(mut matrix: Matrix) -> Vec<(f64, Vec<f64>)> {
+    let n = matrix.len();
+    let mut vectors: Matrix = (0..n)
+        .map(|i| (0..n).map(|j| f64::from(u8::from(i == j))).collect())
+        .collect();
+
+    let total: f64 = matrix.iter().flatten().map(|x| x * x).sum();
+    for _ in 0..64 {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+            .map(|(p, q)| matrix[p][q] * matrix[p][q])
+            .sum();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * total {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if matrix[p][q] == 0.0 {
+                    continue;
+                }
+                // Rotate in the (p, q) plane so that matrix[p][q] becomes zero.
+                let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+                let tangent = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let cos = 1.0 / tangent.hypot(1.0);
+                let sin = tangent * cos;
+                for row in &mut matrix {
+                    let (first, second) = (row[p], row[q]);
+                    row[p] = cos * first - sin * second;
+                    row[q] = sin * first + cos * second;
+                }
+                let (row_p, row_q) = (matrix[p].clone(), matrix[q].clone());
+                for (k, (first, second)) in row_p.into_iter().zip(row_q).enumerate() {
+                    matrix[p][k] = cos * first - sin * second;
+                    matrix[q][k] = sin * first + cos * second;
+                }
+                for row in &mut vectors {
+                    let (first, second) = (row[p], row[q]);
+                    row[p] = cos * first - sin * second;
+                    row[q] = sin * first + cos * second;
+                }
+            }
+        }
+    }
+
+    let mut pairs: Vec<(f64, Vec<f64>)> = (0..n)
+        .map(|j| (matrix[j][j], vectors.iter().map(|row| row[j]).collect()))
+        .collect();
+    pairs.sort_by(|first, second| first.0.total_cmp(&second.0));
+    pairs
+}
+
+/// Computes the spectrum of one of the matrices of a graph.
+///
+/// The eigenvalues are found with the cyclic Jacobi method, which takes `O(n^3)`
+/// time per sweep and `O(n^2)` memory, so it suits graphs with up to a few
+/// hundred vertices.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to inspect.
+/// * `kind` - Which matrix of the graph to use.
+///
+/// # Returns
+///
+/// The eigenvalues in ascending order, repeated according to their
+/// multiplicity. The result is empty for the empty graph.
+#[must_use]
+pub fn spectrum<G>(graph: &G, kind: GraphMatrix) -> Vec<f64>
+where
+    G: Graph,
+{
+    trace_span!("spectrum", vertices = graph.order(), ?kind);
+    symmetric_eigen(graph_matrix(graph, kind))
+        .into_iter()
+        .map(|(value, _)| value)
+        .collect()
+}
+
+/// Computes the spectral radius of a graph, the largest absolute eigenvalue of
+/// its adjacency matrix.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The spectral radius.
+/// * `None` - If the graph is empty.
+#[must_use]
+pub fn spectral_radius<G>(graph: &G) -> Option<f64>
+where
+    G: Graph,
+{
+    spectrum(graph, GraphMatrix::Adjacency)
+        .into_iter()
+        .map(f64::abs)
+        .max_by(f64::total_cmp)
+}
+
+/// Computes the energy of a graph, the sum of the absolute eigenvalues of its
+/// adjacency matrix.
+///
+/// # Returns
+///
+/// The energy, `0.0` for a graph without edges.
+#[must_use]
+pub fn energy<G>(graph: &G) -> f64
+where
+    G: Graph,
+{
+    spectrum(graph, GraphMatrix::Adjacency)
+        .into_iter()
+        .fold(0.0, |energy, value| energy + value.abs())
+}
+
+/// Computes the algebraic connectivity of a graph, the second smallest
+/// eigenvalue of its Laplacian.
+///
+/// It is positive exactly when the underlying undirected graph is connected.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The algebraic connectivity.
+/// * `None` - If the graph has fewer than two vertices.
+#[must_use]
+pub fn algebraic_connectivity<G>(graph: &G) -> Option<f64>
+where
+    G: Graph,
+{
+    spectrum(graph, GraphMatrix::Laplacian).get(1).copied()
+}
+
+/// Computes a Fiedler vector of a graph, an eigenvector of its Laplacian for
+/// the [algebraic connectivity](algebraic_connectivity).
+///
+/// Vertices far apart in the graph get distant values, which makes the vector
+/// the basis of [spectral bisection](spectral_bisection). The vector has unit
+/// length and an arbitrary sign; when the eigenvalue is repeated, any vector
+/// of its eigenspace may be returned.
+///
+/// # Returns
+///
+/// * `Some(HashMap<V, f64>)` - The component of the vector for every vertex.
+/// * `None` - If the graph has fewer than two vertices.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::spectral::fiedler_vector, graph};
+///
+/// // The components along a path are monotone, with the middle vertex at zero.
+/// let fiedler = fiedler_vector(&graph! { 1 - 2, 2 - 3 }).unwrap();
+/// assert!(fiedler[&2].abs() < 1e-9);
+/// assert!(fiedler[&1] * fiedler[&3] < 0.0);
+/// ```
+#[must_use]
+pub fn fiedler_vector<G>(graph: &G) -> Option<HashMap<G::Vertex, f64>>
+where
+    G: Graph,
+{
+    trace_span!("fiedler_vector", vertices = graph.order());
+    let (_, vector) = symmetric_eigen(graph_matrix(graph, GraphMatrix::Laplacian))
+        .into_iter()
+        .nth(1)?;
+    Some(graph.vertices().cloned().zip(vector).collect())
+}
+
+/// The two halves of a [spectral bisection](spectral_bisection).
+pub type Bisection<V> = (HashSet<V>, HashSet<V>);
+
+/// Splits the vertices of a graph into two halves with few edges between them,
+/// by spectral bisection.
+///
+/// The vertices are sorted by their component in the [Fiedler
+/// vector](fiedler_vector) and split at the median, so the halves differ in
+/// size by at most one. The cut is not minimal in general but usually small on
+/// meshes and other geometric graphs, which makes the bisection a classic way to
+/// distribute such graphs over the processors of parallel solvers; applying it
+/// recursively to each half gives `2^k` parts.
+///
+/// # Returns
+///
+/// * `Some(Bisection<V>)` - The two halves, the first one being the
+///   larger when the order is odd.
+/// * `None` - If the graph has fewer than two vertices.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::spectral::spectral_bisection, graph};
+///
+/// // Two squares joined by a single edge are separated along that edge.
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 1, 5 - 6, 6 - 7, 7 - 8, 8 - 5, 4 - 5 };
+/// let (first, second) = spectral_bisection(&graph).unwrap();
+/// assert_eq!(first.len(), 4);
+/// assert!(first.contains(&1) != second.contains(&1));
+/// assert_eq!(first.contains(&1), first.contains(&3));
+/// assert_ne!(first.contains(&1), first.contains(&7));
+/// ```
+#[must_use]
+pub fn spectral_bisection<G>(graph: &G) -> Option<Bisection<G::Vertex>>
+where
+    G: Graph,
+{
+    trace_span!("spectral_bisection", vertices = graph.order());
+    let (_, vector) = symmetric_eigen(graph_matrix(graph, GraphMatrix::Laplacian))
+        .into_iter()
+        .nth(1)?;
+    let mut order: Vec<(f64, &G::Vertex)> = vector.into_iter().zip(graph.vertices()).collect();
+    order.sort_by(|first, second| first.0.total_cmp(&second.0));
+    let second = order.split_off(order.len().div_ceil(2));
+    Some((
+        order.into_iter().map(|(_, v)| v.clone()).collect(),
+        second.into_iter().map(|(_, v)| v.clone()).collect(),
+    ))
+}
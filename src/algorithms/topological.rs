@@ -0,0 +1,125 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{error::AlgorithmError, Graph, GraphError};
+
+/// Computes a topological ordering of a directed acyclic graph using Kahn's
+/// algorithm.
+///
+/// # Arguments
+///
+/// * `graph` - The directed graph to sort.
+///
+/// # Returns
+///
+/// The vertices (cloned) ordered so that every edge `(u, v)` has `u` before `v`.
+///
+/// # Errors
+///
+/// Returns `GraphError::Algorithm` if the graph is undirected or contains a
+/// cycle.
+pub fn topological_sort<G>(graph: &G) -> Result<Vec<G::Vertex>, GraphError>
+where
+    G: Graph,
+{
+    if !graph.is_directed() {
+        return Err(AlgorithmError::RequiresDirected.into());
+    }
+    trace_span!("topological_sort");
+
+    let mut in_degree: HashMap<&G::Vertex, usize> = graph.vertices().map(|v| (v, 0)).collect();
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if let Some(degree) = in_degree.get_mut(v) {
+                *degree += 1;
+            }
+        }
+    }
+
+    let mut queue: VecDeque<&G::Vertex> = in_degree
+        .iter()
+        .filter(|&(_, &d)| d == 0)
+        .map(|(&v, _)| v)
+        .collect();
+    let mut order = Vec::with_capacity(in_degree.len());
+
+    while let Some(u) = queue.pop_front() {
+        order.push(u.clone());
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if let Some(degree) = in_degree.get_mut(v) {
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+
+    trace_event!(
+        sorted = order.len(),
+        vertices = in_degree.len(),
+        "queue drained"
+    );
+    if order.len() < in_degree.len() {
+        return Err(AlgorithmError::CycleFound.into());
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GraphMut, SimpleGraph};
+
+    #[test]
+    fn orders_every_edge_forward() {
+        let mut graph = SimpleGraph::from_edges(
+            [
+                (5, 11),
+                (7, 11),
+                (7, 8),
+                (3, 8),
+                (3, 10),
+                (11, 2),
+                (11, 9),
+                (11, 10),
+                (8, 9),
+            ],
+            true,
+        );
+        graph.add_vertex(1).unwrap();
+        let order = topological_sort(&graph).unwrap();
+        assert_eq!(order.len(), 9);
+        let position: HashMap<_, _> = order.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        for u in graph.vertices() {
+            for v in graph.neighbors(u).unwrap() {
+                assert!(position[u] < position[v], "{u} -> {v}");
+            }
+        }
+    }
+
+    #[test]
+    fn sorts_the_empty_graph() {
+        let graph = SimpleGraph::<u32>::new_directed();
+        assert!(topological_sort(&graph).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_cycles_and_undirected_graphs() {
+        let cyclic = SimpleGraph::from_edges([(0, 1), (1, 2), (2, 3), (3, 1)], true);
+        assert!(matches!(
+            topological_sort(&cyclic),
+            Err(GraphError::Algorithm(AlgorithmError::CycleFound))
+        ));
+        let looped = SimpleGraph::from_edges([(0, 0)], true);
+        assert!(matches!(
+            topological_sort(&looped),
+            Err(GraphError::Algorithm(AlgorithmError::CycleFound))
+        ));
+        let undirected = SimpleGraph::from_edges([(0, 1)], false);
+        assert!(matches!(
+            topological_sort(&undirected),
+            Err(GraphError::Algorithm(AlgorithmError::RequiresDirected))
+        ));
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{algorithms::shortest_path::PathTree, Graph};
+
+/// Computes the hop distance from `source` to every vertex reachable from it.
+///
+/// For directed graphs only outgoing edges are followed.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to traverse.
+/// * `source` - The vertex where the search starts.
+///
+/// # Returns
+///
+/// * `Some(HashMap<V, usize>)` - The distance of every reachable vertex, including
+///   `source` itself at distance `0`.
+/// * `None` - If `source` is not in the graph.
+pub fn bfs_distances<G>(graph: &G, source: &G::Vertex) -> Option<HashMap<G::Vertex, usize>>
+where
+    G: Graph,
+{
+    if !graph.contains_vertex(source) {
+        return None;
+    }
+    trace_span!("bfs_distances");
+
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    distances.insert(source.clone(), 0);
+    queue.push_back(source.clone());
+
+    while let Some(u) = queue.pop_front() {
+        let next = distances[&u] + 1;
+        if let Some(neighbors) = graph.neighbors(&u) {
+            for w in neighbors {
+                if !distances.contains_key(w) {
+                    distances.insert(w.clone(), next);
+                    queue.push_back(w.clone());
+                }
+            }
+        }
+    }
+
+    trace_event!(visited = distances.len(), "search complete");
+    Some(distances)
+}
+
+/// Computes the breadth-first tree from `source`, whose paths use the fewest
+/// edges.
+///
+/// This is [`bfs_distances`] recording the predecessor of every vertex. For
+/// directed graphs only outgoing edges are followed.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to traverse.
+/// * `source` - The root of the tree.
+///
+/// # Returns
+///
+/// * `Some(PathTree<V, usize>)` - The hop distance and a shortest path to
+///   every reachable vertex.
+/// * `None` - If `source` is not in the graph.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::traversal::bfs_tree, graph};
+///
+/// let tree = bfs_tree(&graph! { 1 - 2, 2 - 3, 3 - 4, 1 - 5, 5 - 4 }, &1).unwrap();
+/// assert_eq!(tree.distance_to(&4), Some(&2));
+/// assert_eq!(tree.path_to(&3), Some(vec![1, 2, 3]));
+/// ```
+pub fn bfs_tree<G>(graph: &G, source: &G::Vertex) -> Option<PathTree<G::Vertex, usize>>
+where
+    G: Graph,
+{
+    if !graph.contains_vertex(source) {
+        return None;
+    }
+    trace_span!("bfs_tree");
+
+    let mut tree = PathTree::new(source.clone(), 0);
+    let mut queue = VecDeque::from([(source, 0)]);
+    while let Some((u, distance)) = queue.pop_front() {
+        for w in graph.neighbors(u).into_iter().flatten() {
+            if !tree.contains(w) {
+                tree.insert(w.clone(), distance + 1, u.clone());
+                queue.push_back((w, distance + 1));
+            }
+        }
+    }
+
+    trace_event!(visited = tree.distances().len(), "search complete");
+    Some(tree)
+}
+
+/// Finds a path with the fewest edges from `source` to `target` by
+/// bidirectional breadth-first search.
+///
+/// One search grows from `source` along outgoing edges and another from
+/// `target` along incoming edges, always extending the smaller frontier, until
+/// they meet. On graphs of large diameter and bounded degree, such as road
+/// networks, this visits a small fraction of the vertices that a single search
+/// would. Directed graphs have their incoming edges collected first, in
+/// `O(n + m)` time.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search.
+/// * `source` - The vertex where the path starts.
+/// * `target` - The vertex where the path ends.
+///
+/// # Returns
+///
+/// * `Some(Vec<V>)` - The vertices of a shortest path, `source` and `target`
+///   included.
+/// * `None` - If `source` or `target` is not in the graph, or `target` is not
+///   reachable from `source`.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::traversal::bidirectional_bfs, graph};
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 5, 1 - 6, 6 - 5, 7 - 8 };
+/// assert_eq!(bidirectional_bfs(&graph, &1, &5), Some(vec![1, 6, 5]));
+/// assert_eq!(bidirectional_bfs(&graph, &1, &8), None);
+/// ```
+#[must_use]
+pub fn bidirectional_bfs<G>(
+    graph: &G,
+    source: &G::Vertex,
+    target: &G::Vertex,
+) -> Option<Vec<G::Vertex>>
+where
+    G: Graph,
+{
+    if !graph.contains_vertex(source) || !graph.contains_vertex(target) {
+        return None;
+    }
+    if source == target {
+        return Some(vec![source.clone()]);
+    }
+    trace_span!("bidirectional_bfs");
+
+    let reverse = graph.is_directed().then(|| reverse_adjacency(graph));
+    let mut forward: HashMap<&G::Vertex, Option<&G::Vertex>> = HashMap::from([(source, None)]);
+    let mut backward: HashMap<&G::Vertex, Option<&G::Vertex>> = HashMap::from([(target, None)]);
+    let (mut forward_frontier, mut backward_frontier) = (vec![source], vec![target]);
+
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        // Every vertex of the frontier is at the same depth, so the first
+        // meeting closes a shortest path.
+        let extend_forward = forward_frontier.len() <= backward_frontier.len();
+        let (frontier, parents, others) = if extend_forward {
+            (&mut forward_frontier, &mut forward, &backward)
+        } else {
+            (&mut backward_frontier, &mut backward, &forward)
+        };
+        let mut next = Vec::new();
+        for u in std::mem::take(frontier) {
+            let neighbors: Vec<&G::Vertex> = match (&reverse, extend_forward) {
+                (Some(reverse), false) => reverse.get(u).cloned().unwrap_or_default(),
+                _ => graph.neighbors(u).into_iter().flatten().collect(),
+            };
+            for w in neighbors {
+                if parents.contains_key(w) {
+                    continue;
+                }
+                parents.insert(w, Some(u));
+                if others.contains_key(w) {
+                    trace_event!(visited = forward.len() + backward.len(), "searches met");
+                    return Some(join_paths(&forward, &backward, w));
+                }
+                next.push(w);
+            }
+        }
+        *frontier = next;
+    }
+    None
+}
+
+/// Joins the path from the root of `forward` to `meeting` with the path from
+/// `meeting` to the root of `backward`.
+pub(crate) fn join_paths<V: Eq + std::hash::Hash + Clone>(
+    forward: &HashMap<&V, Option<&V>>,
+    backward: &HashMap<&V, Option<&V>>,
+    meeting: &V,
+) -> Vec<V> {
+    let mut path = vec![meeting.clone()];
+    let mut current = meeting;
+    while let Some(&Some(previous)) = forward.get(current) {
+        path.push(previous.clone());
+        current = previous;
+    }
+    path.reverse();
+    current = meeting;
+    while let Some(&Some(next)) = backward.get(current) {
+        path.push(next.clone());
+        current = next;
+    }
+    path
+}
+
+/// Collects the in-neighbors of every vertex of a graph with incoming edges.
+pub(crate) fn reverse_adjacency<G>(graph: &G) -> HashMap<&G::Vertex, Vec<&G::Vertex>>
+where
+    G: Graph,
+{
+    let mut reverse: HashMap<&G::Vertex, Vec<&G::Vertex>> = HashMap::new();
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            reverse.entry(v).or_default().push(u);
+        }
+    }
+    reverse
+}
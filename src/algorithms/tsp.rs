@@ -0,0 +1,460 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+    algorithms::shortest_path::checked_weight,
+    error::AlgorithmError,
+    traits::{
+        measure::{Measure, OverflowPolicy},
+        weighted::WeightedGraph,
+    },
+    GraphError,
+};
+
+/// A closed tour: the vertices in the order they are visited, each once, and
+/// the total length including the edge back to the first vertex.
+pub type Tour<V, W> = (Vec<V>, W);
+
+/// Builds a tour with the nearest neighbor heuristic: starting at `start`,
+/// repeatedly travel to the closest vertex not visited yet.
+///
+/// The tour takes `O(n^2)` time to build and is usually within 25% of the
+/// optimum on metric instances, a good starting point for [`two_opt`] or
+/// [`three_opt`].
+///
+/// # Arguments
+///
+/// * `graph` - A complete undirected graph, with distances as edge weights.
+/// * `start` - The first vertex of the tour.
+///
+/// # Returns
+///
+/// The tour, starting at `start`.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `start` is not in the graph.
+/// * `GraphError::Algorithm` - If the graph is directed or not complete,
+///   or an edge has a negative or invalid weight.
+/// * `GraphError::WeightOverflow` - If the length of the tour overflows.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::tsp::nearest_neighbor_tour, graph};
+///
+/// let cities = graph! { 1 - 2 [weight = 1], 1 - 3 [weight = 4], 1 - 4 [weight = 2],
+///                       2 - 3 [weight = 2], 2 - 4 [weight = 3], 3 - 4 [weight = 5] };
+/// let (tour, length) = nearest_neighbor_tour(&cities, &1).unwrap();
+/// assert_eq!(tour, [1, 2, 3, 4]);
+/// assert_eq!(length, 10);
+/// ```
+pub fn nearest_neighbor_tour<G>(
+    graph: &G,
+    start: &G::Vertex,
+) -> Result<Tour<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    let instance = Instance::new(graph)?;
+    let Some(&first) = instance.index.get(start) else {
+        return Err(GraphError::VertexNotFound);
+    };
+    trace_span!("nearest_neighbor_tour", vertices = instance.len());
+    let mut visited = vec![false; instance.len()];
+    visited[first] = true;
+    let mut order = vec![first];
+    let mut current = first;
+    for _ in 1..instance.len() {
+        let next = (0..instance.len())
+            .filter(|&v| !visited[v])
+            .min_by(|&u, &v| instance.distance[current][u].compare(&instance.distance[current][v]));
+        let Some(next) = next else { break };
+        visited[next] = true;
+        order.push(next);
+        current = next;
+    }
+    instance.tour(&order)
+}
+
+/// Builds a tour with the Christofides heuristic for metric instances.
+///
+/// The tour is the shortcut Euler circuit of a minimum spanning tree joined with
+/// a matching of its odd-degree vertices. Christofides' 3/2 approximation bound
+/// holds with a minimum-weight perfect matching; this implementation matches the
+/// odd vertices greedily and then improves the matching by exchanging partners
+/// until no exchange helps, which keeps the construction `O(n^2)` for the tree
+/// and fast in practice but gives no worst-case bound. On instances satisfying
+/// the triangle inequality the tours are typically within 10 to 15% of the
+/// optimum.
+///
+/// # Arguments
+///
+/// * `graph` - A complete undirected graph, with distances as edge weights.
+///
+/// # Returns
+///
+/// The tour, empty for the empty graph.
+///
+/// # Errors
+///
+/// * `GraphError::Algorithm` - If the graph is directed or not complete,
+///   or an edge has a negative or invalid weight.
+/// * `GraphError::WeightOverflow` - If the length of the tour overflows.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::tsp::christofides_tour, graph};
+///
+/// // The corners of a unit square, diagonals of length 1.5.
+/// let square = graph! { 1 - 2 [weight = 1.0], 2 - 3 [weight = 1.0], 3 - 4 [weight = 1.0],
+///                       4 - 1 [weight = 1.0], 1 - 3 [weight = 1.5], 2 - 4 [weight = 1.5] };
+/// let (tour, length) = christofides_tour(&square).unwrap();
+/// assert_eq!(tour.len(), 4);
+/// assert_eq!(length, 4.0);
+/// ```
+pub fn christofides_tour<G>(graph: &G) -> Result<Tour<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    let instance = Instance::new(graph)?;
+    let n = instance.len();
+    trace_span!("christofides_tour", vertices = n);
+    if n < 3 {
+        return instance.tour(&(0..n).collect::<Vec<_>>());
+    }
+    let distance = &instance.distance;
+
+    // Prim's algorithm on the dense distance matrix.
+    let mut multigraph: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut in_tree = vec![false; n];
+    let mut closest: Vec<(usize, usize)> = (0..n).map(|v| (0, v)).collect();
+    in_tree[0] = true;
+    for _ in 1..n {
+        let Some(v) = (0..n)
+            .filter(|&v| !in_tree[v])
+            .min_by(|&u, &v| distance[closest[u].0][u].compare(&distance[closest[v].0][v]))
+        else {
+            break;
+        };
+        let parent = closest[v].0;
+        in_tree[v] = true;
+        multigraph[parent].push(v);
+        multigraph[v].push(parent);
+        for u in (0..n).filter(|&u| !in_tree[u]) {
+            if distance[v][u].compare(&distance[closest[u].0][u]) == Ordering::Less {
+                closest[u].0 = v;
+            }
+        }
+    }
+
+    let odd: Vec<usize> = (0..n).filter(|&v| multigraph[v].len() % 2 == 1).collect();
+    for (u, v) in instance.matching(&odd)? {
+        multigraph[u].push(v);
+        multigraph[v].push(u);
+    }
+
+    // Hierholzer's algorithm, skipping the vertices already visited.
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut stack = vec![0];
+    while let Some(&u) = stack.last() {
+        if let Some(v) = multigraph[u].pop() {
+            if let Some(back) = multigraph[v].iter().position(|&w| w == u) {
+                multigraph[v].swap_remove(back);
+            }
+            stack.push(v);
+        } else {
+            stack.pop();
+            if !visited[u] {
+                visited[u] = true;
+                order.push(u);
+            }
+        }
+    }
+    order.reverse();
+    instance.tour(&order)
+}
+
+/// Improves a tour with the 2-opt local search.
+///
+/// A 2-opt move removes two edges of the tour and reconnects the two paths left
+/// the other way, reversing one of them. Improving moves are applied until none
+/// remains, each pass taking `O(n^2)` time. The result has no crossing edges
+/// on Euclidean instances.
+///
+/// # Arguments
+///
+/// * `graph` - A complete undirected graph, with distances as edge weights.
+/// * `tour` - The tour to improve, visiting every vertex of the graph once.
+///
+/// # Returns
+///
+/// The improved tour, starting at the same vertex.
+///
+/// # Errors
+///
+/// * `GraphError::Algorithm` - If the graph is directed or not complete,
+///   an edge has a negative or invalid weight, or `tour` does not visit every
+///   vertex exactly once.
+/// * `GraphError::WeightOverflow` - If the length of the tour overflows.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::tsp::two_opt, graph};
+///
+/// let square = graph! { 1 - 2 [weight = 1], 2 - 3 [weight = 1], 3 - 4 [weight = 1],
+///                       4 - 1 [weight = 1], 1 - 3 [weight = 3], 2 - 4 [weight = 3] };
+/// // The tour 1, 3, 2, 4 crosses itself along both diagonals.
+/// let (tour, length) = two_opt(&square, &[1, 3, 2, 4]).unwrap();
+/// assert_eq!(length, 4);
+/// assert_eq!(tour[0], 1);
+/// ```
+pub fn two_opt<G>(graph: &G, tour: &[G::Vertex]) -> Result<Tour<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    let instance = Instance::new(graph)?;
+    let mut order = instance.order_of(tour)?;
+    trace_span!("two_opt", vertices = order.len());
+    let n = order.len();
+    let mut improved = n >= 4;
+    while improved {
+        improved = false;
+        for i in 0..n - 2 {
+            for j in i + 2..n {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+                let (first, second) = ((order[i], order[i + 1]), (order[j], order[(j + 1) % n]));
+                let current = instance.length(&[first, second])?;
+                let candidate = instance.length(&[(first.0, second.0), (first.1, second.1)])?;
+                if candidate.compare(&current) == Ordering::Less {
+                    order[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+    instance.tour(&order)
+}
+
+/// Improves a tour with the 3-opt local search.
+///
+/// A 3-opt move removes three edges of the tour and reconnects the three paths
+/// left in the best of the ways that yield a tour. It finds improvements 2-opt
+/// cannot, such as moving a segment elsewhere in the tour, at a cost of
+/// `O(n^3)` time per pass, so it suits tours of up to a few hundred vertices;
+/// running [`two_opt`] first makes it converge faster.
+///
+/// # Arguments
+///
+/// * `graph` - A complete undirected graph, with distances as edge weights.
+/// * `tour` - The tour to improve, visiting every vertex of the graph once.
+///
+/// # Returns
+///
+/// The improved tour, starting at the same vertex.
+///
+/// # Errors
+///
+/// Same as [`two_opt`].
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::tsp::{three_opt, two_opt}, graph};
+///
+/// let square = graph! { 1 - 2 [weight = 1], 2 - 3 [weight = 1], 3 - 4 [weight = 1],
+///                       4 - 1 [weight = 1], 1 - 3 [weight = 3], 2 - 4 [weight = 3] };
+/// let (tour, length) = three_opt(&square, &[1, 3, 2, 4]).unwrap();
+/// assert_eq!(length, 4);
+/// assert_eq!(two_opt(&square, &tour).unwrap().1, 4);
+/// ```
+pub fn three_opt<G>(graph: &G, tour: &[G::Vertex]) -> Result<Tour<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Measure,
+{
+    let instance = Instance::new(graph)?;
+    let mut order = instance.order_of(tour)?;
+    trace_span!("three_opt", vertices = order.len());
+    let n = order.len();
+    let mut improved = n >= 4;
+    while improved {
+        improved = false;
+        for i in 1..n {
+            for j in i + 1..n {
+                for k in j + 1..=n {
+                    let first = (order[i - 1], order[i]);
+                    let second = (order[j - 1], order[j]);
+                    let third = (order[k - 1], order[k % n]);
+                    let mut best = instance.length(&[first, second, third])?;
+                    let mut best_move = None;
+                    let moves = [
+                        [(first.0, second.0), (first.1, second.1), third],
+                        [first, (second.0, third.0), (second.1, third.1)],
+                        [(first.0, second.1), (third.0, first.1), (second.0, third.1)],
+                        [(first.0, third.0), (second.1, second.0), (first.1, third.1)],
+                    ];
+                    // Reversing a single vertex, or every vertex but the first,
+                    // leaves the tour unchanged: skipping these moves keeps
+                    // rounding errors from cycling between equal tours.
+                    let unchanged = [j == i + 1, k == j + 1, false, i == 1 && k == n];
+                    for (reconnection, edges) in moves.iter().enumerate() {
+                        if unchanged[reconnection] {
+                            continue;
+                        }
+                        let length = instance.length(edges)?;
+                        if length.compare(&best) == Ordering::Less {
+                            best = length;
+                            best_move = Some(reconnection);
+                        }
+                    }
+                    match best_move {
+                        Some(0) => order[i..j].reverse(),
+                        Some(1) => order[j..k].reverse(),
+                        Some(2) => order[i..k].rotate_left(j - i),
+                        Some(_) => order[i..k].reverse(),
+                        None => continue,
+                    }
+                    improved = true;
+                }
+            }
+        }
+    }
+    instance.tour(&order)
+}
+
+/// The distance matrix of a complete graph, with its vertices numbered.
+struct Instance<'a, V, W> {
+    vertices: Vec<&'a V>,
+    index: HashMap<&'a V, usize>,
+    distance: Vec<Vec<W>>,
+}
+
+impl<'a, V, W> Instance<'a, V, W>
+where
+    V: Eq + std::hash::Hash + Clone + std::fmt::Debug,
+    W: Measure,
+{
+    fn new<G>(graph: &'a G) -> Result<Self, GraphError>
+    where
+        G: WeightedGraph<Vertex = V, Weight = W>,
+    {
+        if graph.is_directed() {
+            return Err(AlgorithmError::RequiresUndirected.into());
+        }
+        let vertices: Vec<&V> = graph.vertices().collect();
+        let index: HashMap<&V, usize> = vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        let mut distance = vec![vec![W::zero(); vertices.len()]; vertices.len()];
+        for (i, &u) in vertices.iter().enumerate() {
+            for (j, &v) in vertices.iter().enumerate().skip(i + 1) {
+                if !graph.contains_edge(u, v) {
+                    return Err(AlgorithmError::missing_edge(u, v).into());
+                }
+                let weight = checked_weight(graph, u, v)?;
+                distance[i][j] = weight.clone();
+                distance[j][i] = weight.clone();
+            }
+        }
+        Ok(Self {
+            vertices,
+            index,
+            distance,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Numbers the vertices of a tour, checking that it visits every vertex
+    /// exactly once.
+    fn order_of(&self, tour: &[V]) -> Result<Vec<usize>, GraphError> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::with_capacity(tour.len());
+        for v in tour {
+            match self.index.get(v) {
+                Some(&i) if !seen[i] => {
+                    seen[i] = true;
+                    order.push(i);
+                }
+                _ => break,
+            }
+        }
+        if order.len() != tour.len() || order.len() != self.len() {
+            return Err(AlgorithmError::InvalidArgument(
+                "A tour must visit every vertex of the graph exactly once".to_string(),
+            )
+            .into());
+        }
+        Ok(order)
+    }
+
+    /// Sums the lengths of the given edges.
+    fn length(&self, edges: &[(usize, usize)]) -> Result<W, GraphError> {
+        edges.iter().try_fold(W::zero(), |sum, &(u, v)| {
+            sum.add_with(&self.distance[u][v], OverflowPolicy::Checked)
+        })
+    }
+
+    /// Matches the given vertices greedily, shortest pairs first, then
+    /// exchanges partners between pairs while it shortens the matching.
+    fn matching(&self, vertices: &[usize]) -> Result<Vec<(usize, usize)>, GraphError> {
+        let mut pairs: Vec<(usize, usize)> = vertices
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &u)| vertices[i + 1..].iter().map(move |&v| (u, v)))
+            .collect();
+        pairs.sort_by(|&(a, b), &(c, d)| self.distance[a][b].compare(&self.distance[c][d]));
+        let mut matched = vec![false; self.len()];
+        let mut matching = Vec::with_capacity(vertices.len() / 2);
+        for (u, v) in pairs {
+            if !matched[u] && !matched[v] {
+                matched[u] = true;
+                matched[v] = true;
+                matching.push((u, v));
+            }
+        }
+
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..matching.len() {
+                for j in i + 1..matching.len() {
+                    let (pair, other) = (matching[i], matching[j]);
+                    let current = self.length(&[pair, other])?;
+                    let swaps = [
+                        [(pair.0, other.0), (pair.1, other.1)],
+                        [(pair.0, other.1), (pair.1, other.0)],
+                    ];
+                    for [first, second] in swaps {
+                        if self.length(&[first, second])?.compare(&current) == Ordering::Less {
+                            matching[i] = first;
+                            matching[j] = second;
+                            improved = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Returns the tour visiting the vertices in `order`, with its length.
+    fn tour(&self, order: &[usize]) -> Result<Tour<V, W>, GraphError> {
+        let mut length = W::zero();
+        for (i, &u) in order.iter().enumerate() {
+            let v = order[(i + 1) % order.len()];
+            length = length.add_with(&self.distance[u][v], OverflowPolicy::Checked)?;
+        }
+        let vertices = order.iter().map(|&i| self.vertices[i].clone()).collect();
+        Ok((vertices, length))
+    }
+}
@@ -1,12 +1,19 @@
 use std::error::Error;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
+use std::io;
+use std::sync::Arc;
 
 /// Enum representing errors related to graph operations.
 ///
 /// This enum includes common errors that might occur during graph manipulation,
-/// such as invalid operations, missing vertices or edges, and duplicates.
-///
+/// such as invalid operations, missing vertices or edges, and duplicates. The
+/// mutation methods of the graph traits return the narrower [`MutationError`],
+/// which converts into this type, the algorithms report inputs they cannot
+/// handle as [`GraphError::Algorithm`], and the readers of the `utils` formats
+/// report failed reads as [`GraphError::Io`] and malformed input as
+/// [`GraphError::Parse`].
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum GraphError {
     /// Vertex not found in the graph.
     VertexNotFound,
@@ -16,28 +23,453 @@ pub enum GraphError {
     EdgeAlreadyExists,
     /// Edge not found in the graph.
     EdgeNotFound,
+    /// The graph holds at most the given number of vertices.
+    CapacityExceeded(usize),
+    /// The graph does not allow the edge, such as an edge joining two vertices
+    /// on the same side of a bipartite graph.
+    EdgeNotAllowed,
+    /// The graph does not allow the vertex, such as a [`StableGraph`] handle
+    /// that the graph did not issue next.
+    ///
+    /// [`StableGraph`]: crate::graphs::stable::StableGraph
+    VertexNotAllowed,
+    /// Accumulating edge weights exceeded the range of the weight type.
+    WeightOverflow,
     /// An invalid operation was requested for the graph.
     ///
     /// A detailed reason can be provided as a `String`.
     InvalidOperation(String),
+    /// Reading or writing a file or stream failed.
+    Io(io::Error),
+    /// The input does not follow the expected format.
+    Parse(ParseError),
+    /// An algorithm cannot run on the given graph or arguments.
+    Algorithm(AlgorithmError),
+    /// A value could not be written in an output format, such as a vertex
+    /// that does not serialize to JSON.
+    Encode(Box<dyn Error + Send + Sync>),
 }
 
-impl std::fmt::Display for GraphError {
+impl fmt::Display for GraphError {
     /// Formats the error for user-friendly display.
     ///
     /// This implementation converts each variant of `GraphError` into a
     /// readable string message. It is primarily used to print or log errors.
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             GraphError::VertexNotFound => write!(f, "Vertex not found."),
             GraphError::VertexAlreadyExists => write!(f, "Vertex already exists."),
             GraphError::EdgeAlreadyExists => write!(f, "Edge already exists."),
             GraphError::EdgeNotFound => write!(f, "Edge not found."),
+            GraphError::CapacityExceeded(capacity) => {
+                write!(f, "The graph holds at most {capacity} vertices.")
+            }
+            GraphError::EdgeNotAllowed => write!(f, "Edge not allowed."),
+            GraphError::VertexNotAllowed => write!(f, "Vertex not allowed."),
+            GraphError::WeightOverflow => write!(f, "Weight overflow."),
             GraphError::InvalidOperation(msg) => {
                 write!(f, "Invalid operation: {msg}")
             }
+            GraphError::Io(e) => write!(f, "I/O error: {e}"),
+            GraphError::Parse(e) => write!(f, "{e}"),
+            GraphError::Algorithm(e) => write!(f, "{e}"),
+            GraphError::Encode(e) => write!(f, "Encoding error: {e}"),
+        }
+    }
+}
+
+impl Error for GraphError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GraphError::Io(e) => Some(e),
+            GraphError::Parse(e) => Some(e),
+            GraphError::Algorithm(e) => Some(e),
+            GraphError::Encode(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GraphError {
+    fn from(e: io::Error) -> Self {
+        GraphError::Io(e)
+    }
+}
+
+impl From<ParseError> for GraphError {
+    fn from(e: ParseError) -> Self {
+        GraphError::Parse(e)
+    }
+}
+
+impl From<AlgorithmError> for GraphError {
+    fn from(e: AlgorithmError) -> Self {
+        GraphError::Algorithm(e)
+    }
+}
+
+impl From<MutationError> for GraphError {
+    fn from(e: MutationError) -> Self {
+        match e {
+            MutationError::VertexNotFound => GraphError::VertexNotFound,
+            MutationError::VertexAlreadyExists => GraphError::VertexAlreadyExists,
+            MutationError::EdgeAlreadyExists => GraphError::EdgeAlreadyExists,
+            MutationError::EdgeNotFound => GraphError::EdgeNotFound,
+            MutationError::CapacityExceeded(capacity) => GraphError::CapacityExceeded(capacity),
+            MutationError::EdgeNotAllowed => GraphError::EdgeNotAllowed,
+            MutationError::VertexNotAllowed => GraphError::VertexNotAllowed,
+        }
+    }
+}
+
+/// Errors of the methods adding and removing vertices and edges.
+///
+/// ```
+/// use kambo_graph::{error::MutationError, graph, GraphMut};
+///
+/// let mut graph = graph! { 1 - 2 };
+/// assert_eq!(graph.add_edge(&1, &2), Err(MutationError::EdgeAlreadyExists));
+/// assert_eq!(graph.remove_vertex(&3), Err(MutationError::VertexNotFound));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MutationError {
+    /// Vertex not found in the graph.
+    VertexNotFound,
+    /// Vertex already exists in the graph.
+    VertexAlreadyExists,
+    /// Edge already exists in the graph.
+    EdgeAlreadyExists,
+    /// Edge not found in the graph.
+    EdgeNotFound,
+    /// The graph holds at most the given number of vertices.
+    CapacityExceeded(usize),
+    /// The graph does not allow the edge, such as an edge joining two vertices
+    /// on the same side of a bipartite graph.
+    EdgeNotAllowed,
+    /// The graph does not allow the vertex, such as a [`StableGraph`] handle
+    /// that the graph did not issue next.
+    ///
+    /// [`StableGraph`]: crate::graphs::stable::StableGraph
+    VertexNotAllowed,
+}
+
+impl fmt::Display for MutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MutationError::VertexNotFound => write!(f, "Vertex not found."),
+            MutationError::VertexAlreadyExists => write!(f, "Vertex already exists."),
+            MutationError::EdgeAlreadyExists => write!(f, "Edge already exists."),
+            MutationError::EdgeNotFound => write!(f, "Edge not found."),
+            MutationError::CapacityExceeded(capacity) => {
+                write!(f, "The graph holds at most {capacity} vertices.")
+            }
+            MutationError::EdgeNotAllowed => write!(f, "Edge not allowed."),
+            MutationError::VertexNotAllowed => write!(f, "Vertex not allowed."),
+        }
+    }
+}
+
+impl Error for MutationError {}
+
+/// An input that does not follow the format being read.
+///
+/// ```
+/// use kambo_graph::{utils::edge_list::parse_edge_list_from_reader, GraphError};
+///
+/// let error = parse_edge_list_from_reader::<u32, f64, _>("1 2\n2 x\n".as_bytes()).unwrap_err();
+/// let GraphError::Parse(error) = error else { panic!() };
+/// assert_eq!(error.line, Some(2));
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ParseError {
+    /// The line where the input went wrong, counted from 1, or `None` if the
+    /// error concerns the input as a whole (such as a missing header).
+    pub line: Option<usize>,
+    /// What is wrong with the input.
+    pub message: String,
+    /// The error of the underlying parser, such as a `serde_json::Error`.
+    source: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl ParseError {
+    /// Creates an error about line `line` of the input.
+    pub(crate) fn at(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Creates an error about the input as a whole.
+    pub(crate) fn input(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Attaches the error of the underlying parser, returned by
+    /// [`Error::source`].
+    #[cfg(any(feature = "json", feature = "neo4j"))]
+    pub(crate) fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+}
+
+/// Two errors are equal when they concern the same line with the same message,
+/// whatever their sources.
+impl PartialEq for ParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line && self.message == other.message
+    }
+}
+
+impl Eq for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "Parse error at line {line}: {}", self.message),
+            None => write!(f, "Parse error: {}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|e| e as &(dyn Error + 'static))
+    }
+}
+
+/// Errors of algorithms given a graph or arguments they cannot handle.
+///
+/// Vertices and weights are stored formatted with `Debug`, since the error is
+/// not generic over the vertex and weight types.
+///
+/// ```
+/// use kambo_graph::{algorithms::topological::topological_sort, error::AlgorithmError, graph, GraphError};
+///
+/// let error = topological_sort(&graph! { 1 -> 2, 2 -> 1 }).unwrap_err();
+/// assert!(matches!(error, GraphError::Algorithm(AlgorithmError::CycleFound)));
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AlgorithmError {
+    /// The algorithm requires a directed graph.
+    RequiresDirected,
+    /// The algorithm requires an undirected graph.
+    RequiresUndirected,
+    /// The graph contains a cycle and the algorithm requires an acyclic graph.
+    CycleFound,
+    /// The graph contains a self-loop, which the algorithm does not allow.
+    SelfLoop,
+    /// The algorithm requires a bipartite graph.
+    NotBipartite,
+    /// The graph lacks an edge the algorithm requires, such as an edge of a
+    /// complete graph.
+    MissingEdge {
+        /// The endpoints of the missing edge.
+        edge: (String, String),
+    },
+    /// An edge has no weight.
+    MissingWeight {
+        /// The endpoints of the edge.
+        edge: (String, String),
+    },
+    /// An edge has a weight the algorithm cannot use, such as a negative or
+    /// `NaN` weight.
+    InvalidWeight {
+        /// The endpoints of the edge.
+        edge: (String, String),
+        /// The weight of the edge.
+        weight: String,
+    },
+    /// An edge has no capacity.
+    MissingCapacity {
+        /// The endpoints of the edge.
+        edge: (String, String),
+    },
+    /// An edge has a negative or invalid capacity.
+    InvalidCapacity {
+        /// The endpoints of the edge.
+        edge: (String, String),
+        /// The capacity of the edge.
+        capacity: String,
+    },
+    /// A vertex has no weight.
+    MissingVertexWeight {
+        /// The vertex.
+        vertex: String,
+    },
+    /// A vertex has a weight the algorithm cannot use, such as a negative or
+    /// `NaN` weight.
+    InvalidVertexWeight {
+        /// The vertex.
+        vertex: String,
+        /// The weight of the vertex.
+        weight: String,
+    },
+    /// The input is larger than an exact algorithm supports.
+    TooLarge {
+        /// The largest supported number of vertices.
+        limit: usize,
+        /// The number of vertices the input needs.
+        actual: usize,
+    },
+    /// The result does not fit in its numeric type.
+    ResultOverflow,
+    /// No invariant is registered under the name.
+    UnknownInvariant(String),
+    /// An argument is outside its valid range; the message explains which.
+    InvalidArgument(String),
+    /// An edge weight could not be converted to another weight type.
+    WeightConversion {
+        /// The endpoints of the edge.
+        edge: (String, String),
+        /// The weight of the edge.
+        weight: String,
+        /// The error of the conversion.
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl AlgorithmError {
+    /// Formats the endpoints of an edge.
+    fn edge(u: impl Debug, v: impl Debug) -> (String, String) {
+        (format!("{u:?}"), format!("{v:?}"))
+    }
+
+    /// Creates a [`AlgorithmError::MissingEdge`] error.
+    pub(crate) fn missing_edge(u: impl Debug, v: impl Debug) -> Self {
+        Self::MissingEdge {
+            edge: Self::edge(u, v),
+        }
+    }
+
+    /// Creates a [`AlgorithmError::MissingWeight`] error.
+    pub(crate) fn missing_weight(u: impl Debug, v: impl Debug) -> Self {
+        Self::MissingWeight {
+            edge: Self::edge(u, v),
+        }
+    }
+
+    /// Creates a [`AlgorithmError::InvalidWeight`] error.
+    pub(crate) fn invalid_weight(u: impl Debug, v: impl Debug, weight: impl Debug) -> Self {
+        Self::InvalidWeight {
+            edge: Self::edge(u, v),
+            weight: format!("{weight:?}"),
+        }
+    }
+
+    /// Creates a [`AlgorithmError::MissingCapacity`] error.
+    pub(crate) fn missing_capacity(u: impl Debug, v: impl Debug) -> Self {
+        Self::MissingCapacity {
+            edge: Self::edge(u, v),
+        }
+    }
+
+    /// Creates a [`AlgorithmError::InvalidCapacity`] error.
+    pub(crate) fn invalid_capacity(u: impl Debug, v: impl Debug, capacity: impl Debug) -> Self {
+        Self::InvalidCapacity {
+            edge: Self::edge(u, v),
+            capacity: format!("{capacity:?}"),
+        }
+    }
+
+    /// Creates a [`AlgorithmError::WeightConversion`] error.
+    pub(crate) fn weight_conversion(
+        u: impl Debug,
+        v: impl Debug,
+        weight: impl Debug,
+        source: impl Into<Box<dyn Error + Send + Sync>>,
+    ) -> Self {
+        Self::WeightConversion {
+            edge: Self::edge(u, v),
+            weight: format!("{weight:?}"),
+            source: source.into(),
+        }
+    }
+
+    /// Creates a [`AlgorithmError::MissingVertexWeight`] error.
+    pub(crate) fn missing_vertex_weight(vertex: impl Debug) -> Self {
+        Self::MissingVertexWeight {
+            vertex: format!("{vertex:?}"),
+        }
+    }
+
+    /// Creates a [`AlgorithmError::InvalidVertexWeight`] error.
+    pub(crate) fn invalid_vertex_weight(vertex: impl Debug, weight: impl Debug) -> Self {
+        Self::InvalidVertexWeight {
+            vertex: format!("{vertex:?}"),
+            weight: format!("{weight:?}"),
+        }
+    }
+}
+
+impl fmt::Display for AlgorithmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlgorithmError::RequiresDirected => {
+                write!(f, "The algorithm requires a directed graph.")
+            }
+            AlgorithmError::RequiresUndirected => {
+                write!(f, "The algorithm requires an undirected graph.")
+            }
+            AlgorithmError::CycleFound => write!(f, "Graph contains a cycle."),
+            AlgorithmError::SelfLoop => write!(f, "Graph contains a self-loop."),
+            AlgorithmError::NotBipartite => write!(f, "Graph is not bipartite."),
+            AlgorithmError::MissingEdge { edge: (u, v) } => {
+                write!(f, "Edge ({u}, {v}) is missing.")
+            }
+            AlgorithmError::MissingWeight { edge: (u, v) } => {
+                write!(f, "Edge ({u}, {v}) has no weight.")
+            }
+            AlgorithmError::InvalidWeight {
+                edge: (u, v),
+                weight,
+            } => write!(f, "Edge ({u}, {v}) has an invalid weight: {weight}"),
+            AlgorithmError::MissingCapacity { edge: (u, v) } => {
+                write!(f, "Edge ({u}, {v}) has no capacity.")
+            }
+            AlgorithmError::InvalidCapacity {
+                edge: (u, v),
+                capacity,
+            } => write!(f, "Edge ({u}, {v}) has an invalid capacity: {capacity}"),
+            AlgorithmError::MissingVertexWeight { vertex } => {
+                write!(f, "Vertex {vertex} has no weight.")
+            }
+            AlgorithmError::InvalidVertexWeight { vertex, weight } => {
+                write!(f, "Vertex {vertex} has an invalid weight: {weight}")
+            }
+            AlgorithmError::TooLarge { limit, actual } => write!(
+                f,
+                "The algorithm supports at most {limit} vertices, but the input needs {actual}."
+            ),
+            AlgorithmError::ResultOverflow => write!(f, "The result overflows its type."),
+            AlgorithmError::UnknownInvariant(name) => write!(f, "Unknown invariant: {name}"),
+            AlgorithmError::InvalidArgument(msg) => write!(f, "Invalid argument: {msg}"),
+            AlgorithmError::WeightConversion {
+                edge: (u, v),
+                weight,
+                source,
+            } => write!(
+                f,
+                "Edge ({u}, {v}) has weight {weight} that cannot be converted: {source}"
+            ),
         }
     }
 }
 
-impl Error for GraphError {}
+impl Error for AlgorithmError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AlgorithmError::WeightConversion { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use std::{fmt::Debug, hash::Hash};
+
+use crate::{
+    error::MutationError,
+    traits::{
+        graph::EdgeList,
+        weighted::{WeightedGraph, WeightedGraphMut},
+    },
+    Graph, GraphMut, SimpleGraph,
+};
+
+/// A vertex of a [`BipartiteGraph`], on its left or right side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Side<L, R> {
+    /// A vertex of the left side.
+    Left(L),
+    /// A vertex of the right side.
+    Right(R),
+}
+
+impl<L, R> Side<L, R> {
+    /// Returns `true` for a vertex of the left side.
+    #[must_use]
+    pub fn is_left(&self) -> bool {
+        matches!(self, Side::Left(_))
+    }
+
+    /// Returns `true` for a vertex of the right side.
+    #[must_use]
+    pub fn is_right(&self) -> bool {
+        matches!(self, Side::Right(_))
+    }
+
+    /// Returns the vertex if it is on the left side.
+    #[must_use]
+    pub fn left(&self) -> Option<&L> {
+        match self {
+            Side::Left(l) => Some(l),
+            Side::Right(_) => None,
+        }
+    }
+
+    /// Returns the vertex if it is on the right side.
+    #[must_use]
+    pub fn right(&self) -> Option<&R> {
+        match self {
+            Side::Left(_) => None,
+            Side::Right(r) => Some(r),
+        }
+    }
+}
+
+/// An undirected graph whose vertices are split into a left side of type `L` and
+/// a right side of type `R`, with edges only between the two sides.
+///
+/// The typed methods ([`BipartiteGraph::add_left`], [`BipartiteGraph::add_link`],
+/// [`BipartiteGraph::right_neighbors`], ...) take the vertices of each side
+/// directly, so an edge inside one side cannot even be written. The graph also
+/// implements [`Graph`] and [`GraphMut`] over [`Side`] vertices, so every
+/// algorithm of the crate runs on it; there, an edge joining two vertices of the
+/// same side is rejected with `MutationError::EdgeNotAllowed`.
+///
+/// [`BipartiteGraph::left_projection`] and [`BipartiteGraph::right_projection`]
+/// turn the graph into a graph over one side, linking two vertices that share
+/// neighbors on the other side.
+///
+/// ```
+/// use kambo_graph::{graphs::bipartite::BipartiteGraph, traits::weighted::WeightedGraph};
+///
+/// // Users on the left, the items they rated on the right.
+/// let mut ratings = BipartiteGraph::new();
+/// for (user, item, stars) in [("ana", 1, 5), ("ana", 2, 3), ("bia", 1, 4), ("bia", 2, 4), ("caio", 3, 1)] {
+///     ratings.add_weighted_link_with_vertices(user, item, stars);
+/// }
+/// assert_eq!(ratings.left_count(), 3);
+/// assert_eq!(ratings.link_weight(&"ana", &2), Some(&3));
+///
+/// // Ana and Bia rated two items in common.
+/// let similar = ratings.left_projection();
+/// assert_eq!(similar.edge_weight(&"ana", &"bia"), Some(&2));
+/// assert_eq!(similar.degree(&"caio"), Some(0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BipartiteGraph<L, R, W = ()>
+where
+    L: Eq + Hash + Clone + Debug,
+    R: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    graph: SimpleGraph<Side<L, R>, W>,
+}
+
+impl<L, R, W> Default for BipartiteGraph<L, R, W>
+where
+    L: Eq + Hash + Clone + Debug,
+    R: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    fn default() -> Self {
+        Self {
+            graph: SimpleGraph::new_weighted_undirected(),
+        }
+    }
+}
+
+impl<L, R, W> BipartiteGraph<L, R, W>
+where
+    L: Eq + Hash + Clone + Debug,
+    R: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    /// Creates an empty bipartite graph.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the underlying graph over [`Side`] vertices.
+    #[must_use]
+    pub fn graph(&self) -> &SimpleGraph<Side<L, R>, W> {
+        &self.graph
+    }
+
+    /// Adds a vertex to the left side.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexAlreadyExists` if the vertex is already on
+    /// the left side.
+    pub fn add_left(&mut self, l: L) -> Result<(), MutationError> {
+        self.graph.add_vertex(Side::Left(l))
+    }
+
+    /// Adds a vertex to the right side.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexAlreadyExists` if the vertex is already on
+    /// the right side.
+    pub fn add_right(&mut self, r: R) -> Result<(), MutationError> {
+        self.graph.add_vertex(Side::Right(r))
+    }
+
+    /// Returns `true` if `l` is on the left side.
+    #[must_use]
+    pub fn contains_left(&self, l: &L) -> bool {
+        self.graph.contains_vertex(&Side::Left(l.clone()))
+    }
+
+    /// Returns `true` if `r` is on the right side.
+    #[must_use]
+    pub fn contains_right(&self, r: &R) -> bool {
+        self.graph.contains_vertex(&Side::Right(r.clone()))
+    }
+
+    /// Returns an iterator over the vertices of the left side.
+    pub fn left_vertices(&self) -> impl Iterator<Item = &L> {
+        self.graph.vertices().filter_map(Side::left)
+    }
+
+    /// Returns an iterator over the vertices of the right side.
+    pub fn right_vertices(&self) -> impl Iterator<Item = &R> {
+        self.graph.vertices().filter_map(Side::right)
+    }
+
+    /// Returns the number of vertices on the left side.
+    #[must_use]
+    pub fn left_count(&self) -> usize {
+        self.left_vertices().count()
+    }
+
+    /// Returns the number of vertices on the right side.
+    #[must_use]
+    pub fn right_count(&self) -> usize {
+        self.right_vertices().count()
+    }
+
+    /// Links `l` on the left side to `r` on the right side, without a weight.
+    ///
+    /// # Errors
+    ///
+    /// * `MutationError::VertexNotFound` - If `l` or `r` is not in the graph.
+    /// * `MutationError::EdgeAlreadyExists` - If they are already linked.
+    pub fn add_link(&mut self, l: &L, r: &R) -> Result<(), MutationError> {
+        let (l, r) = Self::pair(l, r);
+        self.graph.add_edge(&l, &r)
+    }
+
+    /// Links `l` on the left side to `r` on the right side with a weight.
+    ///
+    /// # Errors
+    ///
+    /// * `MutationError::VertexNotFound` - If `l` or `r` is not in the graph.
+    /// * `MutationError::EdgeAlreadyExists` - If they are already linked.
+    pub fn add_weighted_link(&mut self, l: &L, r: &R, weight: W) -> Result<(), MutationError> {
+        let (l, r) = Self::pair(l, r);
+        self.graph.add_weighted_edge(&l, &r, weight)
+    }
+
+    /// Links `l` to `r` with a weight, adding them to their sides if needed. An
+    /// existing link has its weight replaced.
+    pub fn add_weighted_link_with_vertices(&mut self, l: L, r: R, weight: W) {
+        self.graph
+            .insert_weighted_edge_with_vertices(Side::Left(l), Side::Right(r), weight);
+    }
+
+    /// Removes the link between `l` and `r` and returns its weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeNotFound` if they are not linked.
+    pub fn remove_link(&mut self, l: &L, r: &R) -> Result<Option<W>, MutationError> {
+        let (l, r) = Self::pair(l, r);
+        self.graph.remove_weighted_edge(&l, &r)
+    }
+
+    /// Returns `true` if `l` and `r` are linked.
+    #[must_use]
+    pub fn has_link(&self, l: &L, r: &R) -> bool {
+        let (l, r) = Self::pair(l, r);
+        self.graph.contains_edge(&l, &r)
+    }
+
+    /// Returns the weight of the link between `l` and `r`, or `None` if they are
+    /// not linked or the link has no weight.
+    #[must_use]
+    pub fn link_weight(&self, l: &L, r: &R) -> Option<&W> {
+        let (l, r) = Self::pair(l, r);
+        self.graph.edge_weight(&l, &r)
+    }
+
+    /// Returns the vertices of the right side linked to `l`, or `None` if `l`
+    /// is not on the left side.
+    #[must_use]
+    pub fn right_neighbors(&self, l: &L) -> Option<impl Iterator<Item = &R>> {
+        let neighbors = self.graph.neighbors(&Side::Left(l.clone()))?;
+        Some(neighbors.filter_map(Side::right))
+    }
+
+    /// Returns the vertices of the left side linked to `r`, or `None` if `r` is
+    /// not on the right side.
+    #[must_use]
+    pub fn left_neighbors(&self, r: &R) -> Option<impl Iterator<Item = &L>> {
+        let neighbors = self.graph.neighbors(&Side::Right(r.clone()))?;
+        Some(neighbors.filter_map(Side::left))
+    }
+
+    /// Projects the graph onto its left side: two left vertices are adjacent when
+    /// they share a neighbor, and the weight of their edge is the number of
+    /// neighbors they share.
+    ///
+    /// Every left vertex is kept, including those without shared neighbors. The
+    /// projection takes `O(sum of d(r)^2)` time over the right vertices `r`.
+    #[must_use]
+    pub fn left_projection(&self) -> SimpleGraph<L, usize> {
+        self.projection(Side::left)
+    }
+
+    /// Projects the graph onto its right side: two right vertices are adjacent
+    /// when they share a neighbor, and the weight of their edge is the number of
+    /// neighbors they share.
+    ///
+    /// Every right vertex is kept, including those without shared neighbors. The
+    /// projection takes `O(sum of d(l)^2)` time over the left vertices `l`.
+    #[must_use]
+    pub fn right_projection(&self) -> SimpleGraph<R, usize> {
+        self.projection(Side::right)
+    }
+
+    /// Builds the projection onto the side selected by `side`, counting the
+    /// common neighbors through each vertex of the other side.
+    fn projection<'a, X>(
+        &'a self,
+        side: fn(&'a Side<L, R>) -> Option<&'a X>,
+    ) -> SimpleGraph<X, usize>
+    where
+        X: Eq + Hash + Clone + Debug + 'a,
+    {
+        let mut projection: SimpleGraph<X, usize> = SimpleGraph::new_weighted_undirected();
+        for x in self.graph.vertices().filter_map(side) {
+            let _ = projection.add_vertex(x.clone());
+        }
+        for hub in self.graph.vertices().filter(|v| side(v).is_none()) {
+            let members: Vec<&X> = self
+                .graph
+                .neighbors(hub)
+                .into_iter()
+                .flatten()
+                .filter_map(side)
+                .collect();
+            for (i, &a) in members.iter().enumerate() {
+                for &b in &members[i + 1..] {
+                    let count = projection.edge_weight(a, b).map_or(1, |c| c + 1);
+                    projection.insert_weighted_edge_with_vertices(a.clone(), b.clone(), count);
+                }
+            }
+        }
+        projection
+    }
+
+    fn pair(l: &L, r: &R) -> (Side<L, R>, Side<L, R>) {
+        (Side::Left(l.clone()), Side::Right(r.clone()))
+    }
+
+    /// Rejects an edge joining two vertices of the same side.
+    fn check_sides(u: &Side<L, R>, v: &Side<L, R>) -> Result<(), MutationError> {
+        if u.is_left() == v.is_left() {
+            return Err(MutationError::EdgeNotAllowed);
+        }
+        Ok(())
+    }
+}
+
+impl<L, R, W> Graph for BipartiteGraph<L, R, W>
+where
+    L: Eq + Hash + Clone + Debug,
+    R: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    type Vertex = Side<L, R>;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.graph.vertices()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, L, R, W>> {
+        self.graph.neighbors(v)
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.graph.contains_vertex(v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.graph.contains_edge(u, v)
+    }
+
+    fn is_directed(&self) -> bool {
+        false
+    }
+
+    fn order(&self) -> usize {
+        self.graph.order()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+
+    fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+        self.graph.degree(v)
+    }
+}
+
+impl<L, R, W> GraphMut for BipartiteGraph<L, R, W>
+where
+    L: Eq + Hash + Clone + Debug,
+    R: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_vertex(vertex)
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        self.graph.remove_vertex(vertex)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        Self::check_sides(u, v)?;
+        self.graph.add_edge(u, v)
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.remove_edge(u, v)
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        self.graph.remove_isolated_vertices()
+    }
+}
+
+impl<L, R, W> WeightedGraph for BipartiteGraph<L, R, W>
+where
+    L: Eq + Hash + Clone + Debug,
+    R: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight> {
+        self.graph.edge_weight(u, v)
+    }
+}
+
+impl<L, R, W> WeightedGraphMut for BipartiteGraph<L, R, W>
+where
+    L: Eq + Hash + Clone + Debug,
+    R: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    fn add_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<(), MutationError> {
+        Self::check_sides(u, v)?;
+        self.graph.add_weighted_edge(u, v, weight)
+    }
+
+    fn update_edge_weight(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        self.graph.update_edge_weight(u, v, weight)
+    }
+
+    fn remove_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        self.graph.remove_weighted_edge(u, v)
+    }
+}
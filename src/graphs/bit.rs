@@ -0,0 +1,267 @@
+use crate::{
+    error::MutationError,
+    graphs::vertex_set::{VertexIndex, VertexSet},
+    traits::graph::EdgeList,
+    Graph, GraphMut,
+};
+
+/// A dense graph on vertices numbered `0..capacity`, storing each adjacency row
+/// as a [`VertexSet`] bitset.
+///
+/// Edge queries are single bit tests and neighborhoods can be intersected,
+/// united and counted a machine word at a time through [`BitGraph::row`], which
+/// suits branch-and-bound searches such as clique or domination solvers on
+/// dense graphs with up to a few thousand vertices. Unlike
+/// [`SmallGraph`](crate::graphs::small::SmallGraph) the number of vertices is
+/// not bounded: adding a vertex beyond the capacity grows every row. Memory use
+/// is quadratic in the capacity.
+///
+/// ```
+/// use kambo_graph::{graphs::bit::BitGraph, Graph, GraphMut};
+///
+/// let mut graph = BitGraph::with_order(4, false);
+/// graph.add_edge(&0, &1).unwrap();
+/// graph.add_edge(&0, &2).unwrap();
+/// graph.add_edge(&1, &2).unwrap();
+///
+/// let common = graph.common_neighbors(0, 1).unwrap();
+/// assert_eq!(common.iter().collect::<Vec<_>>(), vec![2]);
+/// assert_eq!(graph.edge_count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitGraph {
+    rows: Vec<VertexSet>,
+    present: VertexSet,
+    /// Vertex ids `0..capacity`, so iterators can yield references to vertices
+    /// that are only stored as bits.
+    ids: Vec<usize>,
+    directed: bool,
+    /// Number of edges, kept up to date so `edge_count` is O(1).
+    edge_count: usize,
+}
+
+impl BitGraph {
+    /// Creates an empty graph with room for the vertices `0..capacity`.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of vertex ids to allocate rows for.
+    /// * `directed` - `true` for a directed graph, `false` for an undirected graph.
+    #[must_use]
+    pub fn with_capacity(capacity: usize, directed: bool) -> Self {
+        Self {
+            rows: vec![VertexSet::new(capacity); capacity],
+            present: VertexSet::new(capacity),
+            ids: (0..capacity).collect(),
+            directed,
+            edge_count: 0,
+        }
+    }
+
+    /// Creates an empty directed graph.
+    #[must_use]
+    pub fn new_directed() -> Self {
+        Self::with_capacity(0, true)
+    }
+
+    /// Creates an empty undirected graph.
+    #[must_use]
+    pub fn new_undirected() -> Self {
+        Self::with_capacity(0, false)
+    }
+
+    /// Creates a graph with the vertices `0..n` and no edges.
+    #[must_use]
+    pub fn with_order(n: usize, directed: bool) -> Self {
+        let mut graph = Self::with_capacity(n, directed);
+        graph.present = VertexSet::full(n);
+        graph
+    }
+
+    /// Copies any graph, numbering its vertices in iteration order.
+    ///
+    /// # Returns
+    ///
+    /// The bit graph and the numbering, which maps vertex `i` back to the
+    /// original vertex.
+    pub fn from_graph<G>(graph: &G) -> (Self, VertexIndex<G::Vertex>)
+    where
+        G: Graph,
+    {
+        let index = VertexIndex::new(graph);
+        let mut bits = Self::with_order(index.len(), graph.is_directed());
+        bits.rows = index.neighborhoods(graph);
+        bits.edge_count = graph.edge_count();
+        (bits, index)
+    }
+
+    /// Returns the number of vertex ids the graph has rows for.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns the set of the vertices in the graph, over the universe
+    /// `0..capacity`.
+    #[must_use]
+    pub fn vertex_set(&self) -> &VertexSet {
+        &self.present
+    }
+
+    /// Returns the adjacency row of `v`, the set of vertices `w` such that
+    /// `(v, w)` is an edge, over the universe `0..capacity`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&VertexSet)` - The out-neighborhood of `v`.
+    /// * `None` - If `v` is not in the graph.
+    #[must_use]
+    pub fn row(&self, v: usize) -> Option<&VertexSet> {
+        self.present.contains(v).then(|| &self.rows[v])
+    }
+
+    /// Returns the vertices adjacent from both `u` and `v`, computed with
+    /// word-level intersection.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(VertexSet)` - The common out-neighbors.
+    /// * `None` - If `u` or `v` is not in the graph.
+    #[must_use]
+    pub fn common_neighbors(&self, u: usize, v: usize) -> Option<VertexSet> {
+        Some(self.row(u)?.intersection(self.row(v)?))
+    }
+
+    /// Grows every row so that `v` is a valid vertex id, at least doubling the
+    /// capacity to keep repeated growth amortized.
+    fn reserve_id(&mut self, v: usize) {
+        let capacity = self.capacity();
+        if v < capacity {
+            return;
+        }
+        let capacity = (v + 1).max(2 * capacity);
+        for row in &mut self.rows {
+            row.grow(capacity);
+        }
+        self.rows.resize(capacity, VertexSet::new(capacity));
+        self.present.grow(capacity);
+        self.ids.extend(self.ids.len()..capacity);
+    }
+}
+
+impl Default for BitGraph {
+    fn default() -> Self {
+        Self::new_undirected()
+    }
+}
+
+impl Graph for BitGraph {
+    type Vertex = usize;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.present.iter().map(|v| &self.ids[v])
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a>> {
+        self.row(*v).map(|row| row.iter().map(|w| &self.ids[w]))
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.present.contains(*v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.row(*u).is_some_and(|row| row.contains(*v))
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn order(&self) -> usize {
+        self.present.len()
+    }
+
+    fn size(&self) -> usize {
+        self.edge_count
+    }
+
+    fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+        self.row(*v).map(VertexSet::len)
+    }
+}
+
+impl GraphMut for BitGraph {
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        if self.contains_vertex(&vertex) {
+            return Err(MutationError::VertexAlreadyExists);
+        }
+        self.reserve_id(vertex);
+        self.present.insert(vertex);
+        Ok(())
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        if !self.contains_vertex(vertex) {
+            return Err(MutationError::VertexNotFound);
+        }
+        let v = *vertex;
+        let mut removed: Vec<(usize, usize)> = self.rows[v].iter().map(|w| (v, w)).collect();
+        self.rows[v] = VertexSet::new(self.capacity());
+        for u in self.present.iter() {
+            // Arcs entering v only count separately in directed graphs; the loop
+            // at v was already counted with its row.
+            if self.rows[u].remove(v) && self.directed && u != v {
+                removed.push((u, v));
+            }
+        }
+        self.present.remove(v);
+        self.edge_count -= removed.len();
+        Ok(removed)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        if !self.contains_vertex(u) || !self.contains_vertex(v) {
+            return Err(MutationError::VertexNotFound);
+        }
+        if self.contains_edge(u, v) {
+            return Err(MutationError::EdgeAlreadyExists);
+        }
+        self.rows[*u].insert(*v);
+        if !self.directed {
+            self.rows[*v].insert(*u);
+        }
+        self.edge_count += 1;
+        Ok(())
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        if !self.contains_edge(u, v) {
+            return Err(MutationError::EdgeNotFound);
+        }
+        self.rows[*u].remove(*v);
+        if !self.directed {
+            self.rows[*v].remove(*u);
+        }
+        self.edge_count -= 1;
+        Ok(())
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        let isolated: Vec<usize> = self
+            .present
+            .iter()
+            .filter(|&v| self.rows[v].is_empty())
+            .collect();
+        for v in &isolated {
+            self.remove_vertex(v)?;
+        }
+        Ok(isolated)
+    }
+}
@@ -0,0 +1,159 @@
+use std::{error::Error, fmt, fmt::Debug, hash::Hash};
+
+use crate::{error::MutationError, traits::weighted::WeightedGraphMut, GraphMut, SimpleGraph};
+
+/// The errors collected while building a graph with [`GraphBuilder`].
+#[derive(Debug)]
+pub struct BuildError {
+    /// Each failed operation, described as it was written (e.g. `edge(1, 2)`),
+    /// paired with the error it raised.
+    pub errors: Vec<(String, MutationError)>,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to build graph ({} errors)", self.errors.len())?;
+        for (operation, error) in &self.errors {
+            write!(f, "\n  {operation}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for BuildError {}
+
+/// A fluent builder for [`SimpleGraph`].
+///
+/// Edges create their endpoints when needed, so vertices only have to be listed
+/// explicitly when they are isolated. Errors (such as duplicate vertices or edges)
+/// do not interrupt the chain; they are all reported by [`GraphBuilder::build`].
+///
+/// ```
+/// use kambo_graph::{graphs::builder::GraphBuilder, Graph};
+///
+/// let graph = GraphBuilder::undirected()
+///     .vertices(0..10)
+///     .edge(0, 1)
+///     .edge_weighted(1, 2, 5.0)
+///     .build()?;
+/// assert_eq!((graph.order(), graph.size()), (10, 2));
+/// assert_eq!(graph.edge_weight(&2, &1), Some(&5.0));
+///
+/// let error = GraphBuilder::<u32>::directed().edge(1, 2).edge(1, 2).build().unwrap_err();
+/// assert_eq!(error.errors.len(), 1);
+/// # Ok::<(), kambo_graph::graphs::builder::BuildError>(())
+/// ```
+#[derive(Debug)]
+pub struct GraphBuilder<V, W = ()>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    graph: SimpleGraph<V, W>,
+    errors: Vec<(String, MutationError)>,
+}
+
+impl<V, W> GraphBuilder<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    /// Starts building a directed graph.
+    #[must_use]
+    pub fn directed() -> Self {
+        Self {
+            graph: SimpleGraph::new_weighted_directed(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Starts building an undirected graph.
+    #[must_use]
+    pub fn undirected() -> Self {
+        Self {
+            graph: SimpleGraph::new_weighted_undirected(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, operation: impl FnOnce() -> String, result: Result<(), MutationError>) {
+        if let Err(error) = result {
+            self.errors.push((operation(), error));
+        }
+    }
+
+    fn ensure_vertex(&mut self, v: &V) {
+        if !self.graph.contains_vertex(v) {
+            let result = self.graph.add_vertex(v.clone());
+            self.record(|| format!("vertex({v:?})"), result);
+        }
+    }
+
+    /// Adds a vertex. Adding an existing vertex is recorded as an error.
+    #[must_use]
+    pub fn vertex(mut self, v: V) -> Self {
+        let operation = format!("vertex({v:?})");
+        let result = self.graph.add_vertex(v);
+        self.record(|| operation, result);
+        self
+    }
+
+    /// Adds every vertex of `vertices`. Adding an existing vertex is recorded as an
+    /// error.
+    #[must_use]
+    pub fn vertices<I>(self, vertices: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+    {
+        vertices.into_iter().fold(self, Self::vertex)
+    }
+
+    /// Adds the edge `(u, v)`, creating missing endpoints. Adding an existing edge
+    /// is recorded as an error.
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn edge(mut self, u: V, v: V) -> Self {
+        self.ensure_vertex(&u);
+        self.ensure_vertex(&v);
+        let result = self.graph.add_edge(&u, &v);
+        self.record(|| format!("edge({u:?}, {v:?})"), result);
+        self
+    }
+
+    /// Adds every edge of `edges`, creating missing endpoints. Adding an existing
+    /// edge is recorded as an error.
+    #[must_use]
+    pub fn edges<I>(self, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (V, V)>,
+    {
+        edges.into_iter().fold(self, |b, (u, v)| b.edge(u, v))
+    }
+
+    /// Adds the edge `(u, v)` with a weight, creating missing endpoints. Adding an
+    /// existing edge is recorded as an error.
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn edge_weighted(mut self, u: V, v: V, weight: W) -> Self {
+        self.ensure_vertex(&u);
+        self.ensure_vertex(&v);
+        let result = self.graph.add_weighted_edge(&u, &v, weight);
+        self.record(|| format!("edge_weighted({u:?}, {v:?})"), result);
+        self
+    }
+
+    /// Finishes the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] listing every operation that failed.
+    pub fn build(self) -> Result<SimpleGraph<V, W>, BuildError> {
+        if self.errors.is_empty() {
+            Ok(self.graph)
+        } else {
+            Err(BuildError {
+                errors: self.errors,
+            })
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use crate::{
+    error::MutationError,
+    traits::{
+        capacitated::{CapacitatedGraph, CapacitatedGraphMut},
+        graph::EdgeList,
+        weighted::{WeightedGraph, WeightedGraphMut},
+    },
+    Graph, GraphMut,
+};
+
+/// Wraps any graph and attaches a capacity to each of its edges.
+///
+/// The wrapper implements [`Graph`] and [`GraphMut`] by delegating to the inner
+/// graph, and [`WeightedGraph`] and [`WeightedGraphMut`] when the inner graph
+/// does, so every edge can carry both a weight, such as a cost or a length, and
+/// a capacity. Removing an edge or a vertex also drops the capacities of the
+/// removed edges. Edges added through [`GraphMut::add_edge`] or
+/// [`WeightedGraphMut::add_weighted_edge`] start without a capacity.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::{flow::capacitated_maximum_flow, shortest_path::dijkstra},
+///     graph,
+///     graphs::capacitated::Capacitated,
+///     traits::capacitated::CapacitatedGraphMut,
+/// };
+///
+/// // Links with a latency as their weight and a bandwidth as their capacity.
+/// let links = graph! { 1 -> 2 [weight = 5u32], 2 -> 3 [weight = 5u32], 1 -> 3 [weight = 20u32] };
+/// let mut network = Capacitated::new(links);
+/// network.set_capacity(&1, &2, 10u32).unwrap();
+/// network.set_capacity(&2, &3, 4).unwrap();
+/// network.set_capacity(&1, &3, 100).unwrap();
+///
+/// assert_eq!(dijkstra(&network, &1).unwrap()[&3], 10);
+/// assert_eq!(capacitated_maximum_flow(&network, &1, &3).unwrap().0, 104);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Capacitated<G, C>
+where
+    G: Graph,
+{
+    graph: G,
+    capacities: HashMap<G::Vertex, HashMap<G::Vertex, C>>,
+}
+
+impl<G, C> Capacitated<G, C>
+where
+    G: Graph,
+    C: Clone + Debug,
+{
+    /// Wraps a graph without assigning any edge capacity.
+    #[must_use]
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph,
+            capacities: HashMap::new(),
+        }
+    }
+
+    /// Wraps a graph, computing the capacity of every edge with `capacity`.
+    ///
+    /// Each undirected edge is passed once, in an unspecified orientation.
+    pub fn with_capacities<F>(graph: G, mut capacity: F) -> Self
+    where
+        F: FnMut(&G::Vertex, &G::Vertex) -> C,
+    {
+        let mut wrapped = Self::new(graph);
+        let directed = wrapped.graph.is_directed();
+        for u in wrapped.graph.vertices() {
+            for v in wrapped.graph.neighbors(u).into_iter().flatten() {
+                let known = wrapped
+                    .capacities
+                    .get(v)
+                    .is_some_and(|row| row.contains_key(u));
+                if directed || !known {
+                    let value = capacity(u, v);
+                    Self::store(&mut wrapped.capacities, directed, u, v, value);
+                }
+            }
+        }
+        wrapped
+    }
+
+    /// Returns a reference to the inner graph.
+    #[must_use]
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Unwraps the inner graph, dropping the capacities.
+    #[must_use]
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+
+    /// Stores the capacity of the edge from `u` to `v`, and from `v` to `u` in
+    /// an undirected graph, returning the previous one.
+    fn store(
+        capacities: &mut HashMap<G::Vertex, HashMap<G::Vertex, C>>,
+        directed: bool,
+        u: &G::Vertex,
+        v: &G::Vertex,
+        capacity: C,
+    ) -> Option<C> {
+        if !directed {
+            capacities
+                .entry(v.clone())
+                .or_default()
+                .insert(u.clone(), capacity.clone());
+        }
+        capacities
+            .entry(u.clone())
+            .or_default()
+            .insert(v.clone(), capacity)
+    }
+
+    /// Drops the capacity of the edge from `u` to `v`, and from `v` to `u` in
+    /// an undirected graph.
+    fn forget(&mut self, u: &G::Vertex, v: &G::Vertex) {
+        if let Some(row) = self.capacities.get_mut(u) {
+            row.remove(v);
+        }
+        if !self.graph.is_directed() {
+            if let Some(row) = self.capacities.get_mut(v) {
+                row.remove(u);
+            }
+        }
+    }
+}
+
+impl<G, C> Graph for Capacitated<G, C>
+where
+    G: Graph,
+    C: Clone + Debug,
+{
+    type Vertex = G::Vertex;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.graph.vertices()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, G, C>> {
+        self.graph.neighbors(v)
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.graph.contains_vertex(v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.graph.contains_edge(u, v)
+    }
+
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+}
+
+impl<G, C> GraphMut for Capacitated<G, C>
+where
+    G: GraphMut,
+    C: Clone + Debug,
+{
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_vertex(vertex)
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        let removed = self.graph.remove_vertex(vertex)?;
+        for (u, v) in &removed {
+            self.forget(u, v);
+        }
+        self.capacities.remove(vertex);
+        Ok(removed)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_edge(u, v)
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.remove_edge(u, v)?;
+        self.forget(u, v);
+        Ok(())
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        let removed = self.graph.remove_isolated_vertices()?;
+        for v in &removed {
+            self.capacities.remove(v);
+        }
+        Ok(removed)
+    }
+}
+
+impl<G, C> WeightedGraph for Capacitated<G, C>
+where
+    G: WeightedGraph,
+    C: Clone + Debug,
+{
+    type Weight = G::Weight;
+
+    fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight> {
+        self.graph.edge_weight(u, v)
+    }
+
+    fn weighted_edges(
+        &self,
+    ) -> impl Iterator<Item = (&Self::Vertex, &Self::Vertex, &Self::Weight)> {
+        self.graph.weighted_edges()
+    }
+}
+
+impl<G, C> WeightedGraphMut for Capacitated<G, C>
+where
+    G: WeightedGraphMut,
+    C: Clone + Debug,
+{
+    fn add_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<(), MutationError> {
+        self.graph.add_weighted_edge(u, v, weight)
+    }
+
+    fn update_edge_weight(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        self.graph.update_edge_weight(u, v, weight)
+    }
+}
+
+impl<G, C> CapacitatedGraph for Capacitated<G, C>
+where
+    G: Graph,
+    C: Clone + Debug,
+{
+    type Capacity = C;
+
+    fn capacity(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Capacity> {
+        self.capacities.get(u)?.get(v)
+    }
+}
+
+impl<G, C> CapacitatedGraphMut for Capacitated<G, C>
+where
+    G: Graph,
+    C: Clone + Debug,
+{
+    fn set_capacity(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        capacity: Self::Capacity,
+    ) -> Result<Option<Self::Capacity>, MutationError> {
+        if !self.graph.contains_edge(u, v) {
+            return Err(MutationError::EdgeNotFound);
+        }
+        let directed = self.graph.is_directed();
+        Ok(Self::store(&mut self.capacities, directed, u, v, capacity))
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{traits::weighted::WeightedGraph, Graph};
+
+/// A view of a graph hiding the vertices and edges that fail the given
+/// predicates, without copying the graph.
+///
+/// A vertex is visible if `vertex_filter` accepts it, and an edge if both of its
+/// endpoints are visible and `edge_filter` accepts it. For undirected graphs the
+/// edge filter must accept both orientations, so the view stays symmetric. The
+/// predicates are evaluated on every query, so counting the vertices or edges of
+/// the view takes time proportional to the underlying graph.
+///
+/// The view implements [`Graph`], and [`WeightedGraph`] when the underlying graph
+/// does, so any algorithm of the crate runs on it directly.
+///
+/// ```
+/// use kambo_graph::{algorithms::shortest_path::dijkstra, graph, graphs::filtered::FilteredGraph, Graph};
+///
+/// let roads = graph! { 1 - 2 [weight = 1], 2 - 3 [weight = 1], 3 - 4 [weight = 5], 1 - 4 [weight = 9] };
+/// // Close the junction 2: the way from 1 to 4 is now the direct road.
+/// let closed = FilteredGraph::with_vertex_filter(&roads, |&v| v != 2);
+/// assert_eq!(closed.order(), 3);
+/// assert_eq!(dijkstra(&roads, &1).unwrap()[&4], 7);
+/// assert_eq!(dijkstra(&closed, &1).unwrap()[&4], 9);
+///
+/// // Close the road between 1 and 4 instead.
+/// let closed = FilteredGraph::with_edge_filter(&roads, |&u, &v| u.min(v) != 1 || u.max(v) != 4);
+/// assert!(!closed.contains_edge(&4, &1));
+/// assert_eq!(closed.edge_count(), 3);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FilteredGraph<'g, G, FP, EP> {
+    graph: &'g G,
+    vertex_filter: FP,
+    edge_filter: EP,
+}
+
+/// The type of a filter accepting every vertex.
+pub type AllVertices<V> = fn(&V) -> bool;
+
+/// The type of a filter accepting every edge.
+pub type AllEdges<V> = fn(&V, &V) -> bool;
+
+impl<'g, G, FP, EP> FilteredGraph<'g, G, FP, EP>
+where
+    G: Graph,
+    FP: Fn(&G::Vertex) -> bool,
+    EP: Fn(&G::Vertex, &G::Vertex) -> bool,
+{
+    /// Creates a view of `graph` keeping the vertices accepted by
+    /// `vertex_filter` and the edges accepted by `edge_filter`.
+    pub fn new(graph: &'g G, vertex_filter: FP, edge_filter: EP) -> Self {
+        Self {
+            graph,
+            vertex_filter,
+            edge_filter,
+        }
+    }
+
+    /// Returns the underlying graph.
+    #[must_use]
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+
+    fn keeps_vertex(&self, v: &G::Vertex) -> bool {
+        (self.vertex_filter)(v)
+    }
+
+    /// Checks the endpoints and the edge filter for an edge known to exist.
+    fn keeps_edge(&self, u: &G::Vertex, v: &G::Vertex) -> bool {
+        self.keeps_vertex(u) && self.keeps_vertex(v) && (self.edge_filter)(u, v)
+    }
+}
+
+impl<'g, G, FP> FilteredGraph<'g, G, FP, AllEdges<G::Vertex>>
+where
+    G: Graph,
+    FP: Fn(&G::Vertex) -> bool,
+{
+    /// Creates a view of `graph` keeping the vertices accepted by
+    /// `vertex_filter`, that is the subgraph they induce.
+    pub fn with_vertex_filter(graph: &'g G, vertex_filter: FP) -> Self {
+        Self::new(graph, vertex_filter, |_, _| true)
+    }
+}
+
+impl<'g, G, EP> FilteredGraph<'g, G, AllVertices<G::Vertex>, EP>
+where
+    G: Graph,
+    EP: Fn(&G::Vertex, &G::Vertex) -> bool,
+{
+    /// Creates a view of `graph` with every vertex, keeping the edges accepted by
+    /// `edge_filter`.
+    pub fn with_edge_filter(graph: &'g G, edge_filter: EP) -> Self {
+        Self::new(graph, |_| true, edge_filter)
+    }
+}
+
+impl<'g, G, FP, EP> Graph for FilteredGraph<'g, G, FP, EP>
+where
+    G: Graph,
+    FP: Fn(&G::Vertex) -> bool,
+    EP: Fn(&G::Vertex, &G::Vertex) -> bool,
+{
+    type Vertex = G::Vertex;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.graph.vertices().filter(|v| self.keeps_vertex(v))
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, 'g, G, FP, EP>> {
+        if !self.keeps_vertex(v) {
+            return None;
+        }
+        let neighbors = self.graph.neighbors(v)?;
+        let u = v.clone();
+        Some(neighbors.filter(move |w| self.keeps_edge(&u, w)))
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.graph.contains_vertex(v) && self.keeps_vertex(v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.graph.contains_edge(u, v) && self.keeps_edge(u, v)
+    }
+
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+}
+
+impl<G, FP, EP> WeightedGraph for FilteredGraph<'_, G, FP, EP>
+where
+    G: WeightedGraph,
+    FP: Fn(&G::Vertex) -> bool,
+    EP: Fn(&G::Vertex, &G::Vertex) -> bool,
+{
+    type Weight = G::Weight;
+
+    fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight> {
+        if self.keeps_edge(u, v) {
+            self.graph.edge_weight(u, v)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+};
+
+use crate::{error::MutationError, graphs::bipartite::BipartiteGraph, SimpleGraph};
+
+/// A handle to a hyperedge of a [`Hypergraph`].
+///
+/// Handles stay valid until their hyperedge is removed and are never handed out
+/// again afterwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HyperedgeId(usize);
+
+impl HyperedgeId {
+    /// Creates a handle from a raw index.
+    #[must_use]
+    pub fn new(index: usize) -> Self {
+        HyperedgeId(index)
+    }
+
+    /// Returns the raw index of the handle.
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A hypergraph: vertices and hyperedges, each hyperedge joining any set of
+/// vertices.
+///
+/// Hyperedges are identified by the [`HyperedgeId`] returned when they are
+/// added, so two hyperedges may join the same vertices. Each vertex keeps the
+/// hyperedges incident to it, so incidence queries take time proportional to
+/// their answer.
+///
+/// A hypergraph converts to graphs in two ways:
+///
+/// - [`Hypergraph::incidence_graph`] is the bipartite graph linking every vertex
+///   to the hyperedges containing it.
+/// - [`Hypergraph::two_section`] is the graph on the same vertices where two
+///   vertices are adjacent when some hyperedge contains both.
+///
+/// ```
+/// use kambo_graph::{graphs::hypergraph::Hypergraph, traits::weighted::WeightedGraph, Graph};
+///
+/// // Three constraints over the variables they involve.
+/// let mut constraints = Hypergraph::new();
+/// let sum = constraints.add_hyperedge(["x", "y", "z"]);
+/// let diff = constraints.add_hyperedge(["x", "y"]);
+/// constraints.add_hyperedge(["z", "w"]);
+///
+/// assert_eq!(constraints.degree(&"x"), Some(2));
+/// assert!(constraints.is_incident(&"z", sum));
+/// assert!(!constraints.is_incident(&"z", diff));
+/// assert_eq!(constraints.rank(), 3);
+///
+/// let graph = constraints.two_section();
+/// assert_eq!(graph.edge_weight(&"x", &"y"), Some(&2));
+/// assert!(!graph.contains_edge(&"x", &"w"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hypergraph<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    /// The hyperedges incident to each vertex.
+    incidence: HashMap<V, HashSet<HyperedgeId>>,
+    /// The members of each hyperedge, `None` once it is removed.
+    hyperedges: Vec<Option<HashSet<V>>>,
+    hyperedge_count: usize,
+}
+
+impl<V> Default for Hypergraph<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    fn default() -> Self {
+        Self {
+            incidence: HashMap::new(),
+            hyperedges: Vec::new(),
+            hyperedge_count: 0,
+        }
+    }
+}
+
+impl<V> Hypergraph<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    /// Creates an empty hypergraph.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a vertex without hyperedges.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexAlreadyExists` if the vertex is already in
+    /// the hypergraph.
+    pub fn add_vertex(&mut self, vertex: V) -> Result<(), MutationError> {
+        if self.incidence.contains_key(&vertex) {
+            return Err(MutationError::VertexAlreadyExists);
+        }
+        self.incidence.insert(vertex, HashSet::new());
+        Ok(())
+    }
+
+    /// Removes a vertex from the hypergraph and from every hyperedge containing
+    /// it. The hyperedges themselves are kept, even if they become empty.
+    ///
+    /// # Returns
+    ///
+    /// The hyperedges that contained the vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexNotFound` if the vertex is not in the
+    /// hypergraph.
+    pub fn remove_vertex(&mut self, vertex: &V) -> Result<Vec<HyperedgeId>, MutationError> {
+        let incident = self
+            .incidence
+            .remove(vertex)
+            .ok_or(MutationError::VertexNotFound)?;
+        for id in &incident {
+            if let Some(members) = &mut self.hyperedges[id.0] {
+                members.remove(vertex);
+            }
+        }
+        Ok(incident.into_iter().collect())
+    }
+
+    /// Adds a hyperedge joining `members`, adding the vertices that are not in
+    /// the hypergraph yet. Repeated members are joined once.
+    ///
+    /// # Arguments
+    ///
+    /// * `members` - The vertices of the new hyperedge.
+    ///
+    /// # Returns
+    ///
+    /// The handle of the new hyperedge.
+    pub fn add_hyperedge<I>(&mut self, members: I) -> HyperedgeId
+    where
+        I: IntoIterator<Item = V>,
+    {
+        let id = HyperedgeId(self.hyperedges.len());
+        let members: HashSet<V> = members.into_iter().collect();
+        for v in &members {
+            self.incidence.entry(v.clone()).or_default().insert(id);
+        }
+        self.hyperedges.push(Some(members));
+        self.hyperedge_count += 1;
+        id
+    }
+
+    /// Removes a hyperedge and returns its members, or `None` if it is not in the
+    /// hypergraph. Its vertices are kept.
+    pub fn remove_hyperedge(&mut self, id: HyperedgeId) -> Option<HashSet<V>> {
+        let members = self.hyperedges.get_mut(id.0)?.take()?;
+        for v in &members {
+            if let Some(incident) = self.incidence.get_mut(v) {
+                incident.remove(&id);
+            }
+        }
+        self.hyperedge_count -= 1;
+        Some(members)
+    }
+
+    /// Returns `true` if the hypergraph contains the vertex.
+    #[must_use]
+    pub fn contains_vertex(&self, v: &V) -> bool {
+        self.incidence.contains_key(v)
+    }
+
+    /// Returns an iterator over the vertices.
+    pub fn vertices(&self) -> impl Iterator<Item = &V> {
+        self.incidence.keys()
+    }
+
+    /// Returns the number of vertices.
+    #[must_use]
+    pub fn order(&self) -> usize {
+        self.incidence.len()
+    }
+
+    /// Returns the number of hyperedges.
+    #[must_use]
+    pub fn hyperedge_count(&self) -> usize {
+        self.hyperedge_count
+    }
+
+    /// Returns the members of a hyperedge, or `None` if it is not in the
+    /// hypergraph.
+    #[must_use]
+    pub fn hyperedge(&self, id: HyperedgeId) -> Option<&HashSet<V>> {
+        self.hyperedges.get(id.0)?.as_ref()
+    }
+
+    /// Returns an iterator over the hyperedges and their members.
+    pub fn hyperedges(&self) -> impl Iterator<Item = (HyperedgeId, &HashSet<V>)> {
+        self.hyperedges
+            .iter()
+            .enumerate()
+            .filter_map(|(i, members)| Some((HyperedgeId(i), members.as_ref()?)))
+    }
+
+    /// Returns the hyperedges containing `v`, or `None` if `v` is not in the
+    /// hypergraph.
+    pub fn incident_hyperedges(&self, v: &V) -> Option<impl Iterator<Item = HyperedgeId> + '_> {
+        Some(self.incidence.get(v)?.iter().copied())
+    }
+
+    /// Returns `true` if hyperedge `id` contains `v`.
+    #[must_use]
+    pub fn is_incident(&self, v: &V, id: HyperedgeId) -> bool {
+        self.incidence
+            .get(v)
+            .is_some_and(|incident| incident.contains(&id))
+    }
+
+    /// Returns the number of hyperedges containing `v`, or `None` if `v` is not
+    /// in the hypergraph.
+    #[must_use]
+    pub fn degree(&self, v: &V) -> Option<usize> {
+        self.incidence.get(v).map(HashSet::len)
+    }
+
+    /// Returns the vertices sharing a hyperedge with `v`, `v` excluded, or
+    /// `None` if `v` is not in the hypergraph.
+    #[must_use]
+    pub fn neighbors(&self, v: &V) -> Option<HashSet<&V>> {
+        let incident = self.incidence.get(v)?;
+        Some(
+            incident
+                .iter()
+                .filter_map(|id| self.hyperedge(*id))
+                .flatten()
+                .filter(|&w| w != v)
+                .collect(),
+        )
+    }
+
+    /// Returns the rank of the hypergraph, the size of its largest hyperedge, or
+    /// `0` if it has none.
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.hyperedges()
+            .map(|(_, members)| members.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Builds the incidence graph: the bipartite graph with the vertices on the
+    /// left side, the hyperedges on the right side, and a link from every vertex
+    /// to each hyperedge containing it.
+    #[must_use]
+    pub fn incidence_graph(&self) -> BipartiteGraph<V, HyperedgeId> {
+        let mut graph = BipartiteGraph::new();
+        for v in self.vertices() {
+            let _ = graph.add_left(v.clone());
+        }
+        for (id, members) in self.hyperedges() {
+            let _ = graph.add_right(id);
+            for v in members {
+                let _ = graph.add_link(v, &id);
+            }
+        }
+        graph
+    }
+
+    /// Builds the 2-section (or primal graph): the undirected graph on the same
+    /// vertices where two vertices are adjacent when some hyperedge contains
+    /// both, weighted by the number of hyperedges containing both.
+    ///
+    /// Each hyperedge with `k` members contributes `k(k - 1) / 2` pairs.
+    #[must_use]
+    pub fn two_section(&self) -> SimpleGraph<V, usize> {
+        self.incidence_graph().left_projection()
+    }
+}
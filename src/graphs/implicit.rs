@@ -0,0 +1,328 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use crate::{
+    algorithms::{budget::Partial, heap::MinScored},
+    error::AlgorithmError,
+    traits::{graph::GraphMut, measure::Measure},
+    Graph, GraphError, SimpleGraph,
+};
+
+/// A directed graph defined by a successor function instead of stored vertices
+/// and edges, such as the state space of a puzzle.
+///
+/// Vertices are generated on demand while a search runs, so the graph may be
+/// far too large to materialize, or even infinite. The searches keep only the
+/// vertices they reach:
+///
+/// - [`ImplicitGraph::bfs`] finds a path with the fewest edges to a goal.
+/// - [`ImplicitGraph::astar`] finds a cheapest path to a goal, guided by a
+///   heuristic.
+/// - [`ImplicitGraph::explore`] materializes the region around a vertex as a
+///   [`SimpleGraph`], on which every algorithm of the crate runs.
+///
+/// The searches return once they reach a goal. If no goal is reachable they
+/// stop only when the reachable region is exhausted, which never happens in an
+/// infinite graph.
+///
+/// ```
+/// use kambo_graph::graphs::implicit::ImplicitGraph;
+///
+/// // Two jugs of 3 and 5 liters: fill, empty or pour one into the other.
+/// let jugs = ImplicitGraph::new(|&(a, b): &(u8, u8)| {
+///     let pour_ab = a.min(5 - b);
+///     let pour_ba = b.min(3 - a);
+///     vec![
+///         (3, b),
+///         (a, 5),
+///         (0, b),
+///         (a, 0),
+///         (a - pour_ab, b + pour_ab),
+///         (a + pour_ba, b - pour_ba),
+///     ]
+/// });
+///
+/// let path = jugs.bfs(&(0, 0), |&(_, b)| b == 4).unwrap();
+/// assert_eq!(path.len(), 7);
+/// assert_eq!(path.last(), Some(&(3, 4)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ImplicitGraph<V, F> {
+    successors: F,
+    vertex: PhantomData<fn(&V)>,
+}
+
+impl<V, F> Debug for ImplicitGraph<V, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImplicitGraph").finish_non_exhaustive()
+    }
+}
+
+impl<V, F> ImplicitGraph<V, F>
+where
+    V: Eq + Hash + Clone + Debug,
+    F: Fn(&V) -> Vec<V>,
+{
+    /// Creates a graph whose out-neighbors of `v` are `successors(v)`.
+    ///
+    /// The successor function should be deterministic, since the searches may
+    /// call it more than once for the same vertex.
+    pub fn new(successors: F) -> Self {
+        Self {
+            successors,
+            vertex: PhantomData,
+        }
+    }
+
+    /// Returns the out-neighbors of `v`.
+    #[must_use]
+    pub fn successors(&self, v: &V) -> Vec<V> {
+        (self.successors)(v)
+    }
+
+    /// Finds a path with the fewest edges from `start` to a vertex accepted by
+    /// `is_goal`, with a breadth-first search.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The vertex where the path starts.
+    /// * `is_goal` - Whether a vertex ends the search.
+    ///
+    /// # Returns
+    ///
+    /// The vertices of the path, from `start` to the goal, or `None` if no goal
+    /// is reachable. If `start` is a goal the path is `[start]`.
+    pub fn bfs<P>(&self, start: &V, mut is_goal: P) -> Option<Vec<V>>
+    where
+        P: FnMut(&V) -> bool,
+    {
+        if is_goal(start) {
+            return Some(vec![start.clone()]);
+        }
+        let mut vertices = vec![start.clone()];
+        let mut parent = vec![usize::MAX];
+        let mut index: HashMap<V, usize> = HashMap::from([(start.clone(), 0)]);
+        let mut queue = VecDeque::from([0]);
+        while let Some(u) = queue.pop_front() {
+            for v in (self.successors)(&vertices[u]) {
+                let Entry::Vacant(entry) = index.entry(v) else {
+                    continue;
+                };
+                let i = vertices.len();
+                let found = is_goal(entry.key());
+                vertices.push(entry.key().clone());
+                entry.insert(i);
+                parent.push(u);
+                if found {
+                    return Some(trace_back(&vertices, &parent, i));
+                }
+                queue.push_back(i);
+            }
+        }
+        None
+    }
+
+    /// Finds a cheapest path from `start` to a vertex accepted by `is_goal` with
+    /// the A* algorithm.
+    ///
+    /// The path found is optimal when `heuristic` is admissible, never
+    /// overestimating the cost from a vertex to the nearest goal. A vertex is
+    /// expanded again if a cheaper path to it is found later, so the heuristic
+    /// need not be consistent. The zero heuristic turns the search into
+    /// Dijkstra's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The vertex where the path starts.
+    /// * `is_goal` - Whether a vertex ends the search.
+    /// * `cost` - The cost of the edge from a vertex to one of its successors.
+    /// * `heuristic` - An estimate of the cost from a vertex to the nearest goal.
+    ///
+    /// # Returns
+    ///
+    /// The vertices of the path, from `start` to the goal, and its cost, or
+    /// `None` if no goal is reachable.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::Algorithm` - If an edge cost is negative or invalid
+    ///   (such as `NaN`), or a heuristic value is invalid.
+    /// * `GraphError::WeightOverflow` - If a path cost overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::graphs::implicit::ImplicitGraph;
+    ///
+    /// // An unbounded grid where moving up costs twice as much as moving right.
+    /// let grid = ImplicitGraph::new(|&(x, y): &(i32, i32)| {
+    ///     vec![(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+    /// });
+    /// let cost = |a: &(i32, i32), b: &(i32, i32)| if a.1 == b.1 { 1u32 } else { 2 };
+    /// let heuristic = |&(x, y): &(i32, i32)| (3 - x).unsigned_abs() + 2 * (2 - y).unsigned_abs();
+    ///
+    /// let (path, total) = grid.astar(&(0, 0), |&v| v == (3, 2), cost, heuristic).unwrap().unwrap();
+    /// assert_eq!(total, 7);
+    /// assert_eq!(path.len(), 6);
+    /// ```
+    pub fn astar<W, P, C, H>(
+        &self,
+        start: &V,
+        mut is_goal: P,
+        mut cost: C,
+        mut heuristic: H,
+    ) -> Result<Option<(Vec<V>, W)>, GraphError>
+    where
+        W: Measure,
+        P: FnMut(&V) -> bool,
+        C: FnMut(&V, &V) -> W,
+        H: FnMut(&V) -> W,
+    {
+        let mut estimate = |v: &V, g: &W| {
+            let h = heuristic(v);
+            if !h.is_valid() {
+                return Err(AlgorithmError::InvalidArgument(format!(
+                    "The heuristic of {v:?} is invalid: {h:?}"
+                ))
+                .into());
+            }
+            g.checked_add(&h).ok_or(GraphError::WeightOverflow)
+        };
+
+        let mut vertices = vec![start.clone()];
+        let mut parent = vec![usize::MAX];
+        let mut distance = vec![W::zero()];
+        let mut index: HashMap<V, usize> = HashMap::from([(start.clone(), 0)]);
+        let mut heap = BinaryHeap::from([MinScored(estimate(start, &W::zero())?, (0, W::zero()))]);
+        while let Some(MinScored(_, (u, g))) = heap.pop() {
+            // Entries are not removed when a cheaper path is found, so skip the
+            // ones that are stale.
+            if g.compare(&distance[u]) == Ordering::Greater {
+                continue;
+            }
+            if is_goal(&vertices[u]) {
+                return Ok(Some((
+                    trace_back(&vertices, &parent, u),
+                    distance[u].clone(),
+                )));
+            }
+            for v in (self.successors)(&vertices[u]) {
+                let weight = cost(&vertices[u], &v);
+                if !weight.is_valid() || weight.is_negative() {
+                    return Err(AlgorithmError::invalid_weight(&vertices[u], v, weight).into());
+                }
+                let g = distance[u]
+                    .checked_add(&weight)
+                    .ok_or(GraphError::WeightOverflow)?;
+                let i = match index.entry(v) {
+                    Entry::Occupied(entry) => {
+                        let i = *entry.get();
+                        if g.compare(&distance[i]) != Ordering::Less {
+                            continue;
+                        }
+                        distance[i] = g;
+                        parent[i] = u;
+                        i
+                    }
+                    Entry::Vacant(entry) => {
+                        let i = vertices.len();
+                        vertices.push(entry.key().clone());
+                        entry.insert(i);
+                        distance.push(g);
+                        parent.push(u);
+                        i
+                    }
+                };
+                heap.push(MinScored(
+                    estimate(&vertices[i], &distance[i])?,
+                    (i, distance[i].clone()),
+                ));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Materializes the vertices reachable from `start` in breadth-first order,
+    /// up to `limit` vertices, as a directed graph.
+    ///
+    /// The result contains every edge between the materialized vertices, so any
+    /// algorithm of the crate can run on the explored region.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The vertex where the exploration starts.
+    /// * `limit` - The largest number of vertices to materialize.
+    ///
+    /// # Returns
+    ///
+    /// The explored region. It is complete if it holds every vertex reachable
+    /// from `start`; otherwise it holds the `limit` vertices closest to `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graphs::implicit::ImplicitGraph, Graph};
+    ///
+    /// let collatz = ImplicitGraph::new(|&n: &u64| match n {
+    ///     1 => vec![],
+    ///     n if n % 2 == 0 => vec![n / 2],
+    ///     n => vec![3 * n + 1],
+    /// });
+    ///
+    /// let region = collatz.explore(&6, 100);
+    /// assert!(region.complete);
+    /// assert_eq!(region.value.order(), 9);
+    ///
+    /// let region = collatz.explore(&6, 4);
+    /// assert!(!region.complete);
+    /// assert!(region.value.contains_edge(&3, &10));
+    /// ```
+    #[must_use]
+    pub fn explore(&self, start: &V, limit: usize) -> Partial<SimpleGraph<V, ()>> {
+        let mut graph = SimpleGraph::new_directed();
+        if limit == 0 {
+            return Partial {
+                value: graph,
+                complete: false,
+            };
+        }
+        let _ = graph.add_vertex(start.clone());
+        let mut queue = VecDeque::from([start.clone()]);
+        let mut complete = true;
+        while let Some(u) = queue.pop_front() {
+            for v in (self.successors)(&u) {
+                if !graph.contains_vertex(&v) {
+                    if graph.order() == limit {
+                        complete = false;
+                        continue;
+                    }
+                    let _ = graph.add_vertex(v.clone());
+                    queue.push_back(v.clone());
+                }
+                let _ = graph.add_edge(&u, &v);
+            }
+        }
+        Partial {
+            value: graph,
+            complete,
+        }
+    }
+}
+
+/// Follows `parent` from `end` back to the first vertex and returns the path in
+/// order.
+fn trace_back<V: Clone>(vertices: &[V], parent: &[usize], end: usize) -> Vec<V> {
+    let mut path = Vec::new();
+    let mut current = end;
+    while current != usize::MAX {
+        path.push(vertices[current].clone());
+        current = parent[current];
+    }
+    path.reverse();
+    path
+}
@@ -0,0 +1,404 @@
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+use crate::{
+    error::MutationError,
+    traits::{
+        graph::EdgeList,
+        weighted::{WeightedGraph, WeightedGraphMut},
+    },
+    Graph, GraphError, GraphMut, SimpleGraph,
+};
+
+/// A compact handle to a string stored by an [`Interner`].
+///
+/// Symbols are 4 bytes and `Copy`, so a graph keyed by symbols stores each of
+/// its names once, in the interner, instead of once per neighbor set and edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the position of the string in its interner, in the order the
+    /// strings were first interned.
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Stores every distinct string once and hands out a [`Symbol`] for it.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::graphs::interned::Interner;
+///
+/// let mut interner = Interner::new();
+/// let lisbon = interner.intern("Lisbon");
+/// assert_eq!(interner.intern("Lisbon"), lisbon);
+/// assert_eq!(interner.resolve(lisbon), Some("Lisbon"));
+/// assert_eq!(interner.get("Porto"), None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    strings: Vec<Arc<str>>,
+    symbols: HashMap<Arc<str>, Symbol>,
+}
+
+impl Interner {
+    /// Creates an empty interner.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the symbol of `string`, storing the string if it is new.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u32::MAX` strings have already been interned.
+    pub fn intern(&mut self, string: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(string) {
+            return symbol;
+        }
+        let index = u32::try_from(self.strings.len()).expect("too many strings for Symbol");
+        let symbol = Symbol(index);
+        let shared: Arc<str> = Arc::from(string);
+        self.strings.push(Arc::clone(&shared));
+        self.symbols.insert(shared, symbol);
+        symbol
+    }
+
+    /// Returns the symbol of `string`, if it was interned.
+    #[must_use]
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        self.symbols.get(string).copied()
+    }
+
+    /// Returns the string of a symbol, if it was handed out by this interner.
+    #[must_use]
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.strings.get(symbol.index()).map(AsRef::as_ref)
+    }
+
+    /// Returns the number of interned strings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no string was interned.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+/// A graph whose vertices are names, each stored once.
+///
+/// A [`SimpleGraph<String>`] clones the name of a vertex into every neighbor set
+/// and edge map it appears in. This graph interns the names instead and keys
+/// its [`SimpleGraph`] by [`Symbol`]s, so each name is stored once whatever
+/// the degree of its vertex. The `*_named_*` methods take and return names;
+/// the [`Graph`] traits and the algorithms work on symbols, which
+/// [`InternedGraph::name`] turns back into names.
+///
+/// Names of removed vertices stay interned, so their symbols are never reused
+/// for other names.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{algorithms::traversal::bfs_distances, graphs::interned::InternedGraph};
+///
+/// let mut graph = InternedGraph::new_undirected();
+/// graph.add_named_edge("Alice", "Bob").unwrap();
+/// graph.add_named_edge("Bob", "Carol").unwrap();
+///
+/// let alice = graph.symbol("Alice").unwrap();
+/// let distances = bfs_distances(&graph, &alice).unwrap();
+/// let carol = graph.symbol("Carol").unwrap();
+/// assert_eq!(distances[&carol], 2);
+/// assert_eq!(graph.name(carol), Some("Carol"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct InternedGraph<W = ()>
+where
+    W: Clone + Debug,
+{
+    graph: SimpleGraph<Symbol, W>,
+    interner: Interner,
+}
+
+impl<W> InternedGraph<W>
+where
+    W: Clone + Debug,
+{
+    /// Interns the vertices of a graph keyed by strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::VertexAlreadyExists` if two vertices have the same
+    /// string, which cannot happen for `String` or `&str` vertices.
+    pub fn from_named<S>(graph: &SimpleGraph<S, W>) -> Result<Self, GraphError>
+    where
+        S: AsRef<str> + Eq + std::hash::Hash + Clone + Debug,
+    {
+        let mut interner = Interner::new();
+        let graph = graph.map_vertices(|name| interner.intern(name.as_ref()))?;
+        Ok(Self { graph, interner })
+    }
+
+    /// Builds the equivalent graph keyed by owned names.
+    #[must_use]
+    pub fn to_named(&self) -> SimpleGraph<String, W> {
+        let names: HashMap<&Symbol, String> = self
+            .graph
+            .vertices()
+            .filter_map(|s| Some((s, self.interner.resolve(*s)?.to_owned())))
+            .collect();
+        self.graph.rekeyed(&names)
+    }
+
+    /// Adds a vertex with the given name, if it is not already in the graph.
+    ///
+    /// # Returns
+    ///
+    /// The symbol of the vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u32::MAX` names have already been interned.
+    pub fn add_named_vertex(&mut self, name: &str) -> Symbol {
+        let symbol = self.interner.intern(name);
+        if !self.graph.contains_vertex(&symbol) {
+            let _ = self.graph.add_vertex(symbol);
+        }
+        symbol
+    }
+
+    /// Adds an edge between two named vertices, adding the vertices that are
+    /// not already in the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeAlreadyExists` if the edge already exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u32::MAX` names have already been interned.
+    pub fn add_named_edge(&mut self, u: &str, v: &str) -> Result<(), MutationError> {
+        let (u, v) = (self.add_named_vertex(u), self.add_named_vertex(v));
+        self.graph.add_edge(&u, &v)
+    }
+
+    /// Adds a weighted edge between two named vertices, adding the vertices
+    /// that are not already in the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeAlreadyExists` if the edge already exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u32::MAX` names have already been interned.
+    pub fn add_named_weighted_edge(
+        &mut self,
+        u: &str,
+        v: &str,
+        weight: W,
+    ) -> Result<(), MutationError> {
+        let (u, v) = (self.add_named_vertex(u), self.add_named_vertex(v));
+        self.graph.add_weighted_edge(&u, &v, weight)
+    }
+
+    /// Returns the symbol of the vertex with the given name, if it is in the
+    /// graph.
+    #[must_use]
+    pub fn symbol(&self, name: &str) -> Option<Symbol> {
+        self.interner
+            .get(name)
+            .filter(|symbol| self.graph.contains_vertex(symbol))
+    }
+
+    /// Returns the name of a symbol handed out by this graph.
+    #[must_use]
+    pub fn name(&self, symbol: Symbol) -> Option<&str> {
+        self.interner.resolve(symbol)
+    }
+
+    /// Returns a reference to the graph keyed by symbols.
+    #[must_use]
+    pub fn graph(&self) -> &SimpleGraph<Symbol, W> {
+        &self.graph
+    }
+
+    /// Returns a reference to the interner holding the names.
+    #[must_use]
+    pub fn interner(&self) -> &Interner {
+        &self.interner
+    }
+
+    /// Unwraps the graph keyed by symbols and the interner holding the names.
+    #[must_use]
+    pub fn into_parts(self) -> (SimpleGraph<Symbol, W>, Interner) {
+        (self.graph, self.interner)
+    }
+}
+
+impl InternedGraph<()> {
+    /// Creates an empty directed graph.
+    #[must_use]
+    pub fn new_directed() -> Self {
+        Self {
+            graph: SimpleGraph::new_directed(),
+            interner: Interner::new(),
+        }
+    }
+
+    /// Creates an empty undirected graph.
+    #[must_use]
+    pub fn new_undirected() -> Self {
+        Self {
+            graph: SimpleGraph::new_undirected(),
+            interner: Interner::new(),
+        }
+    }
+}
+
+impl<W> InternedGraph<W>
+where
+    W: Clone + Debug,
+{
+    /// Creates an empty weighted directed graph.
+    #[must_use]
+    pub fn new_weighted_directed() -> Self {
+        Self {
+            graph: SimpleGraph::new_weighted_directed(),
+            interner: Interner::new(),
+        }
+    }
+
+    /// Creates an empty weighted undirected graph.
+    #[must_use]
+    pub fn new_weighted_undirected() -> Self {
+        Self {
+            graph: SimpleGraph::new_weighted_undirected(),
+            interner: Interner::new(),
+        }
+    }
+}
+
+impl<W> Graph for InternedGraph<W>
+where
+    W: Clone + Debug,
+{
+    type Vertex = Symbol;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.graph.vertices()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, W>> {
+        self.graph.neighbors(v)
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.graph.contains_vertex(v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.graph.contains_edge(u, v)
+    }
+
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+
+    fn order(&self) -> usize {
+        self.graph.order()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+}
+
+impl<W> GraphMut for InternedGraph<W>
+where
+    W: Clone + Debug,
+{
+    /// Adds the vertex of a symbol interned by this graph, such as one whose
+    /// vertex was removed.
+    ///
+    /// Returns `MutationError::VertexNotFound` for a symbol this graph never
+    /// handed out, which has no name; use [`InternedGraph::add_named_vertex`]
+    /// to add new names.
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        if self.interner.resolve(vertex).is_none() {
+            return Err(MutationError::VertexNotFound);
+        }
+        self.graph.add_vertex(vertex)
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        self.graph.remove_vertex(vertex)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_edge(u, v)
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.remove_edge(u, v)
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        self.graph.remove_isolated_vertices()
+    }
+}
+
+impl<W> WeightedGraph for InternedGraph<W>
+where
+    W: Clone + Debug,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight> {
+        self.graph.edge_weight(u, v)
+    }
+
+    fn weighted_edges(
+        &self,
+    ) -> impl Iterator<Item = (&Self::Vertex, &Self::Vertex, &Self::Weight)> {
+        self.graph.weighted_edges()
+    }
+}
+
+impl<W> WeightedGraphMut for InternedGraph<W>
+where
+    W: Clone + Debug,
+{
+    fn add_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<(), MutationError> {
+        self.graph.add_weighted_edge(u, v, weight)
+    }
+
+    fn update_edge_weight(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        self.graph.update_edge_weight(u, v, weight)
+    }
+}
@@ -0,0 +1,100 @@
+/// Builds a [`SimpleGraph`](crate::SimpleGraph) from an inline list of edges and
+/// vertices, for fixtures and small examples.
+///
+/// Items are separated by commas:
+///
+/// * `u - v` adds an undirected edge.
+/// * `u -> v` adds an arc. If any arc is present the graph is directed, and
+///   `u - v` then adds the arcs in both directions.
+/// * `[weight = w]` after an edge sets its weight. If any edge has a weight the
+///   graph is a `SimpleGraph<V, W>`, otherwise it is a `SimpleGraph<V>`.
+/// * A lone `u` adds an isolated vertex.
+///
+/// Vertices must be single tokens, such as literals or identifiers; wrap other
+/// expressions in parentheses. Endpoints are created as they appear and repeated
+/// edges are ignored, with the last weight winning.
+///
+/// ```
+/// use kambo_graph::{graph, Graph};
+///
+/// let path = graph! { 1 - 2, 2 - 3, 7 };
+/// assert!(!path.is_directed());
+/// assert_eq!((path.order(), path.size()), (4, 2));
+///
+/// let network = graph! { 1 -> 2 [weight = 1.5], 2 -> 3 [weight = 2.5] };
+/// assert!(network.is_directed() && !network.contains_edge(&2, &1));
+/// assert_eq!(network.edge_weight(&2, &3), Some(&2.5));
+/// ```
+#[macro_export]
+macro_rules! graph {
+    (@munch [$($items:tt)*] [$($directed:tt)*] [$($weighted:tt)*]) => {
+        $crate::graph!(@build [$($items)*] [$($directed)*] [$($weighted)*])
+    };
+    (@munch $items:tt $directed:tt $weighted:tt , $($rest:tt)*) => {
+        $crate::graph!(@munch $items $directed $weighted $($rest)*)
+    };
+    (@munch [$($items:tt)*] [$($directed:tt)*] [$($weighted:tt)*]
+        $u:tt -> $v:tt [weight = $w:expr] , $($rest:tt)*) => {
+        $crate::graph!(@munch [$($items)* (arc $u $v ($w))] [$($directed)* d] [$($weighted)* w] $($rest)*)
+    };
+    (@munch [$($items:tt)*] [$($directed:tt)*] [$($weighted:tt)*]
+        $u:tt - $v:tt [weight = $w:expr] , $($rest:tt)*) => {
+        $crate::graph!(@munch [$($items)* (edge $u $v ($w))] [$($directed)*] [$($weighted)* w] $($rest)*)
+    };
+    (@munch [$($items:tt)*] [$($directed:tt)*] $weighted:tt $u:tt -> $v:tt , $($rest:tt)*) => {
+        $crate::graph!(@munch [$($items)* (arc $u $v)] [$($directed)* d] $weighted $($rest)*)
+    };
+    (@munch [$($items:tt)*] $directed:tt $weighted:tt $u:tt - $v:tt , $($rest:tt)*) => {
+        $crate::graph!(@munch [$($items)* (edge $u $v)] $directed $weighted $($rest)*)
+    };
+    (@munch [$($items:tt)*] $directed:tt $weighted:tt $u:tt , $($rest:tt)*) => {
+        $crate::graph!(@munch [$($items)* (vertex $u)] $directed $weighted $($rest)*)
+    };
+
+    (@build [$($items:tt)*] [] []) => {
+        $crate::graph!(@graph false, (), $($items)*)
+    };
+    (@build [$($items:tt)*] [] [$($weighted:tt)+]) => {
+        $crate::graph!(@graph false, _, $($items)*)
+    };
+    (@build [$($items:tt)*] [$($directed:tt)+] []) => {
+        $crate::graph!(@graph true, (), $($items)*)
+    };
+    (@build [$($items:tt)*] [$($directed:tt)+] [$($weighted:tt)+]) => {
+        $crate::graph!(@graph true, _, $($items)*)
+    };
+
+    (@graph $directed:tt, $weight:ty, $($item:tt)*) => {{
+        #[allow(unused_imports)]
+        use $crate::{traits::weighted::WeightedGraphMut as _, Graph as _, GraphMut as _};
+        let mut graph = if $directed {
+            $crate::SimpleGraph::<_, $weight>::new_weighted_directed()
+        } else {
+            $crate::SimpleGraph::<_, $weight>::new_weighted_undirected()
+        };
+        $($crate::graph!(@item graph, $directed, $item);)*
+        graph
+    }};
+
+    (@item $graph:ident, $directed:tt, (vertex $u:tt)) => {
+        if !$graph.contains_vertex(&$u) {
+            let _ = $graph.add_vertex($u);
+        }
+    };
+    (@item $graph:ident, $directed:tt, (arc $u:tt $v:tt $(($w:expr))?)) => {
+        let _ = $graph.add_edge_with_vertices($u, $v);
+        $(let _ = $graph.update_edge_weight(&$u, &$v, $w);)?
+    };
+    (@item $graph:ident, $directed:tt, (edge $u:tt $v:tt $(($w:expr))?)) => {
+        let _ = $graph.add_edge_with_vertices($u, $v);
+        $(let _ = $graph.update_edge_weight(&$u, &$v, $w);)?
+        if $directed {
+            let _ = $graph.add_edge_with_vertices($v, $u);
+            $(let _ = $graph.update_edge_weight(&$v, &$u, $w);)?
+        }
+    };
+
+    ($($body:tt)*) => {
+        $crate::graph!(@munch [] [] [] $($body)* ,)
+    };
+}
@@ -2,3 +2,62 @@
 ///
 /// This module provides a basic implementation of an undirected or directed simple graph.
 pub mod simple;
+
+/// Wrapper attaching vertex weights to any graph.
+pub mod vertex_weighted;
+
+/// Wrapper attaching edge capacities to any graph, alongside its edge weights.
+pub mod capacitated;
+
+/// Fluent builder for constructing simple graphs.
+pub mod builder;
+
+/// Bitset vertex sets over a numbering of a graph's vertices.
+pub mod vertex_set;
+
+/// Dense bitset graphs with at most 128 vertices.
+pub mod small;
+
+/// Dense bitset graphs for graphs with up to a few thousand vertices.
+pub mod bit;
+
+/// Graphs over stable integer handles to vertex payloads stored once.
+pub mod stable;
+
+/// Graphs over interned string names, storing every name once.
+pub mod interned;
+
+/// Graphs recording their mutations for undo, checkpoints and rollback.
+pub mod versioned;
+
+/// Bipartite graphs with separately typed left and right vertices.
+pub mod bipartite;
+
+/// Hypergraphs, whose hyperedges join any number of vertices.
+pub mod hypergraph;
+
+/// Graphs whose edges are active during time intervals.
+pub mod temporal;
+
+/// Graphs generated lazily from a successor function, with searches over them.
+pub mod implicit;
+
+/// Ordered series of graph snapshots with per-step metrics and diffs.
+pub mod series;
+
+/// Rooted trees and forests with lowest common ancestor queries.
+pub mod tree;
+
+/// Atomic batches of graph mutations.
+pub mod transaction;
+
+/// Views of graphs hiding the vertices and edges that fail predicates.
+pub mod filtered;
+
+/// Adapters running the algorithms of `petgraph` over the graphs of this crate.
+#[cfg(feature = "petgraph")]
+#[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+pub mod petgraph_view;
+
+/// The `graph!` macro for inline graph construction.
+mod macros;
@@ -0,0 +1,297 @@
+use std::{
+    cell::OnceCell,
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use petgraph::{
+    visit::{
+        Data, EdgeCount, EdgeRef, GraphBase, IntoEdgeReferences, IntoEdges, IntoEdgesDirected,
+        IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences,
+        NodeCompactIndexable, NodeCount, NodeIndexable, Visitable,
+    },
+    Direction,
+};
+
+use crate::{traits::weighted::WeightedGraph, Graph};
+
+/// The type of the iterators over node identifiers returned by a
+/// [`PetgraphView`].
+type Nodes<'a> = Box<dyn Iterator<Item = usize> + 'a>;
+
+/// The type of the iterators over edge references returned by a
+/// [`PetgraphView`].
+type Edges<'a, 'g, W> = Box<dyn Iterator<Item = PetgraphEdge<'g, W>> + 'a>;
+
+/// A view of a graph implementing the visit traits of `petgraph`, so its
+/// algorithms run over the graph without copying it.
+///
+/// The vertices are numbered from `0` to `order - 1` when the view is created,
+/// and these numbers are the node identifiers seen by `petgraph`; an edge is
+/// identified by the pair of its endpoints. [`PetgraphView::vertex`] and
+/// [`PetgraphView::node_id`] translate between the two. Building the view takes
+/// `O(n)` time and the neighbors are read from the underlying graph on every
+/// query. The predecessors of the vertices of a directed graph, needed for
+/// incoming neighbors and edges, are collected on first use.
+///
+/// The node weights are the vertices and, for weighted graphs, the edge weights
+/// are `Option<&W>`, since an edge may have no stored weight. The directedness of
+/// a graph is only known at run time, so `GraphProp` is not implemented and the
+/// few algorithms requiring it are unavailable.
+///
+/// ```
+/// use kambo_graph::{graph, graphs::petgraph_view::PetgraphView};
+/// use petgraph::{algo::{dijkstra, tarjan_scc}, visit::EdgeRef};
+///
+/// let graph = graph! { 1 -> 2 [weight = 4], 2 -> 3 [weight = 1], 3 -> 2 [weight = 2], 1 -> 3 [weight = 1] };
+/// let view = PetgraphView::new(&graph);
+///
+/// let start = view.node_id(&1).unwrap();
+/// let distances = dijkstra(&view, start, None, |e| *e.weight().unwrap());
+/// assert_eq!(distances[&view.node_id(&2).unwrap()], 3);
+///
+/// let components = tarjan_scc(&view);
+/// assert_eq!(components.len(), 2);
+/// assert!(components.iter().any(|c| c.len() == 2 && !c.contains(&start)));
+/// ```
+#[derive(Debug)]
+pub struct PetgraphView<'g, G: Graph> {
+    graph: &'g G,
+    vertices: Vec<&'g G::Vertex>,
+    index: HashMap<&'g G::Vertex, usize>,
+    predecessors: OnceCell<Vec<Vec<usize>>>,
+}
+
+impl<'g, G: Graph> PetgraphView<'g, G> {
+    /// Creates a view of `graph`, numbering its vertices.
+    #[must_use]
+    pub fn new(graph: &'g G) -> Self {
+        let vertices: Vec<&G::Vertex> = graph.vertices().collect();
+        let index = vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        Self {
+            graph,
+            vertices,
+            index,
+            predecessors: OnceCell::new(),
+        }
+    }
+
+    /// Returns the underlying graph.
+    #[must_use]
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+
+    /// Returns the vertex with node identifier `id`, or `None` if there is no
+    /// such node.
+    #[must_use]
+    pub fn vertex(&self, id: usize) -> Option<&'g G::Vertex> {
+        self.vertices.get(id).copied()
+    }
+
+    /// Returns the node identifier of vertex `v`, or `None` if the graph does
+    /// not contain it.
+    #[must_use]
+    pub fn node_id(&self, v: &G::Vertex) -> Option<usize> {
+        self.index.get(v).copied()
+    }
+
+    /// Returns the node identifiers of the successors of node `id`, or of its
+    /// neighbors in an undirected graph.
+    fn successors(&self, id: usize) -> Nodes<'_> {
+        let neighbors = self.graph.neighbors(self.vertices[id]);
+        Box::new(neighbors.into_iter().flatten().map(|w| self.index[w]))
+    }
+
+    /// Returns the node identifiers of the predecessors of node `id`, or of its
+    /// neighbors in an undirected graph.
+    fn predecessors(&self, id: usize) -> Nodes<'_> {
+        if !self.graph.is_directed() {
+            return self.successors(id);
+        }
+        let predecessors = self.predecessors.get_or_init(|| {
+            let mut predecessors = vec![Vec::new(); self.vertices.len()];
+            for u in 0..self.vertices.len() {
+                for v in self.successors(u) {
+                    predecessors[v].push(u);
+                }
+            }
+            predecessors
+        });
+        Box::new(predecessors[id].iter().copied())
+    }
+}
+
+/// An edge of a [`PetgraphView`], with its endpoints as node identifiers and
+/// its weight, if it has one.
+#[derive(Debug)]
+pub struct PetgraphEdge<'g, W> {
+    source: usize,
+    target: usize,
+    weight: Option<&'g W>,
+}
+
+impl<W> Clone for PetgraphEdge<'_, W> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<W> Copy for PetgraphEdge<'_, W> {}
+
+impl<'g, W> EdgeRef for PetgraphEdge<'g, W> {
+    type NodeId = usize;
+    type EdgeId = (usize, usize);
+    type Weight = Option<&'g W>;
+
+    fn source(&self) -> usize {
+        self.source
+    }
+
+    fn target(&self) -> usize {
+        self.target
+    }
+
+    #[allow(clippy::ref_option_ref)]
+    fn weight(&self) -> &Option<&'g W> {
+        &self.weight
+    }
+
+    fn id(&self) -> (usize, usize) {
+        (self.source, self.target)
+    }
+}
+
+impl<G: Graph> GraphBase for PetgraphView<'_, G> {
+    type NodeId = usize;
+    type EdgeId = (usize, usize);
+}
+
+impl<'g, G: WeightedGraph> Data for PetgraphView<'g, G> {
+    type NodeWeight = G::Vertex;
+    type EdgeWeight = Option<&'g G::Weight>;
+}
+
+impl<G: Graph> NodeCount for PetgraphView<'_, G> {
+    fn node_count(&self) -> usize {
+        self.vertices.len()
+    }
+}
+
+impl<G: Graph> EdgeCount for PetgraphView<'_, G> {
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+}
+
+impl<G: Graph> NodeIndexable for PetgraphView<'_, G> {
+    fn node_bound(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn to_index(&self, id: usize) -> usize {
+        id
+    }
+
+    fn from_index(&self, i: usize) -> usize {
+        i
+    }
+}
+
+impl<G: Graph> NodeCompactIndexable for PetgraphView<'_, G> {}
+
+impl<G: Graph> Visitable for PetgraphView<'_, G> {
+    type Map = HashSet<usize>;
+
+    fn visit_map(&self) -> Self::Map {
+        HashSet::with_capacity(self.vertices.len())
+    }
+
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+    }
+}
+
+impl<'a, G: Graph> IntoNeighbors for &'a PetgraphView<'_, G> {
+    type Neighbors = Nodes<'a>;
+
+    fn neighbors(self, id: usize) -> Self::Neighbors {
+        self.successors(id)
+    }
+}
+
+impl<'a, G: Graph> IntoNeighborsDirected for &'a PetgraphView<'_, G> {
+    type NeighborsDirected = Nodes<'a>;
+
+    fn neighbors_directed(self, id: usize, direction: Direction) -> Self::NeighborsDirected {
+        match direction {
+            Direction::Outgoing => self.successors(id),
+            Direction::Incoming => self.predecessors(id),
+        }
+    }
+}
+
+impl<G: Graph> IntoNodeIdentifiers for &PetgraphView<'_, G> {
+    type NodeIdentifiers = Range<usize>;
+
+    fn node_identifiers(self) -> Range<usize> {
+        0..self.vertices.len()
+    }
+}
+
+impl<'a, 'g, G: WeightedGraph> IntoNodeReferences for &'a PetgraphView<'g, G> {
+    type NodeRef = (usize, &'g G::Vertex);
+    type NodeReferences = Box<dyn Iterator<Item = (usize, &'g G::Vertex)> + 'a>;
+
+    fn node_references(self) -> Self::NodeReferences {
+        Box::new(self.vertices.iter().copied().enumerate())
+    }
+}
+
+impl<'a, 'g, G: WeightedGraph> IntoEdgeReferences for &'a PetgraphView<'g, G> {
+    type EdgeRef = PetgraphEdge<'g, G::Weight>;
+    type EdgeReferences = Edges<'a, 'g, G::Weight>;
+
+    /// Lists every edge once, an undirected edge from its endpoint with the
+    /// smaller node identifier.
+    fn edge_references(self) -> Self::EdgeReferences {
+        let directed = self.graph.is_directed();
+        Box::new((0..self.vertices.len()).flat_map(move |u| {
+            self.edges(u)
+                .filter(move |edge| directed || u <= edge.target)
+        }))
+    }
+}
+
+impl<'a, 'g, G: WeightedGraph> IntoEdges for &'a PetgraphView<'g, G> {
+    type Edges = Edges<'a, 'g, G::Weight>;
+
+    fn edges(self, id: usize) -> Self::Edges {
+        self.edges_directed(id, Direction::Outgoing)
+    }
+}
+
+impl<'a, 'g, G: WeightedGraph> IntoEdgesDirected for &'a PetgraphView<'g, G> {
+    type EdgesDirected = Edges<'a, 'g, G::Weight>;
+
+    /// Lists the edges leaving node `id`, or those entering it with `id` as
+    /// their target.
+    fn edges_directed(self, id: usize, direction: Direction) -> Self::EdgesDirected {
+        let graph = self.graph;
+        let vertices = &self.vertices;
+        match direction {
+            Direction::Outgoing => Box::new(self.successors(id).map(move |target| PetgraphEdge {
+                source: id,
+                target,
+                weight: graph.edge_weight(vertices[id], vertices[target]),
+            })),
+            Direction::Incoming => {
+                Box::new(self.predecessors(id).map(move |source| PetgraphEdge {
+                    source,
+                    target: id,
+                    weight: graph.edge_weight(vertices[source], vertices[id]),
+                }))
+            }
+        }
+    }
+}
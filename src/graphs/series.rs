@@ -0,0 +1,287 @@
+use std::{collections::HashSet, fmt::Debug};
+
+use crate::{
+    algorithms::invariants::{InvariantMap, InvariantRegistry, InvariantValue},
+    traits::graph::EdgeList,
+    Graph, GraphError,
+};
+
+/// The metrics computed by [`GraphSeries::metrics`]: the order, the size, the
+/// number of connected components and the density of every snapshot.
+pub const SERIES_METRICS: [&str; 4] = ["order", "size", "components", "density"];
+
+/// The changes between two graphs, as computed by [`diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphDiff<V> {
+    /// The vertices of the second graph missing from the first.
+    pub added_vertices: Vec<V>,
+    /// The vertices of the first graph missing from the second.
+    pub removed_vertices: Vec<V>,
+    /// The edges of the second graph missing from the first.
+    pub added_edges: EdgeList<V>,
+    /// The edges of the first graph missing from the second.
+    pub removed_edges: EdgeList<V>,
+}
+
+impl<V> GraphDiff<V> {
+    /// Returns `true` if the two graphs have the same vertices and edges.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_vertices.is_empty()
+            && self.removed_vertices.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+
+    /// Returns the number of added and removed vertices and edges.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.added_vertices.len()
+            + self.removed_vertices.len()
+            + self.added_edges.len()
+            + self.removed_edges.len()
+    }
+}
+
+/// Computes the vertices and edges added and removed from `before` to `after`.
+///
+/// Each undirected edge is listed once, in an unspecified orientation. Edge
+/// weights are not compared.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{graph, graphs::series::diff};
+///
+/// let before = graph! { 1 - 2, 2 - 3 };
+/// let after = graph! { 1 - 2, 2 - 4 };
+/// let changes = diff(&before, &after);
+/// assert_eq!(changes.added_vertices, vec![4]);
+/// assert_eq!(changes.removed_vertices, vec![3]);
+/// assert_eq!(changes.added_edges.len(), 1);
+/// assert_eq!(changes.removed_edges.len(), 1);
+/// ```
+#[must_use]
+pub fn diff<G, H>(before: &G, after: &H) -> GraphDiff<G::Vertex>
+where
+    G: Graph,
+    H: Graph<Vertex = G::Vertex>,
+{
+    GraphDiff {
+        added_vertices: missing_vertices(after, before),
+        removed_vertices: missing_vertices(before, after),
+        added_edges: missing_edges(after, before),
+        removed_edges: missing_edges(before, after),
+    }
+}
+
+/// Returns the vertices of `graph` that `other` lacks.
+fn missing_vertices<G, H>(graph: &G, other: &H) -> Vec<G::Vertex>
+where
+    G: Graph,
+    H: Graph<Vertex = G::Vertex>,
+{
+    graph
+        .vertices()
+        .filter(|v| !other.contains_vertex(v))
+        .cloned()
+        .collect()
+}
+
+/// Returns the edges of `graph` that `other` lacks, each undirected edge once.
+fn missing_edges<G, H>(graph: &G, other: &H) -> EdgeList<G::Vertex>
+where
+    G: Graph,
+    H: Graph<Vertex = G::Vertex>,
+{
+    let mut visited: HashSet<&G::Vertex> = HashSet::new();
+    let mut edges = Vec::new();
+    for u in graph.vertices() {
+        visited.insert(u);
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if !graph.is_directed() && u != v && visited.contains(v) {
+                continue;
+            }
+            if !other.contains_edge(u, v) {
+                edges.push((u.clone(), v.clone()));
+            }
+        }
+    }
+    edges
+}
+
+/// An ordered series of graph snapshots, such as the yearly states of a
+/// collaboration network, with the bookkeeping of longitudinal studies.
+///
+/// Every snapshot carries a label, typically a timestamp, and labels must
+/// strictly increase along the series. The series tracks metrics per step with
+/// the [invariant registry](InvariantRegistry) and summarizes the changes from
+/// each snapshot to the next with [`GraphDiff`].
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::invariants::InvariantValue,
+///     graph,
+///     graphs::series::GraphSeries,
+/// };
+///
+/// let mut series = GraphSeries::new();
+/// series.push(2021, graph! { 1 - 2, 3 - 4 }).unwrap();
+/// series.push(2022, graph! { 1 - 2, 2 - 3, 3 - 4 }).unwrap();
+/// assert!(series.push(2020, graph! { 1 - 2 }).is_err());
+///
+/// let components = series.metric("components").unwrap();
+/// assert_eq!(components, vec![InvariantValue::Integer(2), InvariantValue::Integer(1)]);
+///
+/// let changes = series.changes();
+/// assert_eq!(changes[0].added_edges.len(), 1);
+/// assert!(changes[0].removed_edges.is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct GraphSeries<T, G> {
+    snapshots: Vec<(T, G)>,
+}
+
+impl<T, G> Default for GraphSeries<T, G> {
+    fn default() -> Self {
+        Self {
+            snapshots: Vec::new(),
+        }
+    }
+}
+
+impl<T, G> GraphSeries<T, G>
+where
+    T: Ord + Debug,
+    G: Graph,
+{
+    /// Creates an empty series.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a snapshot at the end of the series.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::InvalidOperation` if `label` is not greater than the
+    /// label of the last snapshot. The series is left unchanged.
+    pub fn push(&mut self, label: T, graph: G) -> Result<(), GraphError> {
+        if let Some((last, _)) = self.snapshots.last() {
+            if label <= *last {
+                return Err(GraphError::InvalidOperation(format!(
+                    "Snapshot {label:?} does not come after snapshot {last:?}"
+                )));
+            }
+        }
+        self.snapshots.push((label, graph));
+        Ok(())
+    }
+
+    /// Returns the number of snapshots.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns `true` if the series has no snapshots.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Returns the snapshot at position `index` with its label, or `None` if
+    /// the series is shorter.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<(&T, &G)> {
+        self.snapshots
+            .get(index)
+            .map(|(label, graph)| (label, graph))
+    }
+
+    /// Returns the snapshot with the given label, or `None` if there is none.
+    #[must_use]
+    pub fn at(&self, label: &T) -> Option<&G> {
+        let index = self
+            .snapshots
+            .binary_search_by(|(other, _)| other.cmp(label))
+            .ok()?;
+        Some(&self.snapshots[index].1)
+    }
+
+    /// Returns an iterator over the snapshots and their labels, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &G)> {
+        self.snapshots.iter().map(|(label, graph)| (label, graph))
+    }
+
+    /// Computes the [`SERIES_METRICS`] of every snapshot.
+    ///
+    /// # Returns
+    ///
+    /// The metrics of every snapshot, in order.
+    #[must_use]
+    pub fn metrics(&self) -> Vec<InvariantMap> {
+        let registry = InvariantRegistry::with_builtins();
+        self.snapshots
+            .iter()
+            .map(|(_, graph)| registry.compute(graph, &SERIES_METRICS).unwrap_or_default())
+            .collect()
+    }
+
+    /// Computes the named invariants of every snapshot with `registry`, which
+    /// may hold custom invariants.
+    ///
+    /// # Returns
+    ///
+    /// The invariants of every snapshot, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` if a name is not registered.
+    pub fn metrics_with(
+        &self,
+        registry: &InvariantRegistry<'_, G>,
+        names: &[&str],
+    ) -> Result<Vec<InvariantMap>, GraphError> {
+        self.snapshots
+            .iter()
+            .map(|(_, graph)| registry.compute(graph, names))
+            .collect()
+    }
+
+    /// Computes one built-in invariant of every snapshot.
+    ///
+    /// # Returns
+    ///
+    /// The value of the invariant for every snapshot, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` if `name` is not a built-in
+    /// invariant.
+    pub fn metric(&self, name: &str) -> Result<Vec<InvariantValue>, GraphError> {
+        let registry = InvariantRegistry::with_builtins();
+        self.snapshots
+            .iter()
+            .map(|(_, graph)| {
+                let mut values = registry.compute(graph, &[name])?;
+                Ok(values.remove(name).unwrap_or(InvariantValue::Undefined))
+            })
+            .collect()
+    }
+
+    /// Computes the changes from every snapshot to the next one.
+    ///
+    /// # Returns
+    ///
+    /// One [`GraphDiff`] per consecutive pair of snapshots, so one fewer than
+    /// the snapshots.
+    #[must_use]
+    pub fn changes(&self) -> Vec<GraphDiff<G::Vertex>> {
+        self.snapshots
+            .windows(2)
+            .map(|pair| diff(&pair[0].1, &pair[1].1))
+            .collect()
+    }
+}
@@ -1,24 +1,40 @@
 use std::{
-    collections::{HashMap, HashSet},
-    fmt::Debug,
+    borrow::Borrow,
+    collections::{hash_set, HashMap, HashSet},
+    error::Error,
+    fmt::{self, Debug, Display},
     hash::Hash,
+    str::FromStr,
 };
 
 use crate::{
-    traits::weighted::{WeightedGraph, WeightedGraphMut},
-    Graph, GraphMut,
+    error::{AlgorithmError, MutationError},
+    graphs::vertex_set::VertexIndex,
+    traits::{
+        graph::EdgeList,
+        weighted::{WeightedGraph, WeightedGraphMut},
+    },
+    utils::{display::fmt_adjacency_list, edge_list::EdgeListReader},
+    Graph, GraphError, GraphMut,
 };
 
 /// Represents a simple graph using an adjacency list (no self-loops or multiple edges)
-#[derive(Clone, Debug)]
+///
+/// Two graphs compare equal when they have the same directedness, vertices, edges
+/// and edge weights, regardless of insertion order.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SimpleGraph<V, W = ()>
 where
     V: Eq + Hash + Clone + Debug,
     W: Clone + Debug,
 {
     vertices: HashMap<V, HashSet<V>>,
-    edges: HashMap<(V, V), W>,
+    /// Edge weights indexed by source and then target, so lookups borrow the
+    /// vertices instead of building an owned key. Empty inner maps are removed.
+    edges: HashMap<V, HashMap<V, W>>,
     directed: bool,
+    /// Number of edges, kept up to date so `edge_count` is O(1).
+    edge_count: usize,
 }
 
 impl<V, W> SimpleGraph<V, W>
@@ -36,76 +52,101 @@ where
             vertices: HashMap::new(),
             edges: HashMap::new(),
             directed,
+            edge_count: 0,
         }
     }
 }
 
-impl<V> Graph for SimpleGraph<V, ()>
+impl<V, W> Graph for SimpleGraph<V, W>
 where
     V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
 {
     type Vertex = V;
 
     fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
-        Box::new(self.vertices.keys())
+        self.vertices.keys()
     }
 
-    fn neighbors(&self, v: &Self::Vertex) -> Option<Box<dyn Iterator<Item = &Self::Vertex> + '_>> {
-        self.vertices.get(v).map(|neighbors| {
-            Box::new(neighbors.iter()) as Box<dyn Iterator<Item = &Self::Vertex> + '_>
-        })
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, V, W>> {
+        SimpleGraph::neighbors(self, v)
     }
 
     fn contains_vertex(&self, v: &Self::Vertex) -> bool {
-        self.vertices.contains_key(v)
+        SimpleGraph::contains_vertex(self, v)
     }
 
     fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
-        self.edges.contains_key(&(u.clone(), v.clone()))
+        SimpleGraph::contains_edge(self, u, v)
     }
 
     fn is_directed(&self) -> bool {
         self.directed
     }
+
+    fn order(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn size(&self) -> usize {
+        self.edge_count
+    }
+
+    fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+        SimpleGraph::degree(self, v)
+    }
 }
 
-impl<V> GraphMut for SimpleGraph<V, ()>
+impl<V, W> GraphMut for SimpleGraph<V, W>
 where
     V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
 {
-    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), crate::GraphError> {
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
         if self.contains_vertex(&vertex) {
-            Err(crate::GraphError::EdgeAlreadyExists)
+            Err(MutationError::VertexAlreadyExists)
         } else {
             self.vertices.insert(vertex, HashSet::new());
             Ok(())
         }
     }
 
-    fn remove_vertex(&mut self, vertex: &Self::Vertex) -> Result<(), crate::GraphError> {
-        if !self.contains_vertex(vertex) {
-            return Err(crate::GraphError::VertexNotFound);
-        }
-
-        let vertex_clone = vertex.clone();
-        self.vertices.remove(vertex);
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        let Some(targets) = self.vertices.remove(vertex) else {
+            return Err(MutationError::VertexNotFound);
+        };
 
-        self.edges.retain(|(u, v), ()| u != vertex && v != vertex);
+        self.edges.remove(vertex);
+        self.edges.retain(|_, targets| {
+            targets.remove(vertex);
+            !targets.is_empty()
+        });
 
-        for neighbors in self.vertices.values_mut() {
-            neighbors.remove(&vertex_clone);
+        let mut removed: Vec<(V, V)> = targets.into_iter().map(|w| (vertex.clone(), w)).collect();
+        for (u, neighbors) in &mut self.vertices {
+            // In undirected graphs every incident edge is also in the vertex's own set.
+            if neighbors.remove(vertex) && self.directed {
+                removed.push((u.clone(), vertex.clone()));
+            }
         }
+        self.edge_count -= removed.len();
 
-        Ok(())
+        Ok(removed)
     }
 
-    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), crate::GraphError> {
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
         if !self.contains_vertex(u) || !self.contains_vertex(v) {
-            return Err(crate::GraphError::VertexNotFound);
+            return Err(MutationError::VertexNotFound);
         }
 
         if self.contains_edge(u, v) {
-            return Err(crate::GraphError::EdgeAlreadyExists);
+            return Err(MutationError::EdgeAlreadyExists);
         }
 
         // Se o grafo não for dirigido ele adiciona a aresta u em v
@@ -113,26 +154,28 @@ where
         if !self.directed {
             self.vertices.get_mut(v).unwrap().insert(u.clone());
         }
+        self.edge_count += 1;
 
         Ok(())
     }
 
-    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), crate::GraphError> {
-        if !self.contains_edge(u, v) {
-            return Err(crate::GraphError::EdgeNotFound);
-        }
-
-        self.edges.remove(&(u.clone(), v.clone()));
-
-        self.vertices.get_mut(u).unwrap().remove(v);
-        if !self.directed {
-            self.vertices.get_mut(v).unwrap().remove(u);
+    fn add_edge_with_vertices(
+        &mut self,
+        u: Self::Vertex,
+        v: Self::Vertex,
+    ) -> Result<(), MutationError> {
+        if self.insert_edge_with_vertices(u, v) {
+            Ok(())
+        } else {
+            Err(MutationError::EdgeAlreadyExists)
         }
+    }
 
-        Ok(())
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.take_edge(u, v).map(|_| ())
     }
 
-    fn remove_isolated_vertices(&mut self) -> Result<(), crate::GraphError> {
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
         let isolated_vertices: Vec<V> = self
             .vertices
             .iter()
@@ -140,16 +183,12 @@ where
             .map(|(v, _)| v.clone())
             .collect();
 
-        if isolated_vertices.is_empty() {
-            return Err(crate::GraphError::VertexNotFound);
-        }
-
         // Remove cada vértice isolado
         for vertex in &isolated_vertices {
             self.remove_vertex(vertex)?;
         }
 
-        Ok(())
+        Ok(isolated_vertices)
     }
 }
 
@@ -162,7 +201,23 @@ where
     type Weight = W;
 
     fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight> {
-        self.edges.get(&(u.clone(), v.clone()))
+        SimpleGraph::edge_weight(self, u, v)
+    }
+
+    fn weighted_edges(
+        &self,
+    ) -> impl Iterator<Item = (&Self::Vertex, &Self::Vertex, &Self::Weight)> {
+        let (vertices, directed) = (&self.vertices, self.directed);
+        self.edges.iter().flat_map(move |(u, targets)| {
+            targets.iter().filter_map(move |(v, weight)| {
+                // Undirected weights are stored under both endpoints; keep the
+                // copy at the endpoint stored first, as `par_edges` does.
+                let (u, _) = vertices.get_key_value(u)?;
+                let (v, _) = vertices.get_key_value(v)?;
+                (directed || std::ptr::from_ref(u) <= std::ptr::from_ref(v))
+                    .then_some((u, v, weight))
+            })
+        })
     }
 }
 
@@ -172,27 +227,52 @@ where
     W: Clone + Debug,
     SimpleGraph<V, W>: GraphMut<Vertex = V>,
 {
-    fn set_edge_weight(
+    fn add_weighted_edge(
         &mut self,
         u: &Self::Vertex,
         v: &Self::Vertex,
         weight: Self::Weight,
-    ) -> Result<(), crate::GraphError> {
-        if !self.contains_vertex(u) || !self.contains_vertex(v) {
-            return Err(crate::GraphError::VertexNotFound);
+    ) -> Result<(), MutationError> {
+        self.add_edge(u, v)?;
+        if !self.directed && u != v {
+            self.insert_weight(v.clone(), u.clone(), weight.clone());
         }
+        self.insert_weight(u.clone(), v.clone(), weight);
+        Ok(())
+    }
 
-        self.vertices.get_mut(u).unwrap().insert(v.clone());
-        if !self.directed {
-            self.vertices.get_mut(v).unwrap().insert(u.clone());
+    fn update_edge_weight(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        if !self.contains_edge(u, v) {
+            return Err(MutationError::EdgeNotFound);
         }
-
-        self.edges.insert((u.clone(), v.clone()), weight.clone());
-        if !self.directed {
-            self.edges.insert((v.clone(), u.clone()), weight.clone());
+        if !self.directed && u != v {
+            self.insert_weight(v.clone(), u.clone(), weight.clone());
         }
+        Ok(self.insert_weight(u.clone(), v.clone(), weight))
+    }
 
-        Ok(())
+    fn remove_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        self.take_edge(u, v)
+    }
+}
+
+/// Prints the graph as a sorted adjacency list; see [`fmt_adjacency_list`].
+impl<V, W> Display for SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug + Ord,
+    W: Clone + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_adjacency_list(self, f)
     }
 }
 
@@ -206,6 +286,7 @@ where
             vertices: HashMap::new(),
             edges: HashMap::new(),
             directed: false,
+            edge_count: 0,
         }
     }
 }
@@ -222,6 +303,63 @@ pub type WeightedDirectedGraph<V, W> = SimpleGraph<V, W>;
 /// Type alias for an undirected graph with weights of type W
 pub type WeightedUndirectedGraph<V, W> = SimpleGraph<V, W>;
 
+/// Collects `(u, v)` pairs into an undirected graph, as
+/// [`SimpleGraph::default`] would create. Use [`Extend`] on an empty directed
+/// graph to collect arcs.
+impl<V, W> FromIterator<(V, V)> for SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    fn from_iter<I: IntoIterator<Item = (V, V)>>(iter: I) -> Self {
+        let mut graph = Self::default();
+        graph.extend(iter);
+        graph
+    }
+}
+
+/// Collects `(u, v, weight)` tuples into an undirected graph. If an edge appears
+/// more than once, the last weight wins.
+impl<V, W> FromIterator<(V, V, W)> for SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    fn from_iter<I: IntoIterator<Item = (V, V, W)>>(iter: I) -> Self {
+        let mut graph = Self::default();
+        graph.extend(iter);
+        graph
+    }
+}
+
+/// Adds `(u, v)` pairs as edges, creating vertices as they appear. Existing edges
+/// are left unchanged.
+impl<V, W> Extend<(V, V)> for SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    fn extend<I: IntoIterator<Item = (V, V)>>(&mut self, iter: I) {
+        for (u, v) in iter {
+            self.insert_edge_with_vertices(u, v);
+        }
+    }
+}
+
+/// Adds `(u, v, weight)` tuples as weighted edges, creating vertices as they
+/// appear. The weight of an existing edge is overwritten.
+impl<V, W> Extend<(V, V, W)> for SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    fn extend<I: IntoIterator<Item = (V, V, W)>>(&mut self, iter: I) {
+        for (u, v, weight) in iter {
+            self.insert_weighted_edge_with_vertices(u, v, weight);
+        }
+    }
+}
+
 impl<V> SimpleGraph<V, ()>
 where
     V: Eq + Hash + Clone + Debug,
@@ -264,4 +402,822 @@ where
     pub fn new_weighted_undirected() -> Self {
         SimpleGraph::new(false)
     }
+
+    /// Creates an empty graph with room for the given number of vertices and
+    /// edges, so loading them does not rehash the graph's tables.
+    ///
+    /// The vertex table is sized for `vertices` and, for weighted graphs, the
+    /// edge map for every source of the `edges`. Neighbor sets still grow one
+    /// vertex at a time, since their sizes depend on the degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - The number of vertices to make room for.
+    /// * `edges` - The number of edges to make room for.
+    /// * `directed` - `true` for a directed graph, `false` for an undirected graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{Graph, SimpleGraph};
+    ///
+    /// let mut graph = SimpleGraph::<usize, f64>::with_capacity(1000, 5000, false);
+    /// let before = graph.memory_usage().vertex_table;
+    /// graph.extend((0..1000).map(|i| (i, (i + 1) % 1000, 1.0)));
+    /// assert_eq!(graph.memory_usage().vertex_table, before);
+    /// assert_eq!(graph.size(), 1000);
+    /// ```
+    #[must_use]
+    pub fn with_capacity(vertices: usize, edges: usize, directed: bool) -> Self {
+        // Only weighted graphs store weights, under both endpoints of an
+        // undirected edge.
+        let sources = if size_of::<W>() == 0 {
+            0
+        } else if directed {
+            vertices.min(edges)
+        } else {
+            vertices.min(edges.saturating_mul(2))
+        };
+        Self {
+            vertices: HashMap::with_capacity(vertices),
+            edges: HashMap::with_capacity(sources),
+            directed,
+            edge_count: 0,
+        }
+    }
+
+    /// Shrinks the graph's tables to fit their contents, releasing the memory
+    /// left by removals or by a capacity larger than needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{GraphMut, SimpleGraph};
+    ///
+    /// let mut graph = SimpleGraph::<usize>::from_edges((1..1000).map(|i| (0, i)), false);
+    /// for i in 10..1000 {
+    ///     graph.remove_vertex(&i).unwrap();
+    /// }
+    /// let before = graph.memory_usage().total();
+    /// graph.shrink_to_fit();
+    /// assert!(graph.memory_usage().total() < before);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.vertices.shrink_to_fit();
+        for neighbors in self.vertices.values_mut() {
+            neighbors.shrink_to_fit();
+        }
+        self.edges.shrink_to_fit();
+        for targets in self.edges.values_mut() {
+            targets.shrink_to_fit();
+        }
+    }
+}
+
+/// Lookups taking any borrowed form of the vertex type, like [`HashMap::get`].
+///
+/// These shadow the [`Graph`] and [`WeightedGraph`] methods of the same name, so
+/// a `SimpleGraph<String>` can be queried with a `&str` without allocating; see
+/// the [`Graph`] documentation for the limits of this shadowing. Use
+/// [`SimpleGraph::vertex`] to get the stored vertex when an algorithm needs a
+/// `&V`.
+impl<V, W> SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    /// Returns the stored vertex equal to `v`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&V)` - The vertex of the graph equal to `v`.
+    /// * `None` - If `v` is not in the graph.
+    #[must_use]
+    pub fn vertex<Q>(&self, v: &Q) -> Option<&V>
+    where
+        V: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.vertices.get_key_value(v).map(|(vertex, _)| vertex)
+    }
+
+    /// Checks if the graph contains the vertex `v`.
+    #[must_use]
+    pub fn contains_vertex<Q>(&self, v: &Q) -> bool
+    where
+        V: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.vertices.contains_key(v)
+    }
+
+    /// Checks if the graph contains the edge `(u, v)`.
+    #[must_use]
+    pub fn contains_edge<Q>(&self, u: &Q, v: &Q) -> bool
+    where
+        V: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.vertices
+            .get(u)
+            .is_some_and(|neighbors| neighbors.contains(v))
+    }
+
+    /// Returns an iterator over the neighbors of `v`, or `None` if `v` is not in
+    /// the graph.
+    #[must_use]
+    pub fn neighbors<Q>(&self, v: &Q) -> Option<hash_set::Iter<'_, V>>
+    where
+        V: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.vertices.get(v).map(HashSet::iter)
+    }
+
+    /// Returns the degree (out-degree if directed) of `v`, or `None` if `v` is
+    /// not in the graph.
+    #[must_use]
+    pub fn degree<Q>(&self, v: &Q) -> Option<usize>
+    where
+        V: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.vertices.get(v).map(HashSet::len)
+    }
+
+    /// Returns the weight of the edge `(u, v)`, or `None` if the edge has no
+    /// weight.
+    #[must_use]
+    pub fn edge_weight<Q>(&self, u: &Q, v: &Q) -> Option<&W>
+    where
+        V: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.edges.get(u)?.get(v)
+    }
+}
+
+impl<V, W> SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    /// Stores the weight of the arc `(u, v)`, returning the previous one.
+    fn insert_weight(&mut self, u: V, v: V, weight: W) -> Option<W> {
+        self.edges.entry(u).or_default().insert(v, weight)
+    }
+
+    /// Drops the weight of the arc `(u, v)`, returning it if there was one.
+    fn remove_weight(&mut self, u: &V, v: &V) -> Option<W> {
+        let targets = self.edges.get_mut(u)?;
+        let weight = targets.remove(v);
+        if targets.is_empty() {
+            self.edges.remove(u);
+        }
+        weight
+    }
+
+    /// Removes the edge `(u, v)` and returns its weight.
+    fn take_edge(&mut self, u: &V, v: &V) -> Result<Option<W>, MutationError> {
+        if !self.contains_edge(u, v) {
+            return Err(MutationError::EdgeNotFound);
+        }
+
+        let weight = self.remove_weight(u, v);
+        if let Some(neighbors) = self.vertices.get_mut(u) {
+            neighbors.remove(v);
+        }
+        if !self.directed {
+            self.remove_weight(v, u);
+            if let Some(neighbors) = self.vertices.get_mut(v) {
+                neighbors.remove(u);
+            }
+        }
+        self.edge_count -= 1;
+
+        Ok(weight)
+    }
+
+    /// Inserts the edge `(u, v)`, adding missing endpoints first.
+    ///
+    /// Returns `false` if the edge already existed.
+    fn insert_edge_with_vertices(&mut self, u: V, v: V) -> bool {
+        // Inserting the arc (u, v) first reports a new self-loop correctly.
+        let inserted = self
+            .vertices
+            .entry(u.clone())
+            .or_default()
+            .insert(v.clone());
+        let targets = self.vertices.entry(v).or_default();
+        if !self.directed {
+            targets.insert(u);
+        }
+        self.edge_count += usize::from(inserted);
+        inserted
+    }
+
+    /// Inserts the edge `(u, v)` with the given weight, adding missing endpoints
+    /// first. The weight of an existing edge is overwritten.
+    pub(crate) fn insert_weighted_edge_with_vertices(&mut self, u: V, v: V, weight: W) {
+        if !self.directed && u != v {
+            self.insert_weight(v.clone(), u.clone(), weight.clone());
+        }
+        self.insert_weight(u.clone(), v.clone(), weight);
+        self.insert_edge_with_vertices(u, v);
+    }
+
+    /// Builds a graph from `(u, v, weight)` tuples, creating vertices as they
+    /// appear.
+    ///
+    /// If an edge appears more than once, the last weight wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The weighted edges of the graph.
+    /// * `directed` - `true` for a directed graph, `false` for an undirected graph.
+    pub fn from_weighted_edges<I>(edges: I, directed: bool) -> Self
+    where
+        I: IntoIterator<Item = (V, V, W)>,
+    {
+        let mut graph = Self::new(directed);
+        for (u, v, weight) in edges {
+            graph.insert_weighted_edge_with_vertices(u, v, weight);
+        }
+        graph
+    }
+
+    /// Reads a weighted graph from an edge list file, creating vertices as they
+    /// appear.
+    ///
+    /// See [`EdgeListReader`] for the file format. The file is read one line
+    /// at a time, without collecting its edges first. If an edge appears more
+    /// than once, the last weight wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file containing the edge list.
+    /// * `directed` - `true` for a directed graph, `false` for an undirected graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Io` if the file cannot be read, and
+    /// `GraphError::Parse` if it cannot be parsed or a line has no weight.
+    pub fn from_weighted_edge_list_file(path: &str, directed: bool) -> Result<Self, GraphError>
+    where
+        V: FromStr,
+        W: FromStr,
+    {
+        let mut graph = Self::new(directed);
+        let mut edges = EdgeListReader::<V, W, _>::open(path)?;
+        while let Some(edge) = edges.next_weighted() {
+            let (u, v, weight) = edge?;
+            graph.insert_weighted_edge_with_vertices(u, v, weight);
+        }
+        Ok(graph)
+    }
+}
+
+impl<V> SimpleGraph<V, ()>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    /// Builds a graph from `(u, v)` pairs, creating vertices as they appear.
+    ///
+    /// Repeated edges (including the reverse of an undirected edge) are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The edges of the graph.
+    /// * `directed` - `true` for a directed graph, `false` for an undirected graph.
+    pub fn from_edges<I>(edges: I, directed: bool) -> Self
+    where
+        I: IntoIterator<Item = (V, V)>,
+    {
+        let mut graph = Self::new(directed);
+        for (u, v) in edges {
+            graph.insert_edge_with_vertices(u, v);
+        }
+        graph
+    }
+
+    /// Reads a graph from an edge list file, creating vertices as they appear.
+    ///
+    /// See [`EdgeListReader`] for the file format. The file is read one line
+    /// at a time, without collecting its edges first. Weight columns are ignored
+    /// and repeated edges are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file containing the edge list.
+    /// * `directed` - `true` for a directed graph, `false` for an undirected graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Io` if the file cannot be read, and
+    /// `GraphError::Parse` if it cannot be parsed.
+    pub fn from_edge_list_file(path: &str, directed: bool) -> Result<Self, GraphError>
+    where
+        V: FromStr,
+    {
+        let mut graph = Self::new(directed);
+        for edge in EdgeListReader::<V, String, _>::open(path)? {
+            let (u, v, _) = edge?;
+            graph.insert_edge_with_vertices(u, v);
+        }
+        Ok(graph)
+    }
+}
+
+impl<W> SimpleGraph<usize, W>
+where
+    W: Clone + Debug,
+{
+    /// Builds a copy of a graph numbered by [`SimpleGraph::relabel_to_indices`]
+    /// or [`SimpleGraph::relabel_with`] with every number replaced by its vertex
+    /// in `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The vertex of every number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::VertexNotFound` if a vertex of the graph is not a
+    /// number of `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, graphs::vertex_set::VertexIndex, Graph};
+    ///
+    /// let index = VertexIndex::from_vertices(["x", "y", "z"]);
+    /// let named = graph! { 0 - 1, 1 - 2 }.relabel_from_indices(&index).unwrap();
+    /// assert!(named.contains_edge(&"x", &"y"));
+    ///
+    /// assert!(graph! { 0 - 3 }.relabel_from_indices(&index).is_err());
+    /// ```
+    pub fn relabel_from_indices<V>(
+        &self,
+        index: &VertexIndex<V>,
+    ) -> Result<SimpleGraph<V, W>, GraphError>
+    where
+        V: Eq + Hash + Clone + Debug,
+    {
+        let mapped = self
+            .vertices
+            .keys()
+            .map(|i| {
+                Ok((
+                    i,
+                    index
+                        .vertex(*i)
+                        .cloned()
+                        .ok_or(GraphError::VertexNotFound)?,
+                ))
+            })
+            .collect::<Result<HashMap<&usize, V>, GraphError>>()?;
+        Ok(self.rekeyed(&mapped))
+    }
+}
+
+impl<V, W> SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    /// Builds a graph with the same structure and weights, with every vertex
+    /// replaced by `f(vertex)`.
+    ///
+    /// `f` is called once per vertex and must map distinct vertices to distinct
+    /// values, so the edges of the two graphs correspond one to one.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The new value of every vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SimpleGraph<U, W>)` - The re-keyed graph.
+    /// * `Err(GraphError)` - If two vertices are mapped to the same value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::VertexAlreadyExists` if `f` maps two vertices to the
+    /// same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, Graph};
+    ///
+    /// let graph = graph! { "a" - "b" [weight = 1.5], "b" - "c" [weight = 2.0] };
+    /// let keyed = graph.map_vertices(|name| name.to_uppercase()).unwrap();
+    /// assert_eq!(keyed.edge_weight("A", "B"), Some(&1.5));
+    ///
+    /// assert!(graph.map_vertices(|_| 0).is_err());
+    /// ```
+    pub fn map_vertices<U, F>(&self, mut f: F) -> Result<SimpleGraph<U, W>, GraphError>
+    where
+        U: Eq + Hash + Clone + Debug,
+        F: FnMut(&V) -> U,
+    {
+        let mut mapped: HashMap<&V, U> = HashMap::with_capacity(self.vertices.len());
+        let mut values = HashSet::with_capacity(self.vertices.len());
+        for v in self.vertices.keys() {
+            let u = f(v);
+            if !values.insert(u.clone()) {
+                return Err(GraphError::VertexAlreadyExists);
+            }
+            mapped.insert(v, u);
+        }
+        Ok(self.rekeyed(&mapped))
+    }
+
+    /// Builds a copy of the graph with the vertices numbered `0..n` in
+    /// iteration order, together with the numbering.
+    ///
+    /// Numeric algorithms, matrices and most file formats expect vertices
+    /// numbered from zero. The returned [`VertexIndex`] maps numbers back to
+    /// vertices with [`VertexIndex::vertex`] and vertices to numbers with
+    /// [`VertexIndex::index_of`], and [`SimpleGraph::relabel_from_indices`]
+    /// restores the original graph. Use [`SimpleGraph::relabel_with`] to choose
+    /// the numbering, such as sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, Graph};
+    ///
+    /// let graph = graph! { "a" - "b" [weight = 1.5], "b" - "c" [weight = 2.0] };
+    /// let (numbered, index) = graph.relabel_to_indices();
+    /// let (a, b) = (index.index_of(&"a").unwrap(), index.index_of(&"b").unwrap());
+    /// assert_eq!(numbered.edge_weight(&a, &b), Some(&1.5));
+    /// assert!(numbered.vertices().all(|&i| i < 3));
+    ///
+    /// assert_eq!(numbered.relabel_from_indices(&index).unwrap(), graph);
+    /// ```
+    #[must_use]
+    pub fn relabel_to_indices(&self) -> (SimpleGraph<usize, W>, VertexIndex<V>) {
+        let index = VertexIndex::from_vertices(self.vertices.keys().cloned());
+        let mapped: HashMap<&V, usize> = self
+            .vertices
+            .keys()
+            .enumerate()
+            .map(|(i, v)| (v, i))
+            .collect();
+        (self.rekeyed(&mapped), index)
+    }
+
+    /// Builds a copy of the graph with every vertex replaced by its number in
+    /// `index`.
+    ///
+    /// The index may number more vertices than the graph has, so several graphs
+    /// can share one numbering.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The number of every vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::VertexNotFound` if a vertex of the graph is not in
+    /// `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, graphs::vertex_set::VertexIndex, Graph};
+    ///
+    /// let graph = graph! { 30 - 10, 10 - 20 };
+    /// let mut sorted: Vec<i32> = graph.vertices().copied().collect();
+    /// sorted.sort_unstable();
+    /// let numbered = graph.relabel_with(&VertexIndex::from_vertices(sorted)).unwrap();
+    /// assert!(numbered.contains_edge(&0, &1) && numbered.contains_edge(&0, &2));
+    /// ```
+    pub fn relabel_with(
+        &self,
+        index: &VertexIndex<V>,
+    ) -> Result<SimpleGraph<usize, W>, GraphError> {
+        let mapped = self
+            .vertices
+            .keys()
+            .map(|v| Ok((v, index.index_of(v).ok_or(GraphError::VertexNotFound)?)))
+            .collect::<Result<HashMap<&V, usize>, GraphError>>()?;
+        Ok(self.rekeyed(&mapped))
+    }
+
+    /// Builds a copy of the graph with every vertex `v` replaced by
+    /// `mapped[v]`, which must be defined for every vertex and injective.
+    pub(crate) fn rekeyed<U>(&self, mapped: &HashMap<&V, U>) -> SimpleGraph<U, W>
+    where
+        U: Eq + Hash + Clone + Debug,
+    {
+        let vertices = self
+            .vertices
+            .iter()
+            .map(|(v, neighbors)| {
+                let neighbors = neighbors.iter().map(|w| mapped[w].clone()).collect();
+                (mapped[v].clone(), neighbors)
+            })
+            .collect();
+        let edges = self
+            .edges
+            .iter()
+            .map(|(u, targets)| {
+                let targets = targets
+                    .iter()
+                    .map(|(v, weight)| (mapped[v].clone(), weight.clone()))
+                    .collect();
+                (mapped[u].clone(), targets)
+            })
+            .collect();
+        SimpleGraph {
+            vertices,
+            edges,
+            directed: self.directed,
+            edge_count: self.edge_count,
+        }
+    }
+
+    /// Builds a graph with the same vertices and edges, with every edge weight
+    /// replaced by `f(weight)`.
+    ///
+    /// `f` is called once per weighted edge, including undirected ones. Edges
+    /// without a weight stay without one.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The new weight of every edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::graph;
+    ///
+    /// let graph = graph! { 1 - 2 [weight = 1.5], 2 - 3 [weight = 2.25] };
+    /// let scaled = graph.map_weights(|&w: &f64| (w * 100.0).round() as u32);
+    /// assert_eq!(scaled.edge_weight(&3, &2), Some(&225));
+    /// ```
+    pub fn map_weights<X, F>(&self, mut f: F) -> SimpleGraph<V, X>
+    where
+        X: Clone + Debug,
+        F: FnMut(&W) -> X,
+    {
+        let mut mapped = SimpleGraph {
+            vertices: self.vertices.clone(),
+            edges: HashMap::with_capacity(self.edges.len()),
+            directed: self.directed,
+            edge_count: self.edge_count,
+        };
+        for (u, v, weight) in self.weighted_edges() {
+            let weight = f(weight);
+            if !self.directed && u != v {
+                mapped.insert_weight(v.clone(), u.clone(), weight.clone());
+            }
+            mapped.insert_weight(u.clone(), v.clone(), weight);
+        }
+        mapped
+    }
+
+    /// Builds a graph with the same vertices and edges, with every edge weight
+    /// replaced by `f(weight)`, failing on the first weight `f` rejects.
+    ///
+    /// `f` is called once per weighted edge, including undirected ones. Edges
+    /// without a weight stay without one.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The new weight of every edge, or the reason it has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` with an
+    /// [`AlgorithmError::WeightConversion`] naming the edge and its weight, whose
+    /// source is the error returned by `f`, if `f` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{error::AlgorithmError, graph, GraphError};
+    ///
+    /// // Budgets in whole cents, refusing amounts that do not fit in a `u32`.
+    /// let to_cents = |&w: &f64| {
+    ///     let cents = (w * 100.0).round();
+    ///     if (0.0..=f64::from(u32::MAX)).contains(&cents) {
+    ///         Ok(cents as u32)
+    ///     } else {
+    ///         Err(format!("{w} is not a valid budget"))
+    ///     }
+    /// };
+    ///
+    /// let graph = graph! { 1 - 2 [weight = 1.5], 2 - 3 [weight = 2.25] };
+    /// assert_eq!(graph.try_map_weights(to_cents).unwrap().edge_weight(&3, &2), Some(&225));
+    ///
+    /// let graph = graph! { 1 - 2 [weight = -4.0] };
+    /// let error = graph.try_map_weights(to_cents).unwrap_err();
+    /// assert!(error.to_string().contains("-4 is not a valid budget"));
+    ///
+    /// let GraphError::Algorithm(AlgorithmError::WeightConversion { edge, .. }) = error else {
+    ///     panic!()
+    /// };
+    /// let mut endpoints = [edge.0, edge.1];
+    /// endpoints.sort();
+    /// assert_eq!(endpoints, ["1", "2"]);
+    /// ```
+    pub fn try_map_weights<X, E, F>(&self, mut f: F) -> Result<SimpleGraph<V, X>, GraphError>
+    where
+        X: Clone + Debug,
+        E: Into<Box<dyn Error + Send + Sync>>,
+        F: FnMut(&W) -> Result<X, E>,
+    {
+        let mut mapped = SimpleGraph {
+            vertices: self.vertices.clone(),
+            edges: HashMap::with_capacity(self.edges.len()),
+            directed: self.directed,
+            edge_count: self.edge_count,
+        };
+        for (u, v, weight) in self.weighted_edges() {
+            let weight =
+                f(weight).map_err(|e| AlgorithmError::weight_conversion(u, v, weight, e))?;
+            if !self.directed && u != v {
+                mapped.insert_weight(v.clone(), u.clone(), weight.clone());
+            }
+            mapped.insert_weight(u.clone(), v.clone(), weight);
+        }
+        Ok(mapped)
+    }
+
+    /// Converts every edge weight to `X` with [`TryFrom`], failing on the first
+    /// weight that does not convert.
+    ///
+    /// This is [`SimpleGraph::try_map_weights`] with `X::try_from`. Conversions
+    /// without a `TryFrom` implementation, such as from floating-point weights
+    /// to integers, go through `try_map_weights` with an explicit rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Algorithm` with an
+    /// [`AlgorithmError::WeightConversion`] naming the edge and its weight, whose
+    /// source is the conversion error, if a weight does not convert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use kambo_graph::graph;
+    ///
+    /// let graph = graph! { 1 -> 2 [weight = 7i64], 2 -> 3 [weight = 300] };
+    /// let small = graph.convert_weights::<u16>().unwrap();
+    /// assert_eq!(small.edge_weight(&2, &3), Some(&300));
+    ///
+    /// let error = graph.convert_weights::<u8>().unwrap_err();
+    /// assert!(error.to_string().contains("Edge (2, 3) has weight 300"));
+    /// assert!(error.source().unwrap().source().is_some());
+    /// ```
+    pub fn convert_weights<X>(&self) -> Result<SimpleGraph<V, X>, GraphError>
+    where
+        X: TryFrom<W> + Clone + Debug,
+        X::Error: Error + Send + Sync + 'static,
+    {
+        self.try_map_weights(|weight| X::try_from(weight.clone()))
+    }
+}
+
+impl<V, W> SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug,
+    W: Clone + Debug,
+{
+    /// Estimates the heap memory held by the graph's tables.
+    ///
+    /// The estimate follows the layout of the standard library's hash tables:
+    /// one bucket of key and value per slot, plus a control byte per slot and a
+    /// group of trailing control bytes. It counts allocated capacity rather than
+    /// occupied slots, so it shows the memory left behind by removals. Only the
+    /// inline size of vertices and weights is counted; memory they own
+    /// themselves, such as the bytes of a `String`, is not.
+    ///
+    /// # Returns
+    ///
+    /// The bytes held by the vertex table, the neighbor sets and the edge map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::graph;
+    ///
+    /// let graph = graph! { 1 - 2 [weight = 1.5], 2 - 3 [weight = 2.0] };
+    /// let usage = graph.memory_usage();
+    /// assert!(usage.neighbor_sets > 0 && usage.edge_map > 0);
+    /// assert_eq!(
+    ///     usage.total(),
+    ///     usage.vertex_table + usage.neighbor_sets + usage.edge_map
+    /// );
+    /// ```
+    #[must_use]
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let neighbor_sets = self
+            .vertices
+            .values()
+            .map(|neighbors| table_bytes::<V>(neighbors.capacity()))
+            .sum();
+        let edge_map = table_bytes::<(V, HashMap<V, W>)>(self.edges.capacity())
+            + self
+                .edges
+                .values()
+                .map(|targets| table_bytes::<(V, W)>(targets.capacity()))
+                .sum::<usize>();
+        MemoryUsage {
+            vertex_table: table_bytes::<(V, HashSet<V>)>(self.vertices.capacity()),
+            neighbor_sets,
+            edge_map,
+        }
+    }
+}
+
+/// Approximate heap footprint of a [`SimpleGraph`], in bytes, as reported by
+/// [`SimpleGraph::memory_usage`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The table mapping every vertex to its neighbor set.
+    pub vertex_table: usize,
+    /// The neighbor sets of all vertices.
+    pub neighbor_sets: usize,
+    /// The edge weights, indexed by source and then target.
+    pub edge_map: usize,
+}
+
+impl MemoryUsage {
+    /// Returns the bytes of all tables together.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.vertex_table + self.neighbor_sets + self.edge_map
+    }
+}
+
+/// Estimates the allocation of a hash table of `T` entries with the given
+/// capacity, which holds up to 7/8 of its buckets and at least one bucket fewer.
+fn table_bytes<T>(capacity: usize) -> usize {
+    // Matches the group width of the SSE2 implementation of the tables.
+    const GROUP_WIDTH: usize = 16;
+
+    if capacity == 0 {
+        return 0;
+    }
+    let buckets = if capacity < 8 {
+        (capacity + 1).next_power_of_two()
+    } else {
+        (capacity * 8 / 7).next_power_of_two()
+    };
+    buckets * size_of::<T>() + buckets + GROUP_WIDTH
+}
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<V, W> SimpleGraph<V, W>
+where
+    V: Eq + Hash + Clone + Debug + Sync,
+    W: Clone + Debug,
+{
+    /// Returns a parallel iterator over the vertices, splitting the work across
+    /// the rayon thread pool without collecting the vertices first.
+    #[must_use]
+    pub fn par_vertices(&self) -> impl rayon::iter::ParallelIterator<Item = &V> + '_ {
+        use rayon::prelude::*;
+
+        self.vertices.par_iter().map(|(v, _)| v)
+    }
+
+    /// Returns a parallel iterator over the edges as `(source, target)` pairs.
+    ///
+    /// Each undirected edge is yielded once, in an unspecified orientation.
+    #[must_use]
+    pub fn par_edges(&self) -> impl rayon::iter::ParallelIterator<Item = (&V, &V)> + '_ {
+        use rayon::prelude::*;
+
+        let (vertices, directed) = (&self.vertices, self.directed);
+        vertices.par_iter().flat_map_iter(move |(u, neighbors)| {
+            neighbors.iter().filter_map(move |v| {
+                // The neighbor sets hold clones, so borrow the vertex key instead
+                // and keep each undirected edge at the endpoint stored first.
+                let (v, _) = vertices.get_key_value(v)?;
+                (directed || std::ptr::from_ref(u) <= std::ptr::from_ref(v)).then_some((u, v))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_edge_weight_returns_the_previous_self_loop_weight() {
+        let mut graph = SimpleGraph::new_weighted_undirected();
+        graph.add_vertex(1).unwrap();
+        graph.add_weighted_edge(&1, &1, 4).unwrap();
+        assert_eq!(graph.update_edge_weight(&1, &1, 6), Ok(Some(4)));
+        assert_eq!(graph.edge_weight(&1, &1), Some(&6));
+    }
 }
@@ -0,0 +1,360 @@
+use crate::{
+    error::MutationError, graphs::vertex_set::VertexIndex, traits::graph::EdgeList, Graph,
+    GraphError, GraphMut,
+};
+
+/// The largest number of vertices a [`SmallGraph`] can hold.
+pub const SMALL_GRAPH_CAPACITY: usize = 128;
+
+/// Vertex ids `0..128`, so iterators can yield references to vertices that are
+/// only stored as bits.
+static IDS: [usize; SMALL_GRAPH_CAPACITY] = {
+    let mut ids = [0; SMALL_GRAPH_CAPACITY];
+    let mut i = 0;
+    while i < SMALL_GRAPH_CAPACITY {
+        ids[i] = i;
+        i += 1;
+    }
+    ids
+};
+
+/// Iterates over the positions of the set bits of `mask` in increasing order.
+fn bits<'a>(mut mask: u128) -> impl Iterator<Item = &'a usize> {
+    std::iter::from_fn(move || {
+        if mask == 0 {
+            return None;
+        }
+        let bit = mask.trailing_zeros() as usize;
+        mask &= mask - 1;
+        Some(&IDS[bit])
+    })
+}
+
+/// A dense graph on the vertices `0..128`, storing each adjacency row as a
+/// `u128` bitmask.
+///
+/// Edge queries are single bit tests and neighborhoods can be combined with
+/// word-level operations through [`SmallGraph::row`], which makes this
+/// representation well suited to exhaustive searches on small graphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SmallGraph {
+    rows: [u128; SMALL_GRAPH_CAPACITY],
+    present: u128,
+    directed: bool,
+}
+
+impl SmallGraph {
+    /// Creates an empty directed graph.
+    #[must_use]
+    pub fn new_directed() -> Self {
+        Self {
+            rows: [0; SMALL_GRAPH_CAPACITY],
+            present: 0,
+            directed: true,
+        }
+    }
+
+    /// Creates an empty undirected graph.
+    #[must_use]
+    pub fn new_undirected() -> Self {
+        Self {
+            directed: false,
+            ..Self::new_directed()
+        }
+    }
+
+    /// Creates a graph with the vertices `0..n` and no edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::CapacityExceeded` if `n` exceeds
+    /// [`SMALL_GRAPH_CAPACITY`].
+    pub fn with_order(n: usize, directed: bool) -> Result<Self, GraphError> {
+        let mut graph = if directed {
+            Self::new_directed()
+        } else {
+            Self::new_undirected()
+        };
+        graph.present = match n {
+            0 => 0,
+            SMALL_GRAPH_CAPACITY => u128::MAX,
+            n if n < SMALL_GRAPH_CAPACITY => (1 << n) - 1,
+            _ => return Err(GraphError::CapacityExceeded(SMALL_GRAPH_CAPACITY)),
+        };
+        Ok(graph)
+    }
+
+    /// Copies any graph with at most [`SMALL_GRAPH_CAPACITY`] vertices, numbering
+    /// its vertices in iteration order.
+    ///
+    /// # Returns
+    ///
+    /// The small graph and the numbering, which maps vertex `i` back to the
+    /// original vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::CapacityExceeded` if the graph has too many vertices.
+    pub fn from_graph<G>(graph: &G) -> Result<(Self, VertexIndex<G::Vertex>), GraphError>
+    where
+        G: Graph,
+    {
+        let index = VertexIndex::new(graph);
+        let mut small = Self::with_order(index.len(), graph.is_directed())?;
+        for (u, row) in index.neighborhoods(graph).iter().enumerate() {
+            for v in row.iter() {
+                small.rows[u] |= 1 << v;
+            }
+        }
+        Ok((small, index))
+    }
+
+    /// Returns the bitmask of the vertices in the graph.
+    #[must_use]
+    pub fn vertex_mask(&self) -> u128 {
+        self.present
+    }
+
+    /// Returns the adjacency row of `v` as a bitmask, where bit `w` is set if
+    /// `(v, w)` is an edge. Vertices outside the graph have an empty row.
+    #[must_use]
+    pub fn row(&self, v: usize) -> u128 {
+        self.rows.get(v).copied().unwrap_or(0)
+    }
+
+    fn bit(v: usize) -> u128 {
+        1 << v
+    }
+}
+
+impl Default for SmallGraph {
+    fn default() -> Self {
+        Self::new_undirected()
+    }
+}
+
+impl Graph for SmallGraph {
+    type Vertex = usize;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        bits(self.present)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a>> {
+        self.contains_vertex(v).then(|| bits(self.rows[*v]))
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        *v < SMALL_GRAPH_CAPACITY && self.present & Self::bit(*v) != 0
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        *v < SMALL_GRAPH_CAPACITY && self.row(*u) & Self::bit(*v) != 0
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn order(&self) -> usize {
+        self.present.count_ones() as usize
+    }
+
+    fn size(&self) -> usize {
+        let mut arcs = 0;
+        let mut loops = 0;
+        for v in bits(self.present) {
+            arcs += self.rows[*v].count_ones() as usize;
+            loops += usize::from(self.contains_edge(v, v));
+        }
+        if self.directed {
+            arcs
+        } else {
+            (arcs - loops) / 2 + loops
+        }
+    }
+
+    fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+        self.contains_vertex(v)
+            .then(|| self.rows[*v].count_ones() as usize)
+    }
+}
+
+impl GraphMut for SmallGraph {
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        if vertex >= SMALL_GRAPH_CAPACITY {
+            return Err(MutationError::CapacityExceeded(SMALL_GRAPH_CAPACITY));
+        }
+        if self.contains_vertex(&vertex) {
+            return Err(MutationError::VertexAlreadyExists);
+        }
+        self.present |= Self::bit(vertex);
+        Ok(())
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        if !self.contains_vertex(vertex) {
+            return Err(MutationError::VertexNotFound);
+        }
+        let v = *vertex;
+        let bit = Self::bit(v);
+        let mut removed: Vec<(usize, usize)> = bits(self.rows[v]).map(|&w| (v, w)).collect();
+        if self.directed {
+            removed.extend(
+                bits(self.present)
+                    .copied()
+                    .filter(|&u| u != v && self.rows[u] & bit != 0)
+                    .map(|u| (u, v)),
+            );
+        }
+        self.present &= !bit;
+        self.rows[v] = 0;
+        for row in &mut self.rows {
+            *row &= !bit;
+        }
+        Ok(removed)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        if !self.contains_vertex(u) || !self.contains_vertex(v) {
+            return Err(MutationError::VertexNotFound);
+        }
+        if self.contains_edge(u, v) {
+            return Err(MutationError::EdgeAlreadyExists);
+        }
+        self.rows[*u] |= Self::bit(*v);
+        if !self.directed {
+            self.rows[*v] |= Self::bit(*u);
+        }
+        Ok(())
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        if !self.contains_edge(u, v) {
+            return Err(MutationError::EdgeNotFound);
+        }
+        self.rows[*u] &= !Self::bit(*v);
+        if !self.directed {
+            self.rows[*v] &= !Self::bit(*u);
+        }
+        Ok(())
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        let isolated: Vec<usize> = bits(self.present)
+            .copied()
+            .filter(|&v| self.rows[v] == 0)
+            .collect();
+        for v in &isolated {
+            self.remove_vertex(v)?;
+        }
+        Ok(isolated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimpleGraph;
+
+    #[test]
+    fn creates_graphs_up_to_capacity() {
+        for n in [0, 1, 63, 64, 65, 127, 128] {
+            let graph = SmallGraph::with_order(n, false).unwrap();
+            assert_eq!(graph.order(), n);
+            assert_eq!(graph.vertices().last().copied(), n.checked_sub(1));
+        }
+        assert!(matches!(
+            SmallGraph::with_order(129, true),
+            Err(GraphError::CapacityExceeded(SMALL_GRAPH_CAPACITY))
+        ));
+        assert_eq!(
+            SmallGraph::with_order(128, true).unwrap().vertex_mask(),
+            u128::MAX
+        );
+        assert_eq!(SmallGraph::default(), SmallGraph::new_undirected());
+    }
+
+    #[test]
+    fn stores_edges_across_word_boundaries() {
+        let mut graph = SmallGraph::with_order(128, false).unwrap();
+        for (u, v) in [(63, 64), (0, 127), (127, 127)] {
+            graph.add_edge(&u, &v).unwrap();
+        }
+        assert!(graph.contains_edge(&64, &63));
+        assert!(graph.contains_edge(&127, &0));
+        assert!(!graph.contains_edge(&127, &128));
+        assert_eq!(graph.row(127), 1 | 1 << 127);
+        assert_eq!(graph.row(128), 0);
+        assert_eq!(graph.neighbors(&63).unwrap().collect::<Vec<_>>(), [&64]);
+        assert_eq!(graph.degree(&127), Some(2));
+        assert_eq!(graph.size(), 3);
+        assert_eq!(
+            graph.add_edge(&64, &63),
+            Err(MutationError::EdgeAlreadyExists)
+        );
+        assert_eq!(
+            graph.add_vertex(128),
+            Err(MutationError::CapacityExceeded(128))
+        );
+        assert_eq!(
+            graph.add_vertex(127),
+            Err(MutationError::VertexAlreadyExists)
+        );
+    }
+
+    #[test]
+    fn copies_graphs() {
+        let source = SimpleGraph::from_edges([("a", "b"), ("b", "c"), ("c", "c")], true);
+        let (small, index) = SmallGraph::from_graph(&source).unwrap();
+        assert!(small.is_directed());
+        assert_eq!(small.order(), 3);
+        assert_eq!(small.size(), 3);
+        for (u, v) in [("a", "b"), ("b", "c"), ("c", "c")] {
+            let (u, v) = (index.index_of(&u).unwrap(), index.index_of(&v).unwrap());
+            assert!(small.contains_edge(&u, &v));
+            assert!(u == v || !small.contains_edge(&v, &u));
+        }
+    }
+
+    #[test]
+    fn rejects_graphs_beyond_capacity() {
+        let fits = SimpleGraph::from_edges((0..127).map(|v| (v, v + 1)), false);
+        assert_eq!(SmallGraph::from_graph(&fits).unwrap().0.size(), 127);
+
+        let path = SimpleGraph::from_edges((0..128).map(|v| (v, v + 1)), false);
+        assert!(matches!(
+            SmallGraph::from_graph(&path),
+            Err(GraphError::CapacityExceeded(SMALL_GRAPH_CAPACITY))
+        ));
+    }
+
+    #[test]
+    fn removes_vertices_and_edges() {
+        let mut graph = SmallGraph::with_order(100, true).unwrap();
+        for (u, v) in [(1, 64), (64, 1), (64, 99), (70, 64)] {
+            graph.add_edge(&u, &v).unwrap();
+        }
+        let mut removed = graph.remove_vertex(&64).unwrap();
+        removed.sort_unstable();
+        assert_eq!(removed, [(1, 64), (64, 1), (64, 99), (70, 64)]);
+        assert_eq!(graph.row(1), 0);
+        assert_eq!(graph.size(), 0);
+        assert_eq!(graph.remove_vertex(&64), Err(MutationError::VertexNotFound));
+
+        graph.add_edge(&2, &3).unwrap();
+        graph.remove_edge(&2, &3).unwrap();
+        assert_eq!(graph.remove_edge(&2, &3), Err(MutationError::EdgeNotFound));
+
+        graph.add_edge(&2, &3).unwrap();
+        graph.add_edge(&3, &2).unwrap();
+        assert_eq!(graph.remove_isolated_vertices().unwrap().len(), 97);
+        assert_eq!(graph.vertex_mask(), 1 << 2 | 1 << 3);
+    }
+}
@@ -0,0 +1,581 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::MutationError,
+    traits::{
+        graph::EdgeList,
+        weighted::{WeightedGraph, WeightedGraphMut},
+    },
+    Graph, GraphMut,
+};
+
+/// A lightweight handle to a vertex of a [`StableGraph`].
+///
+/// Handles stay valid until their vertex is removed. [`StableGraph::add_vertex`]
+/// never hands out the handle of a removed vertex again, so a stale handle is
+/// reported as missing instead of aliasing a newer vertex.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VertexId(u32);
+
+impl VertexId {
+    /// Creates a handle from a raw index.
+    #[must_use]
+    pub fn new(index: u32) -> Self {
+        VertexId(index)
+    }
+
+    /// Returns the raw index of the handle.
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Slot<N, W> {
+    id: VertexId,
+    payload: N,
+    neighbors: HashSet<VertexId>,
+    /// Sources of the arcs entering this vertex. Only kept for directed graphs.
+    incoming: HashSet<VertexId>,
+    weights: HashMap<VertexId, W>,
+}
+
+/// A graph whose vertices are [`VertexId`] handles to payloads stored once.
+///
+/// `add_vertex` takes the payload and returns its handle, so algorithms work on
+/// small `Copy` ids while the vertex data is never cloned. Use
+/// [`StableGraph::payload`] or indexing to get the data back:
+///
+/// ```
+/// use kambo_graph::{graphs::stable::StableGraph, Graph};
+///
+/// let mut graph = StableGraph::new_undirected();
+/// let alice = graph.add_vertex(String::from("Alice"));
+/// let bob = graph.add_vertex(String::from("Bob"));
+/// graph.add_edge(&alice, &bob).unwrap();
+///
+/// assert!(graph.contains_edge(&bob, &alice));
+/// assert_eq!(graph[bob], "Bob");
+/// ```
+///
+/// [`GraphMut`] is implemented when the payload type has a [`Default`], which
+/// [`GraphMut::add_vertex`] uses for the vertex it is given. That method only
+/// accepts the next handle [`StableGraph::add_vertex`] would return. The edge methods are
+/// also available as inherent methods for every payload type.
+#[derive(Clone, Debug)]
+pub struct StableGraph<N, W = ()>
+where
+    W: Clone + Debug,
+{
+    slots: Vec<Option<Slot<N, W>>>,
+    directed: bool,
+    order: usize,
+    edge_count: usize,
+}
+
+impl<N, W> StableGraph<N, W>
+where
+    W: Clone + Debug,
+{
+    fn new(directed: bool) -> Self {
+        Self {
+            slots: Vec::new(),
+            directed,
+            order: 0,
+            edge_count: 0,
+        }
+    }
+
+    fn slot(&self, id: VertexId) -> Option<&Slot<N, W>> {
+        self.slots.get(id.index())?.as_ref()
+    }
+
+    fn slot_mut(&mut self, id: VertexId) -> Option<&mut Slot<N, W>> {
+        self.slots.get_mut(id.index())?.as_mut()
+    }
+
+    fn push_slot(&mut self, id: VertexId, payload: N) {
+        debug_assert_eq!(id.index(), self.slots.len());
+        self.slots.push(Some(Slot {
+            id,
+            payload,
+            neighbors: HashSet::new(),
+            incoming: HashSet::new(),
+            weights: HashMap::new(),
+        }));
+        self.order += 1;
+    }
+
+    /// Adds a vertex holding `payload`.
+    ///
+    /// # Returns
+    ///
+    /// The handle of the new vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u32::MAX` vertices have already been added.
+    pub fn add_vertex(&mut self, payload: N) -> VertexId {
+        let index = u32::try_from(self.slots.len()).expect("too many vertices for VertexId");
+        let id = VertexId(index);
+        self.push_slot(id, payload);
+        id
+    }
+
+    /// Removes a vertex and its edges.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(N)` - The payload of the removed vertex.
+    /// * `None` - If `id` is not in the graph.
+    pub fn take_vertex(&mut self, id: VertexId) -> Option<N> {
+        let directed = self.directed;
+        let slot = self.slots.get_mut(id.index())?.take()?;
+        for &w in slot.neighbors.iter().filter(|&&w| w != id) {
+            if let Some(other) = self.slot_mut(w) {
+                if directed {
+                    other.incoming.remove(&id);
+                } else {
+                    other.neighbors.remove(&id);
+                    other.weights.remove(&id);
+                }
+            }
+        }
+        for &w in slot.incoming.iter().filter(|&&w| w != id) {
+            if let Some(other) = self.slot_mut(w) {
+                other.neighbors.remove(&id);
+                other.weights.remove(&id);
+            }
+        }
+
+        let has_loop = usize::from(slot.neighbors.contains(&id));
+        self.edge_count -=
+            slot.neighbors.len() + slot.incoming.len() - has_loop * usize::from(directed);
+        self.order -= 1;
+        Some(slot.payload)
+    }
+
+    /// Returns the payload of a vertex, or `None` if `id` is not in the graph.
+    #[must_use]
+    pub fn payload(&self, id: VertexId) -> Option<&N> {
+        self.slot(id).map(|slot| &slot.payload)
+    }
+
+    /// Returns the payload of a vertex mutably, or `None` if `id` is not in the
+    /// graph.
+    pub fn payload_mut(&mut self, id: VertexId) -> Option<&mut N> {
+        self.slot_mut(id).map(|slot| &mut slot.payload)
+    }
+
+    /// Returns an iterator over the vertices and their payloads.
+    pub fn payloads(&self) -> impl Iterator<Item = (VertexId, &N)> {
+        self.slots
+            .iter()
+            .flatten()
+            .map(|slot| (slot.id, &slot.payload))
+    }
+
+    /// Adds the edge `(u, v)`.
+    ///
+    /// # Errors
+    ///
+    /// * `MutationError::VertexNotFound` - If `u` or `v` is not in the graph.
+    /// * `MutationError::EdgeAlreadyExists` - If the edge already exists.
+    pub fn add_edge(&mut self, u: &VertexId, v: &VertexId) -> Result<(), MutationError> {
+        if self.slot(*u).is_none() || self.slot(*v).is_none() {
+            return Err(MutationError::VertexNotFound);
+        }
+        if !self.insert_arcs(*u, *v) {
+            return Err(MutationError::EdgeAlreadyExists);
+        }
+        Ok(())
+    }
+
+    /// Inserts the arcs of the edge `(u, v)` between existing vertices.
+    ///
+    /// Returns `false` if the edge already existed.
+    fn insert_arcs(&mut self, u: VertexId, v: VertexId) -> bool {
+        let directed = self.directed;
+        let source = self.slot_mut(u).expect("u is in the graph");
+        if !source.neighbors.insert(v) {
+            return false;
+        }
+        let target = self.slot_mut(v).expect("v is in the graph");
+        if directed {
+            target.incoming.insert(u);
+        } else {
+            target.neighbors.insert(u);
+        }
+        self.edge_count += 1;
+        true
+    }
+
+    /// Adds the edge `(u, v)` with a weight.
+    ///
+    /// # Errors
+    ///
+    /// * `MutationError::VertexNotFound` - If `u` or `v` is not in the graph.
+    /// * `MutationError::EdgeAlreadyExists` - If the edge already exists.
+    pub fn add_weighted_edge(
+        &mut self,
+        u: &VertexId,
+        v: &VertexId,
+        weight: W,
+    ) -> Result<(), MutationError> {
+        self.add_edge(u, v)?;
+        self.store_weight(*u, *v, weight);
+        Ok(())
+    }
+
+    /// Replaces the weight of the edge `(u, v)`, returning the previous weight if
+    /// it had one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeNotFound` if the edge does not exist.
+    pub fn update_edge_weight(
+        &mut self,
+        u: &VertexId,
+        v: &VertexId,
+        weight: W,
+    ) -> Result<Option<W>, MutationError> {
+        if !self.slot(*u).is_some_and(|slot| slot.neighbors.contains(v)) {
+            return Err(MutationError::EdgeNotFound);
+        }
+        Ok(self.store_weight(*u, *v, weight))
+    }
+
+    /// Stores the weight of the existing edge `(u, v)` on both arcs of an
+    /// undirected edge, returning the previous weight. A self-loop has one arc.
+    fn store_weight(&mut self, u: VertexId, v: VertexId, weight: W) -> Option<W> {
+        if !self.directed && u != v {
+            if let Some(target) = self.slot_mut(v) {
+                target.weights.insert(u, weight.clone());
+            }
+        }
+        self.slot_mut(u)?.weights.insert(v, weight)
+    }
+
+    /// Removes the edge `(u, v)` and returns its weight, if it had one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeNotFound` if the edge does not exist.
+    pub fn remove_edge(&mut self, u: &VertexId, v: &VertexId) -> Result<Option<W>, MutationError> {
+        let directed = self.directed;
+        let source = self.slot_mut(*u).ok_or(MutationError::EdgeNotFound)?;
+        if !source.neighbors.remove(v) {
+            return Err(MutationError::EdgeNotFound);
+        }
+        let weight = source.weights.remove(v);
+        if let Some(target) = self.slot_mut(*v) {
+            if directed {
+                target.incoming.remove(u);
+            } else {
+                target.neighbors.remove(u);
+                target.weights.remove(u);
+            }
+        }
+        self.edge_count -= 1;
+        Ok(weight)
+    }
+
+    /// Removes every vertex without neighbors and returns their handles.
+    pub fn remove_isolated_vertices(&mut self) -> Vec<VertexId> {
+        let isolated: Vec<VertexId> = self
+            .slots
+            .iter()
+            .flatten()
+            .filter(|slot| slot.neighbors.is_empty())
+            .map(|slot| slot.id)
+            .collect();
+        for &id in &isolated {
+            self.take_vertex(id);
+        }
+        isolated
+    }
+}
+
+impl<N, W> StableGraph<N, W>
+where
+    W: Clone + Debug,
+{
+    /// Creates a new directed graph with weights of type W.
+    #[must_use]
+    pub fn new_weighted_directed() -> Self {
+        StableGraph::new(true)
+    }
+
+    /// Creates a new undirected graph with weights of type W.
+    #[must_use]
+    pub fn new_weighted_undirected() -> Self {
+        StableGraph::new(false)
+    }
+}
+
+impl<N> StableGraph<N> {
+    /// Creates a new directed graph without weights.
+    #[must_use]
+    pub fn new_directed() -> Self {
+        StableGraph::new(true)
+    }
+
+    /// Creates a new undirected graph without weights.
+    #[must_use]
+    pub fn new_undirected() -> Self {
+        StableGraph::new(false)
+    }
+}
+
+impl<N, W> Default for StableGraph<N, W>
+where
+    W: Clone + Debug,
+{
+    fn default() -> Self {
+        StableGraph::new(false)
+    }
+}
+
+/// Returns the payload of a vertex.
+///
+/// # Panics
+///
+/// Panics if the vertex is not in the graph.
+impl<N, W> Index<VertexId> for StableGraph<N, W>
+where
+    W: Clone + Debug,
+{
+    type Output = N;
+
+    fn index(&self, id: VertexId) -> &N {
+        self.payload(id).expect("vertex is not in the graph")
+    }
+}
+
+/// Returns the payload of a vertex mutably.
+///
+/// # Panics
+///
+/// Panics if the vertex is not in the graph.
+impl<N, W> IndexMut<VertexId> for StableGraph<N, W>
+where
+    W: Clone + Debug,
+{
+    fn index_mut(&mut self, id: VertexId) -> &mut N {
+        self.payload_mut(id).expect("vertex is not in the graph")
+    }
+}
+
+impl<N, W> Graph for StableGraph<N, W>
+where
+    W: Clone + Debug,
+{
+    type Vertex = VertexId;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.slots.iter().flatten().map(|slot| &slot.id)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, N, W>> {
+        self.slot(*v).map(|slot| slot.neighbors.iter())
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.slot(*v).is_some()
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.slot(*u).is_some_and(|slot| slot.neighbors.contains(v))
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn order(&self) -> usize {
+        self.order
+    }
+
+    fn size(&self) -> usize {
+        self.edge_count
+    }
+
+    fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+        self.slot(*v).map(|slot| slot.neighbors.len())
+    }
+}
+
+impl<N, W> GraphMut for StableGraph<N, W>
+where
+    N: Default,
+    W: Clone + Debug,
+{
+    /// Adds a vertex with the given handle and a default payload.
+    ///
+    /// Only the handle [`StableGraph::add_vertex`] would return next is
+    /// accepted, so handles of removed vertices are never issued again.
+    ///
+    /// # Errors
+    ///
+    /// * `MutationError::VertexAlreadyExists` - If the vertex is in the graph.
+    /// * `MutationError::VertexNotAllowed` - If `vertex` is past the next handle
+    ///   or belonged to a removed vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{
+    ///     error::MutationError,
+    ///     graphs::stable::{StableGraph, VertexId},
+    ///     GraphMut,
+    /// };
+    ///
+    /// let mut graph = StableGraph::<()>::new_undirected();
+    /// assert_eq!(GraphMut::add_vertex(&mut graph, VertexId::new(0)), Ok(()));
+    /// assert_eq!(
+    ///     GraphMut::add_vertex(&mut graph, VertexId::new(5)),
+    ///     Err(MutationError::VertexNotAllowed)
+    /// );
+    ///
+    /// graph.remove_vertex(&VertexId::new(0)).unwrap();
+    /// assert_eq!(
+    ///     GraphMut::add_vertex(&mut graph, VertexId::new(0)),
+    ///     Err(MutationError::VertexNotAllowed)
+    /// );
+    /// assert_eq!(GraphMut::add_vertex(&mut graph, VertexId::new(1)), Ok(()));
+    /// ```
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        if self.contains_vertex(&vertex) {
+            return Err(MutationError::VertexAlreadyExists);
+        }
+        if vertex.index() != self.slots.len() {
+            return Err(MutationError::VertexNotAllowed);
+        }
+        self.push_slot(vertex, N::default());
+        Ok(())
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        let v = *vertex;
+        let slot = self.slot(v).ok_or(MutationError::VertexNotFound)?;
+        let mut removed: Vec<_> = slot.neighbors.iter().map(|&w| (v, w)).collect();
+        removed.extend(slot.incoming.iter().filter(|&&u| u != v).map(|&u| (u, v)));
+        self.take_vertex(v);
+        Ok(removed)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        StableGraph::add_edge(self, u, v)
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        StableGraph::remove_edge(self, u, v).map(|_| ())
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        Ok(StableGraph::remove_isolated_vertices(self))
+    }
+}
+
+impl<N, W> WeightedGraph for StableGraph<N, W>
+where
+    W: Clone + Debug,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight> {
+        self.slot(*u)?.weights.get(v)
+    }
+}
+
+impl<N, W> WeightedGraphMut for StableGraph<N, W>
+where
+    N: Default,
+    W: Clone + Debug,
+{
+    fn add_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<(), MutationError> {
+        StableGraph::add_weighted_edge(self, u, v, weight)
+    }
+
+    fn update_edge_weight(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        StableGraph::update_edge_weight(self, u, v, weight)
+    }
+
+    fn remove_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        StableGraph::remove_edge(self, u, v)
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<N, W> StableGraph<N, W>
+where
+    N: Sync,
+    W: Clone + Debug + Sync,
+{
+    /// Returns a parallel iterator over the vertex handles, splitting the work
+    /// across the rayon thread pool without collecting the handles first.
+    #[must_use]
+    pub fn par_vertices(&self) -> impl rayon::iter::ParallelIterator<Item = &VertexId> + '_ {
+        use rayon::prelude::*;
+
+        self.slots.par_iter().flatten().map(|slot| &slot.id)
+    }
+
+    /// Returns a parallel iterator over the edges as `(source, target)` pairs.
+    ///
+    /// Each undirected edge is yielded once, from its endpoint with the smaller
+    /// handle.
+    #[must_use]
+    pub fn par_edges(
+        &self,
+    ) -> impl rayon::iter::ParallelIterator<Item = (&VertexId, &VertexId)> + '_ {
+        use rayon::prelude::*;
+
+        self.slots.par_iter().flatten().flat_map_iter(move |slot| {
+            slot.neighbors
+                .iter()
+                .filter(move |&v| self.directed || slot.id <= *v)
+                .map(move |v| (&slot.id, v))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_edge_weight_returns_the_previous_self_loop_weight() {
+        let mut graph = StableGraph::new_weighted_undirected();
+        let v = graph.add_vertex(());
+        graph.add_weighted_edge(&v, &v, 4).unwrap();
+        assert_eq!(graph.update_edge_weight(&v, &v, 6), Ok(Some(4)));
+        assert_eq!(graph.edge_weight(&v, &v), Some(&6));
+    }
+}
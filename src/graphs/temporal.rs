@@ -0,0 +1,458 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt::Debug,
+    hash::Hash,
+};
+
+use crate::{
+    error::MutationError, traits::graph::EdgeList, Graph, GraphError, GraphMut, SimpleGraph,
+};
+
+/// A closed time interval `[start, end]` during which an edge is active.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    /// The first instant the edge is active.
+    pub start: T,
+    /// The last instant the edge is active.
+    pub end: T,
+}
+
+impl<T: Ord> Interval<T> {
+    /// Returns `true` if `t` lies in the interval.
+    #[must_use]
+    pub fn contains(&self, t: &T) -> bool {
+        self.start <= *t && *t <= self.end
+    }
+
+    /// Returns `true` if the interval shares an instant with `[from, to]`.
+    #[must_use]
+    pub fn overlaps(&self, from: &T, to: &T) -> bool {
+        self.start <= *to && *from <= self.end
+    }
+}
+
+/// A graph whose edges are active during time intervals.
+///
+/// Each edge holds the list of its contacts, the intervals during which it is
+/// active; a contact at a single timestamp `t` is the interval `[t, t]`. The
+/// time type `T` only needs a total order, so integers, timestamps from
+/// `chrono` or [`std::time::Instant`] all work.
+///
+/// The graph stores every contact once and answers time queries from them:
+///
+/// - [`TemporalGraph::snapshot_at`] and [`TemporalGraph::snapshot_between`]
+///   build the static graph of the edges active at an instant or during a
+///   window.
+/// - [`TemporalGraph::earliest_arrival`] and
+///   [`TemporalGraph::time_respecting_path`] follow contacts in non-decreasing
+///   time, crossing an edge instantly at any instant of one of its contacts.
+///
+/// Through [`Graph`] the graph is seen as its aggregate, with an edge wherever
+/// there was ever a contact, so every algorithm of the crate also runs on it.
+///
+/// ```
+/// use kambo_graph::{graphs::temporal::TemporalGraph, Graph};
+///
+/// let mut contacts = TemporalGraph::new_undirected();
+/// contacts.add_contact("ana", "bia", 1, 3).unwrap();
+/// contacts.add_timestamp("bia", "caio", 2);
+/// contacts.add_timestamp("caio", "davi", 1);
+///
+/// assert!(contacts.snapshot_at(&2).contains_edge(&"bia", &"caio"));
+/// assert!(!contacts.snapshot_at(&2).contains_edge(&"caio", &"davi"));
+///
+/// // Ana reaches Caio at time 2, but Caio met Davi before that.
+/// let (path, arrival) = contacts.time_respecting_path(&"ana", &"caio", &0).unwrap().unwrap();
+/// assert_eq!(path, vec!["ana", "bia", "caio"]);
+/// assert_eq!(arrival, 2);
+/// assert_eq!(contacts.time_respecting_path(&"ana", &"davi", &0).unwrap(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TemporalGraph<V, T>
+where
+    V: Eq + Hash + Clone + Debug,
+    T: Ord + Clone + Debug,
+{
+    /// The contacts of the edges leaving each vertex. Undirected edges are
+    /// stored at both endpoints.
+    adjacency: HashMap<V, HashMap<V, Vec<Interval<T>>>>,
+    directed: bool,
+    edge_count: usize,
+    contact_count: usize,
+}
+
+impl<V, T> TemporalGraph<V, T>
+where
+    V: Eq + Hash + Clone + Debug,
+    T: Ord + Clone + Debug,
+{
+    fn new(directed: bool) -> Self {
+        Self {
+            adjacency: HashMap::new(),
+            directed,
+            edge_count: 0,
+            contact_count: 0,
+        }
+    }
+
+    /// Creates an empty directed temporal graph.
+    #[must_use]
+    pub fn new_directed() -> Self {
+        Self::new(true)
+    }
+
+    /// Creates an empty undirected temporal graph.
+    #[must_use]
+    pub fn new_undirected() -> Self {
+        Self::new(false)
+    }
+
+    /// Adds a contact on the edge `(u, v)` active from `start` to `end`, both
+    /// included, adding the edge and its endpoints if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::InvalidOperation` if `end` is before `start`.
+    pub fn add_contact(&mut self, u: V, v: V, start: T, end: T) -> Result<(), GraphError> {
+        if end < start {
+            return Err(GraphError::InvalidOperation(format!(
+                "The contact on ({u:?}, {v:?}) ends at {end:?}, before it starts at {start:?}"
+            )));
+        }
+        self.insert_contact(u, v, Interval { start, end });
+        Ok(())
+    }
+
+    /// Adds a contact on the edge `(u, v)` at the single instant `t`, adding the
+    /// edge and its endpoints if needed.
+    pub fn add_timestamp(&mut self, u: V, v: V, t: T) {
+        self.insert_contact(
+            u,
+            v,
+            Interval {
+                start: t.clone(),
+                end: t,
+            },
+        );
+    }
+
+    fn insert_contact(&mut self, u: V, v: V, interval: Interval<T>) {
+        self.adjacency.entry(v.clone()).or_default();
+        let contacts = self
+            .adjacency
+            .entry(u.clone())
+            .or_default()
+            .entry(v.clone())
+            .or_default();
+        if contacts.is_empty() {
+            self.edge_count += 1;
+        }
+        contacts.push(interval.clone());
+        if !self.directed && u != v {
+            self.adjacency
+                .entry(v)
+                .or_default()
+                .entry(u)
+                .or_default()
+                .push(interval);
+        }
+        self.contact_count += 1;
+    }
+
+    /// Returns the contacts of the edge `(u, v)` in the order they were added, or
+    /// `None` if the edge does not exist.
+    #[must_use]
+    pub fn contacts(&self, u: &V, v: &V) -> Option<&[Interval<T>]> {
+        self.adjacency.get(u)?.get(v).map(Vec::as_slice)
+    }
+
+    /// Returns the total number of contacts, every undirected contact counted
+    /// once.
+    #[must_use]
+    pub fn contact_count(&self) -> usize {
+        self.contact_count
+    }
+
+    /// Returns `true` if the edge `(u, v)` is active at instant `t`.
+    #[must_use]
+    pub fn is_active(&self, u: &V, v: &V, t: &T) -> bool {
+        self.contacts(u, v)
+            .is_some_and(|contacts| contacts.iter().any(|c| c.contains(t)))
+    }
+
+    /// Builds the static graph of the edges active at instant `t`, with every
+    /// vertex.
+    #[must_use]
+    pub fn snapshot_at(&self, t: &T) -> SimpleGraph<V, ()> {
+        self.snapshot(|contact| contact.contains(t))
+    }
+
+    /// Builds the static graph of the edges active at some instant of the window
+    /// `[from, to]`, with every vertex.
+    #[must_use]
+    pub fn snapshot_between(&self, from: &T, to: &T) -> SimpleGraph<V, ()> {
+        self.snapshot(|contact| contact.overlaps(from, to))
+    }
+
+    fn snapshot(&self, active: impl Fn(&Interval<T>) -> bool) -> SimpleGraph<V, ()> {
+        let mut snapshot = if self.directed {
+            SimpleGraph::new_directed()
+        } else {
+            SimpleGraph::new_undirected()
+        };
+        for v in self.adjacency.keys() {
+            let _ = snapshot.add_vertex(v.clone());
+        }
+        for (u, edges) in &self.adjacency {
+            for (v, contacts) in edges {
+                if contacts.iter().any(&active) {
+                    // The reverse arc of an undirected edge is already there.
+                    let _ = snapshot.add_edge(u, v);
+                }
+            }
+        }
+        snapshot
+    }
+
+    /// Computes the earliest instant at which each vertex can be reached from
+    /// `source`, leaving at `start`.
+    ///
+    /// A time-respecting path crosses its edges in non-decreasing time: an edge
+    /// can be crossed at any instant of one of its contacts that is not before
+    /// the arrival at its tail, and crossing it takes no time. The search is a
+    /// Dijkstra-like sweep over arrival times, in `O((n + c) log n)` time for
+    /// `c` contacts.
+    ///
+    /// # Returns
+    ///
+    /// The earliest arrival at every reachable vertex, `start` for `source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::VertexNotFound` if `source` is not in the graph.
+    pub fn earliest_arrival(&self, source: &V, start: &T) -> Result<HashMap<V, T>, GraphError> {
+        let arrivals = self.arrivals(source, start, None)?;
+        Ok(arrivals
+            .into_iter()
+            .map(|(v, (t, _))| (v.clone(), t))
+            .collect())
+    }
+
+    /// Finds a time-respecting path from `source` to `target` leaving at
+    /// `start` and arriving as early as possible.
+    ///
+    /// See [`TemporalGraph::earliest_arrival`] for how paths follow time.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((path, arrival))` - The vertices of the path, from `source` to
+    ///   `target`, and the instant it reaches `target`.
+    /// * `None` - If `target` cannot be reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::VertexNotFound` if `source` or `target` is not in the
+    /// graph.
+    pub fn time_respecting_path(
+        &self,
+        source: &V,
+        target: &V,
+        start: &T,
+    ) -> Result<Option<(Vec<V>, T)>, GraphError> {
+        if !self.adjacency.contains_key(target) {
+            return Err(GraphError::VertexNotFound);
+        }
+        let arrivals = self.arrivals(source, start, Some(target))?;
+        let Some((arrival, _)) = arrivals.get(target) else {
+            return Ok(None);
+        };
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some((_, Some(previous))) = arrivals.get(current) {
+            path.push((*previous).clone());
+            current = previous;
+        }
+        path.reverse();
+        Ok(Some((path, arrival.clone())))
+    }
+
+    /// Runs the earliest-arrival search from `source`, stopping once `target` is
+    /// settled, and returns the arrival time and predecessor of each reached
+    /// vertex.
+    #[allow(clippy::type_complexity)]
+    fn arrivals<'a>(
+        &'a self,
+        source: &'a V,
+        start: &T,
+        target: Option<&V>,
+    ) -> Result<HashMap<&'a V, (T, Option<&'a V>)>, GraphError> {
+        if !self.adjacency.contains_key(source) {
+            return Err(GraphError::VertexNotFound);
+        }
+        let mut arrivals: HashMap<&V, (T, Option<&V>)> = HashMap::new();
+        arrivals.insert(source, (start.clone(), None));
+        // Vertices are pushed with a sequence number, so the heap needs no
+        // ordering on `V`.
+        let mut pushed: Vec<&V> = vec![source];
+        let mut heap = BinaryHeap::from([Reverse((start.clone(), 0))]);
+        while let Some(Reverse((time, i))) = heap.pop() {
+            let u = pushed[i];
+            if arrivals[u].0 < time {
+                continue;
+            }
+            if target == Some(u) {
+                break;
+            }
+            for (w, contacts) in &self.adjacency[u] {
+                let Some(crossing) = contacts
+                    .iter()
+                    .filter(|c| c.end >= time)
+                    .map(|c| c.start.clone().max(time.clone()))
+                    .min()
+                else {
+                    continue;
+                };
+                if arrivals.get(w).is_some_and(|(t, _)| *t <= crossing) {
+                    continue;
+                }
+                arrivals.insert(w, (crossing.clone(), Some(u)));
+                heap.push(Reverse((crossing, pushed.len())));
+                pushed.push(w);
+            }
+        }
+        Ok(arrivals)
+    }
+}
+
+impl<V, T> Graph for TemporalGraph<V, T>
+where
+    V: Eq + Hash + Clone + Debug,
+    T: Ord + Clone + Debug,
+{
+    type Vertex = V;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.adjacency.keys()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, V, T>> {
+        self.adjacency.get(v).map(HashMap::keys)
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.adjacency.contains_key(v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.adjacency
+            .get(u)
+            .is_some_and(|edges| edges.contains_key(v))
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn order(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    fn size(&self) -> usize {
+        self.edge_count
+    }
+
+    fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+        self.adjacency.get(v).map(HashMap::len)
+    }
+}
+
+impl<V, T> GraphMut for TemporalGraph<V, T>
+where
+    V: Eq + Hash + Clone + Debug,
+    T: Ord + Clone + Debug,
+{
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        if self.adjacency.contains_key(&vertex) {
+            return Err(MutationError::VertexAlreadyExists);
+        }
+        self.adjacency.insert(vertex, HashMap::new());
+        Ok(())
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        let edges = self
+            .adjacency
+            .remove(vertex)
+            .ok_or(MutationError::VertexNotFound)?;
+        let mut removed = Vec::with_capacity(edges.len());
+        for (w, contacts) in edges {
+            self.contact_count -= contacts.len();
+            removed.push((vertex.clone(), w));
+        }
+        for (u, edges) in &mut self.adjacency {
+            if let Some(contacts) = edges.remove(vertex) {
+                if self.directed {
+                    self.contact_count -= contacts.len();
+                    removed.push((u.clone(), vertex.clone()));
+                }
+            }
+        }
+        self.edge_count -= removed.len();
+        Ok(removed)
+    }
+
+    /// Adds an edge without contacts, present in the aggregate graph but never
+    /// active.
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        if !self.contains_vertex(u) || !self.contains_vertex(v) {
+            return Err(MutationError::VertexNotFound);
+        }
+        if self.contains_edge(u, v) {
+            return Err(MutationError::EdgeAlreadyExists);
+        }
+        self.adjacency
+            .entry(u.clone())
+            .or_default()
+            .insert(v.clone(), Vec::new());
+        if !self.directed {
+            self.adjacency
+                .entry(v.clone())
+                .or_default()
+                .insert(u.clone(), Vec::new());
+        }
+        self.edge_count += 1;
+        Ok(())
+    }
+
+    /// Removes an edge and all its contacts.
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        let contacts = self
+            .adjacency
+            .get_mut(u)
+            .and_then(|edges| edges.remove(v))
+            .ok_or(MutationError::EdgeNotFound)?;
+        if !self.directed {
+            if let Some(edges) = self.adjacency.get_mut(v) {
+                edges.remove(u);
+            }
+        }
+        self.contact_count -= contacts.len();
+        self.edge_count -= 1;
+        Ok(())
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        let isolated = self.get_isolated_vertices();
+        for v in &isolated {
+            self.remove_vertex(v)?;
+        }
+        Ok(isolated)
+    }
+}
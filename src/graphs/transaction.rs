@@ -0,0 +1,403 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+};
+
+use crate::{error::MutationError, GraphMut};
+
+/// A mutation recorded by a [`Transaction`].
+#[derive(Debug, Clone)]
+enum Operation<V> {
+    AddVertex(V),
+    RemoveVertex(V),
+    AddEdge(V, V),
+    RemoveEdge(V, V),
+}
+
+impl<V: fmt::Debug> fmt::Display for Operation<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::AddVertex(v) => write!(f, "add_vertex({v:?})"),
+            Operation::RemoveVertex(v) => write!(f, "remove_vertex({v:?})"),
+            Operation::AddEdge(u, v) => write!(f, "add_edge({u:?}, {v:?})"),
+            Operation::RemoveEdge(u, v) => write!(f, "remove_edge({u:?}, {v:?})"),
+        }
+    }
+}
+
+/// The error returned when a [`Transaction`] is rejected. The graph is left as it
+/// was before the transaction, within the limits described by
+/// [`Transactional::apply`].
+#[derive(Debug)]
+pub struct TransactionError {
+    /// Position of the failed operation in the transaction.
+    pub index: usize,
+    /// The failed operation, described as it was written (e.g. `add_edge(1, 2)`).
+    pub operation: String,
+    /// The error raised by the operation.
+    pub error: MutationError,
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Transaction rolled back: operation {} ({}) failed: {}",
+            self.index, self.operation, self.error
+        )
+    }
+}
+
+impl Error for TransactionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A batch of mutations applied together by [`Transactional::apply`].
+///
+/// The methods only record the operations; nothing changes until the whole batch
+/// has been checked.
+#[derive(Debug, Clone)]
+pub struct Transaction<V> {
+    operations: Vec<Operation<V>>,
+}
+
+impl<V> Transaction<V> {
+    /// Records the addition of a vertex.
+    pub fn add_vertex(&mut self, v: V) -> &mut Self {
+        self.operations.push(Operation::AddVertex(v));
+        self
+    }
+
+    /// Records the removal of a vertex and its edges.
+    pub fn remove_vertex(&mut self, v: V) -> &mut Self {
+        self.operations.push(Operation::RemoveVertex(v));
+        self
+    }
+
+    /// Records the addition of the edge `(u, v)`.
+    pub fn add_edge(&mut self, u: V, v: V) -> &mut Self {
+        self.operations.push(Operation::AddEdge(u, v));
+        self
+    }
+
+    /// Records the removal of the edge `(u, v)`.
+    pub fn remove_edge(&mut self, u: V, v: V) -> &mut Self {
+        self.operations.push(Operation::RemoveEdge(u, v));
+        self
+    }
+
+    /// Returns the number of recorded operations.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if no operation was recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+/// The graph as it would be after the operations checked so far, tracked as
+/// changes over the untouched graph.
+struct Overlay<'a, G: GraphMut> {
+    graph: &'a G,
+    vertices: HashMap<G::Vertex, bool>,
+    /// Vertices removed at some point, whose original edges are all gone.
+    cleared: HashSet<G::Vertex>,
+    edges: HashMap<(G::Vertex, G::Vertex), bool>,
+}
+
+impl<'a, G: GraphMut> Overlay<'a, G> {
+    fn new(graph: &'a G) -> Self {
+        Self {
+            graph,
+            vertices: HashMap::new(),
+            cleared: HashSet::new(),
+            edges: HashMap::new(),
+        }
+    }
+
+    fn has_vertex(&self, v: &G::Vertex) -> bool {
+        self.vertices
+            .get(v)
+            .copied()
+            .unwrap_or_else(|| self.graph.contains_vertex(v))
+    }
+
+    fn has_edge(&self, u: &G::Vertex, v: &G::Vertex) -> bool {
+        match self.edges.get(&(u.clone(), v.clone())) {
+            Some(&present) => present,
+            None => {
+                self.graph.contains_edge(u, v)
+                    && !self.cleared.contains(u)
+                    && !self.cleared.contains(v)
+            }
+        }
+    }
+
+    fn set_edge(&mut self, u: &G::Vertex, v: &G::Vertex, present: bool) {
+        self.edges.insert((u.clone(), v.clone()), present);
+        if !self.graph.is_directed() {
+            self.edges.insert((v.clone(), u.clone()), present);
+        }
+    }
+
+    /// Checks an operation with the same rules as [`GraphMut`] and records its
+    /// effect.
+    fn check(&mut self, operation: &Operation<G::Vertex>) -> Result<(), MutationError> {
+        match operation {
+            Operation::AddVertex(v) => {
+                if self.has_vertex(v) {
+                    return Err(MutationError::VertexAlreadyExists);
+                }
+                self.vertices.insert(v.clone(), true);
+            }
+            Operation::RemoveVertex(v) => {
+                if !self.has_vertex(v) {
+                    return Err(MutationError::VertexNotFound);
+                }
+                self.vertices.insert(v.clone(), false);
+                self.cleared.insert(v.clone());
+                self.edges.retain(|(a, b), _| a != v && b != v);
+            }
+            Operation::AddEdge(u, v) => {
+                if !self.has_vertex(u) || !self.has_vertex(v) {
+                    return Err(MutationError::VertexNotFound);
+                }
+                if self.has_edge(u, v) {
+                    return Err(MutationError::EdgeAlreadyExists);
+                }
+                self.set_edge(u, v, true);
+            }
+            Operation::RemoveEdge(u, v) => {
+                if !self.has_edge(u, v) {
+                    return Err(MutationError::EdgeNotFound);
+                }
+                self.set_edge(u, v, false);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How to undo an applied operation.
+enum Undo<V> {
+    RemoveVertex(V),
+    RestoreVertex(V, Vec<(V, V)>),
+    RemoveEdge(V, V),
+    AddEdge(V, V),
+}
+
+/// Applies batches of mutations atomically.
+///
+/// Implemented for every [`GraphMut`].
+pub trait Transactional: GraphMut {
+    /// Records a batch of mutations with `build` and applies all of them, or none
+    /// if one fails.
+    ///
+    /// Every operation is first checked against the graph as it would be after
+    /// the operations before it, so missing vertices, duplicate edges and similar
+    /// errors are reported before the graph is touched. If the graph still rejects
+    /// an operation for a reason of its own, such as a capacity limit, the
+    /// operations already applied are undone through [`GraphMut`].
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - Records the operations on the given [`Transaction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransactionError`] naming the first operation that failed.
+    /// When the failure is found while checking, the graph is unchanged. When the
+    /// graph rejects an operation and the applied ones are undone, its vertices and
+    /// edges are restored but not what [`GraphMut`] cannot recreate:
+    ///
+    /// * Removed edges come back without their weights.
+    /// * A removed vertex the graph refuses to take back stays removed with its
+    ///   edges. This is the case of [`StableGraph`], which never reissues a handle,
+    ///   so the payload of the vertex is lost as well.
+    ///
+    /// [`StableGraph`]: crate::graphs::stable::StableGraph
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, graphs::transaction::Transactional, Graph};
+    ///
+    /// let mut graph = graph! { 1 - 2, 2 - 3 };
+    /// let result = graph.apply(|tx| {
+    ///     tx.add_vertex(4);
+    ///     tx.add_edge(3, 4);
+    ///     tx.remove_vertex(1);
+    ///     tx.add_edge(1, 4);
+    /// });
+    ///
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.index, 3);
+    /// assert!(graph.contains_vertex(&1));
+    /// assert!(!graph.contains_vertex(&4));
+    /// ```
+    fn apply<F>(&mut self, build: F) -> Result<(), TransactionError>
+    where
+        F: FnOnce(&mut Transaction<Self::Vertex>),
+        Self: Sized,
+    {
+        let mut transaction = Transaction {
+            operations: Vec::new(),
+        };
+        build(&mut transaction);
+        let operations = transaction.operations;
+        let failure = |index: usize, error| TransactionError {
+            index,
+            operation: operations[index].to_string(),
+            error,
+        };
+
+        let mut overlay = Overlay::new(self);
+        for (index, operation) in operations.iter().enumerate() {
+            overlay
+                .check(operation)
+                .map_err(|error| failure(index, error))?;
+        }
+
+        let mut undo = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.iter().enumerate() {
+            let result = match operation {
+                Operation::AddVertex(v) => self
+                    .add_vertex(v.clone())
+                    .map(|()| Undo::RemoveVertex(v.clone())),
+                Operation::RemoveVertex(v) => self
+                    .remove_vertex(v)
+                    .map(|edges| Undo::RestoreVertex(v.clone(), edges)),
+                Operation::AddEdge(u, v) => self
+                    .add_edge(u, v)
+                    .map(|()| Undo::RemoveEdge(u.clone(), v.clone())),
+                Operation::RemoveEdge(u, v) => self
+                    .remove_edge(u, v)
+                    .map(|()| Undo::AddEdge(u.clone(), v.clone())),
+            };
+            match result {
+                Ok(step) => undo.push(step),
+                Err(error) => {
+                    roll_back(self, undo);
+                    return Err(failure(index, error));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<G: GraphMut> Transactional for G {}
+
+/// Undoes applied operations in reverse order. A step the graph refuses, such as
+/// adding back a vertex whose handle it does not reissue, is skipped.
+fn roll_back<G: GraphMut>(graph: &mut G, undo: Vec<Undo<G::Vertex>>) {
+    for step in undo.into_iter().rev() {
+        match step {
+            Undo::RemoveVertex(v) => {
+                let _ = graph.remove_vertex(&v);
+            }
+            Undo::RestoreVertex(v, edges) => {
+                let _ = graph.add_vertex(v);
+                for (a, b) in edges {
+                    let _ = graph.add_edge(&a, &b);
+                }
+            }
+            Undo::RemoveEdge(u, v) => {
+                let _ = graph.remove_edge(&u, &v);
+            }
+            Undo::AddEdge(u, v) => {
+                let _ = graph.add_edge(&u, &v);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graphs::{
+            small::{SmallGraph, SMALL_GRAPH_CAPACITY},
+            stable::StableGraph,
+        },
+        Graph,
+    };
+
+    fn path() -> SmallGraph {
+        let mut graph = SmallGraph::with_order(4, false).unwrap();
+        for (u, v) in [(0, 1), (1, 2), (2, 3)] {
+            graph.add_edge(&u, &v).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn applies_every_operation() {
+        let mut graph = path();
+        graph
+            .apply(|tx| {
+                tx.remove_vertex(3).add_vertex(5).add_edge(2, 5);
+                tx.remove_edge(0, 1);
+            })
+            .unwrap();
+        assert!(!graph.contains_vertex(&3));
+        assert!(graph.contains_edge(&5, &2));
+        assert!(!graph.contains_edge(&0, &1));
+        assert_eq!(graph.size(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_operations_before_touching_the_graph() {
+        let mut graph = path();
+        let error = graph
+            .apply(|tx| {
+                tx.remove_edge(0, 1).add_edge(0, 1).add_edge(1, 0);
+            })
+            .unwrap_err();
+        assert_eq!(error.index, 2);
+        assert_eq!(error.operation, "add_edge(1, 0)");
+        assert_eq!(error.error, MutationError::EdgeAlreadyExists);
+        assert_eq!(graph, path());
+    }
+
+    #[test]
+    fn undoes_applied_operations_when_the_graph_rejects_one() {
+        let mut graph = path();
+        let error = graph
+            .apply(|tx| {
+                tx.remove_edge(0, 1).remove_vertex(2).add_vertex(5);
+                tx.add_vertex(SMALL_GRAPH_CAPACITY);
+            })
+            .unwrap_err();
+        assert_eq!(error.index, 3);
+        assert_eq!(
+            error.error,
+            MutationError::CapacityExceeded(SMALL_GRAPH_CAPACITY)
+        );
+        assert_eq!(graph, path());
+    }
+
+    #[test]
+    fn cannot_restore_vertices_a_stable_graph_does_not_reissue() {
+        let mut graph = StableGraph::<()>::new_undirected();
+        let a = graph.add_vertex(());
+        let b = graph.add_vertex(());
+        graph.add_edge(&a, &b).unwrap();
+
+        let error = graph
+            .apply(|tx| {
+                tx.remove_vertex(b).add_vertex(b);
+            })
+            .unwrap_err();
+        assert_eq!(error.error, MutationError::VertexNotAllowed);
+        assert!(graph.contains_vertex(&a));
+        assert!(!graph.contains_vertex(&b));
+    }
+}
@@ -0,0 +1,365 @@
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+use crate::{Graph, GraphError};
+
+/// A rooted forest answering parent, children, subtree and lowest common
+/// ancestor queries.
+///
+/// The forest is built once, from a graph with [`RootedForest::from_graph`] or
+/// from a parent map with [`RootedForest::from_parents`], and is immutable
+/// afterwards. Building takes `O(n log n)` time and space; then
+/// [`RootedForest::lca`], [`RootedForest::is_ancestor`] and
+/// [`RootedForest::distance`] take `O(1)` time, and [`RootedForest::subtree`]
+/// lists a subtree in time proportional to its size.
+///
+/// The vertices are numbered in depth-first preorder, so every subtree is a
+/// contiguous range of that order. The lowest common ancestor of two vertices
+/// `u` and `v`, with `u` first in preorder, is the parent of the shallowest
+/// vertex after `u` up to `v`, found with a sparse table of range minima.
+///
+/// ```
+/// use kambo_graph::{graph, graphs::tree::RootedForest};
+///
+/// //        1
+/// //      /   \
+/// //     2     3
+/// //    / \     \
+/// //   4   5     6
+/// let graph = graph! { 1 - 2, 1 - 3, 2 - 4, 2 - 5, 3 - 6 };
+/// let tree = RootedForest::from_graph(&graph, &1).unwrap();
+///
+/// assert_eq!(tree.parent(&4), Some(&2));
+/// assert_eq!(tree.lca(&4, &5), Some(&2));
+/// assert_eq!(tree.lca(&5, &6), Some(&1));
+/// assert_eq!(tree.lca(&2, &4), Some(&2));
+/// assert_eq!(tree.distance(&4, &6), Some(4));
+///
+/// let mut below: Vec<_> = tree.subtree(&2).unwrap().copied().collect();
+/// below.sort();
+/// assert_eq!(below, vec![2, 4, 5]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RootedForest<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    /// The vertices in preorder; a vertex is identified by its position.
+    vertices: Vec<V>,
+    index: HashMap<V, usize>,
+    parent: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+    /// The end, exclusive, of the preorder range of each subtree.
+    end: Vec<usize>,
+    /// `sparse[k][i]` is the shallowest vertex among `i..i + 2^k`.
+    sparse: Vec<Vec<usize>>,
+}
+
+impl<V> RootedForest<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    /// Roots a tree at `root`.
+    ///
+    /// An undirected graph must be connected and acyclic. A directed graph must
+    /// be an out-tree from `root`: every other vertex is reached from `root` by
+    /// exactly one path, along arcs pointing from parents to children.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The tree.
+    /// * `root` - The root of the tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::VertexNotFound` if `root` is not in the graph, and
+    /// `GraphError::InvalidOperation` if the graph is not a tree.
+    pub fn from_graph<G>(graph: &G, root: &V) -> Result<Self, GraphError>
+    where
+        G: Graph<Vertex = V>,
+    {
+        if !graph.contains_vertex(root) {
+            return Err(GraphError::VertexNotFound);
+        }
+        let not_a_tree = || GraphError::InvalidOperation("The graph is not a tree".to_string());
+
+        let mut parents: HashMap<&V, Option<&V>> = HashMap::with_capacity(graph.order());
+        parents.insert(root, None);
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            let parent = parents[u];
+            for w in graph.neighbors(u).into_iter().flatten() {
+                if !graph.is_directed() && parent == Some(w) {
+                    continue;
+                }
+                if parents.insert(w, Some(u)).is_some() {
+                    return Err(not_a_tree());
+                }
+                stack.push(w);
+            }
+        }
+        if parents.len() < graph.order() {
+            return Err(not_a_tree());
+        }
+        Self::from_parents(
+            parents
+                .into_iter()
+                .map(|(v, parent)| (v.clone(), parent.cloned())),
+        )
+    }
+
+    /// Builds a forest from the parent of every vertex, `None` for the roots.
+    ///
+    /// # Arguments
+    ///
+    /// * `parents` - Pairs `(vertex, parent)`, one per vertex.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::VertexAlreadyExists` - If a vertex is listed twice.
+    /// * `GraphError::VertexNotFound` - If a parent is not listed as a vertex.
+    /// * `GraphError::InvalidOperation` - If the parents form a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::graphs::tree::RootedForest;
+    ///
+    /// let forest = RootedForest::from_parents([
+    ///     ("root", None),
+    ///     ("left", Some("root")),
+    ///     ("right", Some("root")),
+    ///     ("other", None),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(forest.lca(&"left", &"right"), Some(&"root"));
+    /// assert_eq!(forest.lca(&"left", &"other"), None);
+    /// ```
+    pub fn from_parents<I>(parents: I) -> Result<Self, GraphError>
+    where
+        I: IntoIterator<Item = (V, Option<V>)>,
+    {
+        let mut input: Vec<V> = Vec::new();
+        let mut input_parents: Vec<Option<V>> = Vec::new();
+        let mut position: HashMap<V, usize> = HashMap::new();
+        for (v, parent) in parents {
+            if position.insert(v.clone(), input.len()).is_some() {
+                return Err(GraphError::VertexAlreadyExists);
+            }
+            input.push(v);
+            input_parents.push(parent);
+        }
+
+        let n = input.len();
+        let mut children = vec![Vec::new(); n];
+        let mut roots = Vec::new();
+        for (i, parent) in input_parents.iter().enumerate() {
+            match parent {
+                Some(p) => {
+                    let &p = position.get(p).ok_or(GraphError::VertexNotFound)?;
+                    children[p].push(i);
+                }
+                None => roots.push(i),
+            }
+        }
+
+        // Number the vertices in preorder. Vertices on a cycle hang from no root
+        // and are never reached.
+        let mut preorder = Vec::with_capacity(n);
+        let mut depth_of = vec![0; n];
+        let mut stack: Vec<usize> = roots.into_iter().rev().collect();
+        while let Some(u) = stack.pop() {
+            preorder.push(u);
+            for &c in children[u].iter().rev() {
+                depth_of[c] = depth_of[u] + 1;
+                stack.push(c);
+            }
+        }
+        if preorder.len() < n {
+            return Err(GraphError::InvalidOperation(
+                "The parents form a cycle".to_string(),
+            ));
+        }
+
+        let mut renumber = vec![0; n];
+        for (i, &u) in preorder.iter().enumerate() {
+            renumber[u] = i;
+        }
+        let vertices: Vec<V> = preorder.iter().map(|&u| input[u].clone()).collect();
+        let parent: Vec<Option<usize>> = preorder
+            .iter()
+            .map(|&u| input_parents[u].as_ref().map(|p| renumber[position[p]]))
+            .collect();
+        let children: Vec<Vec<usize>> = preorder
+            .iter()
+            .map(|&u| children[u].iter().map(|&c| renumber[c]).collect())
+            .collect();
+        let depth: Vec<usize> = preorder.iter().map(|&u| depth_of[u]).collect();
+
+        let mut end: Vec<usize> = (1..=n).collect();
+        for v in (0..n).rev() {
+            if let Some(p) = parent[v] {
+                end[p] = end[p].max(end[v]);
+            }
+        }
+
+        let index = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone(), i))
+            .collect();
+        let sparse = Self::sparse_table(&depth);
+
+        Ok(Self {
+            vertices,
+            index,
+            parent,
+            children,
+            depth,
+            end,
+            sparse,
+        })
+    }
+
+    /// Builds the sparse table of the shallowest vertex over every range whose
+    /// length is a power of two.
+    fn sparse_table(depth: &[usize]) -> Vec<Vec<usize>> {
+        let mut sparse = vec![(0..depth.len()).collect::<Vec<_>>()];
+        let mut width = 1;
+        while 2 * width <= depth.len() {
+            let previous = &sparse[sparse.len() - 1];
+            let level = (0..=depth.len() - 2 * width)
+                .map(|i| {
+                    let (a, b) = (previous[i], previous[i + width]);
+                    if depth[b] < depth[a] {
+                        b
+                    } else {
+                        a
+                    }
+                })
+                .collect();
+            sparse.push(level);
+            width *= 2;
+        }
+        sparse
+    }
+
+    /// Returns the shallowest vertex among the positions `from..to`, which must
+    /// not be empty.
+    fn shallowest(&self, from: usize, to: usize) -> usize {
+        let k = (to - from).ilog2() as usize;
+        let (a, b) = (self.sparse[k][from], self.sparse[k][to - (1 << k)]);
+        if self.depth[b] < self.depth[a] {
+            b
+        } else {
+            a
+        }
+    }
+
+    fn position(&self, v: &V) -> Option<usize> {
+        self.index.get(v).copied()
+    }
+
+    /// Returns the number of vertices.
+    #[must_use]
+    pub fn order(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns `true` if the forest contains the vertex.
+    #[must_use]
+    pub fn contains_vertex(&self, v: &V) -> bool {
+        self.index.contains_key(v)
+    }
+
+    /// Returns an iterator over the vertices in depth-first preorder.
+    pub fn vertices(&self) -> impl Iterator<Item = &V> {
+        self.vertices.iter()
+    }
+
+    /// Returns an iterator over the roots.
+    pub fn roots(&self) -> impl Iterator<Item = &V> {
+        self.parent
+            .iter()
+            .zip(&self.vertices)
+            .filter(|(parent, _)| parent.is_none())
+            .map(|(_, v)| v)
+    }
+
+    /// Returns the parent of `v`, or `None` if `v` is a root or not in the
+    /// forest.
+    #[must_use]
+    pub fn parent(&self, v: &V) -> Option<&V> {
+        let p = self.parent[self.position(v)?]?;
+        Some(&self.vertices[p])
+    }
+
+    /// Returns the children of `v`, or `None` if `v` is not in the forest.
+    pub fn children(&self, v: &V) -> Option<impl Iterator<Item = &V>> {
+        let children = &self.children[self.position(v)?];
+        Some(children.iter().map(|&c| &self.vertices[c]))
+    }
+
+    /// Returns the depth of `v`, `0` for a root, or `None` if `v` is not in the
+    /// forest.
+    #[must_use]
+    pub fn depth(&self, v: &V) -> Option<usize> {
+        Some(self.depth[self.position(v)?])
+    }
+
+    /// Returns the vertices of the subtree rooted at `v` in depth-first
+    /// preorder, starting with `v`, or `None` if `v` is not in the forest.
+    pub fn subtree(&self, v: &V) -> Option<impl Iterator<Item = &V>> {
+        let i = self.position(v)?;
+        Some(self.vertices[i..self.end[i]].iter())
+    }
+
+    /// Returns the number of vertices in the subtree rooted at `v`, or `None`
+    /// if `v` is not in the forest.
+    #[must_use]
+    pub fn subtree_size(&self, v: &V) -> Option<usize> {
+        let i = self.position(v)?;
+        Some(self.end[i] - i)
+    }
+
+    /// Returns `true` if `ancestor` is an ancestor of `v` or `v` itself.
+    #[must_use]
+    pub fn is_ancestor(&self, ancestor: &V, v: &V) -> bool {
+        match (self.position(ancestor), self.position(v)) {
+            (Some(a), Some(i)) => a <= i && i < self.end[a],
+            _ => false,
+        }
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`, the deepest vertex
+    /// having both in its subtree.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&V)` - The lowest common ancestor, which is `u` if `u` is an
+    ///   ancestor of `v`.
+    /// * `None` - If `u` and `v` are in different trees or not in the forest.
+    #[must_use]
+    pub fn lca(&self, u: &V, v: &V) -> Option<&V> {
+        let lca = self.lca_position(self.position(u)?, self.position(v)?)?;
+        Some(&self.vertices[lca])
+    }
+
+    fn lca_position(&self, u: usize, v: usize) -> Option<usize> {
+        if u == v {
+            return Some(u);
+        }
+        let (u, v) = (u.min(v), u.max(v));
+        // Between two trees, the shallowest vertex is a root without a parent.
+        self.parent[self.shallowest(u + 1, v + 1)]
+    }
+
+    /// Returns the number of edges on the path between `u` and `v`, or `None`
+    /// if they are in different trees or not in the forest.
+    #[must_use]
+    pub fn distance(&self, u: &V, v: &V) -> Option<usize> {
+        let (u, v) = (self.position(u)?, self.position(v)?);
+        let lca = self.lca_position(u, v)?;
+        Some(self.depth[u] + self.depth[v] - 2 * self.depth[lca])
+    }
+}
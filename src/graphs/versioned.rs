@@ -0,0 +1,365 @@
+use crate::{
+    error::MutationError,
+    traits::{
+        graph::EdgeList,
+        weighted::{WeightedEdge, WeightedGraph, WeightedGraphMut},
+    },
+    Graph, GraphMut,
+};
+
+/// A change recorded by [`VersionedGraph`], with what is needed to revert it.
+#[derive(Clone, Debug)]
+enum Change<V, W> {
+    AddVertex(V),
+    /// A removed vertex and the edges it had, arcs entering it included.
+    RemoveVertex(V, Vec<WeightedEdge<V, Option<W>>>),
+    AddEdge(V, V),
+    RemoveEdge(V, V, Option<W>),
+    /// A new weight on an existing edge and the weight it replaced.
+    SetWeight(V, V, Option<W>),
+}
+
+/// Wraps a weighted graph and records every mutation so it can be undone.
+///
+/// Each mutation made through [`GraphMut`] or [`WeightedGraphMut`] is logged with
+/// the state it overwrote, so [`VersionedGraph::undo`] reverts it in time
+/// proportional to the change instead of copying the graph. Checkpoints mark
+/// positions in the log: [`VersionedGraph::rollback`] reverts every change made
+/// since the last one and [`VersionedGraph::commit`] keeps them. Edge weights are
+/// restored along with the edges; payloads kept by the inner graph for removed
+/// vertices, such as those of a [`StableGraph`](crate::graphs::stable::StableGraph),
+/// are not.
+///
+/// The log grows with every mutation; call [`VersionedGraph::clear_history`] once
+/// the changes are final.
+///
+/// ```
+/// use kambo_graph::{graph, graphs::versioned::VersionedGraph, Graph, GraphMut};
+///
+/// let mut graph = VersionedGraph::new(graph! { 1 - 2, 2 - 3 });
+/// graph.checkpoint();
+/// graph.remove_edge(&1, &2).unwrap();
+/// graph.add_edge(&1, &3).unwrap();
+/// assert!(graph.rollback());
+/// assert!(graph.contains_edge(&1, &2));
+/// assert!(!graph.contains_edge(&1, &3));
+/// ```
+#[derive(Clone, Debug)]
+pub struct VersionedGraph<G>
+where
+    G: WeightedGraph,
+{
+    graph: G,
+    log: Vec<Change<G::Vertex, G::Weight>>,
+    /// Log lengths at the open checkpoints, oldest first.
+    checkpoints: Vec<usize>,
+}
+
+impl<G> VersionedGraph<G>
+where
+    G: WeightedGraphMut,
+{
+    /// Wraps a graph with an empty history.
+    #[must_use]
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph,
+            log: Vec::new(),
+            checkpoints: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the inner graph.
+    #[must_use]
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Unwraps the inner graph, discarding the history.
+    #[must_use]
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+
+    /// Returns the number of recorded changes that can be undone.
+    #[must_use]
+    pub fn history_len(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Returns the number of open checkpoints.
+    #[must_use]
+    pub fn checkpoint_count(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Opens a checkpoint at the current state. Checkpoints nest: each
+    /// [`VersionedGraph::rollback`] or [`VersionedGraph::commit`] closes the most
+    /// recent one.
+    pub fn checkpoint(&mut self) {
+        self.checkpoints.push(self.log.len());
+    }
+
+    /// Reverts the last recorded change.
+    ///
+    /// Checkpoints opened after the restored state are closed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a change was reverted, `false` if the history is empty.
+    pub fn undo(&mut self) -> bool {
+        let Some(change) = self.log.pop() else {
+            return false;
+        };
+        self.revert(change);
+        let len = self.log.len();
+        while self.checkpoints.last().is_some_and(|&mark| mark > len) {
+            self.checkpoints.pop();
+        }
+        true
+    }
+
+    /// Reverts every change made since the most recent checkpoint and closes it.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a checkpoint was open, `false` otherwise, in which case nothing
+    /// changes.
+    pub fn rollback(&mut self) -> bool {
+        let Some(mark) = self.checkpoints.pop() else {
+            return false;
+        };
+        while self.log.len() > mark {
+            if let Some(change) = self.log.pop() {
+                self.revert(change);
+            }
+        }
+        true
+    }
+
+    /// Closes the most recent checkpoint, keeping the changes made since then.
+    /// They can still be reverted by an enclosing checkpoint or by
+    /// [`VersionedGraph::undo`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if a checkpoint was open, `false` otherwise.
+    pub fn commit(&mut self) -> bool {
+        self.checkpoints.pop().is_some()
+    }
+
+    /// Forgets the recorded changes and closes every checkpoint, keeping the
+    /// current state.
+    pub fn clear_history(&mut self) {
+        self.log.clear();
+        self.checkpoints.clear();
+    }
+
+    /// Applies the inverse of a change to the inner graph. Reverting only
+    /// restores a state the graph was in, so the operations cannot fail and
+    /// their results are ignored.
+    fn revert(&mut self, change: Change<G::Vertex, G::Weight>) {
+        match change {
+            Change::AddVertex(v) => {
+                let _ = self.graph.remove_vertex(&v);
+            }
+            Change::RemoveVertex(v, edges) => {
+                let _ = self.graph.add_vertex(v);
+                for (a, b, weight) in edges {
+                    self.restore_edge(&a, &b, weight);
+                }
+            }
+            Change::AddEdge(u, v) => {
+                let _ = self.graph.remove_edge(&u, &v);
+            }
+            Change::RemoveEdge(u, v, weight) => self.restore_edge(&u, &v, weight),
+            Change::SetWeight(u, v, Some(weight)) => {
+                let _ = self.graph.update_edge_weight(&u, &v, weight);
+            }
+            Change::SetWeight(u, v, None) => {
+                let _ = self.graph.remove_edge(&u, &v);
+                let _ = self.graph.add_edge(&u, &v);
+            }
+        }
+    }
+
+    fn restore_edge(&mut self, u: &G::Vertex, v: &G::Vertex, weight: Option<G::Weight>) {
+        let _ = match weight {
+            Some(weight) => self.graph.add_weighted_edge(u, v, weight),
+            None => self.graph.add_edge(u, v),
+        };
+    }
+}
+
+impl<G> Graph for VersionedGraph<G>
+where
+    G: WeightedGraph,
+{
+    type Vertex = G::Vertex;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.graph.vertices()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, G>> {
+        self.graph.neighbors(v)
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.graph.contains_vertex(v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.graph.contains_edge(u, v)
+    }
+
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+
+    fn order(&self) -> usize {
+        self.graph.order()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+
+    fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+        self.graph.degree(v)
+    }
+}
+
+impl<G> GraphMut for VersionedGraph<G>
+where
+    G: WeightedGraphMut,
+{
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_vertex(vertex.clone())?;
+        self.log.push(Change::AddVertex(vertex));
+        Ok(())
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        let edges = self.graph.remove_weighted_vertex(vertex)?;
+        let removed = edges
+            .iter()
+            .map(|(u, v, _)| (u.clone(), v.clone()))
+            .collect();
+        self.log.push(Change::RemoveVertex(vertex.clone(), edges));
+        Ok(removed)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_edge(u, v)?;
+        self.log.push(Change::AddEdge(u.clone(), v.clone()));
+        Ok(())
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        let weight = self.graph.edge_weight(u, v).cloned();
+        self.graph.remove_edge(u, v)?;
+        self.log
+            .push(Change::RemoveEdge(u.clone(), v.clone(), weight));
+        Ok(())
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        // Removing the vertices one at a time logs the arcs entering those of a
+        // directed graph.
+        let isolated = self.graph.get_isolated_vertices();
+        for v in &isolated {
+            self.remove_vertex(v)?;
+        }
+        Ok(isolated)
+    }
+}
+
+impl<G> WeightedGraph for VersionedGraph<G>
+where
+    G: WeightedGraph,
+{
+    type Weight = G::Weight;
+
+    fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight> {
+        self.graph.edge_weight(u, v)
+    }
+}
+
+impl<G> WeightedGraphMut for VersionedGraph<G>
+where
+    G: WeightedGraphMut,
+{
+    fn add_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<(), MutationError> {
+        self.graph.add_weighted_edge(u, v, weight)?;
+        self.log.push(Change::AddEdge(u.clone(), v.clone()));
+        Ok(())
+    }
+
+    fn update_edge_weight(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        let previous = self.graph.update_edge_weight(u, v, weight)?;
+        self.log
+            .push(Change::SetWeight(u.clone(), v.clone(), previous.clone()));
+        Ok(previous)
+    }
+
+    fn remove_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        let weight = self.graph.remove_weighted_edge(u, v)?;
+        self.log
+            .push(Change::RemoveEdge(u.clone(), v.clone(), weight.clone()));
+        Ok(weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimpleGraph;
+
+    #[test]
+    fn undo_restores_the_previous_self_loop_weight() {
+        let mut graph = VersionedGraph::new(SimpleGraph::new_weighted_undirected());
+        graph.add_vertex(1).unwrap();
+        graph.add_weighted_edge(&1, &1, 4).unwrap();
+        graph.update_edge_weight(&1, &1, 6).unwrap();
+        graph.update_edge_weight(&1, &1, 8).unwrap();
+
+        assert!(graph.undo());
+        assert_eq!(graph.edge_weight(&1, &1), Some(&6));
+        assert!(graph.undo());
+        assert_eq!(graph.edge_weight(&1, &1), Some(&4));
+    }
+
+    #[test]
+    fn rollback_restores_the_self_loop_weight_at_the_checkpoint() {
+        let mut graph = VersionedGraph::new(SimpleGraph::new_weighted_undirected());
+        graph.add_vertex(1).unwrap();
+        graph.add_weighted_edge(&1, &1, 4).unwrap();
+        graph.checkpoint();
+        graph.update_edge_weight(&1, &1, 6).unwrap();
+        graph.update_edge_weight(&1, &1, 8).unwrap();
+
+        assert!(graph.rollback());
+        assert_eq!(graph.edge_weight(&1, &1), Some(&4));
+    }
+}
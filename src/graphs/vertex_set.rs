@@ -0,0 +1,434 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Graph;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A numbering of the vertices of a graph as `0..n`, the universe of the
+/// [`VertexSet`]s built from it.
+///
+/// The index is a snapshot: vertices added to the graph afterwards are unknown
+/// to it.
+#[derive(Debug, Clone)]
+pub struct VertexIndex<V> {
+    vertices: Vec<V>,
+    positions: HashMap<V, usize>,
+}
+
+impl<V> VertexIndex<V>
+where
+    V: Eq + std::hash::Hash + Clone + std::fmt::Debug,
+{
+    /// Numbers the vertices of `graph` in iteration order.
+    #[must_use]
+    pub fn new<G>(graph: &G) -> Self
+    where
+        G: Graph<Vertex = V>,
+    {
+        Self::from_vertices(graph.vertices().cloned())
+    }
+
+    /// Numbers the given vertices in order, ignoring repeated vertices.
+    #[must_use]
+    pub fn from_vertices<I>(vertices: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+    {
+        let mut index = Self {
+            vertices: Vec::new(),
+            positions: HashMap::new(),
+        };
+        for v in vertices {
+            if !index.positions.contains_key(&v) {
+                index.positions.insert(v.clone(), index.vertices.len());
+                index.vertices.push(v);
+            }
+        }
+        index
+    }
+
+    /// Returns the number of vertices in the universe.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns `true` if the universe is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Returns the number of `v`, or `None` if `v` is not in the universe.
+    #[must_use]
+    pub fn index_of(&self, v: &V) -> Option<usize> {
+        self.positions.get(v).copied()
+    }
+
+    /// Returns the vertex numbered `i`, or `None` if `i` is out of range.
+    #[must_use]
+    pub fn vertex(&self, i: usize) -> Option<&V> {
+        self.vertices.get(i)
+    }
+
+    /// Returns an empty set over this universe.
+    #[must_use]
+    pub fn empty_set(&self) -> VertexSet {
+        VertexSet::new(self.len())
+    }
+
+    /// Returns the set of all vertices of this universe.
+    #[must_use]
+    pub fn full_set(&self) -> VertexSet {
+        VertexSet::full(self.len())
+    }
+
+    /// Builds a set from vertices, ignoring vertices outside the universe.
+    #[must_use]
+    pub fn set_of<'a, I>(&self, vertices: I) -> VertexSet
+    where
+        I: IntoIterator<Item = &'a V>,
+        V: 'a,
+    {
+        let mut set = self.empty_set();
+        for i in vertices.into_iter().filter_map(|v| self.index_of(v)) {
+            set.insert(i);
+        }
+        set
+    }
+
+    /// Iterates over the vertices of a set.
+    pub fn vertices_of<'a>(&'a self, set: &'a VertexSet) -> impl Iterator<Item = &'a V> + 'a {
+        set.iter().filter_map(|i| self.vertex(i))
+    }
+
+    /// Converts a set into a `HashSet` of vertices.
+    #[must_use]
+    pub fn to_hash_set(&self, set: &VertexSet) -> HashSet<V> {
+        self.vertices_of(set).cloned().collect()
+    }
+
+    /// Returns the out-neighborhood of every vertex as a set, indexed by vertex
+    /// number. Neighbors outside the universe are ignored.
+    ///
+    /// Exact subset solvers (cliques, covers, dominating sets) can then test and
+    /// combine neighborhoods with word-level operations.
+    #[must_use]
+    pub fn neighborhoods<G>(&self, graph: &G) -> Vec<VertexSet>
+    where
+        G: Graph<Vertex = V>,
+    {
+        self.vertices
+            .iter()
+            .map(|v| self.set_of(graph.neighbors(v).into_iter().flatten()))
+            .collect()
+    }
+}
+
+/// A set of vertex numbers from a [`VertexIndex`], stored as a bitset.
+///
+/// Union, intersection, difference and complement work a machine word at a
+/// time. Binary operations expect both sets to share the same universe.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VertexSet {
+    words: Vec<u64>,
+    universe: usize,
+}
+
+impl VertexSet {
+    /// Creates an empty set over the universe `0..universe`.
+    #[must_use]
+    pub fn new(universe: usize) -> Self {
+        Self {
+            words: vec![0; universe.div_ceil(BITS)],
+            universe,
+        }
+    }
+
+    /// Creates the set of every number in `0..universe`.
+    #[must_use]
+    pub fn full(universe: usize) -> Self {
+        let mut set = Self {
+            words: vec![u64::MAX; universe.div_ceil(BITS)],
+            universe,
+        };
+        set.clear_excess();
+        set
+    }
+
+    /// Clears the bits of the last word beyond the universe.
+    fn clear_excess(&mut self) {
+        let rem = self.universe % BITS;
+        if rem != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << rem) - 1;
+            }
+        }
+    }
+
+    /// Extends the universe to `0..universe`, keeping the elements. Smaller
+    /// universes are ignored.
+    pub(crate) fn grow(&mut self, universe: usize) {
+        if universe > self.universe {
+            self.words.resize(universe.div_ceil(BITS), 0);
+            self.universe = universe;
+        }
+    }
+
+    /// Returns the size of the universe.
+    #[must_use]
+    pub fn universe(&self) -> usize {
+        self.universe
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if the set has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns `true` if `i` is in the set.
+    #[must_use]
+    pub fn contains(&self, i: usize) -> bool {
+        i < self.universe && self.words[i / BITS] & (1 << (i % BITS)) != 0
+    }
+
+    /// Adds `i` to the set, returning `true` if it was not present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is outside the universe.
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(i < self.universe, "vertex {i} is outside the universe");
+        let (word, bit) = (i / BITS, 1 << (i % BITS));
+        let absent = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        absent
+    }
+
+    /// Removes `i` from the set, returning `true` if it was present.
+    pub fn remove(&mut self, i: usize) -> bool {
+        if !self.contains(i) {
+            return false;
+        }
+        self.words[i / BITS] &= !(1 << (i % BITS));
+        true
+    }
+
+    /// Adds every element of `other` to the set.
+    pub fn union_with(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    /// Keeps only the elements also in `other`.
+    pub fn intersect_with(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= b;
+        }
+    }
+
+    /// Removes every element of `other` from the set.
+    pub fn difference_with(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= !b;
+        }
+    }
+
+    /// Returns the elements in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set.union_with(other);
+        set
+    }
+
+    /// Returns the elements in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set.intersect_with(other);
+        set
+    }
+
+    /// Returns the elements of `self` that are not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set.difference_with(other);
+        set
+    }
+
+    /// Returns the elements of the universe that are not in the set.
+    #[must_use]
+    pub fn complement(&self) -> Self {
+        let mut set = Self {
+            words: self.words.iter().map(|w| !w).collect(),
+            universe: self.universe,
+        };
+        set.clear_excess();
+        set
+    }
+
+    /// Returns the number of elements in both sets without building the
+    /// intersection.
+    #[must_use]
+    pub fn intersection_len(&self, other: &Self) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if every element of the set is in `other`.
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    /// Returns `true` if the sets have no element in common.
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    /// Iterates over the elements in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * BITS + bit)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimpleGraph;
+
+    fn set(universe: usize, elements: &[usize]) -> VertexSet {
+        let mut set = VertexSet::new(universe);
+        for &i in elements {
+            set.insert(i);
+        }
+        set
+    }
+
+    #[test]
+    fn handles_word_boundaries() {
+        let mut set = VertexSet::new(130);
+        for i in [0, 63, 64, 127, 128] {
+            assert!(set.insert(i));
+            assert!(!set.insert(i));
+        }
+        assert!(!set.contains(62) && !set.contains(65) && !set.contains(126));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 64, 127, 128]);
+        assert_eq!(set.len(), 5);
+
+        assert!(set.remove(64));
+        assert!(!set.remove(64));
+        assert!(set.contains(63) && !set.contains(64));
+        assert!(!set.contains(130));
+        assert!(!set.remove(500));
+    }
+
+    #[test]
+    fn full_sets_stop_at_the_universe() {
+        for universe in [0, 1, 63, 64, 65, 127, 128, 129] {
+            let full = VertexSet::full(universe);
+            assert_eq!(full.len(), universe);
+            assert_eq!(full.iter().last(), universe.checked_sub(1));
+            assert!(full.complement().is_empty());
+            assert_eq!(VertexSet::new(universe).complement(), full);
+        }
+        assert_eq!(
+            set(128, &[0, 64]).complement().len(),
+            126,
+            "the complement of a full last word is empty"
+        );
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(128, &[1, 63, 64, 100]);
+        let b = set(128, &[63, 64, 127]);
+        assert_eq!(a.union(&b), set(128, &[1, 63, 64, 100, 127]));
+        assert_eq!(a.intersection(&b), set(128, &[63, 64]));
+        assert_eq!(a.difference(&b), set(128, &[1, 100]));
+        assert_eq!(a.intersection_len(&b), 2);
+        assert!(!a.is_subset(&b) && !a.is_disjoint(&b));
+        assert!(a.intersection(&b).is_subset(&a));
+        assert!(a.difference(&b).is_disjoint(&b));
+
+        let mut c = a.clone();
+        c.union_with(&b);
+        c.difference_with(&set(128, &[1]));
+        c.intersect_with(&set(128, &[1, 64, 127]));
+        assert_eq!(c, set(128, &[64, 127]));
+    }
+
+    #[test]
+    fn growing_keeps_the_elements() {
+        let mut set = set(64, &[0, 63]);
+        set.grow(128);
+        assert_eq!(set.universe(), 128);
+        assert!(set.insert(127));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 127]);
+        set.grow(10);
+        assert_eq!(set.universe(), 128);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the universe")]
+    fn inserting_outside_the_universe_panics() {
+        VertexSet::new(64).insert(64);
+    }
+
+    #[test]
+    fn numbers_vertices() {
+        let index = VertexIndex::from_vertices(["a", "b", "a", "c"]);
+        assert_eq!(index.len(), 3);
+        assert!(!index.is_empty());
+        assert_eq!(index.index_of(&"c"), Some(2));
+        assert_eq!(index.index_of(&"z"), None);
+        assert_eq!(index.vertex(1), Some(&"b"));
+        assert_eq!(index.vertex(3), None);
+
+        let set = index.set_of(&["c", "a", "z"]);
+        assert_eq!(index.vertices_of(&set).collect::<Vec<_>>(), [&"a", &"c"]);
+        assert_eq!(index.to_hash_set(&set), HashSet::from(["a", "c"]));
+        assert!(index.empty_set().is_empty());
+        assert_eq!(index.full_set().len(), 3);
+        assert!(VertexIndex::<u32>::from_vertices([]).is_empty());
+    }
+
+    #[test]
+    fn builds_neighborhoods() {
+        let graph = SimpleGraph::from_edges([(0, 1), (1, 2), (2, 2)], true);
+        let index = VertexIndex::new(&graph);
+        let neighborhoods = index.neighborhoods(&graph);
+        for (i, neighborhood) in neighborhoods.iter().enumerate() {
+            let v = index.vertex(i).unwrap();
+            let expected: HashSet<u32> = graph.neighbors(v).unwrap().copied().collect();
+            assert_eq!(index.to_hash_set(neighborhood), expected);
+        }
+    }
+}
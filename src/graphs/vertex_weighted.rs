@@ -0,0 +1,215 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use crate::{
+    error::MutationError,
+    traits::{
+        graph::EdgeList,
+        vertex_weighted::{VertexWeightedGraph, VertexWeightedGraphMut},
+    },
+    Graph, GraphMut,
+};
+
+/// Wraps any graph and attaches a weight to each of its vertices.
+///
+/// The wrapper implements [`Graph`] and [`GraphMut`] by delegating to the inner
+/// graph; removing a vertex also drops its weight. Vertices added through
+/// [`GraphMut::add_vertex`] start without a weight.
+#[derive(Clone, Debug)]
+pub struct VertexWeighted<G, X>
+where
+    G: Graph,
+{
+    graph: G,
+    weights: HashMap<G::Vertex, X>,
+}
+
+impl<G, X> VertexWeighted<G, X>
+where
+    G: Graph,
+    X: Clone + Debug,
+{
+    /// Wraps a graph without assigning any vertex weight.
+    #[must_use]
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph,
+            weights: HashMap::new(),
+        }
+    }
+
+    /// Wraps a graph, computing the weight of every vertex with `weight`.
+    pub fn with_weights<F>(graph: G, mut weight: F) -> Self
+    where
+        F: FnMut(&G::Vertex) -> X,
+    {
+        let weights = graph.vertices().map(|v| (v.clone(), weight(v))).collect();
+        Self { graph, weights }
+    }
+
+    /// Returns a reference to the inner graph.
+    #[must_use]
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Unwraps the inner graph and the vertex weights.
+    #[must_use]
+    pub fn into_parts(self) -> (G, HashMap<G::Vertex, X>) {
+        (self.graph, self.weights)
+    }
+}
+
+impl<G, X> Graph for VertexWeighted<G, X>
+where
+    G: Graph,
+    X: Clone + Debug,
+{
+    type Vertex = G::Vertex;
+
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+        self.graph.vertices()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, G, X>> {
+        self.graph.neighbors(v)
+    }
+
+    fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.graph.contains_vertex(v)
+    }
+
+    fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.graph.contains_edge(u, v)
+    }
+
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+}
+
+impl<G, X> GraphMut for VertexWeighted<G, X>
+where
+    G: GraphMut,
+    X: Clone + Debug,
+{
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_vertex(vertex)
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        let removed = self.graph.remove_vertex(vertex)?;
+        self.weights.remove(vertex);
+        Ok(removed)
+    }
+
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.add_edge(u, v)
+    }
+
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.graph.remove_edge(u, v)
+    }
+
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        let removed = self.graph.remove_isolated_vertices()?;
+        for v in &removed {
+            self.weights.remove(v);
+        }
+        Ok(removed)
+    }
+}
+
+impl<G, X> VertexWeightedGraph for VertexWeighted<G, X>
+where
+    G: Graph,
+    X: Clone + Debug,
+{
+    type VertexWeight = X;
+
+    fn vertex_weight(&self, v: &Self::Vertex) -> Option<&Self::VertexWeight> {
+        self.weights.get(v)
+    }
+}
+
+impl<G, X> VertexWeightedGraphMut for VertexWeighted<G, X>
+where
+    G: Graph,
+    X: Clone + Debug,
+{
+    fn set_vertex_weight(
+        &mut self,
+        v: &Self::Vertex,
+        weight: Self::VertexWeight,
+    ) -> Result<(), MutationError> {
+        if !self.graph.contains_vertex(v) {
+            return Err(MutationError::VertexNotFound);
+        }
+        self.weights.insert(v.clone(), weight);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimpleGraph;
+
+    fn path() -> VertexWeighted<SimpleGraph<u32>, u32> {
+        let graph = SimpleGraph::from_edges([(1, 2), (2, 3)], false);
+        VertexWeighted::with_weights(graph, |&v| 10 * v)
+    }
+
+    #[test]
+    fn delegates_to_the_inner_graph() {
+        let graph = path();
+        assert!(!graph.is_directed());
+        assert!(graph.contains_vertex(&2));
+        assert!(graph.contains_edge(&3, &2));
+        let mut neighbors: Vec<_> = graph.neighbors(&2).unwrap().copied().collect();
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, [1, 3]);
+        assert_eq!(graph.vertices().count(), graph.graph().vertices().count());
+    }
+
+    #[test]
+    fn computes_and_sets_weights() {
+        let mut graph = path();
+        assert_eq!(graph.vertex_weight(&3), Some(&30));
+        graph.set_vertex_weight(&3, 7).unwrap();
+        assert_eq!(graph.vertex_weight(&3), Some(&7));
+        assert_eq!(
+            graph.set_vertex_weight(&4, 1),
+            Err(MutationError::VertexNotFound)
+        );
+
+        graph.add_vertex(4).unwrap();
+        assert_eq!(graph.vertex_weight(&4), None);
+        graph.add_edge(&3, &4).unwrap();
+        assert!(graph.graph().contains_edge(&4, &3));
+
+        let unweighted: VertexWeighted<_, u32> = VertexWeighted::new(graph.graph().clone());
+        assert_eq!(unweighted.vertex_weight(&1), None);
+    }
+
+    #[test]
+    fn removing_vertices_drops_their_weights() {
+        let mut graph = path();
+        graph.remove_vertex(&1).unwrap();
+        assert_eq!(graph.vertex_weight(&1), None);
+
+        graph.remove_edge(&2, &3).unwrap();
+        let mut removed = graph.remove_isolated_vertices().unwrap();
+        removed.sort_unstable();
+        assert_eq!(removed, [2, 3]);
+
+        let (inner, weights) = graph.into_parts();
+        assert_eq!(inner.vertices().count(), 0);
+        assert!(weights.is_empty());
+    }
+}
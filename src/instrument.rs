@@ -0,0 +1,49 @@
+//! Optional `tracing` instrumentation.
+//!
+//! The macros expand to nothing unless the `tracing` feature is enabled, so
+//! instrumented code pays no cost by default.
+
+/// Enters an `INFO` span named `$name` for the rest of the enclosing block. When
+/// the span is left, a `DEBUG` event with its elapsed time in microseconds is
+/// emitted inside it.
+macro_rules! trace_span {
+    ($name:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = $crate::instrument::TimedSpan::enter(
+            tracing::info_span!($name $(, $($fields)*)?),
+        );
+    };
+}
+
+/// Emits a `DEBUG` event, such as the end of a phase or a visited count.
+macro_rules! trace_event {
+    ($($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($args)*);
+    };
+}
+
+/// An entered span that reports its duration when dropped.
+#[cfg(feature = "tracing")]
+pub(crate) struct TimedSpan {
+    span: tracing::span::EnteredSpan,
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl TimedSpan {
+    pub(crate) fn enter(span: tracing::Span) -> Self {
+        Self {
+            span: span.entered(),
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for TimedSpan {
+    fn drop(&mut self) {
+        let elapsed_us = u64::try_from(self.start.elapsed().as_micros()).unwrap_or(u64::MAX);
+        tracing::debug!(parent: self.span.id(), elapsed_us, "finished");
+    }
+}
@@ -9,6 +9,9 @@
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
 
+#[macro_use]
+mod instrument;
+
 /// Module for handling errors related to graph operations.
 ///
 /// This module provides the `GraphError` enum, which defines a variety of
@@ -16,6 +19,9 @@
 /// vertices, duplicate edges, or invalid operations.
 pub mod error;
 
+/// Graph algorithms implemented generically over the graph traits.
+pub mod algorithms;
+
 /// Graph struct implementations
 pub mod graphs;
 
@@ -25,6 +31,9 @@ pub mod traits;
 /// Module defining utilities functions
 pub mod utils;
 
+/// Utilities for validating custom graph implementations against the trait contracts.
+pub mod testing;
+
 pub use error::GraphError;
 pub use graphs::simple::SimpleGraph;
 pub use traits::graph::Graph;
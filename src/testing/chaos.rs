@@ -0,0 +1,479 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug},
+    hash::Hash,
+    mem::discriminant,
+};
+
+use rand::Rng;
+
+use crate::{error::MutationError, Graph, GraphMut};
+
+/// A single mutation applied by the chaos harness.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mutation<V> {
+    /// `add_vertex(v)`
+    AddVertex(V),
+    /// `remove_vertex(&v)`
+    RemoveVertex(V),
+    /// `add_edge(&u, &v)`
+    AddEdge(V, V),
+    /// `remove_edge(&u, &v)`
+    RemoveEdge(V, V),
+    /// `remove_isolated_vertices()`
+    RemoveIsolatedVertices,
+}
+
+/// Parameters of a chaos run.
+#[derive(Debug, Clone, Copy)]
+pub struct ChaosConfig {
+    /// Number of mutations to apply.
+    pub steps: usize,
+    /// Number of distinct vertices mutations are drawn from.
+    ///
+    /// A small pool makes invalid mutations (duplicates, missing endpoints) frequent.
+    pub vertex_pool: usize,
+}
+
+impl Default for ChaosConfig {
+    fn default() -> Self {
+        Self {
+            steps: 1000,
+            vertex_pool: 16,
+        }
+    }
+}
+
+/// Summary of a successful chaos run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChaosReport {
+    /// Mutations that succeeded.
+    pub applied: usize,
+    /// Mutations that were rejected with the expected error.
+    pub rejected: usize,
+}
+
+/// Describes the first divergence between the graph under test and the model.
+#[derive(Debug, Clone)]
+pub struct ChaosFailure<V> {
+    /// Index of the failing step.
+    pub step: usize,
+    /// All mutations applied so far, ending with the failing one. Replaying them on
+    /// an empty graph reproduces the failure.
+    pub history: Vec<Mutation<V>>,
+    /// What went wrong.
+    pub reason: String,
+}
+
+impl<V: Debug> fmt::Display for ChaosFailure<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Chaos check failed at step {} ({:?}): {}",
+            self.step,
+            self.history.last(),
+            self.reason
+        )
+    }
+}
+
+impl<V: Debug> std::error::Error for ChaosFailure<V> {}
+
+/// What a successful mutation removed, compared regardless of order.
+#[derive(Debug, PartialEq, Eq)]
+struct Removed<V: Eq + Hash> {
+    vertices: HashSet<V>,
+    edges: HashSet<(V, V)>,
+}
+
+impl<V: Eq + Hash> Removed<V> {
+    fn nothing() -> Self {
+        Self {
+            vertices: HashSet::new(),
+            edges: HashSet::new(),
+        }
+    }
+
+    fn edges(edges: impl IntoIterator<Item = (V, V)>) -> Self {
+        Self {
+            vertices: HashSet::new(),
+            edges: edges.into_iter().collect(),
+        }
+    }
+
+    fn vertices(vertices: impl IntoIterator<Item = V>) -> Self {
+        Self {
+            vertices: vertices.into_iter().collect(),
+            edges: HashSet::new(),
+        }
+    }
+}
+
+/// Reference implementation of the [`GraphMut`] contract.
+struct Model<V> {
+    adjacency: HashMap<V, HashSet<V>>,
+    directed: bool,
+}
+
+impl<V> Model<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    fn from_graph<G: Graph<Vertex = V>>(graph: &G) -> Self {
+        let adjacency = graph
+            .vertices()
+            .map(|v| {
+                let neighbors = graph.neighbors(v).into_iter().flatten().cloned().collect();
+                (v.clone(), neighbors)
+            })
+            .collect();
+        Self {
+            adjacency,
+            directed: graph.is_directed(),
+        }
+    }
+
+    fn has_edge(&self, u: &V, v: &V) -> bool {
+        self.adjacency.get(u).is_some_and(|n| n.contains(v))
+    }
+
+    fn apply(&mut self, mutation: &Mutation<V>) -> Result<Removed<V>, MutationError> {
+        match mutation {
+            Mutation::AddVertex(v) => {
+                if self.adjacency.contains_key(v) {
+                    return Err(MutationError::VertexAlreadyExists);
+                }
+                self.adjacency.insert(v.clone(), HashSet::new());
+            }
+            Mutation::RemoveVertex(v) => {
+                let Some(targets) = self.adjacency.remove(v) else {
+                    return Err(MutationError::VertexNotFound);
+                };
+                let mut edges: Vec<(V, V)> = targets.into_iter().map(|w| (v.clone(), w)).collect();
+                for (u, neighbors) in &mut self.adjacency {
+                    if neighbors.remove(v) && self.directed {
+                        edges.push((u.clone(), v.clone()));
+                    }
+                }
+                return Ok(Removed::edges(edges));
+            }
+            Mutation::AddEdge(u, v) => {
+                if !self.adjacency.contains_key(u) || !self.adjacency.contains_key(v) {
+                    return Err(MutationError::VertexNotFound);
+                }
+                if self.has_edge(u, v) {
+                    return Err(MutationError::EdgeAlreadyExists);
+                }
+                self.adjacency.get_mut(u).unwrap().insert(v.clone());
+                if !self.directed {
+                    self.adjacency.get_mut(v).unwrap().insert(u.clone());
+                }
+            }
+            Mutation::RemoveEdge(u, v) => {
+                if !self.has_edge(u, v) {
+                    return Err(MutationError::EdgeNotFound);
+                }
+                self.adjacency.get_mut(u).unwrap().remove(v);
+                if !self.directed {
+                    self.adjacency.get_mut(v).unwrap().remove(u);
+                }
+            }
+            Mutation::RemoveIsolatedVertices => {
+                let isolated: Vec<V> = self
+                    .adjacency
+                    .iter()
+                    .filter(|(_, neighbors)| neighbors.is_empty())
+                    .map(|(v, _)| v.clone())
+                    .collect();
+                for v in &isolated {
+                    self.apply(&Mutation::RemoveVertex(v.clone()))?;
+                }
+                return Ok(Removed::vertices(isolated));
+            }
+        }
+        Ok(Removed::nothing())
+    }
+
+    fn edge_count(&self) -> usize {
+        let arcs: usize = self.adjacency.values().map(HashSet::len).sum();
+        if self.directed {
+            arcs
+        } else {
+            // A self-loop appears once in the adjacency, every other edge twice.
+            let loops = self
+                .adjacency
+                .iter()
+                .filter(|(v, neighbors)| neighbors.contains(v))
+                .count();
+            (arcs - loops) / 2 + loops
+        }
+    }
+
+    fn check<G: Graph<Vertex = V>>(&self, graph: &G) -> Result<(), String> {
+        if graph.is_directed() != self.directed {
+            return Err("directedness changed".to_string());
+        }
+        if graph.order() != self.adjacency.len() {
+            return Err(format!(
+                "order is {}, expected {}",
+                graph.order(),
+                self.adjacency.len()
+            ));
+        }
+        if graph.edge_count() != self.edge_count() {
+            return Err(format!(
+                "edge_count is {}, expected {}",
+                graph.edge_count(),
+                self.edge_count()
+            ));
+        }
+        for (v, expected) in &self.adjacency {
+            if !graph.contains_vertex(v) {
+                return Err(format!("vertex {v:?} is missing"));
+            }
+            let Some(neighbors) = graph.neighbors(v) else {
+                return Err(format!("neighbors of {v:?} returned None"));
+            };
+            let actual: HashSet<V> = neighbors.cloned().collect();
+            if &actual != expected {
+                return Err(format!(
+                    "neighbors of {v:?} are {actual:?}, expected {expected:?}"
+                ));
+            }
+            if graph.degree(v) != Some(expected.len()) {
+                return Err(format!(
+                    "degree of {v:?} is {:?}, expected {}",
+                    graph.degree(v),
+                    expected.len()
+                ));
+            }
+            for w in expected {
+                if !graph.contains_edge(v, w) {
+                    return Err(format!("edge ({v:?}, {w:?}) is missing"));
+                }
+            }
+        }
+        let isolated = self.adjacency.values().any(HashSet::is_empty);
+        if graph.has_isolated_vertex() != isolated {
+            return Err(format!(
+                "has_isolated_vertex is {}, expected {isolated}",
+                graph.has_isolated_vertex()
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn random_mutation<V, R, F>(rng: &mut R, pool: usize, make_vertex: &mut F) -> Mutation<V>
+where
+    R: Rng + ?Sized,
+    F: FnMut(usize) -> V,
+{
+    let pool = pool.max(2);
+    let u = rng.random_range(0..pool);
+    // Offsetting by 1..pool keeps the second endpoint distinct, so no self-loops.
+    let v = (u + rng.random_range(1..pool)) % pool;
+    match rng.random_range(0..100) {
+        0..30 => Mutation::AddVertex(make_vertex(u)),
+        30..45 => Mutation::RemoveVertex(make_vertex(u)),
+        45..80 => Mutation::AddEdge(make_vertex(u), make_vertex(v)),
+        80..97 => Mutation::RemoveEdge(make_vertex(u), make_vertex(v)),
+        _ => Mutation::RemoveIsolatedVertices,
+    }
+}
+
+fn apply_to_graph<G: GraphMut>(
+    graph: &mut G,
+    mutation: &Mutation<G::Vertex>,
+) -> Result<Removed<G::Vertex>, MutationError> {
+    match mutation {
+        Mutation::AddVertex(v) => graph.add_vertex(v.clone()).map(|()| Removed::nothing()),
+        Mutation::RemoveVertex(v) => graph.remove_vertex(v).map(Removed::edges),
+        Mutation::AddEdge(u, v) => graph.add_edge(u, v).map(|()| Removed::nothing()),
+        Mutation::RemoveEdge(u, v) => graph.remove_edge(u, v).map(|()| Removed::nothing()),
+        Mutation::RemoveIsolatedVertices => graph.remove_isolated_vertices().map(Removed::vertices),
+    }
+}
+
+/// Applies a random sequence of valid and invalid mutations to `graph`, checking
+/// after every step that it behaves exactly like a reference model of the
+/// [`GraphMut`] contract.
+///
+/// After each mutation the harness compares the returned result (the removed
+/// vertices or edges on success, or the error variant) and then the observable
+/// state: vertex set, neighbor sets, `degree`, `order`, `edge_count`,
+/// `contains_edge` and `has_isolated_vertex`.
+/// Self-loops are never generated. The graph may start non-empty; the model is
+/// initialized from its current state.
+///
+/// # Arguments
+///
+/// * `graph` - The graph implementation under test.
+/// * `rng` - The random source; use a seeded RNG for reproducible runs.
+/// * `config` - Number of steps and size of the vertex pool.
+/// * `make_vertex` - Builds the vertex for a pool index in `0..config.vertex_pool`.
+///   It must return equal vertices for equal indices and distinct ones otherwise.
+///
+/// # Returns
+///
+/// A [`ChaosReport`] counting accepted and rejected mutations.
+///
+/// # Errors
+///
+/// Returns a [`ChaosFailure`] describing the first divergence from the model,
+/// together with the mutation history that reproduces it.
+pub fn check_mutations<G, R, F>(
+    graph: &mut G,
+    rng: &mut R,
+    config: &ChaosConfig,
+    mut make_vertex: F,
+) -> Result<ChaosReport, ChaosFailure<G::Vertex>>
+where
+    G: GraphMut,
+    R: Rng + ?Sized,
+    F: FnMut(usize) -> G::Vertex,
+{
+    let mut model = Model::from_graph(graph);
+    let mut report = ChaosReport::default();
+    let mut history = Vec::with_capacity(config.steps);
+
+    for step in 0..config.steps {
+        let mutation = random_mutation(rng, config.vertex_pool, &mut make_vertex);
+        let expected = model.apply(&mutation);
+        let actual = apply_to_graph(graph, &mutation);
+        history.push(mutation);
+
+        let fail = |history: Vec<Mutation<G::Vertex>>, reason: String| ChaosFailure {
+            step,
+            history,
+            reason,
+        };
+
+        match (&expected, &actual) {
+            (Ok(e), Ok(a)) if e == a => report.applied += 1,
+            (Err(e), Err(a)) if discriminant(e) == discriminant(a) => report.rejected += 1,
+            _ => {
+                return Err(fail(
+                    history,
+                    format!("returned {actual:?}, expected {expected:?}"),
+                ))
+            }
+        }
+
+        if let Err(reason) = model.check(graph) {
+            return Err(fail(history, reason));
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{traits::graph::EdgeList, SimpleGraph};
+
+    const CONFIG: ChaosConfig = ChaosConfig {
+        steps: 2000,
+        vertex_pool: 8,
+    };
+
+    fn id(i: usize) -> u32 {
+        u32::try_from(i).unwrap()
+    }
+
+    fn run(mut graph: SimpleGraph<u32>, seed: u64) -> ChaosReport {
+        let mut rng = StdRng::seed_from_u64(seed);
+        check_mutations(&mut graph, &mut rng, &CONFIG, id)
+            .unwrap_or_else(|failure| panic!("seed {seed}: {failure:?}"))
+    }
+
+    #[test]
+    fn simple_graph_directed_matches_the_model() {
+        for seed in 0..4 {
+            let report = run(SimpleGraph::new_directed(), seed);
+            assert_eq!(report.applied + report.rejected, CONFIG.steps);
+            assert!(report.applied > 0 && report.rejected > 0);
+        }
+    }
+
+    #[test]
+    fn simple_graph_undirected_matches_the_model() {
+        for seed in 0..4 {
+            let report = run(SimpleGraph::new_undirected(), seed);
+            assert_eq!(report.applied + report.rejected, CONFIG.steps);
+            assert!(report.applied > 0 && report.rejected > 0);
+        }
+    }
+
+    #[test]
+    fn starts_from_a_non_empty_graph() {
+        let mut graph = SimpleGraph::new_undirected();
+        graph.add_edge_with_vertices(0, 1).unwrap();
+        graph.add_edge_with_vertices(1, 2).unwrap();
+        run(graph, 7);
+    }
+
+    /// Forgets to report the edges removed together with a vertex.
+    struct ForgetfulRemoval(SimpleGraph<u32>);
+
+    impl Graph for ForgetfulRemoval {
+        type Vertex = u32;
+
+        fn vertices(&self) -> impl Iterator<Item = &u32> {
+            self.0.vertices()
+        }
+
+        fn neighbors<'a>(&'a self, v: &u32) -> Option<impl Iterator<Item = &'a u32> + use<'a>> {
+            self.0.neighbors(v)
+        }
+
+        fn contains_vertex(&self, v: &u32) -> bool {
+            self.0.contains_vertex(v)
+        }
+
+        fn contains_edge(&self, u: &u32, v: &u32) -> bool {
+            self.0.contains_edge(u, v)
+        }
+
+        fn is_directed(&self) -> bool {
+            self.0.is_directed()
+        }
+    }
+
+    impl GraphMut for ForgetfulRemoval {
+        fn add_vertex(&mut self, vertex: u32) -> Result<(), MutationError> {
+            self.0.add_vertex(vertex)
+        }
+
+        fn remove_vertex(&mut self, vertex: &u32) -> Result<EdgeList<u32>, MutationError> {
+            self.0.remove_vertex(vertex).map(|_| Vec::new())
+        }
+
+        fn add_edge(&mut self, u: &u32, v: &u32) -> Result<(), MutationError> {
+            self.0.add_edge(u, v)
+        }
+
+        fn remove_edge(&mut self, u: &u32, v: &u32) -> Result<(), MutationError> {
+            self.0.remove_edge(u, v)
+        }
+
+        fn remove_isolated_vertices(&mut self) -> Result<Vec<u32>, MutationError> {
+            self.0.remove_isolated_vertices()
+        }
+    }
+
+    #[test]
+    fn detects_a_wrong_remove_vertex_result() {
+        let mut graph = ForgetfulRemoval(SimpleGraph::new_undirected());
+        let mut rng = StdRng::seed_from_u64(0);
+        let failure = check_mutations(&mut graph, &mut rng, &CONFIG, id).unwrap_err();
+        assert_eq!(failure.history.len(), failure.step + 1);
+        assert!(matches!(
+            failure.history.last(),
+            Some(Mutation::RemoveVertex(_))
+        ));
+    }
+}
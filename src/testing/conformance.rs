@@ -0,0 +1,434 @@
+use std::collections::HashSet;
+
+use crate::{error::MutationError, GraphMut};
+
+/// Instantiates the conformance checks of [`crate::testing::conformance`] as
+/// `#[test]` functions inside a new module.
+///
+/// # Arguments
+///
+/// * `$name` - Name of the generated test module.
+/// * `$new` - An expression creating a fresh, empty graph. It is evaluated once per
+///   test.
+/// * `$vertex` - A closure `Fn(usize) -> Vertex` returning distinct vertices for
+///   distinct indices.
+///
+/// The generated module imports its parent with `use super::*`, so both
+/// expressions may refer to items in scope where the macro is invoked.
+///
+/// ```
+/// use kambo_graph::{testing::conformance, SimpleGraph};
+///
+/// kambo_graph::graph_trait_tests!(
+///     simple_graph_contract,
+///     SimpleGraph::<u32>::new_undirected(),
+///     |i: usize| u32::try_from(i).unwrap(),
+/// );
+///
+/// # fn main() {
+/// // Each generated test runs one check, which can also be called directly.
+/// conformance::check_size(SimpleGraph::<u32>::new_undirected(), |i: usize| {
+///     u32::try_from(i).unwrap()
+/// });
+/// # }
+/// ```
+#[macro_export]
+macro_rules! graph_trait_tests {
+    ($name:ident, $new:expr, $vertex:expr $(,)?) => {
+        #[cfg(test)]
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn add_vertex() {
+                $crate::testing::conformance::check_add_vertex($new, $vertex);
+            }
+
+            #[test]
+            fn remove_vertex() {
+                $crate::testing::conformance::check_remove_vertex($new, $vertex);
+            }
+
+            #[test]
+            fn add_edge() {
+                $crate::testing::conformance::check_add_edge($new, $vertex);
+            }
+
+            #[test]
+            fn remove_edge() {
+                $crate::testing::conformance::check_remove_edge($new, $vertex);
+            }
+
+            #[test]
+            fn directedness() {
+                $crate::testing::conformance::check_directedness($new, $vertex);
+            }
+
+            #[test]
+            fn isolated_vertices() {
+                $crate::testing::conformance::check_isolated_vertices($new, $vertex);
+            }
+
+            #[test]
+            fn size() {
+                $crate::testing::conformance::check_size($new, $vertex);
+            }
+
+            #[test]
+            fn iterator_consistency() {
+                $crate::testing::conformance::check_iterator_consistency($new, $vertex);
+            }
+        }
+    };
+}
+
+fn add_vertices<G, F>(graph: &mut G, vertex: &F, n: usize)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    for i in 0..n {
+        graph
+            .add_vertex(vertex(i))
+            .unwrap_or_else(|e| panic!("add_vertex({:?}) failed: {e}", vertex(i)));
+    }
+}
+
+/// Checks `add_vertex` and `contains_vertex`, including the
+/// `VertexAlreadyExists` error.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_add_vertex<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    assert!(
+        !graph.contains_vertex(&vertex(0)),
+        "new graph must be empty"
+    );
+    assert_eq!(graph.order(), 0, "new graph must have order 0");
+
+    add_vertices(&mut graph, &vertex, 3);
+    for i in 0..3 {
+        assert!(graph.contains_vertex(&vertex(i)), "vertex {i} must exist");
+    }
+    assert_eq!(graph.order(), 3);
+    assert!(
+        matches!(
+            graph.add_vertex(vertex(1)),
+            Err(MutationError::VertexAlreadyExists)
+        ),
+        "adding a duplicate vertex must fail with VertexAlreadyExists"
+    );
+    assert_eq!(
+        graph.order(),
+        3,
+        "a rejected add_vertex must not change the graph"
+    );
+}
+
+/// Checks `remove_vertex`, including removal of incident edges, the edges it
+/// returns and the `VertexNotFound` error.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_remove_vertex<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    add_vertices(&mut graph, &vertex, 3);
+    graph.add_edge(&vertex(0), &vertex(1)).unwrap();
+    graph.add_edge(&vertex(2), &vertex(1)).unwrap();
+
+    let removed: HashSet<_> = graph
+        .remove_vertex(&vertex(1))
+        .unwrap()
+        .into_iter()
+        .collect();
+    let expected: HashSet<_> = if graph.is_directed() {
+        [(vertex(0), vertex(1)), (vertex(2), vertex(1))].into()
+    } else {
+        [(vertex(1), vertex(0)), (vertex(1), vertex(2))].into()
+    };
+    assert_eq!(removed, expected, "the incident edges must be returned");
+    assert!(!graph.contains_vertex(&vertex(1)));
+    assert_eq!(graph.order(), 2);
+    assert_eq!(graph.edge_count(), 0, "incident edges must be removed");
+    assert!(!graph.contains_edge(&vertex(0), &vertex(1)));
+    assert!(!graph.contains_edge(&vertex(2), &vertex(1)));
+    assert_eq!(graph.degree(&vertex(0)), Some(0));
+    assert_eq!(graph.degree(&vertex(2)), Some(0));
+
+    assert!(
+        matches!(
+            graph.remove_vertex(&vertex(1)),
+            Err(MutationError::VertexNotFound)
+        ),
+        "removing a missing vertex must fail with VertexNotFound"
+    );
+}
+
+/// Checks `add_edge` and `contains_edge`, including the `VertexNotFound` and
+/// `EdgeAlreadyExists` errors.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_add_edge<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    add_vertices(&mut graph, &vertex, 2);
+    assert!(
+        matches!(
+            graph.add_edge(&vertex(0), &vertex(5)),
+            Err(MutationError::VertexNotFound)
+        ),
+        "adding an edge to a missing vertex must fail with VertexNotFound"
+    );
+    assert!(
+        !graph.contains_vertex(&vertex(5)),
+        "a rejected add_edge must not create vertices"
+    );
+
+    graph.add_edge(&vertex(0), &vertex(1)).unwrap();
+    assert!(graph.contains_edge(&vertex(0), &vertex(1)));
+    assert_eq!(graph.edge_count(), 1);
+    assert!(
+        matches!(
+            graph.add_edge(&vertex(0), &vertex(1)),
+            Err(MutationError::EdgeAlreadyExists)
+        ),
+        "adding a duplicate edge must fail with EdgeAlreadyExists"
+    );
+    assert_eq!(graph.edge_count(), 1);
+}
+
+/// Checks `remove_edge`, including the `EdgeNotFound` error.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_remove_edge<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    add_vertices(&mut graph, &vertex, 2);
+    assert!(
+        matches!(
+            graph.remove_edge(&vertex(0), &vertex(1)),
+            Err(MutationError::EdgeNotFound)
+        ),
+        "removing a missing edge must fail with EdgeNotFound"
+    );
+
+    graph.add_edge(&vertex(0), &vertex(1)).unwrap();
+    graph.remove_edge(&vertex(0), &vertex(1)).unwrap();
+    assert!(!graph.contains_edge(&vertex(0), &vertex(1)));
+    assert!(!graph.contains_edge(&vertex(1), &vertex(0)));
+    assert_eq!(graph.edge_count(), 0);
+    assert_eq!(graph.order(), 2, "removing an edge must keep its endpoints");
+}
+
+/// Checks that edges are symmetric in undirected graphs and one-way in directed
+/// graphs, as reported by `is_directed`.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_directedness<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    add_vertices(&mut graph, &vertex, 2);
+    graph.add_edge(&vertex(0), &vertex(1)).unwrap();
+
+    let reverse = graph.contains_edge(&vertex(1), &vertex(0));
+    let reverse_neighbor = graph
+        .neighbors(&vertex(1))
+        .is_some_and(|mut n| n.any(|w| *w == vertex(0)));
+    if graph.is_directed() {
+        assert!(!reverse, "directed edges must not be visible in reverse");
+        assert!(!reverse_neighbor);
+        assert!(
+            graph.add_edge(&vertex(1), &vertex(0)).is_ok(),
+            "the reverse of a directed edge must be a distinct edge"
+        );
+        assert_eq!(graph.edge_count(), 2);
+    } else {
+        assert!(
+            reverse,
+            "undirected edges must be visible in both directions"
+        );
+        assert!(reverse_neighbor);
+        assert!(
+            matches!(
+                graph.add_edge(&vertex(1), &vertex(0)),
+                Err(MutationError::EdgeAlreadyExists)
+            ),
+            "the reverse of an undirected edge is the same edge"
+        );
+        assert_eq!(graph.edge_count(), 1);
+    }
+}
+
+/// Checks `get_isolated_vertices`, `has_isolated_vertex` and
+/// `remove_isolated_vertices`.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_isolated_vertices<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    add_vertices(&mut graph, &vertex, 3);
+    graph.add_edge(&vertex(0), &vertex(1)).unwrap();
+    if graph.is_directed() {
+        graph.add_edge(&vertex(1), &vertex(0)).unwrap();
+    }
+
+    assert!(graph.has_isolated_vertex());
+    assert_eq!(graph.get_isolated_vertices(), vec![vertex(2)]);
+
+    assert_eq!(graph.remove_isolated_vertices(), Ok(vec![vertex(2)]));
+    assert!(!graph.contains_vertex(&vertex(2)));
+    assert!(!graph.has_isolated_vertex());
+    assert!(graph.get_isolated_vertices().is_empty());
+    assert_eq!(graph.order(), 2);
+    assert_eq!(
+        graph.remove_isolated_vertices(),
+        Ok(Vec::new()),
+        "a graph without isolated vertices must return an empty list"
+    );
+}
+
+/// Checks `size` and `edge_count`: every edge counts once, arcs in opposite
+/// directions count separately in directed graphs, and a self-loop counts once
+/// if the graph accepts one.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_size<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    add_vertices(&mut graph, &vertex, 3);
+    assert_eq!(graph.size(), 0, "a graph without edges must have size 0");
+
+    graph.add_edge(&vertex(0), &vertex(1)).unwrap();
+    graph.add_edge(&vertex(1), &vertex(2)).unwrap();
+    assert_eq!(graph.size(), 2);
+    if graph.is_directed() {
+        graph.add_edge(&vertex(1), &vertex(0)).unwrap();
+        assert_eq!(graph.size(), 3, "opposite arcs must count separately");
+    }
+
+    let size = graph.size();
+    if graph.add_edge(&vertex(2), &vertex(2)).is_ok() {
+        assert_eq!(graph.size(), size + 1, "a self-loop must count once");
+        assert_eq!(graph.edge_count(), graph.size());
+        graph.remove_edge(&vertex(2), &vertex(2)).unwrap();
+        assert_eq!(graph.size(), size);
+    }
+    assert_eq!(
+        graph.edge_count(),
+        graph.size(),
+        "edge_count must agree with size"
+    );
+}
+
+/// Checks that `vertices`, `neighbors`, `degree`, `order` and `edge_count` agree
+/// with each other and with `contains_vertex`/`contains_edge`.
+///
+/// # Panics
+///
+/// Panics if the graph violates the contract.
+pub fn check_iterator_consistency<G, F>(mut graph: G, vertex: F)
+where
+    G: GraphMut,
+    F: Fn(usize) -> G::Vertex,
+{
+    add_vertices(&mut graph, &vertex, 5);
+    for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+        graph.add_edge(&vertex(u), &vertex(v)).unwrap();
+    }
+
+    let vertices: Vec<_> = graph.vertices().cloned().collect();
+    let unique: HashSet<_> = vertices.iter().cloned().collect();
+    assert_eq!(vertices.len(), unique.len(), "vertices must not repeat");
+    assert_eq!(vertices.len(), graph.order());
+
+    let mut arcs = 0;
+    for v in &vertices {
+        assert!(graph.contains_vertex(v));
+        let neighbors: Vec<_> = graph
+            .neighbors(v)
+            .expect("neighbors of an existing vertex")
+            .cloned()
+            .collect();
+        let unique: HashSet<_> = neighbors.iter().cloned().collect();
+        assert_eq!(
+            neighbors.len(),
+            unique.len(),
+            "neighbors of {v:?} must not repeat"
+        );
+        assert_eq!(graph.degree(v), Some(neighbors.len()));
+        for w in &neighbors {
+            assert!(graph.contains_vertex(w), "neighbor {w:?} must be a vertex");
+            assert!(graph.contains_edge(v, w), "edge ({v:?}, {w:?}) must exist");
+        }
+        arcs += neighbors.len();
+    }
+    let expected = if graph.is_directed() { arcs } else { arcs / 2 };
+    assert_eq!(graph.edge_count(), expected);
+    assert_eq!(graph.edge_count(), 5);
+
+    assert!(graph.neighbors(&vertex(9)).is_none());
+    assert_eq!(graph.degree(&vertex(9)), None);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graphs::{
+            bit::BitGraph,
+            small::SmallGraph,
+            stable::{StableGraph, VertexId},
+        },
+        SimpleGraph,
+    };
+
+    fn id(i: usize) -> u32 {
+        u32::try_from(i).unwrap()
+    }
+
+    crate::graph_trait_tests!(simple_directed, SimpleGraph::<u32>::new_directed(), id);
+    crate::graph_trait_tests!(simple_undirected, SimpleGraph::<u32>::new_undirected(), id);
+    crate::graph_trait_tests!(
+        stable_directed,
+        StableGraph::<()>::new_directed(),
+        |i: usize| VertexId::new(id(i)),
+    );
+    crate::graph_trait_tests!(
+        stable_undirected,
+        StableGraph::<()>::new_undirected(),
+        |i: usize| VertexId::new(id(i)),
+    );
+    crate::graph_trait_tests!(bit_directed, BitGraph::new_directed(), |i: usize| i);
+    crate::graph_trait_tests!(bit_undirected, BitGraph::new_undirected(), |i: usize| i);
+    crate::graph_trait_tests!(small_directed, SmallGraph::new_directed(), |i: usize| i);
+    crate::graph_trait_tests!(small_undirected, SmallGraph::new_undirected(), |i: usize| i);
+}
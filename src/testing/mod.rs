@@ -0,0 +1,9 @@
+/// Reusable checks of the [`Graph`](crate::Graph)/[`GraphMut`](crate::GraphMut)
+/// contracts, instantiated as tests by [`graph_trait_tests!`](crate::graph_trait_tests).
+pub mod conformance;
+
+/// Randomized mutation harness that cross-checks a [`GraphMut`](crate::GraphMut)
+/// implementation against a reference model.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod chaos;
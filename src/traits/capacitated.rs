@@ -0,0 +1,58 @@
+use std::fmt::Debug;
+
+use crate::{error::MutationError, Graph};
+
+/// A trait for graphs carrying a capacity on their edges, kept apart from the
+/// edge weight.
+///
+/// Flow algorithms such as
+/// [`capacitated_maximum_flow`](crate::algorithms::flow::capacitated_maximum_flow)
+/// read capacities through this trait, so a network can keep a cost or a length
+/// as its [`WeightedGraph::Weight`](crate::traits::weighted::WeightedGraph::Weight)
+/// for shortest paths without packing both numbers into a tuple.
+pub trait CapacitatedGraph: Graph {
+    /// The type of capacities associated with the edges.
+    type Capacity: Clone + Debug;
+
+    /// Returns the capacity of the edge between two vertices, if the edge
+    /// exists and has a capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - A reference to the source vertex.
+    /// * `v` - A reference to the target vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Self::Capacity)` - If the edge has a capacity.
+    /// * `None` - If the edge does not exist or has no capacity.
+    fn capacity(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Capacity>;
+}
+
+/// A trait for mutable operations on capacitated graphs.
+pub trait CapacitatedGraphMut: CapacitatedGraph {
+    /// Sets the capacity of an existing edge, replacing any previous capacity.
+    ///
+    /// In an undirected graph the capacity is shared by both orientations of the
+    /// edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - A reference to the source vertex.
+    /// * `v` - A reference to the target vertex.
+    /// * `capacity` - The capacity to assign to the edge.
+    ///
+    /// # Returns
+    ///
+    /// The capacity the edge had before, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeNotFound` if the edge is not in the graph.
+    fn set_capacity(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        capacity: Self::Capacity,
+    ) -> Result<Option<Self::Capacity>, MutationError>;
+}
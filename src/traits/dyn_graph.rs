@@ -0,0 +1,295 @@
+use std::{fmt::Debug, hash::Hash, rc::Rc, sync::Arc};
+
+use crate::{error::MutationError, traits::graph::EdgeList, Graph, GraphMut};
+
+/// An object-safe counterpart of [`Graph`].
+///
+/// [`Graph`] returns concrete iterator types to keep iteration allocation-free,
+/// which makes it unusable as a trait object. `DynGraph` boxes the iterators
+/// instead, so heterogeneous graph implementations can be stored as
+/// `Box<dyn DynGraph<Vertex = V>>`. It is implemented for every [`Graph`], and
+/// [`DynGraphAdapter`] turns a trait object back into a [`Graph`] so the
+/// algorithms of this crate accept it.
+///
+/// The methods carry a `dyn_` prefix so they never clash with the [`Graph`]
+/// methods when both traits are in scope.
+pub trait DynGraph {
+    /// The type of vertices in the graph.
+    type Vertex;
+
+    /// Returns a boxed iterator over all vertices in the graph.
+    fn dyn_vertices(&self) -> Box<dyn Iterator<Item = &Self::Vertex> + '_>;
+
+    /// Returns a boxed iterator over the neighbors of `v`, or `None` if `v` does
+    /// not exist in the graph.
+    fn dyn_neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<Box<dyn Iterator<Item = &'a Self::Vertex> + 'a>>;
+
+    /// Checks if the graph contains the vertex `v`.
+    fn dyn_contains_vertex(&self, v: &Self::Vertex) -> bool;
+
+    /// Checks if the graph contains an edge from `u` to `v`.
+    fn dyn_contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool;
+
+    /// Determines if the graph is directed.
+    fn dyn_is_directed(&self) -> bool;
+
+    /// Returns the number of vertices in the graph.
+    fn dyn_order(&self) -> usize;
+
+    /// Returns the number of edges in the graph.
+    fn dyn_edge_count(&self) -> usize;
+
+    /// Returns the degree (out-degree for directed graphs) of `v`, or `None` if `v`
+    /// does not exist in the graph.
+    fn dyn_degree(&self, v: &Self::Vertex) -> Option<usize>;
+}
+
+/// An object-safe counterpart of [`GraphMut`], implemented for every
+/// [`GraphMut`].
+pub trait DynGraphMut: DynGraph {
+    /// Adds a vertex to the graph. See [`GraphMut::add_vertex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexAlreadyExists` if the vertex is already in the graph.
+    fn dyn_add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError>;
+
+    /// Removes a vertex from the graph. See [`GraphMut::remove_vertex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexNotFound` if the vertex does not exist.
+    fn dyn_remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError>;
+
+    /// Adds an edge to the graph. See [`GraphMut::add_edge`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexNotFound` if an endpoint does not exist, or
+    /// `MutationError::EdgeAlreadyExists` if the edge already exists.
+    fn dyn_add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError>;
+
+    /// Removes an edge from the graph. See [`GraphMut::remove_edge`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeNotFound` if the edge does not exist.
+    fn dyn_remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError>;
+
+    /// Removes all isolated vertices. See [`GraphMut::remove_isolated_vertices`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if removing a vertex fails.
+    fn dyn_remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError>;
+}
+
+impl<G> DynGraph for G
+where
+    G: Graph,
+{
+    type Vertex = G::Vertex;
+
+    fn dyn_vertices(&self) -> Box<dyn Iterator<Item = &Self::Vertex> + '_> {
+        Box::new(self.vertices())
+    }
+
+    fn dyn_neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<Box<dyn Iterator<Item = &'a Self::Vertex> + 'a>> {
+        self.neighbors(v)
+            .map(|neighbors| Box::new(neighbors) as Box<dyn Iterator<Item = &'a Self::Vertex> + 'a>)
+    }
+
+    fn dyn_contains_vertex(&self, v: &Self::Vertex) -> bool {
+        self.contains_vertex(v)
+    }
+
+    fn dyn_contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.contains_edge(u, v)
+    }
+
+    fn dyn_is_directed(&self) -> bool {
+        self.is_directed()
+    }
+
+    fn dyn_order(&self) -> usize {
+        self.order()
+    }
+
+    fn dyn_edge_count(&self) -> usize {
+        self.size()
+    }
+
+    fn dyn_degree(&self, v: &Self::Vertex) -> Option<usize> {
+        self.degree(v)
+    }
+}
+
+impl<G> DynGraphMut for G
+where
+    G: GraphMut,
+{
+    fn dyn_add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+        self.add_vertex(vertex)
+    }
+
+    fn dyn_remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+        self.remove_vertex(vertex)
+    }
+
+    fn dyn_add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.add_edge(u, v)
+    }
+
+    fn dyn_remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+        self.remove_edge(u, v)
+    }
+
+    fn dyn_remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+        self.remove_isolated_vertices()
+    }
+}
+
+/// Implements [`Graph`] (and [`GraphMut`]) for a pointer to a graph trait object,
+/// such as `&dyn DynGraph<Vertex = V>`, `Box<dyn DynGraphMut<Vertex = V>>` or
+/// `Arc<dyn DynGraph<Vertex = V>>`.
+///
+/// This lets heterogeneous graphs stored behind trait objects be passed to any
+/// function generic over [`Graph`], at the cost of one allocation per iterator.
+///
+/// ```
+/// use kambo_graph::{
+///     algorithms::core::core_numbers,
+///     graph,
+///     traits::dyn_graph::{DynGraphAdapter, DynGraphMut},
+/// };
+///
+/// let plugins: Vec<Box<dyn DynGraphMut<Vertex = u32>>> = vec![
+///     Box::new(graph! { 1 - 2, 2 - 3, 3 - 1, 3 - 4 }),
+///     Box::new(graph! { 1 - 2 }.map_vertices(|&v| v * 10).unwrap()),
+/// ];
+/// let cores: Vec<_> = plugins
+///     .iter()
+///     .map(|graph| core_numbers(&DynGraphAdapter::new(graph.as_ref())))
+///     .collect();
+/// assert_eq!((cores[0][&1], cores[0][&4]), (2, 1));
+/// assert_eq!(cores[1][&20], 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DynGraphAdapter<P>(P);
+
+impl<P> DynGraphAdapter<P> {
+    /// Wraps a pointer to a graph trait object.
+    pub fn new(graph: P) -> Self {
+        Self(graph)
+    }
+
+    /// Returns a reference to the wrapped pointer.
+    pub fn get_ref(&self) -> &P {
+        &self.0
+    }
+
+    /// Returns the wrapped pointer.
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
+
+/// Implements [`Graph`] for `DynGraphAdapter` over each pointer type. Naming the
+/// pointer type lets the compiler infer that the trait object outlives borrows
+/// of the adapter.
+macro_rules! impl_graph_for_adapter {
+    ($([$($lt:lifetime)?] $pointer:ty),*) => {$(
+        impl<$($lt,)? D> Graph for DynGraphAdapter<$pointer>
+        where
+            D: DynGraph + ?Sized,
+            D::Vertex: Eq + Hash + Clone + Debug,
+        {
+            type Vertex = D::Vertex;
+
+            fn vertices(&self) -> impl Iterator<Item = &Self::Vertex> {
+                self.0.dyn_vertices()
+            }
+
+            fn neighbors<'a>(
+                &'a self,
+                v: &Self::Vertex,
+            ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, $($lt,)? D>> {
+                self.0.dyn_neighbors(v)
+            }
+
+            fn contains_vertex(&self, v: &Self::Vertex) -> bool {
+                self.0.dyn_contains_vertex(v)
+            }
+
+            fn contains_edge(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+                self.0.dyn_contains_edge(u, v)
+            }
+
+            fn is_directed(&self) -> bool {
+                self.0.dyn_is_directed()
+            }
+
+            fn order(&self) -> usize {
+                self.0.dyn_order()
+            }
+
+            fn size(&self) -> usize {
+                self.0.dyn_edge_count()
+            }
+
+            fn degree(&self, v: &Self::Vertex) -> Option<usize> {
+                self.0.dyn_degree(v)
+            }
+        }
+    )*};
+}
+
+impl_graph_for_adapter!(['g] &'g D, ['g] &'g mut D, [] Box<D>, [] Rc<D>, [] Arc<D>);
+
+/// Implements [`GraphMut`] for `DynGraphAdapter` over each mutable pointer type.
+macro_rules! impl_graph_mut_for_adapter {
+    ($([$($lt:lifetime)?] $pointer:ty),*) => {$(
+        impl<$($lt,)? D> GraphMut for DynGraphAdapter<$pointer>
+        where
+            D: DynGraphMut + ?Sized,
+            D::Vertex: Eq + Hash + Clone + Debug,
+        {
+            fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError> {
+                self.0.dyn_add_vertex(vertex)
+            }
+
+            fn remove_vertex(
+                &mut self,
+                vertex: &Self::Vertex,
+            ) -> Result<EdgeList<Self::Vertex>, MutationError> {
+                self.0.dyn_remove_vertex(vertex)
+            }
+
+            fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+                self.0.dyn_add_edge(u, v)
+            }
+
+            fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError> {
+                self.0.dyn_remove_edge(u, v)
+            }
+
+            fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError> {
+                self.0.dyn_remove_isolated_vertices()
+            }
+        }
+    )*};
+}
+
+impl_graph_mut_for_adapter!(['g] &'g mut D, [] Box<D>);
@@ -1,6 +1,9 @@
 use std::{fmt::Debug, hash::Hash};
 
-use crate::GraphError;
+use crate::error::MutationError;
+
+/// An owned list of edges, each represented as `(source, target)`.
+pub type EdgeList<V> = Vec<(V, V)>;
 
 /// A trait defining the core functionality of a graph.
 ///
@@ -12,6 +15,29 @@ use crate::GraphError;
 /// # Associated Types
 /// - `Vertex`: Represents the type of the vertices in the graph. It must implement
 ///   `Eq`, `Hash`, `Clone`, and `Debug` traits.
+///
+/// # Shadowing by inherent methods
+/// [`SimpleGraph`](crate::SimpleGraph) has inherent `contains_vertex`,
+/// `contains_edge`, `neighbors` and `degree` methods (and `edge_weight`, which
+/// shadows [`WeightedGraph`](crate::traits::weighted::WeightedGraph)) that accept
+/// any borrowed form of the vertex, such as a `&str` for `String` vertices. Method
+/// calls on a concrete `SimpleGraph` resolve to these inherent methods, which has
+/// some limits:
+/// - The argument type is inferred from the call, so an argument like
+///   `&"a".into()` is ambiguous and needs an explicit type.
+/// - `neighbors` returns a `std::collections::hash_set::Iter` rather than the
+///   opaque iterator of the trait.
+///
+/// Code generic over `G: Graph` always calls the trait methods. To call them on a
+/// `SimpleGraph`, use the fully qualified form:
+///
+/// ```
+/// use kambo_graph::{Graph, SimpleGraph};
+///
+/// let graph = SimpleGraph::from_edges([(String::from("a"), String::from("b"))], false);
+/// assert!(graph.contains_vertex("a"));
+/// assert_eq!(Graph::neighbors(&graph, &String::from("a")).unwrap().count(), 1);
+/// ```
 pub trait Graph {
     /// The type of vertices in the graph.
     ///
@@ -21,19 +47,27 @@ pub trait Graph {
     /// Returns an iterator over all vertices in the graph.
     ///
     /// # Returns
-    /// A boxed iterator that yields references to the vertices in the graph.
+    /// An iterator that yields references to the vertices in the graph.
     fn vertices(&self) -> impl Iterator<Item = &Self::Vertex>;
 
     /// Returns an iterator over the neighbors of a given vertex.
     ///
+    /// The returned iterator is a concrete type chosen by the implementation, so
+    /// iterating neighbors neither allocates nor goes through dynamic dispatch. It
+    /// borrows only the graph, not `v`. Use [`DynGraph`](crate::traits::dyn_graph::DynGraph)
+    /// when an object-safe interface is needed.
+    ///
     /// # Parameters
     /// - `v`: A reference to the vertex whose neighbors are to be retrieved.
     ///
     /// # Returns
-    /// - `Some(Box<dyn Iterator<Item = &Self::Vertex> + '_>)`: If the vertex exists,
+    /// - `Some(impl Iterator<Item = &Self::Vertex>)`: If the vertex exists,
     ///   an iterator over its neighbors.
     /// - `None`: If the vertex does not exist in the graph.
-    fn neighbors(&self, v: &Self::Vertex) -> Option<Box<dyn Iterator<Item = &Self::Vertex> + '_>>;
+    fn neighbors<'a>(
+        &'a self,
+        v: &Self::Vertex,
+    ) -> Option<impl Iterator<Item = &'a Self::Vertex> + use<'a, Self>>;
 
     /// Checks if the graph contains a specific vertex.
     ///
@@ -71,28 +105,57 @@ pub trait Graph {
         self.vertices().count()
     }
 
-    /// Calculates the number of edges in the graph.
+    /// Returns the size of the graph, its number of edges.
+    ///
+    /// Every edge is counted exactly once:
+    ///
+    /// - In a directed graph each arc counts once, so the arcs `(u, v)` and
+    ///   `(v, u)` are two edges.
+    /// - In an undirected graph each edge counts once, although it appears in
+    ///   the neighbors of both endpoints.
+    /// - A self-loop counts once in either kind of graph, and adds one to the
+    ///   [`Graph::degree`] of its vertex, which counts neighbors.
+    ///
+    /// The default implementation derives the size from the neighbor lists in
+    /// `O(n + m)` time; graphs that keep a count should override it. The
+    /// algorithms of this crate rely on this method to count edges.
     ///
     /// # Returns
-    /// The total number of edges in the graph.
+    /// The number of edges in the graph.
     ///
-    /// # Notes
-    /// - For directed graphs, each directed edge is counted once.
-    /// - For undirected graphs, each edge is counted once (even though it
-    ///   appears as two neighbors).
-    fn edge_count(&self) -> usize {
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, Graph};
+    ///
+    /// assert_eq!(graph! { 1 - 2, 2 - 3 }.size(), 2);
+    /// assert_eq!(graph! { 1 -> 2, 2 -> 1 }.size(), 2);
+    /// ```
+    fn size(&self) -> usize {
+        let arcs: usize = self
+            .vertices()
+            .map(|v| self.neighbors(v).map_or(0, std::iter::Iterator::count))
+            .sum();
         if self.is_directed() {
-            self.vertices()
-                .map(|v| self.neighbors(v).map_or(0, std::iter::Iterator::count))
-                .sum()
+            arcs
         } else {
-            self.vertices()
-                .map(|v| self.neighbors(v).map_or(0, std::iter::Iterator::count))
-                .sum::<usize>()
-                / 2
+            // A self-loop appears once among the neighbors, every other edge twice.
+            let loops = self.vertices().filter(|v| self.contains_edge(v, v)).count();
+            (arcs - loops) / 2 + loops
         }
     }
 
+    /// Calculates the number of edges in the graph.
+    ///
+    /// This is the same as [`Graph::size`], which implementations override
+    /// instead of this method.
+    ///
+    /// # Returns
+    /// The total number of edges in the graph.
+    fn edge_count(&self) -> usize {
+        self.size()
+    }
+
     /// Returns a list of isolated vertices in the graph.
     ///
     /// An isolated vertex is a vertex with no neighbors.
@@ -127,8 +190,75 @@ pub trait Graph {
         })
     }
 
-    // Returns the degree of the vertex in an undirected graph,
-    /// or the out-degree in a directed graph.
+    /// Returns the vertices in ascending order, numbered from `0`.
+    ///
+    /// The numbering is the canonical order of
+    /// [`write_canonical`](crate::utils::canonical::write_canonical), so it
+    /// depends only on the vertices of the graph, never on `HashMap` iteration
+    /// order. Colors, marks or distances can then be kept in plain arrays
+    /// indexed by these numbers.
+    ///
+    /// # Returns
+    /// An iterator over `(index, vertex)` pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, Graph};
+    ///
+    /// let graph = graph! { 3 - 1, 1 - 2 };
+    /// let numbered: Vec<(usize, &i32)> = graph.vertices_enumerated().collect();
+    /// assert_eq!(numbered, [(0, &1), (1, &2), (2, &3)]);
+    /// ```
+    fn vertices_enumerated(&self) -> impl Iterator<Item = (usize, &Self::Vertex)>
+    where
+        Self::Vertex: Ord,
+    {
+        let mut vertices: Vec<&Self::Vertex> = self.vertices().collect();
+        vertices.sort_unstable();
+        vertices.into_iter().enumerate()
+    }
+
+    /// Returns the edges in ascending order, numbered from `0`.
+    ///
+    /// Edges are ordered as `(source, target)` pairs, and every undirected edge
+    /// is listed once, as `(u, v)` with `u <= v`. This is the canonical order
+    /// of [`write_canonical`](crate::utils::canonical::write_canonical), so the
+    /// numbering is stable across runs and machines.
+    ///
+    /// # Returns
+    /// An iterator over `(index, (source, target))` pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, Graph};
+    ///
+    /// let graph = graph! { 3 - 1, 2 - 1 };
+    /// let numbered: Vec<(usize, (&i32, &i32))> = graph.edges_enumerated().collect();
+    /// assert_eq!(numbered, [(0, (&1, &2)), (1, (&1, &3))]);
+    /// ```
+    fn edges_enumerated(&self) -> impl Iterator<Item = (usize, (&Self::Vertex, &Self::Vertex))>
+    where
+        Self::Vertex: Ord,
+    {
+        let directed = self.is_directed();
+        let mut edges = Vec::new();
+        for u in self.vertices() {
+            for v in self.neighbors(u).into_iter().flatten() {
+                if directed || u <= v {
+                    edges.push((u, v));
+                }
+            }
+        }
+        edges.sort_unstable();
+        edges.into_iter().enumerate()
+    }
+
+    /// Returns the degree of the vertex in an undirected graph, or the
+    /// out-degree in a directed graph.
+    ///
+    /// The degree is the number of neighbors, so a self-loop adds one to it.
     ///
     /// # Arguments
     ///
@@ -137,6 +267,16 @@ pub trait Graph {
     /// # Returns
     ///
     /// * `Option<usize>` - The degree of the vertex if it exists in the graph, None otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, Graph};
+    ///
+    /// let graph = graph! { 1 - 1, 1 - 2 };
+    /// assert_eq!(graph.degree(&1), Some(2));
+    /// assert_eq!(graph.size(), 2);
+    /// ```
     fn degree(&self, v: &Self::Vertex) -> Option<usize> {
         if self.contains_vertex(v) {
             self.neighbors(v).map(std::iter::Iterator::count)
@@ -158,10 +298,10 @@ pub trait GraphMut: Graph {
     ///
     /// # Errors
     ///
-    /// Returns `GraphError::VertexAlreadyExists` if the vertex is already in the graph.
-    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), GraphError>;
+    /// Returns `MutationError::VertexAlreadyExists` if the vertex is already in the graph.
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), MutationError>;
 
-    /// Removes a vertex from the graph.
+    /// Removes a vertex from the graph, along with its incident edges.
     ///
     /// Returns an error if the vertex does not exist.
     ///
@@ -169,10 +309,19 @@ pub trait GraphMut: Graph {
     ///
     /// * `vertex` - A reference to the vertex to be removed.
     ///
+    /// # Returns
+    ///
+    /// The removed edges as `(source, target)` pairs: the edges leaving `vertex`
+    /// and, in directed graphs, the arcs entering it. Each undirected edge is
+    /// listed once, with `vertex` as its source.
+    ///
     /// # Errors
     ///
-    /// Returns `GraphError::VertexNotFound` if the vertex does not exist.
-    fn remove_vertex(&mut self, vertex: &Self::Vertex) -> Result<(), GraphError>;
+    /// Returns `MutationError::VertexNotFound` if the vertex does not exist.
+    fn remove_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<EdgeList<Self::Vertex>, MutationError>;
 
     /// Adds an edge to the graph.
     ///
@@ -187,8 +336,36 @@ pub trait GraphMut: Graph {
     ///
     /// # Errors
     ///
-    /// Returns `GraphError::EdgeAlreadyExists` if the edge already exists.
-    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), GraphError>;
+    /// Returns `MutationError::EdgeAlreadyExists` if the edge already exists.
+    fn add_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError>;
+
+    /// Adds an edge to the graph, inserting any endpoint that is not already in
+    /// the graph instead of returning `MutationError::VertexNotFound`.
+    ///
+    /// This avoids a separate pass registering every vertex when loading edge
+    /// lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The source vertex.
+    /// * `v` - The target vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeAlreadyExists` if the edge already exists.
+    fn add_edge_with_vertices(
+        &mut self,
+        u: Self::Vertex,
+        v: Self::Vertex,
+    ) -> Result<(), MutationError> {
+        if !self.contains_vertex(&u) {
+            self.add_vertex(u.clone())?;
+        }
+        if !self.contains_vertex(&v) {
+            self.add_vertex(v.clone())?;
+        }
+        self.add_edge(&u, &v)
+    }
 
     /// Removes an edge from the graph.
     ///
@@ -203,17 +380,19 @@ pub trait GraphMut: Graph {
     ///
     /// # Errors
     ///
-    /// Returns `GraphError::EdgeNotFound` if the edge does not exist.
-    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), GraphError>;
+    /// Returns `MutationError::EdgeNotFound` if the edge does not exist.
+    fn remove_edge(&mut self, u: &Self::Vertex, v: &Self::Vertex) -> Result<(), MutationError>;
 
     /// Remove all isolated vertices from the graph.
     ///
     /// # Returns
     ///
-    /// A vector of the removed vertices.
+    /// A vector of the removed vertices, empty if the graph had no isolated
+    /// vertex.
     ///
     /// # Errors
     ///
-    /// Returns `GraphError::VertexNotFound` if no isolated vertices are found.
-    fn remove_isolated_vertices(&mut self) -> Result<(), GraphError>;
+    /// Implementations may fail if removing a vertex fails; those of this crate
+    /// never do.
+    fn remove_isolated_vertices(&mut self) -> Result<Vec<Self::Vertex>, MutationError>;
 }
@@ -0,0 +1,284 @@
+use std::{cmp::Ordering, fmt::Debug, time::Duration};
+
+use crate::GraphError;
+
+/// Controls what happens when accumulating weights overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Fail with `GraphError::WeightOverflow`.
+    #[default]
+    Checked,
+    /// Clamp the result to the largest representable value.
+    Saturating,
+}
+
+/// A trait for edge weights that can be accumulated along paths and compared.
+///
+/// Weighted algorithms such as shortest paths and minimum spanning trees are
+/// written once against this trait, so they work uniformly for integers,
+/// floating-point numbers, [`Duration`] and user-defined cost types.
+///
+/// Implementations must provide a total order through [`Measure::compare`], even
+/// for types that are only `PartialOrd` (floating-point weights order `NaN`
+/// using [`f64::total_cmp`] and report it through [`Measure::is_valid`]).
+pub trait Measure: Clone + Debug {
+    /// Returns the additive identity, the length of an empty path.
+    fn zero() -> Self;
+
+    /// Adds two measures, returning `None` if the result overflows.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Adds two measures, clamping the result to the largest representable value
+    /// on overflow.
+    #[must_use]
+    fn saturating_add(&self, other: &Self) -> Self;
+
+    /// Compares two measures using a total order.
+    fn compare(&self, other: &Self) -> Ordering;
+
+    /// Checks whether the measure can be used by algorithms.
+    ///
+    /// # Returns
+    /// - `false` for values without a meaningful order, such as `NaN`.
+    /// - `true` otherwise.
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    /// Checks whether the measure is smaller than [`Measure::zero`].
+    fn is_negative(&self) -> bool {
+        self.compare(&Self::zero()) == Ordering::Less
+    }
+
+    /// Adds two measures following the given overflow policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::WeightOverflow` if the sum overflows and `policy` is
+    /// [`OverflowPolicy::Checked`].
+    fn add_with(&self, other: &Self, policy: OverflowPolicy) -> Result<Self, GraphError> {
+        match policy {
+            OverflowPolicy::Checked => self.checked_add(other).ok_or(GraphError::WeightOverflow),
+            OverflowPolicy::Saturating => Ok(self.saturating_add(other)),
+        }
+    }
+}
+
+/// A [`Measure`] that can also be subtracted and has a largest value, as needed
+/// by algorithms that move amounts back and forth, such as maximum flows.
+///
+/// Flow algorithms keep the residual capacity of every arc, which shrinks as
+/// flow is sent and grows as it is cancelled, and use [`Weight::infinity`] as the
+/// capacity of arcs that should never limit the flow. Implementing this trait for
+/// a fixed-point or other user-defined cost type makes those algorithms available
+/// for it, in addition to the ones written against [`Measure`].
+///
+/// ```
+/// use kambo_graph::traits::measure::{Measure, Weight};
+///
+/// assert_eq!(Weight::checked_sub(&5u32, &3), Some(2));
+/// assert_eq!(Weight::checked_sub(&3u32, &5), None);
+/// assert!(f64::infinity().is_infinite());
+/// ```
+pub trait Weight: Measure {
+    /// Subtracts `other` from the weight, returning `None` if the result is not
+    /// representable.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    /// Returns the largest weight, standing for an unlimited amount.
+    ///
+    /// This is positive infinity for floating-point numbers and the maximum value
+    /// for the other types.
+    fn infinity() -> Self;
+}
+
+/// Sums a sequence of measures following the given overflow policy.
+///
+/// # Arguments
+///
+/// * `weights` - The measures to add, such as the edge weights along a path.
+/// * `policy` - What to do when the sum overflows.
+///
+/// # Returns
+///
+/// The total, or [`Measure::zero`] for an empty sequence.
+///
+/// # Errors
+///
+/// Returns `GraphError::WeightOverflow` if the sum overflows and `policy` is
+/// [`OverflowPolicy::Checked`].
+pub fn accumulate<'a, W, I>(weights: I, policy: OverflowPolicy) -> Result<W, GraphError>
+where
+    W: Measure + 'a,
+    I: IntoIterator<Item = &'a W>,
+{
+    weights
+        .into_iter()
+        .try_fold(W::zero(), |total, w| total.add_with(w, policy))
+}
+
+macro_rules! impl_measure_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Measure for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn saturating_add(&self, other: &Self) -> Self {
+                    <$t>::saturating_add(*self, *other)
+                }
+
+                fn compare(&self, other: &Self) -> Ordering {
+                    self.cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_measure_for_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_weight_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *other)
+                }
+
+                fn infinity() -> Self {
+                    <$t>::MAX
+                }
+            }
+        )*
+    };
+}
+
+impl_weight_for_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_measure_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl Measure for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    let sum = self + other;
+                    if sum.is_infinite() && self.is_finite() && other.is_finite() {
+                        None
+                    } else {
+                        Some(sum)
+                    }
+                }
+
+                fn saturating_add(&self, other: &Self) -> Self {
+                    (self + other).clamp(<$t>::MIN, <$t>::MAX)
+                }
+
+                fn compare(&self, other: &Self) -> Ordering {
+                    self.total_cmp(other)
+                }
+
+                fn is_valid(&self) -> bool {
+                    !self.is_nan()
+                }
+            }
+        )*
+    };
+}
+
+impl_measure_for_float!(f32, f64);
+
+macro_rules! impl_weight_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    let difference = self - other;
+                    if difference.is_infinite() && self.is_finite() && other.is_finite() {
+                        None
+                    } else {
+                        Some(difference)
+                    }
+                }
+
+                fn infinity() -> Self {
+                    <$t>::INFINITY
+                }
+            }
+        )*
+    };
+}
+
+impl_weight_for_float!(f32, f64);
+
+impl Measure for Duration {
+    fn zero() -> Self {
+        Duration::ZERO
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Duration::checked_add(*self, *other)
+    }
+
+    fn saturating_add(&self, other: &Self) -> Self {
+        Duration::saturating_add(*self, *other)
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl Weight for Duration {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Duration::checked_sub(*self, *other)
+    }
+
+    fn infinity() -> Self {
+        Duration::MAX
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl Measure for chrono::TimeDelta {
+    fn zero() -> Self {
+        chrono::TimeDelta::zero()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        chrono::TimeDelta::checked_add(self, other)
+    }
+
+    fn saturating_add(&self, other: &Self) -> Self {
+        chrono::TimeDelta::checked_add(self, other).unwrap_or(if other.is_negative() {
+            chrono::TimeDelta::MIN
+        } else {
+            chrono::TimeDelta::MAX
+        })
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl Weight for chrono::TimeDelta {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        chrono::TimeDelta::checked_sub(self, other)
+    }
+
+    fn infinity() -> Self {
+        chrono::TimeDelta::MAX
+    }
+}
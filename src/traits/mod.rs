@@ -3,3 +3,15 @@ pub mod graph;
 
 /// Defines the traits to be used to define weighted graphs
 pub mod weighted;
+
+/// Defines an object-safe facade over the graph traits
+pub mod dyn_graph;
+
+/// Defines the traits used by weighted algorithms to accumulate, compare and subtract weights
+pub mod measure;
+
+/// Defines the traits for graphs with weights on their vertices
+pub mod vertex_weighted;
+
+/// Defines the traits for graphs with capacities on their edges
+pub mod capacitated;
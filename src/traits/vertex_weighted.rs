@@ -0,0 +1,40 @@
+use std::fmt::Debug;
+
+use crate::{error::MutationError, Graph};
+
+/// A trait for graphs carrying weights (costs) on their vertices.
+pub trait VertexWeightedGraph: Graph {
+    /// The type of weights associated with the vertices.
+    type VertexWeight: Clone + Debug;
+
+    /// Returns the weight of a vertex, if the vertex exists and has a weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to the vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Self::VertexWeight)` - If the vertex has a weight.
+    /// * `None` - If the vertex does not exist or has no weight.
+    fn vertex_weight(&self, v: &Self::Vertex) -> Option<&Self::VertexWeight>;
+}
+
+/// A trait for mutable operations on vertex-weighted graphs.
+pub trait VertexWeightedGraphMut: VertexWeightedGraph {
+    /// Sets the weight of a vertex, replacing any previous weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to the vertex.
+    /// * `weight` - The weight to assign to the vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexNotFound` if the vertex is not in the graph.
+    fn set_vertex_weight(
+        &mut self,
+        v: &Self::Vertex,
+        weight: Self::VertexWeight,
+    ) -> Result<(), MutationError>;
+}
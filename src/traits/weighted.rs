@@ -1,6 +1,14 @@
-use std::fmt::Debug;
+use std::{cmp::Ordering, collections::HashMap, fmt::Debug};
 
-use crate::{Graph, GraphError, GraphMut};
+use crate::{
+    error::MutationError, traits::measure::Measure, Graph, GraphError, GraphMut, SimpleGraph,
+};
+
+/// An owned weighted edge, represented as `(source, target, weight)`.
+pub type WeightedEdge<V, W> = (V, V, W);
+
+/// An owned list of weighted edges.
+pub type WeightedEdgeList<V, W> = Vec<WeightedEdge<V, W>>;
 
 /// A trait for weighted graphs, extending the core functionality of a graph.
 pub trait WeightedGraph: Graph {
@@ -21,33 +29,382 @@ pub trait WeightedGraph: Graph {
     /// * `Some(&Self::Weight)` - If an edge exists between the vertices, returns a reference to its weight.
     /// * `None` - If no edge exists between the vertices.
     fn edge_weight(&self, u: &Self::Vertex, v: &Self::Vertex) -> Option<&Self::Weight>;
+
+    /// Returns an iterator over the weighted edges of the graph as
+    /// `(source, target, weight)` triples.
+    ///
+    /// Every undirected edge is listed once, in an unspecified orientation, and
+    /// edges without a weight are skipped. Edge-centric algorithms such as
+    /// Kruskal's or Bellman-Ford's consume this iterator, so graph types that
+    /// store their edges in a list should override it; the default collects the
+    /// edges through [`Graph::neighbors`] and [`WeightedGraph::edge_weight`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, traits::weighted::WeightedGraph};
+    ///
+    /// let graph = graph! { 1 - 2 [weight = 2.0], 2 - 3 [weight = 0.5], 3 - 4 };
+    /// let mut weights: Vec<f64> = graph.weighted_edges().map(|(_, _, &w)| w).collect();
+    /// weights.sort_by(f64::total_cmp);
+    /// assert_eq!(weights, [0.5, 2.0]);
+    /// ```
+    fn weighted_edges(
+        &self,
+    ) -> impl Iterator<Item = (&Self::Vertex, &Self::Vertex, &Self::Weight)> {
+        let directed = self.is_directed();
+        let position: HashMap<&Self::Vertex, usize> =
+            self.vertices().enumerate().map(|(i, v)| (v, i)).collect();
+        let mut edges = Vec::new();
+        for (i, u) in self.vertices().enumerate() {
+            for v in self.neighbors(u).into_iter().flatten() {
+                if directed || position.get(v).is_some_and(|&j| i <= j) {
+                    if let Some(weight) = self.edge_weight(u, v) {
+                        edges.push((u, v, weight));
+                    }
+                }
+            }
+        }
+        edges.into_iter()
+    }
+
+    /// Returns the sum of the weights of all edges, counting every undirected edge
+    /// once.
+    ///
+    /// Edges without a weight are skipped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Weight)` - The total weight, [`Measure::zero`] for a graph
+    ///   without weighted edges.
+    /// * `Err(GraphError)` - If the sum overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::WeightOverflow` if the sum overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, traits::weighted::WeightedGraph};
+    ///
+    /// let graph = graph! { 1 - 2 [weight = 2.0], 2 - 3 [weight = 0.5], 3 - 4 };
+    /// assert_eq!(graph.total_weight().unwrap(), 2.5);
+    /// assert_eq!(graph.strength(&2).unwrap(), 2.5);
+    /// assert_eq!(graph.strength(&4).unwrap(), 0.0);
+    /// ```
+    fn total_weight(&self) -> Result<Self::Weight, GraphError>
+    where
+        Self::Weight: Measure,
+    {
+        self.weighted_edges()
+            .try_fold(Self::Weight::zero(), |total, (_, _, weight)| {
+                total.checked_add(weight).ok_or(GraphError::WeightOverflow)
+            })
+    }
+
+    /// Returns the strength (weighted degree) of a vertex, the sum of the
+    /// weights of the edges at it.
+    ///
+    /// Like [`Graph::degree`], only outgoing edges are counted in directed graphs
+    /// and a self-loop counts once. Edges without a weight are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to the vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Weight)` - The strength of `v`.
+    /// * `Err(GraphError)` - If `v` is not in the graph or the sum overflows.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::VertexNotFound` - If `v` is not in the graph.
+    /// * `GraphError::WeightOverflow` - If the sum overflows.
+    fn strength(&self, v: &Self::Vertex) -> Result<Self::Weight, GraphError>
+    where
+        Self::Weight: Measure,
+    {
+        let neighbors = self.neighbors(v).ok_or(GraphError::VertexNotFound)?;
+        neighbors
+            .filter_map(|w| self.edge_weight(v, w))
+            .try_fold(Self::Weight::zero(), |sum, weight| {
+                sum.checked_add(weight).ok_or(GraphError::WeightOverflow)
+            })
+    }
+
+    /// Returns the smallest edge weight, or `None` if the graph has no weighted
+    /// edge.
+    ///
+    /// Edges without a weight and weights rejected by [`Measure::is_valid`],
+    /// such as `NaN`, are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, traits::weighted::WeightedGraph};
+    ///
+    /// let graph = graph! { 1 - 2 [weight = 0.8], 2 - 3 [weight = -0.3], 3 - 4 [weight = 0.5] };
+    /// assert_eq!(graph.min_weight(), Some(&-0.3));
+    /// assert_eq!(graph.max_weight(), Some(&0.8));
+    /// ```
+    fn min_weight(&self) -> Option<&Self::Weight>
+    where
+        Self::Weight: Measure,
+    {
+        self.weighted_edges()
+            .map(|(_, _, weight)| weight)
+            .filter(|weight| weight.is_valid())
+            .min_by(|a, b| a.compare(b))
+    }
+
+    /// Returns the largest edge weight, or `None` if the graph has no weighted
+    /// edge.
+    ///
+    /// Edges without a weight and weights rejected by [`Measure::is_valid`],
+    /// such as `NaN`, are skipped.
+    fn max_weight(&self) -> Option<&Self::Weight>
+    where
+        Self::Weight: Measure,
+    {
+        self.weighted_edges()
+            .map(|(_, _, weight)| weight)
+            .filter(|weight| weight.is_valid())
+            .max_by(|a, b| a.compare(b))
+    }
+
+    /// Counts the edge weights falling between consecutive bounds.
+    ///
+    /// With `k` ascending bounds `b_0 < ... < b_{k-1}` the histogram has `k + 1`
+    /// bins: bin `0` counts the weights below `b_0`, bin `i` those in
+    /// `[b_{i-1}, b_i)` and bin `k` those at least `b_{k-1}`. Every undirected
+    /// edge counts once. Edges without a weight and weights rejected by
+    /// [`Measure::is_valid`] are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The bin boundaries, in ascending order.
+    ///
+    /// # Returns
+    ///
+    /// The number of weights in each bin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, traits::weighted::WeightedGraph};
+    ///
+    /// let graph = graph! {
+    ///     1 - 2 [weight = 0.1], 2 - 3 [weight = 0.4], 3 - 4 [weight = 0.5], 4 - 1 [weight = 0.9]
+    /// };
+    /// assert_eq!(graph.weight_histogram(&[0.25, 0.5, 0.75]), vec![1, 1, 1, 1]);
+    /// ```
+    fn weight_histogram(&self, bounds: &[Self::Weight]) -> Vec<usize>
+    where
+        Self::Weight: Measure,
+    {
+        let mut bins = vec![0; bounds.len() + 1];
+        for (_, _, weight) in self.weighted_edges() {
+            if weight.is_valid() {
+                let bin =
+                    bounds.partition_point(|bound| bound.compare(weight) != Ordering::Greater);
+                bins[bin] += 1;
+            }
+        }
+        bins
+    }
+
+    /// Builds the subgraph keeping every vertex and the edges whose weight is at
+    /// least `min_weight`, with their weights.
+    ///
+    /// The subgraph is directed if the graph is. Edges without a weight and
+    /// weights rejected by [`Measure::is_valid`] are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_weight` - The smallest weight kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, traits::weighted::WeightedGraph, Graph};
+    ///
+    /// // A correlation network keeping the strong correlations.
+    /// let graph = graph! { 1 - 2 [weight = 0.9], 2 - 3 [weight = 0.2], 3 - 4 [weight = 0.7] };
+    /// let strong = graph.threshold_subgraph(&0.7);
+    /// assert_eq!(strong.order(), 4);
+    /// assert_eq!(strong.edge_weight(&4, &3), Some(&0.7));
+    /// assert!(!strong.contains_edge(&2, &3));
+    /// ```
+    fn threshold_subgraph(
+        &self,
+        min_weight: &Self::Weight,
+    ) -> SimpleGraph<Self::Vertex, Self::Weight>
+    where
+        Self::Weight: Measure,
+    {
+        let mut subgraph = if self.is_directed() {
+            SimpleGraph::new_weighted_directed()
+        } else {
+            SimpleGraph::new_weighted_undirected()
+        };
+        for v in self.vertices() {
+            let _ = subgraph.add_vertex(v.clone());
+        }
+        for (u, v, weight) in self.weighted_edges() {
+            if weight.is_valid() && weight.compare(min_weight) != Ordering::Less {
+                let _ = subgraph.add_weighted_edge(u, v, weight.clone());
+            }
+        }
+        subgraph
+    }
 }
 
 /// A trait for mutable operations on weighted graphs.
+///
+/// Edges are added with [`WeightedGraphMut::add_weighted_edge`] (or
+/// [`GraphMut::add_edge`], which leaves them without a weight), reweighted with
+/// [`WeightedGraphMut::update_edge_weight`] and removed with
+/// [`WeightedGraphMut::remove_weighted_edge`]. Only adding creates adjacency.
 pub trait WeightedGraphMut: WeightedGraph + GraphMut {
-    /// Adds or updates the weight of an edge between two vertices.
+    /// Adds a weighted edge between two existing vertices.
     ///
-    /// If the edge weight does not exist, it will be created with the specified weight.
-    /// If the edge already exists, its weight will be updated.
+    /// # Arguments
+    ///
+    /// * `u` - A reference to the source vertex.
+    /// * `v` - A reference to the target vertex.
+    /// * `weight` - The weight of the new edge.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the edge was added.
+    /// * `Err(MutationError)` - If the edge could not be added.
+    ///
+    /// # Errors
+    ///
+    /// * `MutationError::VertexNotFound` - If one or both vertices are not in the graph.
+    /// * `MutationError::EdgeAlreadyExists` - If the edge is already in the graph.
+    fn add_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+        weight: Self::Weight,
+    ) -> Result<(), MutationError>;
+
+    /// Replaces the weight of an existing edge.
     ///
     /// # Arguments
     ///
     /// * `u` - A reference to the source vertex.
     /// * `v` - A reference to the target vertex.
-    /// * `weight` - The weight to assign to the edge.
+    /// * `weight` - The new weight of the edge.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - If the operation succeeds.
-    /// * `Err(GraphError::VertexNotFound)` - If one or both vertices do not exist in the graph.
+    /// * `Ok(Some(Self::Weight))` - The weight the edge had before.
+    /// * `Ok(None)` - If the edge had no weight.
+    /// * `Err(MutationError)` - If the edge does not exist.
     ///
     /// # Errors
     ///
-    /// Returns `GraphError::VertexNotFound` if one or both of the vertices are not in the graph.
-    fn set_edge_weight(
+    /// Returns `MutationError::EdgeNotFound` if the edge is not in the graph.
+    fn update_edge_weight(
         &mut self,
         u: &Self::Vertex,
         v: &Self::Vertex,
         weight: Self::Weight,
-    ) -> Result<(), GraphError>;
+    ) -> Result<Option<Self::Weight>, MutationError>;
+
+    /// Removes an edge and returns its weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - A reference to the source vertex.
+    /// * `v` - A reference to the target vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Self::Weight))` - The weight of the removed edge.
+    /// * `Ok(None)` - If the removed edge had no weight.
+    /// * `Err(MutationError)` - If the edge does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::EdgeNotFound` if the edge is not in the graph.
+    fn remove_weighted_edge(
+        &mut self,
+        u: &Self::Vertex,
+        v: &Self::Vertex,
+    ) -> Result<Option<Self::Weight>, MutationError> {
+        let weight = self.edge_weight(u, v).cloned();
+        self.remove_edge(u, v)?;
+        Ok(weight)
+    }
+
+    /// Removes a vertex along with its incident edges and returns those edges
+    /// with their weights.
+    ///
+    /// The edges are listed as in [`GraphMut::remove_vertex`]. The default
+    /// implementation reads the weights before removing the vertex, scanning
+    /// every vertex for the arcs entering it in directed graphs.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertex` - A reference to the vertex to be removed.
+    ///
+    /// # Returns
+    ///
+    /// The removed edges as `(source, target, weight)` tuples, with `None` for
+    /// edges without a weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MutationError::VertexNotFound` if the vertex is not in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{graph, traits::weighted::WeightedGraphMut};
+    ///
+    /// let mut graph = graph! { 1 -> 2 [weight = 5], 3 -> 1 [weight = 7] };
+    /// let mut removed = graph.remove_weighted_vertex(&1).unwrap();
+    /// removed.sort();
+    /// assert_eq!(removed, vec![(1, 2, Some(5)), (3, 1, Some(7))]);
+    /// ```
+    fn remove_weighted_vertex(
+        &mut self,
+        vertex: &Self::Vertex,
+    ) -> Result<WeightedEdgeList<Self::Vertex, Option<Self::Weight>>, MutationError> {
+        if !self.contains_vertex(vertex) {
+            return Err(MutationError::VertexNotFound);
+        }
+        let mut edges: Vec<_> = self
+            .neighbors(vertex)
+            .into_iter()
+            .flatten()
+            .map(|w| {
+                (
+                    vertex.clone(),
+                    w.clone(),
+                    self.edge_weight(vertex, w).cloned(),
+                )
+            })
+            .collect();
+        if self.is_directed() {
+            edges.extend(
+                self.vertices()
+                    .filter(|&u| u != vertex && self.contains_edge(u, vertex))
+                    .map(|u| {
+                        (
+                            u.clone(),
+                            vertex.clone(),
+                            self.edge_weight(u, vertex).cloned(),
+                        )
+                    }),
+            );
+        }
+        self.remove_vertex(vertex)?;
+        Ok(edges)
+    }
 }
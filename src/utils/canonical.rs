@@ -0,0 +1,209 @@
+use std::io::Write;
+
+use crate::{traits::weighted::WeightedGraph, GraphError};
+
+/// Writes the canonical text form of a graph.
+///
+/// The output does not depend on `HashMap` iteration order, so two equal graphs
+/// always produce the same bytes, on any machine. It consists of a `directed` or
+/// `undirected` header line, a `vertices <n>` line followed by one `v <vertex>`
+/// line per vertex in ascending order, and an `edges <m>` line followed by one
+/// `e <u> <v>` line per edge in ascending order, with ` <weight>` appended when
+/// the edge has a weight. Vertices and weights are rendered with their `Debug`
+/// implementation. Undirected edges are written once, with `u <= v`.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to serialize.
+/// * `writer` - The destination of the output.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the output cannot be written.
+pub fn write_canonical<G, W>(graph: &G, mut writer: W) -> Result<(), GraphError>
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+    W: Write,
+{
+    let mut vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    vertices.sort_unstable();
+
+    let mut edges = Vec::new();
+    for &u in &vertices {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if graph.is_directed() || u <= v {
+                edges.push((u, v));
+            }
+        }
+    }
+    edges.sort_unstable();
+
+    let kind = if graph.is_directed() {
+        "directed"
+    } else {
+        "undirected"
+    };
+    writeln!(writer, "{kind}")?;
+    writeln!(writer, "vertices {}", vertices.len())?;
+    for v in vertices {
+        writeln!(writer, "v {v:?}")?;
+    }
+    writeln!(writer, "edges {}", edges.len())?;
+    for (u, v) in edges {
+        match graph.edge_weight(u, v) {
+            Some(weight) => writeln!(writer, "e {u:?} {v:?} {weight:?}"),
+            None => writeln!(writer, "e {u:?} {v:?}"),
+        }?;
+    }
+    Ok(())
+}
+
+/// Returns the canonical byte representation of a graph.
+///
+/// See [`write_canonical`] for the format. The bytes are suitable for snapshot
+/// tests and for comparing graphs produced on different machines.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to serialize.
+#[must_use]
+pub fn canonical_bytes<G>(graph: &G) -> Vec<u8>
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+{
+    let mut bytes = Vec::new();
+    // Writing into a `Vec` cannot fail.
+    let _ = write_canonical(graph, &mut bytes);
+    bytes
+}
+
+/// Returns a 64-bit FNV-1a checksum of the canonical bytes of a graph.
+///
+/// Unlike `std::hash::DefaultHasher`, the checksum is stable across Rust releases
+/// and platforms.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to checksum.
+#[must_use]
+pub fn canonical_checksum<G>(graph: &G) -> u64
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+{
+    canonical_bytes(graph)
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::weighted::WeightedGraphMut, Graph, GraphMut, SimpleGraph};
+
+    fn build(edges: &[(u32, u32, i32)], isolated: &[u32], directed: bool) -> SimpleGraph<u32, i32> {
+        let mut graph = if directed {
+            SimpleGraph::new_weighted_directed()
+        } else {
+            SimpleGraph::new_weighted_undirected()
+        };
+        for &v in isolated {
+            graph.add_vertex(v).unwrap();
+        }
+        for &(u, v, weight) in edges {
+            for w in [u, v] {
+                if !graph.contains_vertex(&w) {
+                    graph.add_vertex(w).unwrap();
+                }
+            }
+            graph.add_weighted_edge(&u, &v, weight).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn writes_the_documented_format() {
+        let graph = build(&[(3, 1, 7), (2, 2, -1)], &[9], false);
+        let text = String::from_utf8(canonical_bytes(&graph)).unwrap();
+        assert_eq!(
+            text,
+            "undirected\nvertices 4\nv 1\nv 2\nv 3\nv 9\nedges 2\ne 1 3 7\ne 2 2 -1\n"
+        );
+    }
+
+    /// Reads the canonical form back, for graphs with `u32` vertices and `i32`
+    /// weights.
+    fn read(text: &str) -> SimpleGraph<u32, i32> {
+        let mut lines = text.lines();
+        let directed = lines.next() == Some("directed");
+        let mut graph = build(&[], &[], directed);
+        for line in lines {
+            let fields: Vec<&str> = line.split(' ').collect();
+            match fields.as_slice() {
+                ["v", v] => graph.add_vertex(v.parse().unwrap()).unwrap(),
+                ["e", u, v, w] => graph
+                    .add_weighted_edge(&u.parse().unwrap(), &v.parse().unwrap(), w.parse().unwrap())
+                    .unwrap(),
+                _ => {}
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn canonical_form_round_trips() {
+        for directed in [true, false] {
+            let graph = build(
+                &[(5, 1, 2), (1, 3, 3), (2, 2, 0), (7, 3, -4)],
+                &[8],
+                directed,
+            );
+            let bytes = canonical_bytes(&graph);
+            let parsed = read(std::str::from_utf8(&bytes).unwrap());
+            assert_eq!(parsed.order(), graph.order());
+            assert_eq!(parsed.size(), graph.size());
+            assert_eq!(canonical_bytes(&parsed), bytes);
+        }
+    }
+
+    #[test]
+    fn checksums_ignore_insertion_order() {
+        let edges = [(1, 2, 5), (2, 3, 6), (3, 1, 7), (4, 4, 8)];
+        let mut reversed = edges;
+        reversed.reverse();
+        let flipped: Vec<_> = edges.iter().map(|&(u, v, w)| (v, u, w)).collect();
+
+        for directed in [true, false] {
+            let graph = build(&edges, &[10, 11], directed);
+            let other = build(&reversed, &[11, 10], directed);
+            assert_eq!(canonical_bytes(&graph), canonical_bytes(&other));
+            assert_eq!(canonical_checksum(&graph), canonical_checksum(&other));
+        }
+        // Undirected edges are the same whichever endpoint comes first.
+        assert_eq!(
+            canonical_checksum(&build(&edges, &[], false)),
+            canonical_checksum(&build(&flipped, &[], false))
+        );
+    }
+
+    #[test]
+    fn checksums_tell_graphs_apart() {
+        let graph = build(&[(1, 2, 5)], &[], false);
+        assert_ne!(
+            canonical_checksum(&graph),
+            canonical_checksum(&build(&[(1, 2, 6)], &[], false))
+        );
+        assert_ne!(
+            canonical_checksum(&graph),
+            canonical_checksum(&build(&[(1, 2, 5)], &[], true))
+        );
+        assert_ne!(
+            canonical_checksum(&graph),
+            canonical_checksum(&build(&[(1, 2, 5)], &[3], false))
+        );
+    }
+}
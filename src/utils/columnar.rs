@@ -0,0 +1,263 @@
+use std::{collections::HashMap, error::Error, fmt::Display, sync::Arc};
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray, UInt64Array};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::{traits::weighted::WeightedGraph, Graph, GraphError};
+
+fn arrow_error(e: impl Error + Send + Sync + 'static) -> GraphError {
+    GraphError::Encode(Box::new(e))
+}
+
+/// Assigns every vertex its position in the vertex table.
+fn vertex_ids<G: Graph>(graph: &G) -> HashMap<&G::Vertex, u64> {
+    graph.vertices().zip(0..).collect()
+}
+
+/// Lists the edges as `(source, target)` table rows, writing each undirected
+/// edge once.
+fn edge_rows<'a, G: Graph>(
+    graph: &'a G,
+    ids: &HashMap<&'a G::Vertex, u64>,
+) -> Vec<(&'a G::Vertex, &'a G::Vertex)> {
+    let mut rows = Vec::new();
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if graph.is_directed() || ids[u] <= ids[v] {
+                rows.push((u, v));
+            }
+        }
+    }
+    rows
+}
+
+/// Builds the vertex table of a graph as an Arrow record batch.
+///
+/// The table has the columns `id: UInt64`, the row number used by the edge
+/// tables, and `label: Utf8`, the `Display` representation of the vertex.
+/// Ids follow the iteration order of [`Graph::vertices`], so the vertex and edge
+/// tables must be built from the same, unmodified graph.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if Arrow rejects the batch.
+pub fn vertices_record_batch<G>(graph: &G) -> Result<RecordBatch, GraphError>
+where
+    G: Graph,
+    G::Vertex: Display,
+{
+    let ids: UInt64Array = (0..).take(graph.order()).collect::<Vec<u64>>().into();
+    let labels: StringArray = graph.vertices().map(|v| Some(v.to_string())).collect();
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::UInt64, false),
+        Field::new("label", DataType::Utf8, false),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![Arc::new(ids) as ArrayRef, Arc::new(labels) as ArrayRef],
+    )
+    .map_err(arrow_error)
+}
+
+/// Builds the edge table of a graph as an Arrow record batch.
+///
+/// The table has the columns `source: UInt64` and `target: UInt64`, referring to
+/// the ids of [`vertices_record_batch`]. Undirected edges appear once.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if Arrow rejects the batch.
+pub fn edges_record_batch<G>(graph: &G) -> Result<RecordBatch, GraphError>
+where
+    G: Graph,
+{
+    let ids = vertex_ids(graph);
+    let rows = edge_rows(graph, &ids);
+    let sources: UInt64Array = rows.iter().map(|(u, _)| ids[u]).collect::<Vec<_>>().into();
+    let targets: UInt64Array = rows.iter().map(|(_, v)| ids[v]).collect::<Vec<_>>().into();
+    let schema = Schema::new(vec![
+        Field::new("source", DataType::UInt64, false),
+        Field::new("target", DataType::UInt64, false),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![Arc::new(sources) as ArrayRef, Arc::new(targets) as ArrayRef],
+    )
+    .map_err(arrow_error)
+}
+
+/// Builds the edge table of a weighted graph as an Arrow record batch.
+///
+/// Like [`edges_record_batch`] with an extra nullable `weight: Float64` column;
+/// edges without a weight have a null weight.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if Arrow rejects the batch.
+pub fn weighted_edges_record_batch<G>(graph: &G) -> Result<RecordBatch, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+{
+    let ids = vertex_ids(graph);
+    let rows = edge_rows(graph, &ids);
+    let sources: UInt64Array = rows.iter().map(|(u, _)| ids[u]).collect::<Vec<_>>().into();
+    let targets: UInt64Array = rows.iter().map(|(_, v)| ids[v]).collect::<Vec<_>>().into();
+    let weights: Float64Array = rows
+        .iter()
+        .map(|(u, v)| graph.edge_weight(u, v).map(|&w| w.into()))
+        .collect();
+    let schema = Schema::new(vec![
+        Field::new("source", DataType::UInt64, false),
+        Field::new("target", DataType::UInt64, false),
+        Field::new("weight", DataType::Float64, true),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(sources) as ArrayRef,
+            Arc::new(targets) as ArrayRef,
+            Arc::new(weights) as ArrayRef,
+        ],
+    )
+    .map_err(arrow_error)
+}
+
+/// Writes a record batch, such as the vertex or edge table, as a Parquet file.
+///
+/// # Arguments
+///
+/// * `batch` - The table to write.
+/// * `writer` - The destination, e.g. a `File`.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if encoding or writing fails.
+#[cfg(feature = "parquet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+pub fn write_parquet<W>(batch: &RecordBatch, writer: W) -> Result<(), GraphError>
+where
+    W: std::io::Write + Send,
+{
+    let mut writer =
+        parquet::arrow::ArrowWriter::try_new(writer, batch.schema(), None).map_err(arrow_error)?;
+    writer.write(batch).map_err(arrow_error)?;
+    writer.close().map_err(arrow_error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+
+    use super::*;
+    use crate::{utils::canonical::canonical_bytes, GraphMut, SimpleGraph};
+
+    fn column<'a, T: 'static>(batch: &'a RecordBatch, name: &str) -> &'a T {
+        batch
+            .column_by_name(name)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<T>()
+            .unwrap()
+    }
+
+    /// Rebuilds a graph from its vertex and weighted edge tables.
+    fn from_tables(
+        vertices: &RecordBatch,
+        edges: &RecordBatch,
+        directed: bool,
+    ) -> SimpleGraph<String, f64> {
+        let ids = column::<UInt64Array>(vertices, "id");
+        let labels = column::<StringArray>(vertices, "label");
+        let label: HashMap<u64, String> = ids
+            .values()
+            .iter()
+            .zip(labels.iter())
+            .map(|(&id, label)| (id, label.unwrap().to_string()))
+            .collect();
+
+        let sources = column::<UInt64Array>(edges, "source");
+        let targets = column::<UInt64Array>(edges, "target");
+        let weights = column::<Float64Array>(edges, "weight");
+        let mut graph = SimpleGraph::from_weighted_edges(
+            (0..edges.num_rows()).map(|row| {
+                (
+                    label[&sources.value(row)].clone(),
+                    label[&targets.value(row)].clone(),
+                    weights.value(row),
+                )
+            }),
+            directed,
+        );
+        for v in label.into_values() {
+            if !graph.contains_vertex(&v) {
+                graph.add_vertex(v).unwrap();
+            }
+        }
+        graph
+    }
+
+    fn sample(directed: bool) -> SimpleGraph<String, f64> {
+        let mut graph = SimpleGraph::from_weighted_edges(
+            [
+                ("a".to_string(), "b".to_string(), 1.5),
+                ("b".to_string(), "c".to_string(), 2.0),
+                ("c".to_string(), "c".to_string(), 0.5),
+            ],
+            directed,
+        );
+        graph.add_vertex("d".to_string()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn tables_round_trip() {
+        for directed in [true, false] {
+            let graph = sample(directed);
+            let vertices = vertices_record_batch(&graph).unwrap();
+            let edges = weighted_edges_record_batch(&graph).unwrap();
+            assert_eq!(vertices.num_rows(), 4);
+            assert_eq!(edges.num_rows(), 3, "undirected edges appear once");
+            let rebuilt = from_tables(&vertices, &edges, directed);
+            assert_eq!(canonical_bytes(&rebuilt), canonical_bytes(&graph));
+        }
+    }
+
+    #[test]
+    fn unweighted_edges_have_no_weight_column_and_weights_can_be_null() {
+        let graph = SimpleGraph::<u32>::from_edges([(1, 2), (2, 3)], true);
+        let edges = edges_record_batch(&graph).unwrap();
+        assert_eq!(edges.num_columns(), 2);
+        assert_eq!(edges.num_rows(), 2);
+
+        let mut weighted: SimpleGraph<u32, f32> = SimpleGraph::new_weighted_directed();
+        weighted.add_edge_with_vertices(1, 2).unwrap();
+        let edges = weighted_edges_record_batch(&weighted).unwrap();
+        assert!(column::<Float64Array>(&edges, "weight").is_null(0));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet_round_trips() {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let graph = sample(false);
+        let batch = weighted_edges_record_batch(&graph).unwrap();
+        let path = std::env::temp_dir().join(format!(
+            "kambo-graph-columnar-{}.parquet",
+            std::process::id()
+        ));
+        write_parquet(&batch, std::fs::File::create(&path).unwrap()).unwrap();
+
+        let file = std::fs::File::open(&path).unwrap();
+        let batches: Vec<RecordBatch> = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(batches, [batch]);
+    }
+}
@@ -0,0 +1,449 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::{
+    error::{MutationError, ParseError},
+    traits::{
+        measure::Measure,
+        weighted::{WeightedGraph, WeightedGraphMut},
+    },
+    utils::max_vertex,
+    Graph, GraphError, GraphMut, SimpleGraph,
+};
+
+/// A maximum-flow instance read from a DIMACS `max` file.
+///
+/// The graph is directed and each arc weight holds its capacity.
+#[derive(Clone, Debug)]
+pub struct DimacsFlowNetwork<W>
+where
+    W: Clone + std::fmt::Debug,
+{
+    /// The network, with vertices `1..=n` and capacities as edge weights.
+    pub graph: SimpleGraph<usize, W>,
+    /// The source vertex.
+    pub source: usize,
+    /// The sink vertex.
+    pub sink: usize,
+}
+
+fn open(file_path: &str) -> Result<io::BufReader<File>, GraphError> {
+    Ok(io::BufReader::new(File::open(file_path)?))
+}
+
+fn invalid(line_number: usize, line: &str) -> GraphError {
+    ParseError::at(line_number, format!("Invalid DIMACS line: {line}")).into()
+}
+
+fn parse_field<T: FromStr>(
+    field: Option<&str>,
+    line_number: usize,
+    line: &str,
+) -> Result<T, GraphError> {
+    field
+        .and_then(|f| f.parse().ok())
+        .ok_or_else(|| invalid(line_number, line))
+}
+
+/// Parses a vertex id and checks that it lies in `1..=n`.
+fn parse_vertex(
+    field: Option<&str>,
+    n: usize,
+    line_number: usize,
+    line: &str,
+) -> Result<usize, GraphError> {
+    let v: usize = parse_field(field, line_number, line)?;
+    if v == 0 || v > n {
+        return Err(ParseError::at(line_number, format!("Vertex {v} out of range 1..={n}")).into());
+    }
+    Ok(v)
+}
+
+/// Reads an undirected graph in the DIMACS coloring format (`.col`) from a file.
+///
+/// See [`parse_dimacs_col_from_reader`] for details.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the file cannot be opened or read, and
+/// `GraphError::Parse` if it cannot be parsed.
+pub fn parse_dimacs_col(file_path: &str) -> Result<SimpleGraph<usize>, GraphError> {
+    parse_dimacs_col_from_reader(open(file_path)?)
+}
+
+/// Reads an undirected graph in the DIMACS coloring format (`.col`).
+///
+/// The format consists of comment lines starting with `c`, one problem line
+/// `p edge <n> <m>` (`p col` is also accepted) and one line `e <u> <v>` per edge.
+/// The graph has vertices `1..=n`, including isolated ones. Repeated edges are
+/// ignored.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the input cannot be read, and `GraphError::Parse`
+/// if the problem line is missing or malformed, or an edge line is malformed or
+/// references a vertex outside `1..=n`.
+pub fn parse_dimacs_col_from_reader<R: BufRead>(
+    reader: R,
+) -> Result<SimpleGraph<usize>, GraphError> {
+    trace_span!("parse_dimacs_col");
+    let mut graph = SimpleGraph::new_undirected();
+    let mut n = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = index + 1;
+        let mut fields = line.split_whitespace();
+        match fields.next() {
+            None | Some("c") => {}
+            Some("p") => {
+                if n.is_some() || !matches!(fields.next(), Some("edge" | "col")) {
+                    return Err(invalid(line_number, &line));
+                }
+                let count: usize = parse_field(fields.next(), line_number, &line)?;
+                for v in 1..=count {
+                    graph.add_vertex(v)?;
+                }
+                n = Some(count);
+            }
+            Some("e") => {
+                let n = n.ok_or_else(|| invalid(line_number, &line))?;
+                let u = parse_vertex(fields.next(), n, line_number, &line)?;
+                let v = parse_vertex(fields.next(), n, line_number, &line)?;
+                match graph.add_edge(&u, &v) {
+                    Ok(()) | Err(MutationError::EdgeAlreadyExists) => {}
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            Some(_) => return Err(invalid(line_number, &line)),
+        }
+    }
+
+    if n.is_none() {
+        return Err(ParseError::input("Missing DIMACS problem line").into());
+    }
+    Ok(graph)
+}
+
+/// Writes a graph in the DIMACS coloring format (`.col`).
+///
+/// Vertices must be the integers `1..=n`; `n` is taken as the largest vertex.
+/// Each undirected edge is written once.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to write.
+/// * `writer` - The destination of the output.
+///
+/// # Errors
+///
+/// Returns `GraphError::InvalidOperation` if the graph contains vertex `0`, and
+/// `GraphError::Io` if the output cannot be written.
+pub fn write_dimacs_col<G, W>(graph: &G, mut writer: W) -> Result<(), GraphError>
+where
+    G: Graph<Vertex = usize>,
+    W: Write,
+{
+    let n = max_vertex(graph)?;
+    let mut edges = Vec::new();
+    for &u in graph.vertices() {
+        for &v in graph.neighbors(&u).into_iter().flatten() {
+            if graph.is_directed() || u <= v {
+                edges.push((u, v));
+            }
+        }
+    }
+    edges.sort_unstable();
+
+    writeln!(writer, "p edge {n} {}", edges.len())?;
+    for (u, v) in edges {
+        writeln!(writer, "e {u} {v}")?;
+    }
+    Ok(())
+}
+
+/// Reads a maximum-flow instance in the DIMACS `max` format from a file.
+///
+/// See [`parse_dimacs_flow_from_reader`] for details.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the file cannot be opened or read,
+/// `GraphError::Parse` if it cannot be parsed, and `GraphError::WeightOverflow`
+/// if parallel arc capacities overflow.
+pub fn parse_dimacs_flow<W>(file_path: &str) -> Result<DimacsFlowNetwork<W>, GraphError>
+where
+    W: Measure + FromStr,
+{
+    parse_dimacs_flow_from_reader(open(file_path)?)
+}
+
+/// Reads a maximum-flow instance in the DIMACS `max` format.
+///
+/// The format consists of comment lines starting with `c`, one problem line
+/// `p max <n> <m>`, the node designators `n <id> s` and `n <id> t`, and one line
+/// `a <u> <v> <capacity>` per arc. Capacities of parallel arcs are added together.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the input cannot be read, `GraphError::Parse` if a
+/// line is malformed, a vertex lies outside `1..=n`, or the source or sink is
+/// missing, and `GraphError::WeightOverflow` if parallel arc capacities overflow.
+pub fn parse_dimacs_flow_from_reader<W, R>(reader: R) -> Result<DimacsFlowNetwork<W>, GraphError>
+where
+    W: Measure + FromStr,
+    R: BufRead,
+{
+    trace_span!("parse_dimacs_flow");
+    let mut graph: SimpleGraph<usize, W> = SimpleGraph::new_weighted_directed();
+    let mut n = None;
+    let mut source = None;
+    let mut sink = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = index + 1;
+        let mut fields = line.split_whitespace();
+        match fields.next() {
+            None | Some("c") => {}
+            Some("p") => {
+                if n.is_some() || fields.next() != Some("max") {
+                    return Err(invalid(line_number, &line));
+                }
+                let count: usize = parse_field(fields.next(), line_number, &line)?;
+                for v in 1..=count {
+                    graph.add_vertex(v)?;
+                }
+                n = Some(count);
+            }
+            Some("n") => {
+                let n = n.ok_or_else(|| invalid(line_number, &line))?;
+                let v = parse_vertex(fields.next(), n, line_number, &line)?;
+                match fields.next() {
+                    Some("s") => source = Some(v),
+                    Some("t") => sink = Some(v),
+                    _ => return Err(invalid(line_number, &line)),
+                }
+            }
+            Some("a") => {
+                let n = n.ok_or_else(|| invalid(line_number, &line))?;
+                let u = parse_vertex(fields.next(), n, line_number, &line)?;
+                let v = parse_vertex(fields.next(), n, line_number, &line)?;
+                let capacity: W = parse_field(fields.next(), line_number, &line)?;
+                if let Some(existing) = graph.edge_weight(&u, &v) {
+                    let total = existing
+                        .checked_add(&capacity)
+                        .ok_or(GraphError::WeightOverflow)?;
+                    graph.update_edge_weight(&u, &v, total)?;
+                } else {
+                    graph.add_weighted_edge(&u, &v, capacity)?;
+                }
+            }
+            Some(_) => return Err(invalid(line_number, &line)),
+        }
+    }
+
+    match (n, source, sink) {
+        (Some(_), Some(source), Some(sink)) => Ok(DimacsFlowNetwork {
+            graph,
+            source,
+            sink,
+        }),
+        (None, _, _) => Err(ParseError::input("Missing DIMACS problem line").into()),
+        _ => Err(ParseError::input("Missing DIMACS source or sink").into()),
+    }
+}
+
+/// Writes a maximum-flow instance in the DIMACS `max` format.
+///
+/// Vertices must be the integers `1..=n`; `n` is taken as the largest vertex.
+/// Edge weights are written as capacities, and undirected edges are written as a
+/// pair of opposite arcs.
+///
+/// # Arguments
+///
+/// * `graph` - The network to write.
+/// * `source` - The source vertex.
+/// * `sink` - The sink vertex.
+/// * `writer` - The destination of the output.
+///
+/// # Errors
+///
+/// * `GraphError::VertexNotFound` - If `source` or `sink` is not in the graph.
+/// * `GraphError::InvalidOperation` - If the graph contains vertex `0` or an edge
+///   has no weight.
+/// * `GraphError::Io` - If the output cannot be written.
+pub fn write_dimacs_flow<G, W>(
+    graph: &G,
+    source: usize,
+    sink: usize,
+    mut writer: W,
+) -> Result<(), GraphError>
+where
+    G: WeightedGraph<Vertex = usize>,
+    G::Weight: Display,
+    W: Write,
+{
+    if !graph.contains_vertex(&source) || !graph.contains_vertex(&sink) {
+        return Err(GraphError::VertexNotFound);
+    }
+    let n = max_vertex(graph)?;
+
+    let mut arcs = Vec::new();
+    for &u in graph.vertices() {
+        for &v in graph.neighbors(&u).into_iter().flatten() {
+            let capacity = graph.edge_weight(&u, &v).ok_or_else(|| {
+                GraphError::InvalidOperation(format!("Edge ({u}, {v}) has no weight"))
+            })?;
+            arcs.push((u, v, capacity));
+        }
+    }
+    arcs.sort_unstable_by_key(|&(u, v, _)| (u, v));
+
+    writeln!(writer, "p max {n} {}", arcs.len())?;
+    writeln!(writer, "n {source} s")?;
+    writeln!(writer, "n {sink} t")?;
+    for (u, v, capacity) in arcs {
+        writeln!(writer, "a {u} {v} {capacity}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted_edges<W: Clone + std::fmt::Debug>(
+        graph: &SimpleGraph<usize, W>,
+    ) -> Vec<(usize, usize)> {
+        let mut edges: Vec<_> = graph
+            .vertices()
+            .flat_map(|&u| {
+                graph
+                    .neighbors(&u)
+                    .into_iter()
+                    .flatten()
+                    .map(move |&v| (u, v))
+            })
+            .filter(|&(u, v)| graph.is_directed() || u <= v)
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    fn parse_error<T: std::fmt::Debug>(result: Result<T, GraphError>) -> ParseError {
+        match result {
+            Err(GraphError::Parse(e)) => e,
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn col_round_trips() {
+        let input = "c a triangle, a pendant and an isolated vertex\n\
+                     p edge 5 5\n\
+                     e 1 2\ne 2 3\ne 3 1\ne 3 4\ne 2 1\n";
+        let graph = parse_dimacs_col_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(graph.order(), 5);
+        assert_eq!(graph.size(), 4, "the repeated edge is ignored");
+
+        let mut out = Vec::new();
+        write_dimacs_col(&graph, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out, "p edge 5 4\ne 1 2\ne 1 3\ne 2 3\ne 3 4\n");
+
+        let parsed = parse_dimacs_col_from_reader(out.as_bytes()).unwrap();
+        assert_eq!(
+            parsed.order(),
+            5,
+            "isolated vertices survive the round trip"
+        );
+        assert_eq!(sorted_edges(&parsed), sorted_edges(&graph));
+    }
+
+    #[test]
+    fn col_requires_a_problem_line_first() {
+        let error = parse_error(parse_dimacs_col_from_reader("c empty\n".as_bytes()));
+        assert_eq!(error.line, None);
+        let error = parse_error(parse_dimacs_col_from_reader(
+            "e 1 2\np edge 2 1\n".as_bytes(),
+        ));
+        assert_eq!(error.line, Some(1));
+    }
+
+    #[test]
+    fn col_rejects_out_of_range_vertices() {
+        for edge in ["e 0 1", "e 1 4"] {
+            let input = format!("p edge 3 1\n{edge}\n");
+            let error = parse_error(parse_dimacs_col_from_reader(input.as_bytes()));
+            assert_eq!(error.line, Some(2), "{edge}");
+            assert!(error.message.contains("out of range"));
+        }
+    }
+
+    #[test]
+    fn max_round_trips_and_sums_parallel_arcs() {
+        let input = "c two parallel arcs from 1 to 2\n\
+                     p max 4 4\nn 1 s\nn 4 t\n\
+                     a 1 2 3\na 1 2 4\na 2 4 5\na 1 3 2\n";
+        let network: DimacsFlowNetwork<u32> =
+            parse_dimacs_flow_from_reader(input.as_bytes()).unwrap();
+        assert_eq!((network.source, network.sink), (1, 4));
+        assert_eq!(network.graph.edge_weight(&1, &2), Some(&7));
+        assert_eq!(network.graph.size(), 3);
+
+        let mut out = Vec::new();
+        write_dimacs_flow(&network.graph, network.source, network.sink, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out, "p max 4 3\nn 1 s\nn 4 t\na 1 2 7\na 1 3 2\na 2 4 5\n");
+
+        let parsed: DimacsFlowNetwork<u32> = parse_dimacs_flow_from_reader(out.as_bytes()).unwrap();
+        assert_eq!(sorted_edges(&parsed.graph), sorted_edges(&network.graph));
+        assert_eq!(parsed.graph.edge_weight(&2, &4), Some(&5));
+    }
+
+    #[test]
+    fn max_reports_parallel_capacity_overflow() {
+        let input = "p max 2 2\nn 1 s\nn 2 t\na 1 2 200\na 1 2 100\n";
+        let result = parse_dimacs_flow_from_reader::<u8, _>(input.as_bytes());
+        assert!(matches!(result, Err(GraphError::WeightOverflow)));
+    }
+
+    #[test]
+    fn max_requires_the_problem_line_source_and_sink() {
+        let error = parse_error(parse_dimacs_flow_from_reader::<u32, _>(
+            "c nothing\n".as_bytes(),
+        ));
+        assert!(error.message.contains("problem line"));
+
+        let error = parse_error(parse_dimacs_flow_from_reader::<u32, _>(
+            "n 1 s\n".as_bytes(),
+        ));
+        assert_eq!(error.line, Some(1));
+
+        for designators in ["n 1 s\n", "n 2 t\n", ""] {
+            let input = format!("p max 2 1\n{designators}a 1 2 1\n");
+            let error = parse_error(parse_dimacs_flow_from_reader::<u32, _>(input.as_bytes()));
+            assert!(error.message.contains("source or sink"), "{designators:?}");
+        }
+    }
+
+    #[test]
+    fn max_rejects_out_of_range_vertices() {
+        let input = "p max 2 1\nn 1 s\nn 3 t\n";
+        let error = parse_error(parse_dimacs_flow_from_reader::<u32, _>(input.as_bytes()));
+        assert_eq!(error.line, Some(3));
+
+        let input = "p max 2 1\nn 1 s\nn 2 t\na 1 5 1\n";
+        let error = parse_error(parse_dimacs_flow_from_reader::<u32, _>(input.as_bytes()));
+        assert_eq!(error.line, Some(4));
+    }
+
+    #[test]
+    fn writers_reject_vertex_zero() {
+        let graph = SimpleGraph::<usize>::from_edges([(0, 1)], false);
+        assert!(write_dimacs_col(&graph, Vec::new()).is_err());
+    }
+}
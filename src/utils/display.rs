@@ -0,0 +1,133 @@
+use std::fmt;
+
+use crate::traits::weighted::WeightedGraph;
+
+/// Writes a readable adjacency list of a graph.
+///
+/// The first line gives the directedness, order and size of the graph, followed
+/// by one `vertex: neighbor, neighbor` line per vertex. Vertices and neighbor
+/// lists are sorted, weighted edges show their weight in parentheses, and
+/// vertices and weights use their `Debug` implementation. Graph types can call
+/// this from their `Display` implementation.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to print.
+/// * `f` - The formatter to write to.
+///
+/// # Errors
+///
+/// Returns an error if the formatter fails.
+pub fn fmt_adjacency_list<G>(graph: &G, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    G: WeightedGraph,
+    G::Vertex: Ord,
+{
+    let mut vertices: Vec<&G::Vertex> = graph.vertices().collect();
+    vertices.sort_unstable();
+
+    let kind = if graph.is_directed() {
+        "directed"
+    } else {
+        "undirected"
+    };
+    write!(
+        f,
+        "{kind} graph ({} vertices, {} edges)",
+        vertices.len(),
+        graph.size()
+    )?;
+
+    for u in vertices {
+        let mut neighbors: Vec<&G::Vertex> = graph.neighbors(u).into_iter().flatten().collect();
+        neighbors.sort_unstable();
+
+        write!(f, "\n{u:?}:")?;
+        for (i, v) in neighbors.into_iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            match graph.edge_weight(u, v) {
+                Some(weight) => write!(f, "{separator}{v:?} ({weight:?})")?,
+                None => write!(f, "{separator}{v:?}")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{traits::weighted::WeightedGraphMut, GraphMut, SimpleGraph};
+
+    /// Reads an adjacency list back, for graphs with `u32` vertices and `i32`
+    /// weights.
+    fn read(text: &str) -> SimpleGraph<u32, i32> {
+        let mut lines = text.lines();
+        let header = lines.next().unwrap();
+        let mut graph = if header.starts_with("directed") {
+            SimpleGraph::new_weighted_directed()
+        } else {
+            SimpleGraph::new_weighted_undirected()
+        };
+        let rows: Vec<(u32, &str)> = lines
+            .map(|line| {
+                let (u, rest) = line.split_once(':').unwrap();
+                (u.parse().unwrap(), rest)
+            })
+            .collect();
+        for &(u, _) in &rows {
+            graph.add_vertex(u).unwrap();
+        }
+        for (u, rest) in rows {
+            for entry in rest.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                let (v, weight) = entry.split_once(" (").unwrap();
+                let (v, weight) = (v.parse().unwrap(), weight.trim_end_matches(')'));
+                match graph.add_weighted_edge(&u, &v, weight.parse().unwrap()) {
+                    Ok(()) | Err(crate::error::MutationError::EdgeAlreadyExists) => {}
+                    Err(e) => panic!("{e}"),
+                }
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn prints_a_sorted_adjacency_list() {
+        let mut graph = SimpleGraph::new_weighted_undirected();
+        for v in [3, 1, 2, 9] {
+            graph.add_vertex(v).unwrap();
+        }
+        graph.add_weighted_edge(&3, &1, 7).unwrap();
+        graph.add_weighted_edge(&1, &2, -1).unwrap();
+        graph.add_weighted_edge(&2, &2, 4).unwrap();
+        assert_eq!(
+            graph.to_string(),
+            "undirected graph (4 vertices, 3 edges)\n\
+             1: 2 (-1), 3 (7)\n\
+             2: 1 (-1), 2 (4)\n\
+             3: 1 (7)\n\
+             9:"
+        );
+    }
+
+    #[test]
+    fn adjacency_lists_round_trip() {
+        for directed in [true, false] {
+            let graph = SimpleGraph::from_weighted_edges(
+                [(4, 1, 3), (1, 2, -2), (2, 4, 0), (3, 3, 5)],
+                directed,
+            );
+            let text = graph.to_string();
+            let parsed = read(&text);
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn unweighted_edges_print_without_weights() {
+        let graph = SimpleGraph::<u32>::from_edges([(2, 1)], true);
+        assert_eq!(
+            graph.to_string(),
+            "directed graph (2 vertices, 1 edges)\n1:\n2: 1"
+        );
+    }
+}
@@ -1,12 +1,23 @@
+use std::collections::HashSet;
+use std::fmt::Display;
 use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Write};
+use std::marker::PhantomData;
 use std::path::Path;
+use std::str::FromStr;
 
-use crate::GraphError;
+use crate::{error::ParseError, Graph, GraphError};
+
+/// A parsed edge list, each edge represented as a tuple `(u, v, Option<weight>)`.
+pub type ParsedEdges<V, W> = Vec<(V, V, Option<W>)>;
 
 /// Reads an edge list from a file and returns a vector of edges.
 /// Each edge is represented as a tuple `(u, v, Option<weight>)`.
 ///
+/// Vertices and weights can be of any type implementing `FromStr`, so the
+/// element types usually have to be annotated, e.g.
+/// `parse_edge_list::<usize, i32>(path)`.
+///
 /// # Arguments
 /// * `file_path` - Path to the file containing the edge list.
 ///
@@ -15,64 +26,269 @@ use crate::GraphError;
 ///
 /// # Errors
 /// This function returns a `GraphError` in the following cases:
-/// - If the file cannot be opened.
-/// - If a line cannot be read from the file.
-/// - If a line has an invalid format (e.g., less than two elements).
-/// - If a vertex cannot be parsed into a `usize`.
-/// - If a weight (if present) cannot be parsed into an `i32`.
-pub fn parse_edge_list(file_path: &str) -> Result<Vec<(usize, usize, Option<i32>)>, GraphError> {
+/// - `GraphError::Io` if the file cannot be opened or a line cannot be read.
+/// - `GraphError::Parse` if a line has an invalid format (e.g., less than two
+///   elements), a vertex cannot be parsed into a `V` or a weight (if present)
+///   cannot be parsed into a `W`.
+pub fn parse_edge_list<V, W>(file_path: &str) -> Result<ParsedEdges<V, W>, GraphError>
+where
+    V: FromStr,
+    W: FromStr,
+{
     let path = Path::new(file_path);
-    let file = File::open(path)
-        .map_err(|_| GraphError::InvalidOperation("Failed to open file".to_string()))?;
-    let reader = io::BufReader::new(file);
+    let file = File::open(path)?;
+    parse_edge_list_from_reader(io::BufReader::new(file))
+}
+
+/// Reads an edge list from any buffered reader, such as standard input, a
+/// network stream or a byte slice (`text.as_bytes()`).
+///
+/// Each non-empty line that does not start with `#` must contain two vertices
+/// separated by whitespace, optionally followed by a weight.
+///
+/// # Arguments
+/// * `reader` - The source of the edge list.
+///
+/// # Returns
+/// A vector of edges or an error if the input could not be read.
+///
+/// # Errors
+/// This function returns a `GraphError` in the following cases:
+/// - `GraphError::Io` if a line cannot be read from the reader.
+/// - `GraphError::Parse` if a line has an invalid format (e.g., less than two
+///   elements), a vertex cannot be parsed into a `V` or a weight (if present)
+///   cannot be parsed into a `W`.
+pub fn parse_edge_list_from_reader<V, W, R>(reader: R) -> Result<ParsedEdges<V, W>, GraphError>
+where
+    V: FromStr,
+    W: FromStr,
+    R: BufRead,
+{
+    trace_span!("parse_edge_list");
+    let edges = EdgeListReader::new(reader).collect::<Result<ParsedEdges<V, W>, GraphError>>()?;
+
+    trace_event!(edges = edges.len(), "edge list parsed");
+    Ok(edges)
+}
+
+/// Reads the edges of an edge list one line at a time, without collecting them.
+///
+/// This is the lazy form of [`parse_edge_list_from_reader`], in the same
+/// format: memory stays bounded by the longest line, so files larger than the
+/// available memory can be loaded with [`GraphLoader::load_edge_list`] or
+/// filtered on the fly. Iteration stops after the first error.
+///
+/// [`GraphLoader::load_edge_list`]: crate::utils::loader::GraphLoader::load_edge_list
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::utils::edge_list::EdgeListReader;
+///
+/// let input = "# roads\n1 2 3.5\n2 3\n";
+/// let mut edges = EdgeListReader::<u32, f64, _>::new(input.as_bytes());
+/// assert_eq!(edges.next().unwrap().unwrap(), (1, 2, Some(3.5)));
+/// assert_eq!(edges.next().unwrap().unwrap(), (2, 3, None));
+/// assert!(edges.next().is_none());
+/// ```
+pub struct EdgeListReader<V, W, R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    failed: bool,
+    edges: PhantomData<fn() -> (V, W)>,
+}
+
+impl<V, W, R> EdgeListReader<V, W, R>
+where
+    V: FromStr,
+    W: FromStr,
+    R: BufRead,
+{
+    /// Creates a reader of the edges in `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            failed: false,
+            edges: PhantomData,
+        }
+    }
 
-    let mut edges = Vec::new();
+    /// Returns the number of lines read so far, which is the line of the last
+    /// edge returned.
+    #[must_use]
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
 
-    for (line_number, line) in reader.lines().enumerate() {
-        let line =
-            line.map_err(|_| GraphError::InvalidOperation("Failed to read line".to_string()))?;
+    /// Reads the next edge, requiring it to have a weight.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Ok((u, v, weight)))` - The next edge.
+    /// * `Some(Err(GraphError))` - If a line cannot be read or parsed, or the
+    ///   edge has no weight. Reading stops after the error.
+    /// * `None` - If the input is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{utils::edge_list::EdgeListReader, GraphError};
+    ///
+    /// let input = "1 2 3.5\n# no weight below\n2 3\n";
+    /// let mut edges = EdgeListReader::<u32, f64, _>::new(input.as_bytes());
+    /// assert_eq!(edges.next_weighted().unwrap().unwrap(), (1, 2, 3.5));
+    ///
+    /// let Some(Err(GraphError::Parse(error))) = edges.next_weighted() else { panic!() };
+    /// assert_eq!(error.line, Some(3));
+    /// assert!(edges.next_weighted().is_none());
+    /// ```
+    pub fn next_weighted(&mut self) -> Option<Result<(V, V, W), GraphError>> {
+        match self.next()? {
+            Ok((u, v, Some(weight))) => Some(Ok((u, v, weight))),
+            Ok((_, _, None)) => {
+                self.failed = true;
+                let line = self.line.trim_end_matches(['\n', '\r']);
+                let error = ParseError::at(self.line_number, format!("Missing weight: {line}"));
+                Some(Err(error.into()))
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+impl<V, W> EdgeListReader<V, W, io::BufReader<File>>
+where
+    V: FromStr,
+    W: FromStr,
+{
+    /// Opens an edge list file for reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Io` if the file cannot be opened.
+    pub fn open(file_path: &str) -> Result<Self, GraphError> {
+        let file = File::open(Path::new(file_path))?;
+        Ok(Self::new(io::BufReader::new(file)))
+    }
+}
 
-        if line.trim().is_empty() || line.starts_with('#') {
-            continue; // Skip empty lines and comments
+impl<V, W, R> Iterator for EdgeListReader<V, W, R>
+where
+    V: FromStr,
+    W: FromStr,
+    R: BufRead,
+{
+    type Item = Result<(V, V, Option<W>), GraphError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            // The buffer is reused across lines, so reading allocates only
+            // when a line is longer than every line before it.
+            self.line.clear();
+            let parsed = match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    self.line_number += 1;
+                    let line = self.line.trim_end_matches(['\n', '\r']);
+                    parse_edge_line(line, self.line_number).map_err(GraphError::from)
+                }
+                Err(error) => Err(error.into()),
+            };
+            match parsed {
+                Ok(Some(edge)) => return Some(Ok(edge)),
+                Ok(None) => {}
+                Err(error) => {
+                    self.failed = true;
+                    return Some(Err(error));
+                }
+            }
         }
+        None
+    }
+}
 
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 2 {
-            return Err(GraphError::InvalidOperation(format!(
-                "Invalid format at line {}: {}",
-                line_number + 1,
-                line
-            )));
+/// Writes the edges of a graph as an edge list, one `u v` line per edge, in the
+/// format read by [`parse_edge_list_from_reader`].
+///
+/// Each undirected edge is written once. Isolated vertices do not appear in an
+/// edge list and are lost.
+///
+/// # Arguments
+/// * `graph` - The graph to write.
+/// * `writer` - The destination of the output.
+///
+/// # Errors
+/// Returns `GraphError::Io` if the output cannot be written.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{graph, utils::edge_list::write_edge_list};
+///
+/// let graph = graph! { 1 -> 2 };
+/// let mut output = Vec::new();
+/// write_edge_list(&graph, &mut output).unwrap();
+/// assert_eq!(output, b"1 2\n");
+/// ```
+pub fn write_edge_list<G, W>(graph: &G, mut writer: W) -> Result<(), GraphError>
+where
+    G: Graph,
+    G::Vertex: Display,
+    W: Write,
+{
+    let mut written = HashSet::new();
+    for u in graph.vertices() {
+        written.insert(u);
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if graph.is_directed() || u == v || !written.contains(v) {
+                writeln!(writer, "{u} {v}")?;
+            }
         }
+    }
+    Ok(())
+}
 
-        let u = parts[0].parse::<usize>().map_err(|_| {
-            GraphError::InvalidOperation(format!(
-                "Invalid vertex format at line {}: {}",
-                line_number + 1,
-                line
-            ))
-        })?;
-        let v = parts[1].parse::<usize>().map_err(|_| {
-            GraphError::InvalidOperation(format!(
-                "Invalid vertex format at line {}: {}",
-                line_number + 1,
-                line
-            ))
-        })?;
-        let weight = if parts.len() == 3 {
-            Some(parts[2].parse().map_err(|_| {
-                GraphError::InvalidOperation(format!(
-                    "Invalid weight at line {}: {}",
-                    line_number + 1,
-                    line
-                ))
-            })?)
-        } else {
-            None
-        };
-
-        edges.push((u, v, weight));
+/// Parses a single edge list line.
+///
+/// Returns `Ok(None)` for empty lines and comments.
+fn parse_edge_line<V, W>(
+    line: &str,
+    line_number: usize,
+) -> Result<Option<(V, V, Option<W>)>, ParseError>
+where
+    V: FromStr,
+    W: FromStr,
+{
+    if line.trim().is_empty() || line.starts_with('#') {
+        return Ok(None); // Skip empty lines and comments
     }
 
-    Ok(edges)
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 2 {
+        return Err(ParseError::at(
+            line_number,
+            format!("Invalid format: {line}"),
+        ));
+    }
+
+    let u = parts[0]
+        .parse::<V>()
+        .map_err(|_| ParseError::at(line_number, format!("Invalid vertex format: {line}")))?;
+    let v = parts[1]
+        .parse::<V>()
+        .map_err(|_| ParseError::at(line_number, format!("Invalid vertex format: {line}")))?;
+    let weight = if parts.len() == 3 {
+        Some(
+            parts[2]
+                .parse::<W>()
+                .map_err(|_| ParseError::at(line_number, format!("Invalid weight: {line}")))?,
+        )
+    } else {
+        None
+    };
+
+    Ok(Some((u, v, weight)))
 }
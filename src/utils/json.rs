@@ -0,0 +1,214 @@
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    error::{MutationError, ParseError},
+    traits::weighted::{WeightedGraph, WeightedGraphMut},
+    GraphError, GraphMut, SimpleGraph,
+};
+
+fn json_error(e: serde_json::Error) -> GraphError {
+    GraphError::Encode(Box::new(e))
+}
+
+fn value_error(e: serde_json::Error) -> ParseError {
+    ParseError::input(format!("JSON error: {e}")).with_source(e)
+}
+
+/// Serializes a graph to the node-link JSON structure used by D3 and `NetworkX`
+/// (`networkx.node_link_graph`).
+///
+/// The output has the form
+/// `{"directed": bool, "multigraph": false, "graph": {}, "nodes": [{"id": v}, ...],
+/// "links": [{"source": u, "target": v, "weight": w}, ...]}`, where links refer to
+/// nodes by id. Undirected edges appear once. The `weight` key is omitted for edges
+/// without a weight and for weights that serialize to `null`, such as `()`.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if a vertex or weight cannot be serialized.
+pub fn to_json_node_link<G>(graph: &G) -> Result<String, GraphError>
+where
+    G: WeightedGraph,
+    G::Vertex: Serialize,
+    G::Weight: Serialize,
+{
+    let index: HashMap<&G::Vertex, usize> =
+        graph.vertices().enumerate().map(|(i, v)| (v, i)).collect();
+
+    let nodes = graph
+        .vertices()
+        .map(|v| Ok(json!({ "id": serde_json::to_value(v).map_err(json_error)? })))
+        .collect::<Result<Vec<Value>, GraphError>>()?;
+
+    let mut links = Vec::new();
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if !graph.is_directed() && index[v] < index[u] {
+                continue;
+            }
+            let mut link = Map::new();
+            link.insert(
+                "source".into(),
+                serde_json::to_value(u).map_err(json_error)?,
+            );
+            link.insert(
+                "target".into(),
+                serde_json::to_value(v).map_err(json_error)?,
+            );
+            if let Some(weight) = graph.edge_weight(u, v) {
+                let weight = serde_json::to_value(weight).map_err(json_error)?;
+                if !weight.is_null() {
+                    link.insert("weight".into(), weight);
+                }
+            }
+            links.push(Value::Object(link));
+        }
+    }
+
+    let document = json!({
+        "directed": graph.is_directed(),
+        "multigraph": false,
+        "graph": {},
+        "nodes": nodes,
+        "links": links,
+    });
+    serde_json::to_string(&document).map_err(json_error)
+}
+
+/// Builds a graph from the node-link JSON structure used by D3 and `NetworkX`
+/// (`networkx.node_link_data`).
+///
+/// Links refer to nodes by id, and endpoints missing from `nodes` are created.
+/// Links with a `weight` key become weighted edges, the others plain edges. Extra
+/// node and link attributes are ignored, and repeated links (as in multigraphs)
+/// keep the last weight. `directed` defaults to `false` when absent.
+///
+/// # Errors
+///
+/// Returns `GraphError::Parse` if the input is not valid JSON, lacks the `nodes`
+/// or `links` arrays, or contains an id or weight that cannot be deserialized.
+pub fn from_json_node_link<V, W>(json: &str) -> Result<SimpleGraph<V, W>, GraphError>
+where
+    V: Eq + Hash + Clone + Debug + DeserializeOwned,
+    W: Clone + Debug + DeserializeOwned,
+{
+    let document: Value = serde_json::from_str(json)
+        .map_err(|e| ParseError::at(e.line(), e.to_string()).with_source(e))?;
+    let directed = document["directed"].as_bool().unwrap_or(false);
+    let mut graph = if directed {
+        SimpleGraph::new_weighted_directed()
+    } else {
+        SimpleGraph::new_weighted_undirected()
+    };
+
+    let array = |key: &str| {
+        document[key]
+            .as_array()
+            .ok_or_else(|| ParseError::input(format!("Missing \"{key}\" array")))
+    };
+    let vertex = |value: &Value| V::deserialize(value).map_err(value_error);
+
+    for node in array("nodes")? {
+        match graph.add_vertex(vertex(&node["id"])?) {
+            Ok(()) | Err(MutationError::VertexAlreadyExists) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    for link in array("links")? {
+        let u = vertex(&link["source"])?;
+        let v = vertex(&link["target"])?;
+        for endpoint in [&u, &v] {
+            if !graph.contains_vertex(endpoint) {
+                graph.add_vertex(endpoint.clone())?;
+            }
+        }
+        match link.get("weight") {
+            Some(weight) => {
+                let weight = W::deserialize(weight).map_err(value_error)?;
+                if graph.contains_edge(&u, &v) {
+                    graph.update_edge_weight(&u, &v, weight)?;
+                } else {
+                    graph.add_weighted_edge(&u, &v, weight)?;
+                }
+            }
+            None => match graph.add_edge(&u, &v) {
+                Ok(()) | Err(MutationError::EdgeAlreadyExists) => {}
+                Err(e) => return Err(e.into()),
+            },
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{utils::canonical::canonical_bytes, Graph};
+
+    fn sample(directed: bool) -> SimpleGraph<String, f64> {
+        let mut graph = SimpleGraph::from_weighted_edges(
+            [
+                ("a".to_string(), "b".to_string(), 1.5),
+                ("b".to_string(), "c".to_string(), -2.0),
+                ("c".to_string(), "c".to_string(), 0.25),
+            ],
+            directed,
+        );
+        graph.add_vertex("lonely".to_string()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn node_link_round_trips() {
+        for directed in [true, false] {
+            let graph = sample(directed);
+            let json = to_json_node_link(&graph).unwrap();
+            let parsed: SimpleGraph<String, f64> = from_json_node_link(&json).unwrap();
+            assert_eq!(canonical_bytes(&parsed), canonical_bytes(&graph));
+        }
+    }
+
+    #[test]
+    fn omits_null_weights() {
+        let graph = SimpleGraph::<u32>::from_edges([(1, 2)], false);
+        let document: Value = serde_json::from_str(&to_json_node_link(&graph).unwrap()).unwrap();
+        assert_eq!(document["links"].as_array().unwrap().len(), 1);
+        assert!(document["links"][0].get("weight").is_none());
+    }
+
+    #[test]
+    fn reads_networkx_output() {
+        let json = r#"{
+            "directed": true, "multigraph": false, "graph": {},
+            "nodes": [{"id": 1, "color": "red"}, {"id": 2}],
+            "links": [{"source": 1, "target": 2, "weight": 3}, {"source": 2, "target": 4}]
+        }"#;
+        let graph: SimpleGraph<u32, u32> = from_json_node_link(json).unwrap();
+        assert!(graph.is_directed());
+        assert_eq!(graph.order(), 3, "missing endpoints are created");
+        assert_eq!(graph.edge_weight(&1, &2), Some(&3));
+        assert!(graph.contains_edge(&2, &4));
+        assert!(!graph.contains_edge(&4, &2));
+    }
+
+    #[test]
+    fn reports_invalid_documents() {
+        let error = from_json_node_link::<u32, ()>("{\n  \"nodes\": [\n").unwrap_err();
+        assert!(matches!(error, GraphError::Parse(e) if e.line == Some(3)));
+
+        let error = from_json_node_link::<u32, ()>(r#"{"nodes": []}"#).unwrap_err();
+        assert!(matches!(error, GraphError::Parse(e) if e.message.contains("\"links\"")));
+
+        let error =
+            from_json_node_link::<u32, ()>(r#"{"nodes": [{"id": "x"}], "links": []}"#).unwrap_err();
+        let GraphError::Parse(error) = error else {
+            panic!("expected a parse error")
+        };
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
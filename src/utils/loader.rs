@@ -0,0 +1,338 @@
+use std::{io::BufRead, str::FromStr};
+
+use crate::{
+    error::MutationError, traits::weighted::WeightedGraphMut, utils::edge_list::EdgeListReader,
+    GraphError, GraphMut,
+};
+
+/// Counters reported by [`GraphLoader`] after every batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Rows consumed so far, across all calls.
+    pub rows: usize,
+    /// Vertices inserted so far, including endpoints created for edges.
+    pub vertices: usize,
+    /// Edges inserted so far.
+    pub edges: usize,
+    /// Rows skipped because the vertex or edge already existed.
+    pub skipped: usize,
+}
+
+/// Builds a graph from relational rows, such as the results of `sqlx` or
+/// `rusqlite` queries, without depending on a database driver.
+///
+/// Rows are any iterator items; a mapping closure turns each row into a vertex or
+/// an edge, and can convert driver errors into a `GraphError`. Rows are
+/// processed in batches of [`GraphLoader::batch_size`], and the progress callback
+/// runs after every batch.
+///
+/// By default, missing edge endpoints are created and duplicate vertices or edges
+/// are skipped (counted in [`LoadProgress::skipped`]).
+pub struct GraphLoader<G, P = fn(&LoadProgress)> {
+    graph: G,
+    batch_size: usize,
+    create_missing_vertices: bool,
+    skip_duplicates: bool,
+    progress: LoadProgress,
+    on_progress: P,
+}
+
+impl<G> GraphLoader<G>
+where
+    G: GraphMut,
+{
+    /// Creates a loader that inserts into `graph`.
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph,
+            batch_size: 10_000,
+            create_missing_vertices: true,
+            skip_duplicates: true,
+            progress: LoadProgress::default(),
+            on_progress: |_| {},
+        }
+    }
+}
+
+impl<G, P> GraphLoader<G, P>
+where
+    G: GraphMut,
+    P: FnMut(&LoadProgress),
+{
+    /// Sets how many rows are mapped before they are inserted and progress is
+    /// reported. A size of `0` is treated as `1`.
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Sets whether edges may create their missing endpoints. When disabled, such
+    /// edges fail with `GraphError::VertexNotFound`.
+    #[must_use]
+    pub fn create_missing_vertices(mut self, create: bool) -> Self {
+        self.create_missing_vertices = create;
+        self
+    }
+
+    /// Sets whether duplicate vertices and edges are skipped. When disabled, they
+    /// fail with `GraphError::VertexAlreadyExists` or `GraphError::EdgeAlreadyExists`.
+    #[must_use]
+    pub fn skip_duplicates(mut self, skip: bool) -> Self {
+        self.skip_duplicates = skip;
+        self
+    }
+
+    /// Sets the callback invoked after every batch with the running totals.
+    pub fn on_progress<Q>(self, on_progress: Q) -> GraphLoader<G, Q>
+    where
+        Q: FnMut(&LoadProgress),
+    {
+        GraphLoader {
+            graph: self.graph,
+            batch_size: self.batch_size,
+            create_missing_vertices: self.create_missing_vertices,
+            skip_duplicates: self.skip_duplicates,
+            progress: self.progress,
+            on_progress,
+        }
+    }
+
+    /// Returns the running totals.
+    pub fn progress(&self) -> LoadProgress {
+        self.progress
+    }
+
+    /// Returns the graph built so far.
+    pub fn into_graph(self) -> G {
+        self.graph
+    }
+
+    /// Maps rows in batches and inserts each batch with `insert`.
+    fn load<I, T, F, S>(&mut self, rows: I, mut map: F, mut insert: S) -> Result<(), GraphError>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Result<T, GraphError>,
+        S: FnMut(&mut Self, T) -> Result<(), GraphError>,
+    {
+        trace_span!("graph_loader", batch_size = self.batch_size);
+        let mut batch = Vec::with_capacity(self.batch_size);
+        let mut rows = rows.into_iter().peekable();
+        while rows.peek().is_some() {
+            batch.clear();
+            for row in rows.by_ref().take(self.batch_size) {
+                batch.push(map(row)?);
+            }
+            self.progress.rows += batch.len();
+            for item in batch.drain(..) {
+                insert(self, item)?;
+            }
+            trace_event!(
+                rows = self.progress.rows,
+                vertices = self.progress.vertices,
+                edges = self.progress.edges,
+                skipped = self.progress.skipped,
+                "batch loaded"
+            );
+            (self.on_progress)(&self.progress);
+        }
+        Ok(())
+    }
+
+    fn insert_vertex(&mut self, v: G::Vertex) -> Result<(), GraphError> {
+        match self.graph.add_vertex(v) {
+            Ok(()) => self.progress.vertices += 1,
+            Err(MutationError::VertexAlreadyExists) if self.skip_duplicates => {
+                self.progress.skipped += 1;
+            }
+            Err(e) => return Err(e.into()),
+        }
+        Ok(())
+    }
+
+    fn ensure_endpoints(&mut self, u: &G::Vertex, v: &G::Vertex) -> Result<(), GraphError> {
+        for endpoint in [u, v] {
+            if !self.graph.contains_vertex(endpoint) {
+                if !self.create_missing_vertices {
+                    return Err(GraphError::VertexNotFound);
+                }
+                self.graph.add_vertex(endpoint.clone())?;
+                self.progress.vertices += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts one vertex per row.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The vertex rows.
+    /// * `map` - Converts a row into a vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error raised by `map`, or
+    /// `GraphError::VertexAlreadyExists` for a duplicate vertex when duplicates are
+    /// not skipped. Batches before the failing one stay inserted.
+    pub fn load_vertices<I, F>(&mut self, rows: I, map: F) -> Result<(), GraphError>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Result<G::Vertex, GraphError>,
+    {
+        self.load(rows, map, Self::insert_vertex)
+    }
+
+    /// Inserts one edge per row.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The edge rows.
+    /// * `map` - Converts a row into the `(source, target)` pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error raised by `map`, `GraphError::VertexNotFound` for a
+    /// missing endpoint when endpoints are not created, or
+    /// `GraphError::EdgeAlreadyExists` for a duplicate edge when duplicates are not
+    /// skipped. Batches before the failing one stay inserted.
+    pub fn load_edges<I, F>(&mut self, rows: I, map: F) -> Result<(), GraphError>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Result<(G::Vertex, G::Vertex), GraphError>,
+    {
+        self.load(rows, map, |loader, (u, v)| {
+            loader.ensure_endpoints(&u, &v)?;
+            match loader.graph.add_edge(&u, &v) {
+                Ok(()) => loader.progress.edges += 1,
+                Err(MutationError::EdgeAlreadyExists) if loader.skip_duplicates => {
+                    loader.progress.skipped += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+            Ok(())
+        })
+    }
+
+    /// Inserts the edges of an edge list as it is read, one batch at a time.
+    ///
+    /// See [`EdgeListReader`] for the format; weight columns are ignored. Only
+    /// the current batch is held in memory, so edge lists larger than the
+    /// available memory can be loaded, and the progress callback reports how
+    /// far the load has come.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the edge list, such as a `BufReader<File>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Io` if a line cannot be read, `GraphError::Parse` if
+    /// a line cannot be parsed, and the errors of [`GraphLoader::load_edges`].
+    /// Batches before the failing one stay inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{utils::loader::GraphLoader, Graph, SimpleGraph};
+    ///
+    /// let input = "1 2\n2 3\n3 1\n1 2\n";
+    /// let mut batches = 0;
+    /// let mut loader = GraphLoader::new(SimpleGraph::<u32>::new_undirected())
+    ///     .batch_size(2)
+    ///     .on_progress(|_| batches += 1);
+    /// loader.load_edge_list(input.as_bytes()).unwrap();
+    /// assert_eq!(loader.progress().skipped, 1);
+    /// assert_eq!(loader.into_graph().size(), 3);
+    /// assert_eq!(batches, 2);
+    /// ```
+    pub fn load_edge_list<R>(&mut self, reader: R) -> Result<(), GraphError>
+    where
+        G::Vertex: FromStr,
+        R: BufRead,
+    {
+        let edges = EdgeListReader::<G::Vertex, String, R>::new(reader);
+        self.load_edges(edges, |edge| edge.map(|(u, v, _)| (u, v)))
+    }
+}
+
+impl<G, P> GraphLoader<G, P>
+where
+    G: WeightedGraphMut,
+    P: FnMut(&LoadProgress),
+{
+    /// Inserts one weighted edge per row.
+    ///
+    /// A duplicate edge has its weight replaced when duplicates are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The edge rows.
+    /// * `map` - Converts a row into the `(source, target, weight)` triple.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error raised by `map`, `GraphError::VertexNotFound` for a
+    /// missing endpoint when endpoints are not created, or
+    /// `GraphError::EdgeAlreadyExists` for a duplicate edge when duplicates are not
+    /// skipped. Batches before the failing one stay inserted.
+    pub fn load_weighted_edges<I, F>(&mut self, rows: I, map: F) -> Result<(), GraphError>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Result<(G::Vertex, G::Vertex, G::Weight), GraphError>,
+    {
+        self.load(rows, map, |loader, (u, v, weight)| {
+            loader.ensure_endpoints(&u, &v)?;
+            if loader.graph.contains_edge(&u, &v) {
+                if !loader.skip_duplicates {
+                    return Err(GraphError::EdgeAlreadyExists);
+                }
+                loader.progress.skipped += 1;
+                loader.graph.update_edge_weight(&u, &v, weight)?;
+            } else {
+                loader.progress.edges += 1;
+                loader.graph.add_weighted_edge(&u, &v, weight)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Inserts the weighted edges of an edge list as it is read, one batch at a
+    /// time.
+    ///
+    /// This is [`GraphLoader::load_edge_list`] keeping the weight column, which
+    /// every line must have.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the edge list, such as a `BufReader<File>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::Io` if a line cannot be read, `GraphError::Parse` if
+    /// a line cannot be parsed or has no weight, and the errors of
+    /// [`GraphLoader::load_weighted_edges`]. Batches before the failing one stay
+    /// inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kambo_graph::{utils::loader::GraphLoader, GraphError, SimpleGraph};
+    ///
+    /// let graph = SimpleGraph::<u32, f64>::new_weighted_directed();
+    /// let mut loader = GraphLoader::new(graph).batch_size(1);
+    /// let error = loader.load_weighted_edge_list("1 2 0.5\n2 3\n".as_bytes()).unwrap_err();
+    /// let GraphError::Parse(error) = error else { panic!() };
+    /// assert_eq!(error.line, Some(2));
+    /// assert_eq!(loader.progress().edges, 1);
+    /// ```
+    pub fn load_weighted_edge_list<R>(&mut self, reader: R) -> Result<(), GraphError>
+    where
+        G::Vertex: FromStr,
+        G::Weight: FromStr,
+        R: BufRead,
+    {
+        let mut edges = EdgeListReader::<G::Vertex, G::Weight, R>::new(reader);
+        self.load_weighted_edges(std::iter::from_fn(|| edges.next_weighted()), |edge| edge)
+    }
+}
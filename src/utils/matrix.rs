@@ -0,0 +1,180 @@
+use crate::{
+    algorithms::adjacency::undirected_adjacency, graphs::vertex_set::VertexIndex,
+    traits::weighted::WeightedGraph, Graph,
+};
+
+/// A dense matrix stored by rows, row and column `i` standing for the vertex
+/// numbered `i` by a [`VertexIndex`].
+pub type DenseMatrix = Vec<Vec<f64>>;
+
+/// Builds the 0/1 adjacency matrix of a graph, numbering its vertices in
+/// iteration order.
+///
+/// Entry `(i, j)` is `1.0` if there is an edge from vertex `i` to vertex `j`,
+/// so the matrix of an undirected graph is symmetric. A self-loop sets its
+/// diagonal entry.
+///
+/// # Returns
+///
+/// The matrix and the numbering of its rows and columns, which maps index `i`
+/// back to its vertex.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{graph, utils::matrix::to_adjacency_matrix};
+///
+/// let (matrix, index) = to_adjacency_matrix(&graph! { 1 -> 2, 2 -> 3 });
+/// let (one, two) = (index.index_of(&1).unwrap(), index.index_of(&2).unwrap());
+/// assert_eq!(matrix[one][two], 1.0);
+/// assert_eq!(matrix[two][one], 0.0);
+/// ```
+#[must_use]
+pub fn to_adjacency_matrix<G>(graph: &G) -> (DenseMatrix, VertexIndex<G::Vertex>)
+where
+    G: Graph,
+{
+    let index = VertexIndex::new(graph);
+    (adjacency_matrix(graph, &index), index)
+}
+
+/// Builds the Laplacian matrix `L = D - A` of a graph, numbering its vertices in
+/// iteration order.
+///
+/// Like the matrices of the [spectral](crate::algorithms::spectral) module, the
+/// Laplacian is built from the underlying simple graph: edge directions are
+/// ignored and self-loops are dropped, so it is symmetric and its rows sum to
+/// zero.
+///
+/// # Returns
+///
+/// The matrix and the numbering of its rows and columns, which maps index `i`
+/// back to its vertex.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{graph, utils::matrix::to_laplacian_matrix};
+///
+/// let (matrix, index) = to_laplacian_matrix(&graph! { 1 - 2, 2 - 3 });
+/// let two = index.index_of(&2).unwrap();
+/// assert_eq!(matrix[two][two], 2.0);
+/// assert!(matrix.iter().all(|row| row.iter().sum::<f64>() == 0.0));
+/// ```
+#[must_use]
+pub fn to_laplacian_matrix<G>(graph: &G) -> (DenseMatrix, VertexIndex<G::Vertex>)
+where
+    G: Graph,
+{
+    let index = VertexIndex::new(graph);
+    (laplacian_matrix(graph, &index), index)
+}
+
+/// Builds the 0/1 adjacency matrix of a graph with rows and columns numbered by
+/// `index`, as described in [`to_adjacency_matrix`].
+///
+/// Sharing one index between several graphs, such as the snapshots of an
+/// evolving network, keeps their matrices aligned. Vertices outside the index
+/// are left out.
+#[must_use]
+pub fn adjacency_matrix<G>(graph: &G, index: &VertexIndex<G::Vertex>) -> DenseMatrix
+where
+    G: Graph,
+{
+    let mut matrix = vec![vec![0.0; index.len()]; index.len()];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        let Some(u) = index.vertex(i) else { continue };
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if let Some(j) = index.index_of(v) {
+                row[j] = 1.0;
+            }
+        }
+    }
+    matrix
+}
+
+/// Builds the weighted adjacency matrix of a graph with rows and columns
+/// numbered by `index`.
+///
+/// Entry `(i, j)` holds the weight of the edge from vertex `i` to vertex `j`,
+/// `1.0` for an edge without weight and `0.0` where there is no edge. Vertices
+/// outside the index are left out.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     graphs::vertex_set::VertexIndex, traits::weighted::WeightedGraphMut,
+///     utils::matrix::weighted_adjacency_matrix, GraphMut, SimpleGraph,
+/// };
+///
+/// let mut graph = SimpleGraph::new_weighted_undirected();
+/// graph.add_vertex("a").unwrap();
+/// graph.add_vertex("b").unwrap();
+/// graph.add_weighted_edge(&"a", &"b", 2.5).unwrap();
+///
+/// let index = VertexIndex::from_vertices(["a", "b"]);
+/// let matrix = weighted_adjacency_matrix(&graph, &index);
+/// assert_eq!(matrix, vec![vec![0.0, 2.5], vec![2.5, 0.0]]);
+/// ```
+#[must_use]
+pub fn weighted_adjacency_matrix<G>(graph: &G, index: &VertexIndex<G::Vertex>) -> DenseMatrix
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Into<f64>,
+{
+    let mut matrix = vec![vec![0.0; index.len()]; index.len()];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        let Some(u) = index.vertex(i) else { continue };
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if let Some(j) = index.index_of(v) {
+                row[j] = graph.edge_weight(u, v).map_or(1.0, |&w| w.into());
+            }
+        }
+    }
+    matrix
+}
+
+/// Builds the Laplacian matrix of a graph with rows and columns numbered by
+/// `index`, as described in [`to_laplacian_matrix`].
+///
+/// Degrees only count the neighbors inside the index, so the rows still sum to
+/// zero.
+#[must_use]
+pub fn laplacian_matrix<G>(graph: &G, index: &VertexIndex<G::Vertex>) -> DenseMatrix
+where
+    G: Graph,
+{
+    let adjacency = undirected_adjacency(graph);
+    let mut matrix = vec![vec![0.0; index.len()]; index.len()];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        let Some(neighbors) = index.vertex(i).and_then(|u| adjacency.get(u)) else {
+            continue;
+        };
+        for j in neighbors.iter().filter_map(|&v| index.index_of(v)) {
+            row[j] = -1.0;
+            row[i] += 1.0;
+        }
+    }
+    matrix
+}
+
+/// Converts a dense matrix into an `ndarray` array.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{graph, utils::matrix::{to_adjacency_matrix, to_array}};
+///
+/// let (matrix, _) = to_adjacency_matrix(&graph! { 1 - 2, 2 - 3 });
+/// let array = to_array(&matrix);
+/// assert_eq!(array.shape(), &[3, 3]);
+/// assert_eq!(array.sum(), 4.0);
+/// ```
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+#[must_use]
+pub fn to_array(matrix: &DenseMatrix) -> ndarray::Array2<f64> {
+    let n = matrix.len();
+    ndarray::Array2::from_shape_fn((n, n), |(i, j)| matrix[i][j])
+}
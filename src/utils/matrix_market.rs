@@ -0,0 +1,343 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::{
+    error::{MutationError, ParseError},
+    traits::weighted::{WeightedGraph, WeightedGraphMut},
+    utils::max_vertex,
+    Graph, GraphError, GraphMut, SimpleGraph,
+};
+
+fn invalid(line_number: usize, line: &str) -> GraphError {
+    ParseError::at(line_number, format!("Invalid Matrix Market line: {line}")).into()
+}
+
+/// Reads a graph from a Matrix Market (`.mtx`) file.
+///
+/// See [`parse_matrix_market_from_reader`] for details.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the file cannot be opened or read, and
+/// `GraphError::Parse` if it cannot be parsed.
+pub fn parse_matrix_market<W>(file_path: &str) -> Result<SimpleGraph<usize, W>, GraphError>
+where
+    W: Clone + std::fmt::Debug + FromStr,
+{
+    let file = File::open(file_path)?;
+    parse_matrix_market_from_reader(io::BufReader::new(file))
+}
+
+/// Reads a graph from a square sparse matrix in the Matrix Market coordinate
+/// format.
+///
+/// Row and column `i` become vertex `i` (`1..=n`), and each entry `(i, j)` becomes
+/// the edge `(i, j)`:
+///
+/// - `symmetric` matrices produce undirected graphs, `general` matrices produce
+///   directed graphs.
+/// - `real` and `integer` values become edge weights, parsed as `W`. `pattern`
+///   entries become edges without a weight.
+/// - Diagonal entries `(i, i)` become self-loops.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the input cannot be read, and `GraphError::Parse`
+/// if the header is missing or describes an unsupported matrix (dense `array`
+/// storage, `complex` values, skew-symmetric or Hermitian symmetry, or a
+/// non-square shape), or an entry is malformed or out of range.
+pub fn parse_matrix_market_from_reader<W, R>(reader: R) -> Result<SimpleGraph<usize, W>, GraphError>
+where
+    W: Clone + std::fmt::Debug + FromStr,
+    R: BufRead,
+{
+    trace_span!("parse_matrix_market");
+    let mut lines = reader.lines().enumerate();
+
+    let header = match lines.next() {
+        Some((_, line)) => line?,
+        None => return Err(ParseError::input("Empty input").into()),
+    };
+    let fields: Vec<String> = header
+        .split_whitespace()
+        .map(str::to_ascii_lowercase)
+        .collect();
+    let [banner, object, format, field, symmetry] = fields.as_slice() else {
+        return Err(invalid(1, &header));
+    };
+    if banner != "%%matrixmarket" || object != "matrix" || format != "coordinate" {
+        return Err(
+            ParseError::at(1, format!("Unsupported Matrix Market header: {header}")).into(),
+        );
+    }
+    let pattern = match field.as_str() {
+        "pattern" => true,
+        "real" | "integer" => false,
+        _ => {
+            return Err(
+                ParseError::at(1, format!("Unsupported Matrix Market field: {field}")).into(),
+            )
+        }
+    };
+    let mut graph = match symmetry.as_str() {
+        "general" => SimpleGraph::new_weighted_directed(),
+        "symmetric" => SimpleGraph::new_weighted_undirected(),
+        _ => {
+            return Err(ParseError::at(
+                1,
+                format!("Unsupported Matrix Market symmetry: {symmetry}"),
+            )
+            .into())
+        }
+    };
+
+    let mut n = None;
+    for (index, line) in lines {
+        let line = line?;
+        let line_number = index + 1;
+        if line.trim().is_empty() || line.starts_with('%') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let mut next_index = || -> Result<usize, GraphError> {
+            parts
+                .next()
+                .and_then(|p| p.parse().ok())
+                .ok_or_else(|| invalid(line_number, &line))
+        };
+
+        let Some(n) = n else {
+            let (rows, cols) = (next_index()?, next_index()?);
+            if rows != cols {
+                return Err(ParseError::at(
+                    line_number,
+                    format!("Matrix must be square, found {rows}x{cols}"),
+                )
+                .into());
+            }
+            for v in 1..=rows {
+                graph.add_vertex(v)?;
+            }
+            n = Some(rows);
+            continue;
+        };
+
+        let (i, j) = (next_index()?, next_index()?);
+        if i == 0 || j == 0 || i > n || j > n {
+            return Err(
+                ParseError::at(line_number, format!("Entry ({i}, {j}) out of range")).into(),
+            );
+        }
+        if pattern {
+            match graph.add_edge(&i, &j) {
+                Ok(()) | Err(MutationError::EdgeAlreadyExists) => {}
+                Err(e) => return Err(e.into()),
+            }
+        } else {
+            let weight = parts
+                .next()
+                .and_then(|p| p.parse::<W>().ok())
+                .ok_or_else(|| invalid(line_number, &line))?;
+            if graph.contains_edge(&i, &j) {
+                graph.update_edge_weight(&i, &j, weight)?;
+            } else {
+                graph.add_weighted_edge(&i, &j, weight)?;
+            }
+        }
+    }
+
+    if n.is_none() {
+        return Err(ParseError::input("Missing Matrix Market size line").into());
+    }
+    Ok(graph)
+}
+
+fn matrix_entries<G>(graph: &G) -> Vec<(usize, usize)>
+where
+    G: Graph<Vertex = usize>,
+{
+    let mut entries = Vec::new();
+    for &u in graph.vertices() {
+        for &v in graph.neighbors(&u).into_iter().flatten() {
+            // Symmetric matrices store only the lower triangle (row >= column).
+            if graph.is_directed() || u >= v {
+                entries.push((u, v));
+            }
+        }
+    }
+    entries.sort_unstable_by_key(|&(u, v)| (v, u));
+    entries
+}
+
+fn write_header<W: Write>(
+    writer: &mut W,
+    field: &str,
+    directed: bool,
+    n: usize,
+    nnz: usize,
+) -> Result<(), GraphError> {
+    let symmetry = if directed { "general" } else { "symmetric" };
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix coordinate {field} {symmetry}"
+    )?;
+    writeln!(writer, "{n} {n} {nnz}")?;
+    Ok(())
+}
+
+/// Writes a weighted graph as a `real` Matrix Market coordinate matrix.
+///
+/// Vertices must be the integers `1..=n`; `n` is taken as the largest vertex.
+/// Undirected graphs are written as `symmetric` matrices (lower triangle only),
+/// directed graphs as `general` matrices.
+///
+/// # Errors
+///
+/// Returns `GraphError::InvalidOperation` if the graph contains vertex `0` or an
+/// edge has no weight, and `GraphError::Io` if the output cannot be written.
+pub fn write_matrix_market<G, W>(graph: &G, mut writer: W) -> Result<(), GraphError>
+where
+    G: WeightedGraph<Vertex = usize>,
+    G::Weight: Display,
+    W: Write,
+{
+    let n = max_vertex(graph)?;
+    let entries = matrix_entries(graph);
+    write_header(&mut writer, "real", graph.is_directed(), n, entries.len())?;
+    for (u, v) in entries {
+        let weight = graph.edge_weight(&u, &v).ok_or_else(|| {
+            GraphError::InvalidOperation(format!("Edge ({u}, {v}) has no weight"))
+        })?;
+        writeln!(writer, "{u} {v} {weight}")?;
+    }
+    Ok(())
+}
+
+/// Writes the structure of a graph as a `pattern` Matrix Market coordinate matrix.
+///
+/// Vertices must be the integers `1..=n`; `n` is taken as the largest vertex.
+/// Undirected graphs are written as `symmetric` matrices (lower triangle only),
+/// directed graphs as `general` matrices.
+///
+/// # Errors
+///
+/// Returns `GraphError::InvalidOperation` if the graph contains vertex `0`, and
+/// `GraphError::Io` if the output cannot be written.
+pub fn write_matrix_market_pattern<G, W>(graph: &G, mut writer: W) -> Result<(), GraphError>
+where
+    G: Graph<Vertex = usize>,
+    W: Write,
+{
+    let n = max_vertex(graph)?;
+    let entries = matrix_entries(graph);
+    write_header(
+        &mut writer,
+        "pattern",
+        graph.is_directed(),
+        n,
+        entries.len(),
+    )?;
+    for (u, v) in entries {
+        writeln!(writer, "{u} {v}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted_edges(graph: &SimpleGraph<usize, f64>) -> Vec<(usize, usize, Option<f64>)> {
+        let mut edges: Vec<_> = matrix_entries(graph)
+            .into_iter()
+            .map(|(u, v)| (u, v, graph.edge_weight(&u, &v).copied()))
+            .collect();
+        edges.sort_by_key(|&(u, v, _)| (u, v));
+        edges
+    }
+
+    fn round_trip(graph: &SimpleGraph<usize, f64>, pattern: bool) -> SimpleGraph<usize, f64> {
+        let mut out = Vec::new();
+        if pattern {
+            write_matrix_market_pattern(graph, &mut out).unwrap();
+        } else {
+            write_matrix_market(graph, &mut out).unwrap();
+        }
+        parse_matrix_market_from_reader(out.as_slice()).unwrap()
+    }
+
+    fn sample(directed: bool) -> SimpleGraph<usize, f64> {
+        SimpleGraph::from_weighted_edges(
+            [(1, 2, 0.5), (3, 1, -2.0), (2, 2, 4.0), (4, 4, 1.0)],
+            directed,
+        )
+    }
+
+    #[test]
+    fn general_matrices_round_trip() {
+        let graph = sample(true);
+        let parsed = round_trip(&graph, false);
+        assert!(parsed.is_directed());
+        assert_eq!(parsed.order(), 4);
+        assert_eq!(weighted_edges(&parsed), weighted_edges(&graph));
+    }
+
+    #[test]
+    fn symmetric_matrices_round_trip() {
+        let graph = sample(false);
+        let parsed = round_trip(&graph, false);
+        assert!(!parsed.is_directed());
+        assert_eq!(parsed.size(), 4);
+        assert_eq!(weighted_edges(&parsed), weighted_edges(&graph));
+    }
+
+    #[test]
+    fn pattern_matrices_round_trip() {
+        for directed in [true, false] {
+            let graph = sample(directed);
+            let parsed = round_trip(&graph, true);
+            assert_eq!(parsed.is_directed(), directed);
+            assert_eq!(matrix_entries(&parsed), matrix_entries(&graph));
+            assert!(parsed.edge_weight(&1, &2).is_none());
+        }
+    }
+
+    #[test]
+    fn diagonal_entries_become_self_loops() {
+        let input = "%%MatrixMarket matrix coordinate real symmetric\n\
+                     % comment\n\
+                     2 2 2\n\
+                     1 1 3.5\n\
+                     2 1 1\n";
+        let graph: SimpleGraph<usize, f64> =
+            parse_matrix_market_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(graph.edge_weight(&1, &1), Some(&3.5));
+        assert_eq!(graph.size(), 2);
+    }
+
+    fn parse_error(input: &str) -> ParseError {
+        match parse_matrix_market_from_reader::<f64, _>(input.as_bytes()) {
+            Err(GraphError::Parse(e)) => e,
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_entries() {
+        for entry in ["0 1 1.0", "1 4 1.0", "4 1 1.0"] {
+            let error = parse_error(&format!(
+                "%%MatrixMarket matrix coordinate real general\n3 3 1\n{entry}\n"
+            ));
+            assert_eq!(error.line, Some(3), "{entry}");
+        }
+    }
+
+    #[test]
+    fn rejects_non_square_matrices() {
+        let error = parse_error("%%MatrixMarket matrix coordinate pattern general\n%\n2 3 0\n");
+        assert_eq!(error.line, Some(3));
+        assert!(error.message.contains("2x3"));
+    }
+}
@@ -1,2 +1,62 @@
+use crate::{Graph, GraphError};
+
 /// Edge list utils functions
 pub mod edge_list;
+
+/// DIMACS coloring (`.col`) and maximum-flow (`max`) formats
+pub mod dimacs;
+
+/// Embedded HTTP server previewing a graph in the browser
+#[cfg(feature = "preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "preview")))]
+pub mod preview;
+
+/// Matrix Market (`.mtx`) coordinate format
+pub mod matrix_market;
+
+/// N-Triples and Turtle import mapping predicates to edge weights
+pub mod rdf;
+
+/// Arrow record batch and Parquet export of vertex and edge tables
+#[cfg(feature = "arrow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub mod columnar;
+
+/// Node-link JSON import and export compatible with D3 and `NetworkX`
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod json;
+
+/// Neo4j import through Cypher queries over plain HTTP and export as Cypher or
+/// admin-import CSV
+#[cfg(feature = "neo4j")]
+#[cfg_attr(docsrs, doc(cfg(feature = "neo4j")))]
+pub mod neo4j;
+
+/// Dense adjacency and Laplacian matrices with their vertex numbering
+pub mod matrix;
+
+/// Readable adjacency-list formatting of graphs
+pub mod display;
+
+/// Deterministic serialization for snapshots and checksums
+pub mod canonical;
+
+/// Ego and snowball samples extracted, anonymized and exported in one call
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod sample_export;
+
+/// Driver-agnostic loader building graphs from relational rows
+pub mod loader;
+
+/// Returns the largest vertex of a graph whose vertices are numbered from 1, as
+/// required by the DIMACS and Matrix Market formats.
+pub(crate) fn max_vertex<G: Graph<Vertex = usize>>(graph: &G) -> Result<usize, GraphError> {
+    if graph.contains_vertex(&0) {
+        return Err(GraphError::InvalidOperation(
+            "Vertices must be numbered from 1".to_string(),
+        ));
+    }
+    Ok(graph.vertices().copied().max().unwrap_or(0))
+}
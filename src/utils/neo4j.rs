@@ -0,0 +1,557 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    error::ParseError,
+    traits::weighted::{WeightedGraph, WeightedGraphMut},
+    Graph, GraphError, GraphMut, SimpleGraph,
+};
+
+fn json_error(e: serde_json::Error) -> GraphError {
+    GraphError::Encode(Box::new(e))
+}
+
+/// Connection settings for the Neo4j HTTP API.
+///
+/// **The connection is plain, unencrypted HTTP.** Queries, results and the basic
+/// authentication credentials travel in clear text, so only connect to a server
+/// on the local machine or through a TLS-terminating proxy.
+#[derive(Debug, Clone)]
+pub struct Neo4jHttpConfig {
+    /// Host and port of the HTTP connector, e.g. `"localhost:7474"`.
+    pub address: String,
+    /// Name of the database to query.
+    pub database: String,
+    /// User name for basic authentication, if required.
+    pub user: Option<String>,
+    /// Password for basic authentication.
+    pub password: String,
+    /// How long connecting, sending the request and each read of the response
+    /// may take. Must be nonzero.
+    pub timeout: Duration,
+}
+
+impl Default for Neo4jHttpConfig {
+    fn default() -> Self {
+        Self {
+            address: "localhost:7474".to_string(),
+            database: "neo4j".to_string(),
+            user: None,
+            password: String::new(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A node returned by a Neo4j query.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Neo4jNode {
+    /// The labels of the node.
+    pub labels: Vec<String>,
+    /// The properties of the node.
+    pub properties: Map<String, Value>,
+}
+
+/// A subgraph imported from Neo4j.
+#[derive(Debug, Clone)]
+pub struct Neo4jSubgraph {
+    /// The directed graph of node ids, with each edge weighted by its relationship
+    /// type. Parallel relationships between two nodes keep the last type.
+    pub graph: SimpleGraph<String, String>,
+    /// Labels and properties of every node, by node id.
+    pub nodes: HashMap<String, Neo4jNode>,
+}
+
+fn base64(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(char::from(ALPHABET[(n >> (18 - 6 * i)) as usize & 63]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Extracts the id of a node or relationship end, accepting both the
+/// `elementId`-style strings of Neo4j 5 and the numeric ids of older versions.
+fn element_id(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Connects to the first address `config.address` resolves to that accepts
+/// within the timeout.
+fn connect(config: &Neo4jHttpConfig) -> io::Result<TcpStream> {
+    let mut last_error = None;
+    for addr in config.address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, config.timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "Address resolved to nothing")
+    }))
+}
+
+fn post(config: &Neo4jHttpConfig, body: &str) -> Result<String, GraphError> {
+    let mut stream = connect(config)?;
+    stream.set_read_timeout(Some(config.timeout))?;
+    stream.set_write_timeout(Some(config.timeout))?;
+    let mut request = format!(
+        "POST /db/{}/tx/commit HTTP/1.0\r\nHost: {}\r\nAccept: application/json\r\nContent-Type: application/json\r\nContent-Length: {}\r\n",
+        config.database,
+        config.address,
+        body.len()
+    );
+    if let Some(user) = &config.user {
+        let credentials = base64(format!("{user}:{}", config.password).as_bytes());
+        let _ = write!(request, "Authorization: Basic {credentials}\r\n");
+    }
+    request.push_str("\r\n");
+    request.push_str(body);
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    response_body(&response).map(String::from)
+}
+
+/// Returns the body of an HTTP response, failing unless its status is `2xx`.
+fn response_body(response: &str) -> Result<&str, GraphError> {
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| ParseError::input("Malformed HTTP response"))?;
+    let status = head.split_whitespace().nth(1).unwrap_or("");
+    if !status.starts_with('2') {
+        return Err(io::Error::other(format!("Neo4j returned HTTP {status}: {body}")).into());
+    }
+    Ok(body)
+}
+
+/// Imports the nodes and relationships returned by a Cypher query.
+///
+/// The query runs through the transactional HTTP API
+/// (`POST /db/<database>/tx/commit`) with the `graph` result format, so every node
+/// and relationship appearing in the returned rows is imported, e.g.
+/// `MATCH p = (:Person)-[:KNOWS]->(:Person) RETURN p LIMIT 1000`.
+///
+/// **Only plain HTTP is supported**, so the query and the credentials are sent
+/// unencrypted; use a local connector or a TLS-terminating proxy for remote
+/// servers. See [`Neo4jHttpConfig`].
+///
+/// # Arguments
+///
+/// * `config` - The connection settings.
+/// * `query` - The Cypher query.
+/// * `parameters` - The query parameters, as a JSON object.
+///
+/// # Errors
+///
+/// Returns `GraphError::Io` if the connection fails or times out, or the
+/// server returns an HTTP or Cypher error, and `GraphError::Parse` if the response cannot be
+/// parsed.
+pub fn import_cypher(
+    config: &Neo4jHttpConfig,
+    query: &str,
+    parameters: &Value,
+) -> Result<Neo4jSubgraph, GraphError> {
+    let request = json!({
+        "statements": [{
+            "statement": query,
+            "parameters": parameters,
+            "resultDataContents": ["graph"],
+        }]
+    });
+    let response: Value = serde_json::from_str(&post(config, &request.to_string())?)
+        .map_err(|e| ParseError::at(e.line(), e.to_string()).with_source(e))?;
+    if let Some(error) = response["errors"].as_array().and_then(|e| e.first()) {
+        return Err(io::Error::other(format!(
+            "Cypher error: {}",
+            error["message"].as_str().unwrap_or("unknown error")
+        ))
+        .into());
+    }
+    parse_graph_results(&response)
+}
+
+/// Builds a subgraph from a transactional API response in the `graph` format.
+fn parse_graph_results(response: &Value) -> Result<Neo4jSubgraph, GraphError> {
+    let malformed = || ParseError::input("Malformed Neo4j response");
+    let mut graph = SimpleGraph::new_weighted_directed();
+    let mut nodes = HashMap::new();
+
+    let rows = response["results"]
+        .as_array()
+        .and_then(|r| r.first())
+        .and_then(|r| r["data"].as_array())
+        .ok_or_else(malformed)?;
+    for row in rows {
+        let row_graph = &row["graph"];
+        for node in row_graph["nodes"].as_array().ok_or_else(malformed)? {
+            let id = element_id(&node["id"]).ok_or_else(malformed)?;
+            if graph.contains_vertex(&id) {
+                continue;
+            }
+            graph.add_vertex(id.clone())?;
+            let labels = node["labels"]
+                .as_array()
+                .map(|l| {
+                    l.iter()
+                        .filter_map(|s| s.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let properties = node["properties"].as_object().cloned().unwrap_or_default();
+            nodes.insert(id, Neo4jNode { labels, properties });
+        }
+        for relationship in row_graph["relationships"]
+            .as_array()
+            .ok_or_else(malformed)?
+        {
+            let start = element_id(&relationship["startNode"]).ok_or_else(malformed)?;
+            let end = element_id(&relationship["endNode"]).ok_or_else(malformed)?;
+            let kind = relationship["type"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            for id in [&start, &end] {
+                if !graph.contains_vertex(id) {
+                    graph.add_vertex(id.clone())?;
+                    nodes.insert(id.clone(), Neo4jNode::default());
+                }
+            }
+            if graph.contains_edge(&start, &end) {
+                graph.update_edge_weight(&start, &end, kind)?;
+            } else {
+                graph.add_weighted_edge(&start, &end, kind)?;
+            }
+        }
+    }
+
+    Ok(Neo4jSubgraph { graph, nodes })
+}
+
+/// Renders a JSON value as a Cypher literal.
+fn cypher_literal(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let entries: Vec<String> = map
+                .iter()
+                .map(|(k, v)| format!("`{}`: {}", k.replace('`', "``"), cypher_literal(v)))
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(cypher_literal).collect();
+            format!("[{}]", items.join(", "))
+        }
+        // JSON strings, numbers, booleans and null are valid Cypher literals.
+        other => other.to_string(),
+    }
+}
+
+/// Lists the edges, writing each undirected edge once.
+fn edge_list<G: Graph>(graph: &G) -> Vec<(usize, usize, &G::Vertex, &G::Vertex)> {
+    let index: HashMap<&G::Vertex, usize> =
+        graph.vertices().enumerate().map(|(i, v)| (v, i)).collect();
+    let mut edges = Vec::new();
+    for u in graph.vertices() {
+        for v in graph.neighbors(u).into_iter().flatten() {
+            if graph.is_directed() || index[u] <= index[v] {
+                edges.push((index[u], index[v], u, v));
+            }
+        }
+    }
+    edges
+}
+
+fn weight_value<G>(graph: &G, u: &G::Vertex, v: &G::Vertex) -> Result<Option<Value>, GraphError>
+where
+    G: WeightedGraph,
+    G::Weight: Serialize,
+{
+    match graph.edge_weight(u, v) {
+        Some(w) => {
+            let value = serde_json::to_value(w).map_err(json_error)?;
+            Ok((!value.is_null()).then_some(value))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Writes a Cypher script that recreates the graph with `CREATE` statements.
+///
+/// Every vertex becomes a node with label `label` and an `id` property holding the
+/// serialized vertex; every edge becomes a relationship of type `relationship`
+/// with a `weight` property when it has a non-null weight. Undirected edges are
+/// written once, in an arbitrary direction.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if a vertex or weight cannot be serialized,
+/// and `GraphError::Io` if the output cannot be written.
+pub fn write_cypher_create<G, W>(
+    graph: &G,
+    label: &str,
+    relationship: &str,
+    mut writer: W,
+) -> Result<(), GraphError>
+where
+    G: WeightedGraph,
+    G::Vertex: Serialize,
+    G::Weight: Serialize,
+    W: Write,
+{
+    let label = label.replace('`', "``");
+    let relationship = relationship.replace('`', "``");
+    for (i, v) in graph.vertices().enumerate() {
+        let id = serde_json::to_value(v).map_err(json_error)?;
+        writeln!(
+            writer,
+            "CREATE (n{i}:`{label}` {{id: {}}})",
+            cypher_literal(&id)
+        )?;
+    }
+    for (i, j, u, v) in edge_list(graph) {
+        let properties = match weight_value(graph, u, v)? {
+            Some(w) => format!(" {{weight: {}}}", cypher_literal(&w)),
+            None => String::new(),
+        };
+        writeln!(
+            writer,
+            "CREATE (n{i})-[:`{relationship}`{properties}]->(n{j})"
+        )?;
+    }
+    writeln!(writer, ";")?;
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => csv_field(s),
+        other => csv_field(&other.to_string()),
+    }
+}
+
+/// Writes the graph as the node and relationship CSV files expected by
+/// `neo4j-admin database import`.
+///
+/// The node file has the header `id:ID,:LABEL` and the relationship file
+/// `:START_ID,:END_ID,:TYPE,weight`, where ids are the serialized vertices and the
+/// weight column is empty for edges without a weight. Undirected edges are
+/// written once, in an arbitrary direction.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if a vertex or weight cannot be serialized,
+/// and `GraphError::Io` if the output cannot be written.
+pub fn write_neo4j_admin_csv<G, N, R>(
+    graph: &G,
+    label: &str,
+    relationship: &str,
+    mut nodes: N,
+    mut relationships: R,
+) -> Result<(), GraphError>
+where
+    G: WeightedGraph,
+    G::Vertex: Serialize,
+    G::Weight: Serialize,
+    N: Write,
+    R: Write,
+{
+    let id = |v: &G::Vertex| {
+        serde_json::to_value(v)
+            .map(|v| csv_value(&v))
+            .map_err(json_error)
+    };
+
+    writeln!(nodes, "id:ID,:LABEL")?;
+    for v in graph.vertices() {
+        writeln!(nodes, "{},{}", id(v)?, csv_field(label))?;
+    }
+
+    writeln!(relationships, ":START_ID,:END_ID,:TYPE,weight")?;
+    for (_, _, u, v) in edge_list(graph) {
+        let weight = weight_value(graph, u, v)?
+            .map(|w| csv_value(&w))
+            .unwrap_or_default();
+        writeln!(
+            relationships,
+            "{},{},{},{weight}",
+            id(u)?,
+            id(v)?,
+            csv_field(relationship)
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_matches_rfc_4648() {
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64(input.as_bytes()), expected, "base64({input:?})");
+        }
+    }
+
+    #[test]
+    fn cypher_literal_escapes_keys_and_strings() {
+        let value = json!({ "we`ird": ["say \"hi\"", 1, null, true] });
+        assert_eq!(
+            cypher_literal(&value),
+            r#"{`we``ird`: ["say \"hi\"", 1, null, true]}"#
+        );
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    fn knows() -> SimpleGraph<String, f64> {
+        let mut graph = SimpleGraph::new_weighted_directed();
+        graph.add_vertex("Ann, Jr.".to_string()).unwrap();
+        graph.add_vertex("Bob".to_string()).unwrap();
+        graph
+            .add_weighted_edge(&"Ann, Jr.".to_string(), &"Bob".to_string(), 0.5)
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn writes_cypher_create_statements() {
+        let mut out = Vec::new();
+        write_cypher_create(&knows(), "Per`son", "KNOWS", &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 4);
+
+        let index = |id: &str| {
+            let node = format!(":`Per``son` {{id: \"{id}\"}})");
+            let i = lines.iter().position(|l| l.ends_with(&node)).unwrap();
+            assert_eq!(lines[i], format!("CREATE (n{i}{node}"));
+            i
+        };
+        let (ann, bob) = (index("Ann, Jr."), index("Bob"));
+        assert_eq!(
+            lines[2],
+            format!("CREATE (n{ann})-[:`KNOWS` {{weight: 0.5}}]->(n{bob})")
+        );
+        assert_eq!(lines[3], ";");
+    }
+
+    #[test]
+    fn writes_neo4j_admin_csv() {
+        let (mut nodes, mut relationships) = (Vec::new(), Vec::new());
+        write_neo4j_admin_csv(&knows(), "Person", "KNOWS", &mut nodes, &mut relationships).unwrap();
+
+        let nodes = String::from_utf8(nodes).unwrap();
+        let mut node_lines: Vec<&str> = nodes.lines().collect();
+        node_lines[1..].sort_unstable();
+        assert_eq!(
+            node_lines,
+            ["id:ID,:LABEL", "\"Ann, Jr.\",Person", "Bob,Person"]
+        );
+        assert_eq!(
+            String::from_utf8(relationships).unwrap(),
+            ":START_ID,:END_ID,:TYPE,weight\n\"Ann, Jr.\",Bob,KNOWS,0.5\n"
+        );
+    }
+
+    #[test]
+    fn response_body_checks_the_status() {
+        let body = response_body("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{}");
+        assert_eq!(body.unwrap(), "{}");
+        assert!(matches!(
+            response_body("HTTP/1.1 401 Unauthorized\r\n\r\nNo"),
+            Err(GraphError::Io(_))
+        ));
+        assert!(matches!(
+            response_body("HTTP/1.1 200 OK"),
+            Err(GraphError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn parses_graph_results() {
+        let response = json!({
+            "results": [{
+                "data": [{
+                    "graph": {
+                        "nodes": [
+                            { "id": "4:a:0", "labels": ["Person"], "properties": { "name": "Ann" } },
+                            { "id": "4:a:1", "labels": ["Person"], "properties": {} }
+                        ],
+                        "relationships": [
+                            { "startNode": "4:a:0", "endNode": "4:a:1", "type": "KNOWS" },
+                            { "startNode": 7, "endNode": "4:a:0", "type": "LIKES" }
+                        ]
+                    }
+                }]
+            }],
+            "errors": []
+        });
+        let subgraph = parse_graph_results(&response).unwrap();
+
+        assert_eq!(subgraph.graph.order(), 3);
+        assert_eq!(
+            subgraph.graph.edge_weight("4:a:0", "4:a:1"),
+            Some(&"KNOWS".to_string())
+        );
+        assert_eq!(
+            subgraph.graph.edge_weight("7", "4:a:0"),
+            Some(&"LIKES".to_string())
+        );
+        assert_eq!(subgraph.nodes["4:a:0"].labels, ["Person"]);
+        assert_eq!(subgraph.nodes["4:a:0"].properties["name"], "Ann");
+        assert_eq!(subgraph.nodes["7"], Neo4jNode::default());
+
+        let error = parse_graph_results(&json!({ "results": [] })).unwrap_err();
+        assert!(matches!(error, GraphError::Parse(_)));
+    }
+}
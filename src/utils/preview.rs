@@ -0,0 +1,237 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{traits::weighted::WeightedGraph, utils::json::to_json_node_link, GraphError};
+
+const PAGE: &str = r##"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>kambo-graph preview</title>
+<style>
+  html, body { margin: 0; height: 100%; font-family: sans-serif; background: #fafafa; }
+  canvas { display: block; width: 100%; height: 100%; }
+  #info { position: absolute; top: 8px; left: 8px; color: #555; font-size: 13px; }
+</style>
+</head>
+<body>
+<div id="info"></div>
+<canvas id="view"></canvas>
+<script>
+const canvas = document.getElementById("view");
+const ctx = canvas.getContext("2d");
+let nodes = [], links = [], directed = false, dragged = null;
+
+function resize() {
+  canvas.width = canvas.clientWidth;
+  canvas.height = canvas.clientHeight;
+}
+window.addEventListener("resize", resize);
+resize();
+
+fetch("/graph.json").then(r => r.json()).then(data => {
+  directed = data.directed;
+  nodes = data.nodes.map(n => ({
+    label: typeof n.id === "string" ? n.id : JSON.stringify(n.id),
+    x: canvas.width / 2 + (Math.random() - 0.5) * 300,
+    y: canvas.height / 2 + (Math.random() - 0.5) * 300,
+    vx: 0, vy: 0,
+  }));
+  const byId = new Map(data.nodes.map((n, i) => [JSON.stringify(n.id), nodes[i]]));
+  links = data.links.map(l => ({
+    source: byId.get(JSON.stringify(l.source)),
+    target: byId.get(JSON.stringify(l.target)),
+  }));
+  document.getElementById("info").textContent =
+    `${nodes.length} vertices, ${links.length} edges` + (directed ? " (directed)" : "");
+  requestAnimationFrame(step);
+});
+
+function step() {
+  const k = 60;
+  for (const a of nodes) {
+    for (const b of nodes) {
+      if (a === b) continue;
+      const dx = a.x - b.x, dy = a.y - b.y;
+      const d2 = Math.max(dx * dx + dy * dy, 1);
+      a.vx += (k * k * dx) / d2 / 50;
+      a.vy += (k * k * dy) / d2 / 50;
+    }
+  }
+  for (const l of links) {
+    const a = l.source, b = l.target;
+    const dx = b.x - a.x, dy = b.y - a.y;
+    const d = Math.max(Math.sqrt(dx * dx + dy * dy), 1);
+    const f = (d - k) / d / 10;
+    a.vx += dx * f; a.vy += dy * f;
+    b.vx -= dx * f; b.vy -= dy * f;
+  }
+  for (const n of nodes) {
+    n.vx += (canvas.width / 2 - n.x) * 0.002;
+    n.vy += (canvas.height / 2 - n.y) * 0.002;
+    if (n !== dragged) { n.x += n.vx; n.y += n.vy; }
+    n.vx *= 0.6; n.vy *= 0.6;
+  }
+  draw();
+  requestAnimationFrame(step);
+}
+
+function draw() {
+  ctx.clearRect(0, 0, canvas.width, canvas.height);
+  ctx.strokeStyle = "#999";
+  for (const l of links) {
+    const a = l.source, b = l.target;
+    ctx.beginPath(); ctx.moveTo(a.x, a.y); ctx.lineTo(b.x, b.y); ctx.stroke();
+    if (directed) {
+      const angle = Math.atan2(b.y - a.y, b.x - a.x);
+      const x = b.x - 9 * Math.cos(angle), y = b.y - 9 * Math.sin(angle);
+      ctx.beginPath();
+      ctx.moveTo(x, y);
+      ctx.lineTo(x - 8 * Math.cos(angle - 0.4), y - 8 * Math.sin(angle - 0.4));
+      ctx.lineTo(x - 8 * Math.cos(angle + 0.4), y - 8 * Math.sin(angle + 0.4));
+      ctx.fillStyle = "#999"; ctx.fill();
+    }
+  }
+  for (const n of nodes) {
+    ctx.beginPath(); ctx.arc(n.x, n.y, 8, 0, 2 * Math.PI);
+    ctx.fillStyle = "#4a78c2"; ctx.fill();
+    ctx.fillStyle = "#222"; ctx.fillText(n.label, n.x + 10, n.y + 4);
+  }
+}
+
+canvas.addEventListener("mousedown", e => {
+  dragged = nodes.find(n => Math.hypot(n.x - e.offsetX, n.y - e.offsetY) < 10) || null;
+});
+canvas.addEventListener("mousemove", e => {
+  if (dragged) { dragged.x = e.offsetX; dragged.y = e.offsetY; }
+});
+window.addEventListener("mouseup", () => { dragged = null; });
+</script>
+</body>
+</html>
+"##;
+
+/// How long a client may take to send its request or read the response before
+/// it is dropped, so an idle connection cannot stall the server.
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+fn respond(mut stream: TcpStream, page: &str, graph_json: &str) -> io::Result<()> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers so that closing the connection does not reset it before
+    // the client has read the response.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status, content_type, body) = match path {
+        "/" | "/index.html" => ("200 OK", "text/html; charset=utf-8", page),
+        "/graph.json" => ("200 OK", "application/json", graph_json),
+        _ => ("404 Not Found", "text/plain", "Not found"),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+fn serve(listener: &TcpListener, graph_json: &str) {
+    // A failed accept or request only affects that client.
+    for stream in listener.incoming().flatten() {
+        let _ = respond(stream, PAGE, graph_json);
+    }
+}
+
+/// Serves an interactive force-directed view of `graph` over HTTP.
+///
+/// The page at `/` draws the graph on a canvas and fetches its data from
+/// `/graph.json`, as written by [`to_json_node_link`]. The graph is snapshotted
+/// when the server starts, so later changes are not reflected.
+///
+/// This is a development aid: it handles one connection at a time, drops clients
+/// that stay idle for two seconds, never returns once the address is bound, and
+/// should only be bound to a local address. Run it on a separate thread to keep
+/// working while it serves.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to display.
+/// * `addr` - The address to listen on, e.g. `"127.0.0.1:8080"`.
+///
+/// # Errors
+///
+/// Returns `GraphError::Encode` if a vertex or weight cannot be serialized and
+/// `GraphError::Io` if the address cannot be bound. Failed connections and
+/// requests are skipped.
+pub fn serve_preview<G, A>(graph: &G, addr: A) -> Result<(), GraphError>
+where
+    G: WeightedGraph,
+    G::Vertex: Serialize,
+    G::Weight: Serialize,
+    A: ToSocketAddrs,
+{
+    let graph_json = to_json_node_link(graph)?;
+    let listener = TcpListener::bind(addr)?;
+    serve(&listener, &graph_json);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Read,
+        net::{SocketAddr, TcpListener, TcpStream},
+        thread,
+    };
+
+    use serde_json::Value;
+
+    use super::*;
+    use crate::graph;
+
+    fn get(addr: SocketAddr, path: &str) -> (String, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.lines().next().unwrap().to_string(), body.to_string())
+    }
+
+    #[test]
+    fn serves_the_page_and_the_graph() {
+        let graph_json = to_json_node_link(&graph! { 1 - 2, 3 }).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(&listener, &graph_json));
+
+        // An idle client is dropped after the timeout instead of blocking others.
+        let _idle = TcpStream::connect(addr).unwrap();
+
+        let (status, page) = get(addr, "/");
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert_eq!(page, PAGE);
+
+        let (status, body) = get(addr, "/graph.json");
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        let data: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(data["directed"], false);
+        assert_eq!(data["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(data["links"].as_array().unwrap().len(), 1);
+
+        let (status, _) = get(addr, "/missing");
+        assert_eq!(status, "HTTP/1.1 404 Not Found");
+    }
+}
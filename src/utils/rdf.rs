@@ -0,0 +1,523 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::Chars;
+
+use crate::{error::ParseError, GraphError, GraphMut, SimpleGraph};
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+/// An RDF triple `(subject, predicate, object)`.
+pub type Triple = (String, String, String);
+
+/// An RDF dataset loaded as a graph.
+///
+/// IRIs are stored without angle brackets, blank nodes keep their `_:` label and
+/// literals are kept in N-Triples form, e.g. `"chat"@fr` or
+/// `"42"^^<http://www.w3.org/2001/XMLSchema#integer>`.
+#[derive(Debug, Clone)]
+pub struct RdfGraph {
+    /// Directed graph over subjects and resource objects, with each edge weighted by
+    /// the set of predicate IRIs linking the pair, so no triple is lost when
+    /// several predicates link the same resources.
+    pub graph: SimpleGraph<String, BTreeSet<String>>,
+    /// Triples whose object is a literal; their subjects are vertices of `graph`.
+    pub literals: Vec<Triple>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Iri(String),
+    Name(String),
+    Literal(String),
+    Punct(char),
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    pending_dot: bool,
+}
+
+impl Lexer<'_> {
+    fn error(&self, message: &str) -> GraphError {
+        ParseError::at(self.line, message).into()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    fn skip_blank(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if c == '#' {
+                while self.bump().is_some_and(|c| c != '\n') {}
+            } else if c.is_whitespace() {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn delimited(&mut self, close: char) -> Result<String, GraphError> {
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                Some(c) if c == close => return Ok(text),
+                Some('\\') => {
+                    text.push('\\');
+                    text.push(
+                        self.bump()
+                            .ok_or_else(|| self.error("Unterminated escape"))?,
+                    );
+                }
+                Some('\n') | None => return Err(self.error("Unterminated token")),
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_whitespace() || ";,<\"#()[]".contains(c) {
+                break;
+            }
+            word.push(c);
+            self.bump();
+        }
+        word
+    }
+
+    /// Returns the next token and the line it starts on.
+    fn next_token(&mut self) -> Result<Option<(Token, usize)>, GraphError> {
+        if self.pending_dot {
+            self.pending_dot = false;
+            return Ok(Some((Token::Punct('.'), self.line)));
+        }
+        self.skip_blank();
+        let line = self.line;
+        let Some(&c) = self.chars.peek() else {
+            return Ok(None);
+        };
+        let token = match c {
+            '<' => {
+                self.bump();
+                Token::Iri(self.delimited('>')?)
+            }
+            '"' => {
+                self.bump();
+                Token::Literal(format!("\"{}\"", self.delimited('"')?))
+            }
+            ';' | ',' => {
+                self.bump();
+                Token::Punct(c)
+            }
+            '(' | ')' | '[' | ']' => {
+                return Err(
+                    self.error("Blank node property lists and collections are not supported")
+                );
+            }
+            _ => {
+                let mut word = self.word();
+                // A trailing dot ends the statement rather than the name.
+                if word.len() > 1 && word.ends_with('.') {
+                    word.pop();
+                    self.pending_dot = true;
+                    return Ok(Some((Token::Name(word), line)));
+                }
+                if word == "." {
+                    Token::Punct('.')
+                } else {
+                    Token::Name(word)
+                }
+            }
+        };
+        Ok(Some((token, line)))
+    }
+}
+
+struct Parser<'a> {
+    tokens: Peekable<std::vec::IntoIter<(Token, usize)>>,
+    prefixes: HashMap<String, String>,
+    base: String,
+    triples: &'a mut Vec<(Triple, bool)>,
+}
+
+impl Parser<'_> {
+    fn error(&mut self, message: &str) -> GraphError {
+        match self.tokens.peek() {
+            Some(&(_, line)) => ParseError::at(line, message).into(),
+            None => ParseError::input(format!("{message} at end of input")).into(),
+        }
+    }
+
+    fn next(&mut self) -> Result<Token, GraphError> {
+        let error = self.error("Unexpected end of input");
+        self.tokens.next().map(|(t, _)| t).ok_or(error)
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), GraphError> {
+        if self.tokens.peek().map(|(t, _)| t) == Some(&Token::Punct(punct)) {
+            self.tokens.next();
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{punct}'")))
+        }
+    }
+
+    fn iri(&self, iri: String) -> String {
+        if iri.contains(':') || self.base.is_empty() {
+            iri
+        } else {
+            format!("{}{iri}", self.base)
+        }
+    }
+
+    fn expand(&mut self, name: &str) -> Result<String, GraphError> {
+        let (prefix, local) = name
+            .split_once(':')
+            .ok_or_else(|| self.error(&format!("Invalid term '{name}'")))?;
+        match self.prefixes.get(prefix) {
+            Some(namespace) => Ok(format!("{namespace}{local}")),
+            None => Err(self.error(&format!("Undeclared prefix '{prefix}:'"))),
+        }
+    }
+
+    /// Parses a subject, predicate or object, returning whether it is a literal.
+    fn term(&mut self) -> Result<(String, bool), GraphError> {
+        match self.next()? {
+            Token::Iri(iri) => Ok((self.iri(iri), false)),
+            Token::Name(name) if name == "a" => Ok((RDF_TYPE.to_string(), false)),
+            Token::Name(name) if name.starts_with("_:") => Ok((name, false)),
+            Token::Name(name) if name.contains(':') => Ok((self.expand(&name)?, false)),
+            // Bare numbers and booleans are Turtle shorthand literals.
+            Token::Name(name) => Ok((name, true)),
+            Token::Literal(mut literal) => {
+                let suffix = match self.tokens.peek() {
+                    Some((Token::Name(suffix), _))
+                        if suffix.starts_with("^^") || suffix.starts_with('@') =>
+                    {
+                        suffix.clone()
+                    }
+                    _ => return Ok((literal, true)),
+                };
+                self.tokens.next();
+                if let Some(datatype) = suffix.strip_prefix("^^") {
+                    let datatype = if datatype.is_empty() {
+                        match self.next()? {
+                            Token::Iri(iri) => self.iri(iri),
+                            _ => return Err(self.error("Expected a datatype IRI")),
+                        }
+                    } else {
+                        self.expand(datatype)?
+                    };
+                    literal = format!("{literal}^^<{datatype}>");
+                } else {
+                    literal.push_str(&suffix);
+                }
+                Ok((literal, true))
+            }
+            Token::Punct(c) => Err(self.error(&format!("Unexpected '{c}'"))),
+        }
+    }
+
+    fn directive(&mut self, keyword: &str) -> Result<(), GraphError> {
+        let sparql = !keyword.starts_with('@');
+        if keyword.eq_ignore_ascii_case("@prefix") || keyword.eq_ignore_ascii_case("prefix") {
+            let prefix = match self.next()? {
+                Token::Name(name) if name.ends_with(':') => name[..name.len() - 1].to_string(),
+                _ => return Err(self.error("Expected a prefix name")),
+            };
+            let Token::Iri(namespace) = self.next()? else {
+                return Err(self.error("Expected a namespace IRI"));
+            };
+            let namespace = self.iri(namespace);
+            self.prefixes.insert(prefix, namespace);
+        } else {
+            let Token::Iri(base) = self.next()? else {
+                return Err(self.error("Expected a base IRI"));
+            };
+            self.base = self.iri(base);
+        }
+        if sparql {
+            Ok(())
+        } else {
+            self.expect('.')
+        }
+    }
+
+    fn statement(&mut self) -> Result<(), GraphError> {
+        if let Some((Token::Name(name), _)) = self.tokens.peek() {
+            let keyword = name.clone();
+            if ["@prefix", "@base", "prefix", "base"]
+                .iter()
+                .any(|k| keyword.eq_ignore_ascii_case(k))
+            {
+                self.tokens.next();
+                return self.directive(&keyword);
+            }
+        }
+
+        let (subject, literal) = self.term()?;
+        if literal {
+            return Err(self.error("A literal cannot be a subject"));
+        }
+        loop {
+            let (predicate, literal) = self.term()?;
+            if literal {
+                return Err(self.error("A literal cannot be a predicate"));
+            }
+            loop {
+                let (object, literal) = self.term()?;
+                self.triples
+                    .push(((subject.clone(), predicate.clone(), object), literal));
+                if self.expect(',').is_err() {
+                    break;
+                }
+            }
+            if self.expect(';').is_err() {
+                break;
+            }
+            // A trailing ';' before the final '.' is allowed.
+            if self.tokens.peek().map(|(t, _)| t) == Some(&Token::Punct('.')) {
+                break;
+            }
+        }
+        self.expect('.')
+    }
+}
+
+/// Reads an N-Triples or Turtle file into a graph.
+///
+/// See [`parse_rdf_from_reader`] for the supported syntax.
+///
+/// # Arguments
+/// * `file_path` - Path to the RDF file.
+///
+/// # Errors
+/// Returns `GraphError::Io` if the file cannot be read, and `GraphError::Parse`
+/// if it is not valid N-Triples or Turtle.
+pub fn parse_rdf(file_path: &str) -> Result<RdfGraph, GraphError> {
+    let file = File::open(Path::new(file_path))?;
+    parse_rdf_from_reader(io::BufReader::new(file))
+}
+
+/// Reads N-Triples or Turtle from any buffered reader into a graph.
+///
+/// Subjects and resource objects become vertices and each pair of them linked by
+/// triples an edge weighted by the set of their predicates; triples with a
+/// literal object are collected in
+/// [`RdfGraph::literals`]. Besides N-Triples, the Turtle subset of `@prefix`,
+/// `@base` (and their SPARQL-style forms), prefixed names, the `a` keyword,
+/// `;` and `,` lists and shorthand numeric and boolean literals is accepted.
+/// Blank node property lists (`[ ... ]`), collections and multi-line string
+/// literals are not supported.
+///
+/// # Arguments
+/// * `reader` - The source of the RDF data.
+///
+/// # Errors
+/// Returns `GraphError::Io` if the input cannot be read, and `GraphError::Parse`
+/// if it is not valid N-Triples or Turtle.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::utils::rdf::parse_rdf_from_reader;
+///
+/// let turtle = r#"
+///     @prefix ex: <http://example.org/> .
+///     ex:alice ex:knows ex:bob ; ex:likes ex:bob ; ex:name "Alice" .
+/// "#;
+/// let rdf = parse_rdf_from_reader(turtle.as_bytes()).unwrap();
+///
+/// let predicates = rdf
+///     .graph
+///     .edge_weight("http://example.org/alice", "http://example.org/bob")
+///     .unwrap();
+/// assert_eq!(predicates.len(), 2);
+/// assert_eq!(rdf.literals[0].2, "\"Alice\"");
+/// ```
+pub fn parse_rdf_from_reader<R: BufRead>(mut reader: R) -> Result<RdfGraph, GraphError> {
+    trace_span!("parse_rdf");
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+
+    let mut lexer = Lexer {
+        chars: text.chars().peekable(),
+        line: 1,
+        pending_dot: false,
+    };
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token()? {
+        tokens.push(token);
+    }
+
+    let mut triples = Vec::new();
+    let mut parser = Parser {
+        tokens: tokens.into_iter().peekable(),
+        prefixes: HashMap::new(),
+        base: String::new(),
+        triples: &mut triples,
+    };
+    while parser.tokens.peek().is_some() {
+        parser.statement()?;
+    }
+
+    trace_event!(triples = triples.len(), "input parsed");
+
+    let mut literals = Vec::new();
+    let mut predicates: BTreeMap<(String, String), BTreeSet<String>> = BTreeMap::new();
+    for ((subject, predicate, object), literal) in triples {
+        if literal {
+            literals.push((subject, predicate, object));
+        } else {
+            predicates
+                .entry((subject, object))
+                .or_default()
+                .insert(predicate);
+        }
+    }
+    let edges = predicates
+        .into_iter()
+        .map(|((subject, object), predicates)| (subject, object, predicates));
+    let mut graph = SimpleGraph::from_weighted_edges(edges, true);
+    for (subject, _, _) in &literals {
+        if !graph.contains_vertex(subject) {
+            graph.add_vertex(subject.clone())?;
+        }
+    }
+
+    Ok(RdfGraph { graph, literals })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+
+    const EX: &str = "http://example.org/";
+
+    fn parse(input: &str) -> RdfGraph {
+        parse_rdf_from_reader(input.as_bytes()).unwrap()
+    }
+
+    fn ex(name: &str) -> String {
+        format!("{EX}{name}")
+    }
+
+    fn predicates(rdf: &RdfGraph, subject: &str, object: &str) -> Vec<String> {
+        rdf.graph
+            .edge_weight(&ex(subject), &ex(object))
+            .map(|p| p.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn parses_n_triples() {
+        let rdf = parse(
+            "<http://example.org/a> <http://example.org/p> <http://example.org/b> .\n\
+             _:x <http://example.org/p> <http://example.org/a> .\n",
+        );
+        assert_eq!(rdf.graph.order(), 3);
+        assert_eq!(predicates(&rdf, "a", "b"), [ex("p")]);
+        assert!(rdf.graph.contains_edge(&"_:x".to_string(), &ex("a")));
+    }
+
+    #[test]
+    fn expands_prefixes_and_the_base() {
+        let rdf = parse(
+            "@prefix ex: <http://example.org/> .\n\
+             PREFIX other: <http://other.org/>\n\
+             @base <http://example.org/> .\n\
+             ex:a other:p <b> .\n",
+        );
+        assert_eq!(
+            rdf.graph.edge_weight(&ex("a"), &ex("b")),
+            Some(&BTreeSet::from(["http://other.org/p".to_string()]))
+        );
+    }
+
+    #[test]
+    fn reads_a_as_rdf_type() {
+        let rdf = parse("@prefix ex: <http://example.org/> .\nex:a a ex:Person .\n");
+        assert_eq!(predicates(&rdf, "a", "Person"), [RDF_TYPE]);
+    }
+
+    #[test]
+    fn expands_predicate_and_object_lists() {
+        let rdf = parse(
+            "@prefix ex: <http://example.org/> .\n\
+             ex:a ex:p ex:b, ex:c ;\n    ex:q ex:b ;\n.\n",
+        );
+        assert_eq!(predicates(&rdf, "a", "b"), [ex("p"), ex("q")]);
+        assert_eq!(predicates(&rdf, "a", "c"), [ex("p")]);
+        assert_eq!(rdf.graph.size(), 2);
+    }
+
+    #[test]
+    fn keeps_every_predicate_between_the_same_resources() {
+        let rdf = parse(
+            "<http://example.org/a> <http://example.org/p> <http://example.org/b> .\n\
+             <http://example.org/a> <http://example.org/q> <http://example.org/b> .\n\
+             <http://example.org/a> <http://example.org/p> <http://example.org/b> .\n",
+        );
+        assert_eq!(rdf.graph.size(), 1);
+        assert_eq!(predicates(&rdf, "a", "b"), [ex("p"), ex("q")]);
+    }
+
+    #[test]
+    fn keeps_typed_and_language_tagged_literals() {
+        let rdf = parse(
+            "@prefix ex: <http://example.org/> .\n\
+             @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\
+             ex:a ex:label \"chat\"@fr ;\n\
+                  ex:age \"42\"^^xsd:integer ;\n\
+                  ex:size \"7\"^^<http://www.w3.org/2001/XMLSchema#int> ;\n\
+                  ex:score 1.5 ;\n\
+                  ex:ok true.\n",
+        );
+        let objects: Vec<&str> = rdf.literals.iter().map(|(_, _, o)| o.as_str()).collect();
+        assert_eq!(
+            objects,
+            [
+                "\"chat\"@fr",
+                "\"42\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+                "\"7\"^^<http://www.w3.org/2001/XMLSchema#int>",
+                "1.5",
+                "true",
+            ]
+        );
+        assert!(rdf.literals.iter().all(|(s, _, _)| *s == ex("a")));
+        assert_eq!(rdf.graph.order(), 1, "literal subjects are vertices");
+    }
+
+    #[test]
+    fn splits_statement_final_dots_from_names() {
+        let rdf = parse("@prefix ex: <http://example.org/> .\nex:a ex:p ex:b.\nex:b ex:p ex:a.");
+        assert_eq!(rdf.graph.size(), 2);
+        assert_eq!(predicates(&rdf, "b", "a"), [ex("p")]);
+    }
+
+    #[test]
+    fn reports_the_line_of_errors() {
+        let error =
+            parse_rdf_from_reader("@prefix ex: <http://example.org/> .\nex:a ex:p\n".as_bytes())
+                .unwrap_err();
+        assert!(matches!(error, GraphError::Parse(e) if e.line.is_none()));
+
+        let error = parse_rdf_from_reader("\nun:a un:p un:b .\n".as_bytes()).unwrap_err();
+        assert!(matches!(error, GraphError::Parse(e) if e.line == Some(2)));
+
+        let error = parse_rdf_from_reader("<a> <p> [ <q> <b> ] .\n".as_bytes()).unwrap_err();
+        assert!(matches!(error, GraphError::Parse(e) if e.line == Some(1)));
+    }
+}
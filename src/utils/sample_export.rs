@@ -0,0 +1,227 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+};
+
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    algorithms::{anonymization::relabel_opaque, sampling::induced_subgraph},
+    traits::weighted::WeightedGraph,
+    utils::{
+        dimacs::write_dimacs_col, edge_list::write_edge_list,
+        matrix_market::write_matrix_market_pattern,
+    },
+    GraphError, SimpleGraph,
+};
+
+/// How the vertices of a sample are chosen around its seeds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extraction {
+    /// Every vertex within `radius` edges of a seed, closest first, up to
+    /// `max_vertices` vertices.
+    Ego {
+        /// The largest distance from a seed.
+        radius: usize,
+        /// The largest number of vertices in the sample.
+        max_vertices: usize,
+    },
+    /// Snowball sampling: in each of `waves` waves, every vertex reached in the
+    /// previous wave recruits up to `fanout` random neighbors not sampled yet,
+    /// up to `max_vertices` vertices.
+    Snowball {
+        /// The number of recruitment waves.
+        waves: usize,
+        /// The largest number of neighbors each vertex recruits.
+        fanout: usize,
+        /// The largest number of vertices in the sample.
+        max_vertices: usize,
+    },
+}
+
+/// The file format written by [`export_sample`].
+///
+/// Every format records the structure of the sample only, with the vertices
+/// numbered from 1; edge weights are not exported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One `u v` line per edge, see [`write_edge_list`]. Isolated vertices are
+    /// lost.
+    EdgeList,
+    /// The DIMACS coloring format, see [`write_dimacs_col`].
+    DimacsCol,
+    /// A `pattern` Matrix Market matrix, see [`write_matrix_market_pattern`].
+    MatrixMarket,
+}
+
+/// Extracts the subgraph induced by the vertices chosen around `seeds`.
+///
+/// Edges are followed from their source to their target, as returned by
+/// [`Graph::neighbors`](crate::Graph::neighbors). The sample keeps the
+/// direction and the weights of the edges between its vertices. When a sample
+/// is truncated by its vertex limit, the seeds come first, in the given order.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sample.
+/// * `seeds` - The vertices the sample grows from.
+/// * `extraction` - How the sample grows.
+/// * `rng` - The source of randomness for snowball sampling.
+///
+/// # Errors
+///
+/// Returns `GraphError::VertexNotFound` if a seed is not in the graph.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     graph,
+///     utils::sample_export::{extract_sample, Extraction},
+///     Graph,
+/// };
+///
+/// let graph = graph! { 1 - 2, 2 - 3, 3 - 4, 4 - 5 };
+/// let extraction = Extraction::Ego { radius: 1, max_vertices: 10 };
+/// let ego = extract_sample(&graph, &[3], extraction, &mut rand::rng()).unwrap();
+/// assert_eq!(ego.order(), 3);
+/// assert!(ego.contains_edge(&2, &3));
+/// ```
+pub fn extract_sample<G, R>(
+    graph: &G,
+    seeds: &[G::Vertex],
+    extraction: Extraction,
+    rng: &mut R,
+) -> Result<SimpleGraph<G::Vertex, G::Weight>, GraphError>
+where
+    G: WeightedGraph,
+    R: Rng + ?Sized,
+{
+    if !seeds.iter().all(|seed| graph.contains_vertex(seed)) {
+        return Err(GraphError::VertexNotFound);
+    }
+    let (waves, fanout, max_vertices) = match extraction {
+        Extraction::Ego {
+            radius,
+            max_vertices,
+        } => (radius, usize::MAX, max_vertices),
+        Extraction::Snowball {
+            waves,
+            fanout,
+            max_vertices,
+        } => (waves, fanout, max_vertices),
+    };
+
+    let mut sampled: HashSet<&G::Vertex> = HashSet::new();
+    let mut frontier = Vec::new();
+    for seed in seeds {
+        if sampled.len() < max_vertices && sampled.insert(seed) {
+            frontier.push(seed);
+        }
+    }
+    'waves: for _ in 0..waves {
+        let mut next = Vec::new();
+        for u in frontier {
+            let candidates = graph
+                .neighbors(u)
+                .into_iter()
+                .flatten()
+                .filter(|v| !sampled.contains(v));
+            let recruits = if fanout == usize::MAX {
+                candidates.collect()
+            } else {
+                candidates.choose_multiple(rng, fanout)
+            };
+            for v in recruits {
+                if sampled.len() == max_vertices {
+                    break 'waves;
+                }
+                if sampled.insert(v) {
+                    next.push(v);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+    Ok(induced_subgraph(graph, &sampled))
+}
+
+/// Extracts a sample around `seeds`, relabels its vertices with opaque
+/// identifiers and writes it in the chosen format, in one call.
+///
+/// The sample is extracted as by [`extract_sample`]. Its vertices are then
+/// numbered `1` to `n` in a random order, as by
+/// [`relabel_opaque`], so the output reveals neither the original labels nor
+/// which vertices were the seeds.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sample.
+/// * `seeds` - The vertices the sample grows from.
+/// * `extraction` - How the sample grows.
+/// * `format` - The format of the output.
+/// * `rng` - The source of randomness for the sampling and the relabeling.
+/// * `writer` - The destination of the output.
+///
+/// # Returns
+///
+/// The key mapping every sampled vertex to its identifier in the output. Keep
+/// it private: it is what re-identifies the vertices.
+///
+/// # Errors
+///
+/// Returns `GraphError::VertexNotFound` if a seed is not in the graph, and
+/// `GraphError::Io` if the output cannot be written.
+///
+/// # Examples
+///
+/// ```
+/// use kambo_graph::{
+///     graph,
+///     utils::sample_export::{export_sample, ExportFormat, Extraction},
+/// };
+///
+/// let collaborations = graph! { 1 - 2, 1 - 3, 2 - 3, 3 - 4, 4 - 5 };
+/// let mut output = Vec::new();
+/// let key = export_sample(
+///     &collaborations,
+///     &[1],
+///     Extraction::Snowball { waves: 2, fanout: 2, max_vertices: 100 },
+///     ExportFormat::DimacsCol,
+///     &mut rand::rng(),
+///     &mut output,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(key.len(), 4);
+/// assert!(String::from_utf8(output).unwrap().starts_with("p edge 4 4"));
+/// ```
+pub fn export_sample<G, R, O>(
+    graph: &G,
+    seeds: &[G::Vertex],
+    extraction: Extraction,
+    format: ExportFormat,
+    rng: &mut R,
+    writer: O,
+) -> Result<HashMap<G::Vertex, usize>, GraphError>
+where
+    G: WeightedGraph,
+    R: Rng + ?Sized,
+    O: Write,
+{
+    let sample = extract_sample(graph, seeds, extraction, rng)?;
+    let (anonymous, mut key) = relabel_opaque(&sample, rng);
+    let anonymous = anonymous.map_vertices(|&id| id + 1)?;
+    for id in key.values_mut() {
+        *id += 1;
+    }
+    match format {
+        ExportFormat::EdgeList => write_edge_list(&anonymous, writer)?,
+        ExportFormat::DimacsCol => write_dimacs_col(&anonymous, writer)?,
+        ExportFormat::MatrixMarket => write_matrix_market_pattern(&anonymous, writer)?,
+    }
+    Ok(key)
+}